@@ -18,7 +18,7 @@ pub async fn bench_games(c: &mut Criterion, local: &LocalSet) {
                     game.add_player(black_box("player_d"));
                     game.add_player(black_box("player_e"));
                     game.add_player(black_box("player_f"));
-                    game.start();
+                    game.start().expect("Starting game has failed");
                 }
                 start.elapsed()
             });
@@ -11,4 +11,26 @@ pub struct AppConfig {
 
     #[envconfig(from = "AUTH_SECRET", default = "super-secret")]
     pub auth_secret: String,
+
+    #[envconfig(from = "MAX_PLAYERS_COUNT", default = "10")]
+    pub max_players_count: usize,
+
+    #[envconfig(from = "CHAT_HISTORY_LENGTH", default = "50")]
+    pub chat_history_length: usize,
+}
+
+#[derive(Envconfig, Clone)]
+pub struct SimulationConfig {
+    #[envconfig(from = "SIMULATION_GAMES_COUNT", default = "1000")]
+    pub games_count: usize,
+
+    #[envconfig(from = "SIMULATION_MIN_PLAYERS_COUNT", default = "5")]
+    pub min_players_count: usize,
+
+    #[envconfig(from = "SIMULATION_MAX_PLAYERS_COUNT", default = "10")]
+    pub max_players_count: usize,
+
+    // JSON file with a list of named rule presets, the built-in presets are used without it
+    #[envconfig(from = "SIMULATION_PRESETS")]
+    pub presets_path: Option<String>,
 }
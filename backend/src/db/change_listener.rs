@@ -46,6 +46,16 @@ impl ChangeListener {
                                 error!("Sending PlayerUpdated has failed: {}", &err);
                             }
                         }
+
+                        // spectators only get the public view of the player
+                        let mut update_msg = proto::message::Server_PlayerUpdated::new();
+                        update_msg.set_player(player.clone().into());
+                        let mut msg = proto::message::Server::new();
+                        msg.set_playerUpdated(update_msg);
+                        if let Err(err) = ctx.ws().broadcast_to_spectators(game.token(), msg).await
+                        {
+                            error!("Sending PlayerUpdated to spectators has failed: {}", &err);
+                        }
                     }
                 }
                 Err(_) => {}
@@ -67,6 +77,14 @@ impl ChangeListener {
                     error!("Sending GameUpdated has failed: {}", &err);
                 }
             }
+
+            let mut update_msg = proto::message::Server_GameUpdated::new();
+            update_msg.set_game(game.clone().into());
+            let mut msg = proto::message::Server::new();
+            msg.set_gameUpdated(update_msg);
+            if let Err(err) = ctx.ws().broadcast_to_spectators(game.token(), msg).await {
+                error!("Sending GameUpdated to spectators has failed: {}", &err);
+            }
         }
     }
 }
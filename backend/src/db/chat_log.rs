@@ -0,0 +1,122 @@
+use super::open_db;
+use crate::model::ChatMessage;
+use log::{info, warn};
+use sled::Db;
+use std::convert::TryFrom;
+
+const KEY_SEPARATOR: u8 = b'/';
+
+/// Keeps the latest chat messages of each game, so players can catch up after reconnecting.
+/// Older messages are dropped as soon as a game exceeds the history length.
+#[derive(Clone)]
+pub struct ChatLog {
+    db: Db,
+    history_length: usize,
+}
+
+impl ChatLog {
+    pub fn init(path: &str, history_length: usize) -> ChatLog {
+        ChatLog::open(path, history_length, false)
+    }
+
+    pub fn init_in_memory(path: &str, history_length: usize) -> ChatLog {
+        ChatLog::open(path, history_length, true)
+    }
+
+    fn open(path: &str, history_length: usize, in_memory: bool) -> ChatLog {
+        let db = open_db(path, in_memory).expect("opening chat log has failed");
+
+        db.clear()
+            .and_then(|_| db.flush())
+            .expect("Cleanup of existing chat log has failed");
+        info!("Chat log \"{}\" ready", path);
+
+        ChatLog { db, history_length }
+    }
+
+    pub fn append(&self, game_token: &str, message: ChatMessage) -> Result<(), sled::Error> {
+        let mut key = game_prefix(game_token);
+        key.extend_from_slice(&self.db.generate_id()?.to_be_bytes());
+        self.db.insert(key, message)?;
+
+        let keys = self
+            .db
+            .scan_prefix(game_prefix(game_token))
+            .keys()
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.len() > self.history_length {
+            let mut batch = sled::Batch::default();
+            for key in &keys[..keys.len() - self.history_length] {
+                batch.remove(key);
+            }
+            self.db.apply_batch(batch)?;
+        }
+        self.db.flush().map(|_| ())
+    }
+
+    /// Returns the latest messages of a game, starting with the oldest one.
+    pub fn read(&self, game_token: &str) -> Result<Vec<ChatMessage>, sled::Error> {
+        let mut messages = vec![];
+        for entry in self.db.scan_prefix(game_prefix(game_token)) {
+            let (_, value) = entry?;
+            match ChatMessage::try_from(value) {
+                Ok(message) => messages.push(message),
+                Err(err) => warn!(
+                    "Reading chat message of game {} has failed: {}",
+                    game_token, err
+                ),
+            }
+        }
+        Ok(messages)
+    }
+
+    pub fn remove(&self, game_token: &str) -> Result<(), sled::Error> {
+        let mut batch = sled::Batch::default();
+        for key in self.db.scan_prefix(game_prefix(game_token)).keys() {
+            batch.remove(key?);
+        }
+        self.db.apply_batch(batch)?;
+        self.db.flush().map(|_| ())
+    }
+}
+
+fn game_prefix(game_token: &str) -> Vec<u8> {
+    let mut prefix = game_token.as_bytes().to_vec();
+    prefix.push(KEY_SEPARATOR);
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ChatLog;
+    use crate::model::ChatMessage;
+
+    #[test]
+    fn should_only_keep_latest_messages() {
+        let log = ChatLog::init("chat", 5);
+        let messages = (0..8)
+            .map(|i| ChatMessage::new("player", &format!("message {}", i)))
+            .collect::<Vec<_>>();
+        for message in &messages {
+            log.append("GAME", message.clone())
+                .expect("Appending message has failed");
+        }
+        log.append("OTHER", messages[0].clone())
+            .expect("Appending message has failed");
+
+        assert_eq!(
+            log.read("GAME").expect("Reading messages has failed"),
+            messages[3..].to_vec()
+        );
+
+        log.remove("GAME").expect("Removing messages has failed");
+        assert!(log
+            .read("GAME")
+            .expect("Reading messages has failed")
+            .is_empty());
+        assert_eq!(
+            log.read("OTHER").expect("Reading messages has failed"),
+            messages[..1].to_vec()
+        );
+    }
+}
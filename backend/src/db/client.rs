@@ -1,4 +1,4 @@
-use super::{Command, CommandData, Persist, QueryError};
+use super::{Command, CommandData, Persist, PersistedFunction, QueryError, UpdateFunction};
 use log::{debug, error};
 use nanoid::nanoid;
 use std::{
@@ -117,12 +117,78 @@ impl<T: Persist> Client<T> {
     }
 
     pub async fn persist_batch(&self, values: &[T]) -> Result<(), QueryError> {
-        self.run_query(|data| Command::PersistBatch {
-            values: values.to_owned(),
-            data,
-        })
-        .await
-        .and_then(Self::map_result)
+        let res = self
+            .run_query(|data| Command::PersistBatch {
+                values: values.to_owned(),
+                data,
+            })
+            .await
+            .and_then(Self::map_result);
+
+        if let Some(sender) = &self.change_sender {
+            for elem in values {
+                if let Err(err) = sender.clone().send(elem.clone()).await {
+                    error!("Propagating change has failed: {:?}", err);
+                }
+            }
+        }
+
+        res
+    }
+
+    /// Applies the update function to the item with the given ID atomically and returns the updated item.
+    pub async fn update(
+        &self,
+        id: &str,
+        update_function: UpdateFunction<T>,
+    ) -> Result<T, QueryError> {
+        self.run_update(id, update_function, None).await
+    }
+
+    /// Like `update`, but also runs the persisted function within the same query once the updated item has been persisted.
+    /// The update is undone if the persisted function fails.
+    pub async fn update_and_then(
+        &self,
+        id: &str,
+        update_function: UpdateFunction<T>,
+        persisted_function: PersistedFunction<T>,
+    ) -> Result<T, QueryError> {
+        self.run_update(id, update_function, Some(persisted_function))
+            .await
+    }
+
+    async fn run_update(
+        &self,
+        id: &str,
+        update_function: UpdateFunction<T>,
+        persisted_function: Option<PersistedFunction<T>>,
+    ) -> Result<T, QueryError> {
+        let key = String::from(id);
+        let res = self
+            .run_query(|data| Command::Update {
+                key,
+                update_function,
+                persisted_function,
+                data,
+            })
+            .await
+            .and_then(Self::map_result)
+            .and_then(|res| match res {
+                Some(Ok(elem)) => Ok(elem),
+                Some(Err(err)) => Err(QueryError::new(&err)),
+                None => Err(QueryError::new(&fmt::format(format_args!(
+                    "No item with key \"{}\" found for update",
+                    id
+                )))),
+            });
+
+        if let (Some(sender), Ok(elem)) = (&self.change_sender, &res) {
+            if let Err(err) = sender.clone().send(elem.clone()).await {
+                error!("Propagating change has failed: {:?}", err);
+            }
+        }
+
+        res
     }
 
     pub async fn remove(&self, key: &str) -> Result<(), QueryError> {
@@ -186,6 +252,110 @@ mod tests {
         assert_eq!(res.unwrap().id(), game_id);
     }
 
+    #[tokio::test]
+    async fn should_get_game_with_deck() {
+        let client = init_client();
+        let game = Game::new("admin", "TOKEN");
+
+        client.persist(&game).await.expect("Game persist failed");
+
+        let res = client
+            .get(game.id())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+
+        assert_eq!(res.deck(), game.deck());
+    }
+
+    #[tokio::test]
+    async fn should_update_game() {
+        let client = init_client();
+        let game = Game::new("admin", "TOKEN");
+        client.persist(&game).await.expect("Game persist failed");
+
+        let res = client
+            .update(
+                game.id(),
+                Box::new(|game| {
+                    game.add_player("player");
+                    Ok(())
+                }),
+            )
+            .await
+            .expect("Updating game has failed");
+        assert!(res.player_ids().contains("player"));
+
+        let res = client
+            .get(game.id())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(res.player_ids().contains("player"));
+    }
+
+    #[tokio::test]
+    async fn should_not_update_game_on_error() {
+        let client = init_client();
+        let game = Game::new("admin", "TOKEN");
+        client.persist(&game).await.expect("Game persist failed");
+
+        let res = client
+            .update(
+                game.id(),
+                Box::new(|game| {
+                    game.add_player("player");
+                    Err(String::from("Invalid update"))
+                }),
+            )
+            .await;
+        assert_eq!(res.unwrap_err().to_string(), "Invalid update");
+
+        let res = client
+            .get(game.id())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(!res.player_ids().contains("player"));
+    }
+
+    #[tokio::test]
+    async fn should_restore_game_if_persisted_function_fails() {
+        let client = init_client();
+        let game = Game::new("admin", "TOKEN");
+        client.persist(&game).await.expect("Game persist failed");
+
+        let res = client
+            .update_and_then(
+                game.id(),
+                Box::new(|game| {
+                    game.add_player("player");
+                    Ok(())
+                }),
+                Box::new(|game| {
+                    assert!(game.player_ids().contains("player"));
+                    Err(String::from("Invalid follow-up"))
+                }),
+            )
+            .await;
+        assert_eq!(res.unwrap_err().to_string(), "Invalid follow-up");
+
+        let res = client
+            .get(game.id())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(!res.player_ids().contains("player"));
+    }
+
+    #[tokio::test]
+    async fn should_not_update_unknown_game() {
+        let client = init_client();
+
+        let res = client.update("unknown", Box::new(|_| Ok(()))).await;
+        assert!(res.is_err());
+    }
+
     #[tokio::test]
     async fn should_get_games() {
         let client = init_client();
@@ -1,6 +1,5 @@
-use super::{Command, Persist, ScanFunction};
+use super::{open_db, Command, Persist, PersistedFunction, ScanFunction, UpdateFunction};
 use log::{debug, error, info, warn};
-use nanoid::nanoid;
 use rayon::prelude::*;
 use sled::Db;
 use std::collections::HashMap;
@@ -17,12 +16,16 @@ pub struct Database<T: Persist> {
 
 impl<T: Persist> Database<T> {
     pub fn init(path: &str) -> (Database<T>, mpsc::Sender<Command<T>>) {
-        let db = sled::open(if cfg!(test) {
-            format!(".sled/{}/{}", nanoid!(), &path)
-        } else {
-            format!(".sled/{}", &path)
-        })
-        .expect("opening database has failed");
+        Database::open(path, false)
+    }
+
+    /// Keeps all items in memory only, which is used for simulations without any server.
+    pub fn init_in_memory(path: &str) -> (Database<T>, mpsc::Sender<Command<T>>) {
+        Database::open(path, true)
+    }
+
+    fn open(path: &str, in_memory: bool) -> (Database<T>, mpsc::Sender<Command<T>>) {
+        let db = open_db(path, in_memory).expect("opening database has failed");
 
         let (sender, receiver): (mpsc::Sender<Command<T>>, mpsc::Receiver<Command<T>>) =
             mpsc::channel(256);
@@ -70,6 +73,17 @@ impl<T: Persist> Database<T> {
                 Command::PersistBatch { values, data } => {
                     self.send_result(self.persist_batch(&values), data.responder);
                 }
+                Command::Update {
+                    key,
+                    update_function,
+                    persisted_function,
+                    data,
+                } => {
+                    self.send_result(
+                        self.update(&key, update_function, persisted_function),
+                        data.responder,
+                    );
+                }
                 Command::Remove { key, data } => {
                     self.send_result(self.remove(&key), data.responder);
                 }
@@ -109,6 +123,31 @@ impl<T: Persist> Database<T> {
         self.flush()
     }
 
+    fn update(
+        &self,
+        key: &str,
+        update_function: UpdateFunction<T>,
+        persisted_function: Option<PersistedFunction<T>>,
+    ) -> Result<Option<Result<T, String>>, sled::Error> {
+        let previous = match self.get(key)? {
+            Some(elem) => elem,
+            None => return Ok(None),
+        };
+        let mut elem = previous.clone();
+        if let Err(err) = update_function(&mut elem) {
+            return Ok(Some(Err(err)));
+        }
+        self.persist(elem.clone())?;
+
+        match persisted_function.map_or(Ok(()), |persisted_function| persisted_function(&elem)) {
+            Ok(_) => Ok(Some(Ok(elem))),
+            Err(err) => {
+                warn!("Restoring item \"{}\" after failed update: {}", key, err);
+                self.persist(previous).map(|_| Some(Err(err)))
+            }
+        }
+    }
+
     fn remove(&self, key: &str) -> Result<(), sled::Error> {
         self.db.remove(key).and_then(|res| match res {
             Some(_) => self.flush(),
@@ -0,0 +1,128 @@
+use super::open_db;
+use crate::model::LoggedEvent;
+use log::{info, warn};
+use sled::Db;
+use std::convert::TryFrom;
+
+const KEY_SEPARATOR: u8 = b'/';
+
+/// Append-only log of the events of each game, which are kept in the order they have been appended.
+/// Accessing the log is synchronous, so events can be appended within queries of the games database.
+/// This keeps the order of the log the same as the order the events have been applied to the game.
+#[derive(Clone)]
+pub struct EventLog {
+    db: Db,
+}
+
+impl EventLog {
+    pub fn init(path: &str) -> EventLog {
+        EventLog::open(path, false)
+    }
+
+    pub fn init_in_memory(path: &str) -> EventLog {
+        EventLog::open(path, true)
+    }
+
+    fn open(path: &str, in_memory: bool) -> EventLog {
+        let db = open_db(path, in_memory).expect("opening event log has failed");
+
+        db.clear()
+            .and_then(|_| db.flush())
+            .expect("Cleanup of existing event log has failed");
+        info!("Event log \"{}\" ready", path);
+
+        EventLog { db }
+    }
+
+    pub fn append(&self, game_token: &str, events: &[LoggedEvent]) -> Result<(), sled::Error> {
+        let mut batch = sled::Batch::default();
+        for event in events {
+            let mut key = game_prefix(game_token);
+            key.extend_from_slice(&self.db.generate_id()?.to_be_bytes());
+            batch.insert(key, event.clone());
+        }
+        self.db.apply_batch(batch)?;
+        self.db.flush().map(|_| ())
+    }
+
+    /// Returns all events of a game in the order they have been appended.
+    pub fn read(&self, game_token: &str) -> Result<Vec<LoggedEvent>, sled::Error> {
+        let mut events = vec![];
+        for entry in self.db.scan_prefix(game_prefix(game_token)) {
+            let (_, value) = entry?;
+            match LoggedEvent::try_from(value) {
+                Ok(event) => events.push(event),
+                Err(err) => warn!("Reading event of game {} has failed: {}", game_token, err),
+            }
+        }
+        Ok(events)
+    }
+
+    pub fn remove(&self, game_token: &str) -> Result<(), sled::Error> {
+        let mut batch = sled::Batch::default();
+        for entry in self.db.scan_prefix(game_prefix(game_token)) {
+            let (key, _) = entry?;
+            batch.remove(key);
+        }
+        self.db.apply_batch(batch)?;
+        self.db.flush().map(|_| ())
+    }
+}
+
+fn game_prefix(game_token: &str) -> Vec<u8> {
+    let mut prefix = game_token.as_bytes().to_vec();
+    prefix.push(KEY_SEPARATOR);
+    prefix
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EventLog;
+    use crate::model::{GameEvent, LoggedEvent};
+
+    #[test]
+    fn should_read_events_in_order() {
+        let log = EventLog::init("events");
+        let events = (0..20)
+            .map(|i| {
+                LoggedEvent::new(GameEvent::PlayerJoined {
+                    player_id: format!("player{}", i),
+                    max_players_count: 20,
+                })
+            })
+            .collect::<Vec<_>>();
+        log.append("GAME", &events[..10])
+            .expect("Appending events has failed");
+        log.append("OTHER", &events[..1])
+            .expect("Appending events has failed");
+        log.append("GAME", &events[10..])
+            .expect("Appending events has failed");
+
+        assert_eq!(log.read("GAME").expect("Reading events has failed"), events);
+        assert_eq!(
+            log.read("OTHER").expect("Reading events has failed"),
+            events[..1].to_vec()
+        );
+    }
+
+    #[test]
+    fn should_remove_events_of_game() {
+        let log = EventLog::init("events");
+        let event = LoggedEvent::new(GameEvent::GameStarted);
+        log.append("GAME", std::slice::from_ref(&event))
+            .expect("Appending events has failed");
+        log.append("OTHER", std::slice::from_ref(&event))
+            .expect("Appending events has failed");
+
+        log.remove("GAME").expect("Removing events has failed");
+
+        assert!(log
+            .read("GAME")
+            .expect("Reading events has failed")
+            .is_empty());
+        assert_eq!(
+            log.read("OTHER").expect("Reading events has failed"),
+            vec![event]
+        );
+    }
+}
@@ -1,7 +1,10 @@
 mod change_listener;
+mod chat_log;
 mod client;
 mod database;
+mod event_log;
 
+use nanoid::nanoid;
 use sled::IVec;
 use std::{
     clone::Clone,
@@ -12,8 +15,21 @@ use std::{
 use tokio::sync::oneshot;
 
 pub use self::change_listener::ChangeListener;
+pub use self::chat_log::ChatLog;
 pub use self::client::Client;
 pub use self::database::Database;
+pub use self::event_log::EventLog;
+
+/// Opens the database files of the given path, in-memory databases are dropped together with their last handle.
+fn open_db(path: &str, in_memory: bool) -> Result<sled::Db, sled::Error> {
+    if in_memory {
+        sled::Config::new().temporary(true).open()
+    } else if cfg!(test) {
+        sled::open(format!(".sled/{}/{}", nanoid!(), path))
+    } else {
+        sled::open(format!(".sled/{}", path))
+    }
+}
 
 pub trait Persist: Into<IVec> + TryFrom<IVec> + Clone + Debug + Send {
     fn id(&self) -> &str;
@@ -40,7 +56,7 @@ impl QueryError {
 
 impl fmt::Display for QueryError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "invalid first item to double")
+        write!(f, "{}", self.message)
     }
 }
 
@@ -54,6 +70,14 @@ pub struct CommandData<R: Debug> {
 
 pub type ScanFunction<T> = Box<dyn Fn(&T) -> bool + Send + Sync>;
 
+/// Mutates an item inside the database thread, so no other query can interfere between reading and writing the item.
+/// Returning an error leaves the stored item untouched.
+pub type UpdateFunction<T> = Box<dyn FnOnce(&mut T) -> Result<(), String> + Send>;
+
+/// Runs inside the database thread right after the updated item has been persisted.
+/// Returning an error restores the item as it has been before the update.
+pub type PersistedFunction<T> = Box<dyn FnOnce(&T) -> Result<(), String> + Send>;
+
 #[derive(Derivative)]
 #[derivative(Debug)]
 pub enum Command<T: Persist> {
@@ -78,6 +102,14 @@ pub enum Command<T: Persist> {
         values: Vec<T>,
         data: CommandData<Result<(), sled::Error>>,
     },
+    Update {
+        key: String,
+        #[derivative(Debug = "ignore")]
+        update_function: UpdateFunction<T>,
+        #[derivative(Debug = "ignore")]
+        persisted_function: Option<PersistedFunction<T>>,
+        data: CommandData<Result<Option<Result<T, String>>, sled::Error>>,
+    },
     Remove {
         key: String,
         data: CommandData<Result<(), sled::Error>>,
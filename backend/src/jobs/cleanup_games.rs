@@ -1,16 +1,31 @@
-use crate::{db::Client, model::Game, server::app_context::AppContext};
+use crate::{
+    db::{ChatLog, Client, EventLog},
+    model::Game,
+    server::app_context::AppContext,
+};
 use chrono::{Duration, Utc};
 use log::{debug, info, warn};
 
 pub fn cleanup_games(ctx: &'static AppContext) -> impl Fn() {
     move || {
         tokio::task::spawn(async move {
-            execute_cleanup_games(ctx.db().games(), Duration::minutes(5)).await;
+            execute_cleanup_games(
+                ctx.db().games(),
+                ctx.db().events(),
+                ctx.db().chat(),
+                Duration::minutes(5),
+            )
+            .await;
         });
     }
 }
 
-async fn execute_cleanup_games(client: &Client<Game>, duration: Duration) -> bool {
+async fn execute_cleanup_games(
+    client: &Client<Game>,
+    events: &EventLog,
+    chat: &ChatLog,
+    duration: Duration,
+) -> bool {
     let inactive_games = client
         .scan(Box::new(is_inactive_game(duration)))
         .await
@@ -21,6 +36,14 @@ async fn execute_cleanup_games(client: &Client<Game>, duration: Duration) -> boo
         debug!("Removed no inactive games");
         false
     } else {
+        for token in &inactive_games {
+            if let Err(err) = events.remove(token) {
+                warn!("Removing events of game {} has failed: {:?}", token, err);
+            }
+            if let Err(err) = chat.remove(token) {
+                warn!("Removing chat of game {} has failed: {:?}", token, err);
+            }
+        }
         match client.remove_batch(&inactive_games).await {
             Ok(_) => {
                 info!("Removed {} inactive games", inactive_count);
@@ -50,7 +73,7 @@ fn is_inactive_game(duration: Duration) -> impl Fn(&Game) -> bool {
 mod tests {
     use super::execute_cleanup_games;
     use crate::{
-        db::{Client, Database},
+        db::{ChatLog, Client, Database, EventLog},
         model::Game,
     };
     use chrono::Duration;
@@ -79,7 +102,13 @@ mod tests {
             .is_none());
         assert!(client.get("TOKEN").await.unwrap().is_some());
 
-        let res = execute_cleanup_games(&client, Duration::nanoseconds(1)).await;
+        let res = execute_cleanup_games(
+            &client,
+            &EventLog::init("events"),
+            &ChatLog::init("chat", 1),
+            Duration::nanoseconds(1),
+        )
+        .await;
         assert!(res);
 
         assert!(client.get("TOKEN").await.unwrap().is_none());
@@ -98,7 +127,13 @@ mod tests {
             .admin_id()
             .is_some());
 
-        let res = execute_cleanup_games(&client, Duration::nanoseconds(1)).await;
+        let res = execute_cleanup_games(
+            &client,
+            &EventLog::init("events"),
+            &ChatLog::init("chat", 1),
+            Duration::nanoseconds(1),
+        )
+        .await;
         assert!(!res);
 
         assert!(client.get("TOKEN").await.unwrap().is_some());
@@ -118,7 +153,13 @@ mod tests {
             .admin_id()
             .is_none());
 
-        let res = execute_cleanup_games(&client, Duration::minutes(5)).await;
+        let res = execute_cleanup_games(
+            &client,
+            &EventLog::init("events"),
+            &ChatLog::init("chat", 1),
+            Duration::minutes(5),
+        )
+        .await;
         assert!(!res);
 
         assert!(client.get("TOKEN").await.unwrap().is_some());
@@ -1,4 +1,7 @@
-use crate::{model::Player, server::app_context::AppContext};
+use crate::{
+    model::{Game, GameEvent, GameState, Player},
+    server::app_context::AppContext,
+};
 use chrono::{Duration, Utc};
 use log::{debug, info, warn};
 
@@ -19,6 +22,31 @@ fn is_inactive_player(duration: Duration) -> impl Fn(&Player) -> bool {
     }
 }
 
+fn needs_replacement(game: &Game, player: &Player) -> bool {
+    game.state() == &GameState::Started && game.is_alive(player.id())
+}
+
+/// Keeps the seat of a player who has left a running game, a bot answers all remaining tasks from now on.
+async fn replace_by_bot(player: &mut Player, ctx: &AppContext) {
+    let replaced = GameEvent::PlayerReplacedByBot {
+        player_id: String::from(player.id()),
+    };
+    if let Err(err) = ctx
+        .db()
+        .apply_events(player.game_token(), vec![replaced])
+        .await
+    {
+        warn!("Replacing player {} has failed: {:?}", player.id(), err);
+        return;
+    }
+
+    player.replace_by_bot();
+    match ctx.db().players().persist(player).await {
+        Ok(_) => info!("Player {} has been replaced by a bot", player.id()),
+        Err(err) => warn!("Updating replaced player has failed: {:?}", err),
+    }
+}
+
 async fn execute_cleanup_players(ctx: &AppContext, duration: Duration) -> bool {
     let inactive_players = ctx
         .db()
@@ -26,25 +54,41 @@ async fn execute_cleanup_players(ctx: &AppContext, duration: Duration) -> bool {
         .scan(Box::new(is_inactive_player(duration)))
         .await
         .expect("Scanning players has failed");
-    let inactive_count = inactive_players.len();
 
-    // remove players from maybe existing game
-    for id in inactive_players.clone() {
+    // remove players from maybe existing game, players of a running game are replaced by bots instead
+    let mut removed_players = Vec::with_capacity(inactive_players.len());
+    for id in inactive_players {
         let player = ctx.db().players().get(&id).await;
-        if let Some(player) = player.expect("Reading player has failed") {
+        if let Some(mut player) = player.expect("Reading player has failed") {
             let game = ctx.db().games().get(player.game_token()).await;
-            if let Some(mut game) = game.expect("Reading game has failed") {
-                game.remove_player(&id);
-                if ctx.db().games().persist(&game).await.is_err() {
-                    warn!("Removing player has failed");
+            match game.expect("Reading game has failed") {
+                Some(game) if needs_replacement(&game, &player) => {
+                    replace_by_bot(&mut player, ctx).await;
+                    continue;
+                }
+                Some(_) => {
+                    let left = GameEvent::PlayerLeft {
+                        player_id: String::from(&id),
+                    };
+                    if ctx
+                        .db()
+                        .apply_events(player.game_token(), vec![left])
+                        .await
+                        .is_err()
+                    {
+                        warn!("Removing player has failed");
+                    }
                 }
+                None => {}
             }
         }
+        removed_players.push(id);
     }
+    let inactive_count = removed_players.len();
 
     // remove players
     if inactive_count > 0 {
-        match ctx.db().players().remove_batch(&inactive_players).await {
+        match ctx.db().players().remove_batch(&removed_players).await {
             Ok(_) => {
                 info!("Removed {} inactive players", inactive_count);
                 true
@@ -129,4 +173,53 @@ mod tests {
 
         assert!(ctx.db().players().get(player.id()).await.unwrap().is_some());
     }
+
+    #[tokio::test]
+    async fn should_replace_player_of_running_game_by_bot() {
+        let ctx = AppContext::init();
+        let mut admin = Player::new("GAME");
+        admin.set_inactive();
+        let mut game = Game::new(admin.id(), "GAME");
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new("GAME");
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game failed");
+
+        let res = execute_cleanup_players(&ctx, Duration::nanoseconds(1)).await;
+        assert!(!res);
+
+        let replaced = ctx
+            .db()
+            .players()
+            .get(players[0].id())
+            .await
+            .unwrap()
+            .expect("Replaced player should still exist");
+        assert!(replaced.is_bot());
+        assert!(replaced.last_active_time().is_none());
+        let game = ctx
+            .db()
+            .games()
+            .get("GAME")
+            .await
+            .unwrap()
+            .expect("Game should still exist");
+        assert_eq!(game.state(), &GameState::Started);
+        assert!(game.is_bot(players[0].id()));
+        // the longest attending human takes over the admin role
+        assert_eq!(game.admin_id().as_deref(), Some(players[1].id()));
+    }
 }
@@ -0,0 +1,221 @@
+use crate::{
+    model::Player,
+    server::{
+        app_context::AppContext,
+        tasks::strategy::{resolve_with_strategy, TimeoutStrategy},
+    },
+};
+use chrono::{DateTime, Utc};
+use log::{debug, info, warn};
+
+pub fn expire_tasks(ctx: &'static AppContext) -> impl Fn() {
+    move || {
+        tokio::spawn(async move {
+            execute_expire_tasks(ctx, Utc::now()).await;
+        });
+    }
+}
+
+/// Resolves all tasks which have passed their deadline with a default action.
+/// Tasks in front of an expired task are resolved as well, as they need to be answered first.
+async fn execute_expire_tasks(ctx: &AppContext, now: DateTime<Utc>) -> usize {
+    let expired_ids = ctx
+        .db()
+        .players()
+        .scan(Box::new(move |player: &Player| {
+            player.has_expired_task(&now)
+        }))
+        .await
+        .expect("Scanning players has failed");
+
+    let mut resolved_count = 0;
+    for id in expired_ids {
+        while let Some(player) = ctx
+            .db()
+            .players()
+            .get(&id)
+            .await
+            .expect("Reading player has failed")
+            .filter(|player| player.has_expired_task(&now))
+        {
+            if let Err(err) = resolve_with_strategy(&player, &TimeoutStrategy, ctx).await {
+                warn!(
+                    "Resolving expired task of player {} has failed: {}",
+                    &id, &err
+                );
+                break;
+            }
+            resolved_count += 1;
+        }
+    }
+
+    if resolved_count > 0 {
+        info!("Resolved {} expired tasks", resolved_count);
+    } else {
+        debug!("Resolved no expired tasks");
+    }
+    resolved_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::execute_expire_tasks;
+    use crate::{
+        model::{
+            ChatAudience, ClanLeaderKnowledge, Game, GameSettings, Player, Role, TaskDefinition,
+            TaskType,
+        },
+        server::app_context::AppContext,
+    };
+    use chrono::{Duration, Utc};
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_game(ctx: &AppContext) -> (Game, Vec<Player>) {
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.update_settings(GameSettings::new(
+            vec![],
+            ClanLeaderKnowledge::SmallGamesOnly,
+            true,
+            Some(30),
+            ChatAudience::LivingPlayers,
+        ))
+        .expect("Updating settings has failed");
+        game.start().expect("Starting game has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        (game, players)
+    }
+
+    async fn get_player(ctx: &AppContext, id: &str) -> Player {
+        ctx.db()
+            .players()
+            .get(id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_nominate_first_eligible_player_after_deadline() {
+        let ctx = AppContext::init();
+        let (game, mut players) = init_game(&ctx).await;
+        let candidate_id = String::from(game.presidential_candidate_id().unwrap());
+        let expired = Utc::now() - Duration::seconds(1);
+        for player in players.iter_mut() {
+            player.assign_task(TaskDefinition::DiscoverRole {
+                role: Role::Loyal,
+                clan_member_ids: vec![],
+                clan_leader_id: None,
+            });
+            if player.id() == candidate_id {
+                player.assign_task_until(
+                    TaskDefinition::NominateChancellor {
+                        eligible_player_ids: game.eligible_chancellor_ids(),
+                    },
+                    expired,
+                );
+            }
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+
+        assert_eq!(execute_expire_tasks(&ctx, Utc::now()).await, 2);
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(
+            updated_game.nominated_chancellor_id(),
+            Some(game.eligible_chancellor_ids()[0].as_str())
+        );
+        assert!(get_player(&ctx, &candidate_id)
+            .await
+            .open_tasks()
+            .iter()
+            .all(|task| task.get_type() == TaskType::Vote));
+        // tasks without an expired deadline stay open
+        let other_player = players
+            .iter()
+            .find(|player| player.id() != candidate_id)
+            .unwrap();
+        assert_eq!(
+            get_player(&ctx, other_player.id())
+                .await
+                .open_tasks()
+                .front()
+                .map(|task| task.get_type()),
+            Some(TaskType::DiscoverRole)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_abstain_after_deadline() {
+        let ctx = AppContext::init();
+        let (mut game, mut players) = init_game(&ctx).await;
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        let expired = Utc::now() - Duration::seconds(1);
+        for player in players.iter_mut() {
+            player.assign_task_until(
+                TaskDefinition::Vote {
+                    president_id: String::from(game.presidential_candidate_id().unwrap()),
+                    chancellor_id: chancellor_id.clone(),
+                },
+                expired,
+            );
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+
+        assert_eq!(execute_expire_tasks(&ctx, Utc::now()).await, 5);
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        let decision = updated_game.decision(&TaskType::Vote).unwrap();
+        assert_eq!(decision.abstaining_ids().len(), 5);
+        assert_eq!(updated_game.failed_elections_count(), 1);
+        assert_eq!(
+            updated_game.presidential_candidate_id(),
+            Some(game.seats()[1].as_str())
+        );
+
+        // the next task has a deadline again, as the game has a task timeout
+        let candidate = get_player(&ctx, &game.seats()[1]).await;
+        assert!(candidate
+            .task_deadline(&TaskType::NominateChancellor)
+            .filter(|deadline| **deadline > Utc::now())
+            .is_some());
+    }
+}
@@ -1,13 +1,21 @@
 mod cleanup_games;
 mod cleanup_players;
+mod expire_tasks;
+mod play_bots;
 
 use self::cleanup_games::cleanup_games;
 use self::cleanup_players::cleanup_players;
+use self::expire_tasks::expire_tasks;
+use self::play_bots::play_bots;
 use crate::server::app_context::AppContext;
 use clokwerk::{Scheduler, TimeUnits};
 use std::{thread, time::Duration};
 
 const JOB_INTERVAL: u32 = 60;
+// deadlines of tasks need to be checked more often, as the shortest timeout is only a few seconds
+const TASK_DEADLINE_INTERVAL: u32 = 1;
+// bots answer one task per interval, so humans can still follow their moves
+const BOT_INTERVAL: u32 = 1;
 
 pub fn init_jobs(ctx: &'static AppContext) {
     tokio::task::spawn(async move {
@@ -19,6 +27,10 @@ pub fn init_jobs(ctx: &'static AppContext) {
         scheduler
             .every(JOB_INTERVAL.seconds())
             .run(cleanup_players(ctx));
+        scheduler
+            .every(TASK_DEADLINE_INTERVAL.seconds())
+            .run(expire_tasks(ctx));
+        scheduler.every(BOT_INTERVAL.seconds()).run(play_bots(ctx));
 
         // checks every 100ms the needed execution of the jobs (based on recommended value by Clockwerk)
         loop {
@@ -0,0 +1,114 @@
+use crate::{
+    model::Player,
+    server::{
+        app_context::AppContext,
+        tasks::strategy::{resolve_with_strategy, BotStrategy},
+    },
+};
+use log::{debug, warn};
+
+pub fn play_bots(ctx: &'static AppContext) -> impl Fn() {
+    move || {
+        tokio::spawn(async move {
+            execute_play_bots(ctx).await;
+        });
+    }
+}
+
+/// Answers the next open task of each bot, so humans can follow the moves of the bots one after another.
+async fn execute_play_bots(ctx: &AppContext) -> usize {
+    let bot_ids = ctx
+        .db()
+        .players()
+        .scan(Box::new(|player: &Player| {
+            player.is_bot() && !player.open_tasks().is_empty()
+        }))
+        .await
+        .expect("Scanning players has failed");
+
+    let mut resolved_count = 0;
+    for id in bot_ids {
+        // the player is read again, as a previous answer might have changed the tasks of the others
+        let bot = match ctx
+            .db()
+            .players()
+            .get(&id)
+            .await
+            .expect("Reading player has failed")
+            .filter(|player| !player.open_tasks().is_empty())
+        {
+            Some(bot) => bot,
+            None => continue,
+        };
+        match resolve_with_strategy(&bot, &BotStrategy, ctx).await {
+            Ok(_) => resolved_count += 1,
+            Err(err) => warn!("Bot {} couldn't resolve its task: {}", &id, &err),
+        }
+    }
+
+    debug!("Bots resolved {} tasks", resolved_count);
+    resolved_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::execute_play_bots;
+    use crate::{
+        model::{Game, Player, Role, TaskDefinition, TaskType},
+        server::app_context::AppContext,
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    #[tokio::test]
+    async fn should_answer_tasks_of_bots_only() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let bot = Player::new_bot(GAME_TOKEN);
+            game.add_bot(players[0].id(), bot.id())
+                .expect("Adding bot has failed");
+            players.push(bot);
+        }
+        game.start().expect("Starting game has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        for player in players.iter_mut() {
+            player.assign_task(TaskDefinition::DiscoverRole {
+                role: Role::Loyal,
+                clan_member_ids: vec![],
+                clan_leader_id: None,
+            });
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+
+        assert_eq!(execute_play_bots(&ctx).await, 4);
+
+        let updated_players = ctx
+            .db()
+            .players()
+            .get_batch(&game.all_player_ids())
+            .await
+            .expect("Reading players has failed");
+        for player in updated_players.values() {
+            let expected_task = if player.is_bot() {
+                None
+            } else {
+                Some(TaskType::DiscoverRole)
+            };
+            assert_eq!(
+                player.open_tasks().front().map(|task| task.get_type()),
+                expected_task
+            );
+        }
+    }
+}
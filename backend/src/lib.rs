@@ -7,6 +7,9 @@ pub mod db;
 pub mod jobs;
 pub mod model;
 pub mod server;
+mod simulation;
+
+pub use simulation::run_simulation;
 
 extern crate chrono;
 extern crate envconfig;
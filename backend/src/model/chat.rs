@@ -0,0 +1,59 @@
+use super::proto::{self};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sled::IVec;
+use std::convert::TryFrom;
+
+/// A message a player has posted to the chat of a game.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ChatMessage {
+    player_id: String,
+    text: String,
+    time: DateTime<Utc>,
+}
+
+impl ChatMessage {
+    pub fn new(player_id: &str, text: &str) -> Self {
+        ChatMessage {
+            player_id: String::from(player_id),
+            text: String::from(text),
+            time: Utc::now(),
+        }
+    }
+
+    pub fn player_id(&self) -> &str {
+        &self.player_id
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn time(&self) -> &DateTime<Utc> {
+        &self.time
+    }
+}
+
+impl From<ChatMessage> for proto::message::Server_ChatPosted {
+    fn from(message: ChatMessage) -> Self {
+        let mut posted = proto::message::Server_ChatPosted::new();
+        posted.set_player_id(message.player_id);
+        posted.set_text(message.text);
+        posted.set_time(message.time.to_rfc3339());
+        posted
+    }
+}
+
+impl From<ChatMessage> for IVec {
+    fn from(message: ChatMessage) -> Self {
+        IVec::from(bincode::serialize(&message).unwrap())
+    }
+}
+
+impl TryFrom<IVec> for ChatMessage {
+    type Error = bincode::Error;
+    fn try_from(bytes: IVec) -> Result<Self, Self::Error> {
+        bincode::deserialize(&bytes)
+    }
+}
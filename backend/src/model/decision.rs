@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A decision all given voters need to take part in, like the election of a government.
+/// The answers are kept secret until every voter has answered.
+/// Voters who abstain, e.g. by running out of time, don't count towards the majority.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectiveDecision {
+    voter_ids: Vec<String>,
+    answers: HashMap<String, Option<bool>>,
+}
+
+impl CollectiveDecision {
+    pub fn new(voter_ids: Vec<String>) -> Self {
+        CollectiveDecision {
+            voter_ids,
+            answers: HashMap::default(),
+        }
+    }
+
+    pub fn voter_ids(&self) -> &[String] {
+        &self.voter_ids
+    }
+
+    /// Stores the answer of a voter, which can't be changed afterwards.
+    pub fn answer(&mut self, voter_id: &str, approved: bool) -> Result<(), String> {
+        self.insert_answer(voter_id, Some(approved))
+    }
+
+    /// Completes the part of a voter without approving or rejecting.
+    pub fn abstain(&mut self, voter_id: &str) -> Result<(), String> {
+        self.insert_answer(voter_id, None)
+    }
+
+    fn insert_answer(&mut self, voter_id: &str, answer: Option<bool>) -> Result<(), String> {
+        if !self.voter_ids.iter().any(|id| id == voter_id) {
+            return Err(format!("Player {} is not allowed to vote", voter_id));
+        }
+        if self.answers.contains_key(voter_id) {
+            return Err(format!("Player {} has already voted", voter_id));
+        }
+
+        self.answers.insert(String::from(voter_id), answer);
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.voter_ids
+            .iter()
+            .all(|id| self.answers.contains_key(id))
+    }
+
+    pub fn approving_ids(&self) -> Vec<String> {
+        self.voter_ids_with_answer(true)
+    }
+
+    pub fn rejecting_ids(&self) -> Vec<String> {
+        self.voter_ids_with_answer(false)
+    }
+
+    pub fn abstaining_ids(&self) -> Vec<String> {
+        self.voter_ids
+            .iter()
+            .filter(|id| self.answers.get(*id) == Some(&None))
+            .map(String::from)
+            .collect()
+    }
+
+    /// A decision is approved with a simple majority, so ties are rejected.
+    pub fn is_approved(&self) -> bool {
+        self.approving_ids().len() > self.rejecting_ids().len()
+    }
+
+    fn voter_ids_with_answer(&self, approved: bool) -> Vec<String> {
+        self.voter_ids
+            .iter()
+            .filter(|id| self.answers.get(*id) == Some(&Some(approved)))
+            .map(String::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CollectiveDecision;
+
+    fn init_decision() -> CollectiveDecision {
+        CollectiveDecision::new(vec![
+            String::from("a"),
+            String::from("b"),
+            String::from("c"),
+        ])
+    }
+
+    #[test]
+    fn should_complete_after_all_answers() {
+        let mut decision = init_decision();
+
+        assert!(decision.answer("a", true).is_ok());
+        assert!(decision.answer("b", false).is_ok());
+        assert!(!decision.is_complete());
+        assert!(decision.answer("c", true).is_ok());
+
+        assert!(decision.is_complete());
+        assert!(decision.is_approved());
+        assert_eq!(decision.approving_ids(), vec!["a", "c"]);
+        assert_eq!(decision.rejecting_ids(), vec!["b"]);
+    }
+
+    #[test]
+    fn should_reject_invalid_answers() {
+        let mut decision = init_decision();
+
+        assert!(decision.answer("unknown", true).is_err());
+        assert!(decision.answer("a", true).is_ok());
+        assert!(decision.answer("a", false).is_err());
+        assert_eq!(decision.approving_ids(), vec!["a"]);
+    }
+
+    #[test]
+    fn should_ignore_abstentions_for_majority() {
+        let mut decision = init_decision();
+
+        assert!(decision.answer("a", true).is_ok());
+        assert!(decision.abstain("b").is_ok());
+        assert!(decision.abstain("b").is_err());
+        assert!(!decision.is_complete());
+        assert!(decision.abstain("c").is_ok());
+
+        assert!(decision.is_complete());
+        assert!(decision.is_approved());
+        assert_eq!(decision.abstaining_ids(), vec!["b", "c"]);
+        assert!(decision.rejecting_ids().is_empty());
+    }
+
+    #[test]
+    fn should_reject_ties() {
+        let mut decision = CollectiveDecision::new(vec![String::from("a"), String::from("b")]);
+
+        assert!(decision.answer("a", true).is_ok());
+        assert!(decision.answer("b", false).is_ok());
+
+        assert!(decision.is_complete());
+        assert!(!decision.is_approved());
+    }
+}
@@ -0,0 +1,378 @@
+use super::{
+    proto::{self},
+    GameSettings, Policy,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sled::IVec;
+use std::convert::TryFrom;
+
+/// Each state change of a game is recorded as an event, so the game can be rebuilt by applying all events in order.
+/// Events only describe what has happened, the rules are still checked by the game while applying them.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum GameEvent {
+    #[serde(rename_all = "camelCase")]
+    GameCreated {
+        token: String,
+        admin_id: String,
+        seed: u64,
+        deck_seed: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerJoined {
+        player_id: String,
+        /// The limit is part of the event, as the game only knows about it while a player joins.
+        max_players_count: usize,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerLeft {
+        player_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SettingsUpdated {
+        player_id: String,
+        settings: GameSettings,
+    },
+    GameStarted,
+    #[serde(rename_all = "camelCase")]
+    ChancellorNominated {
+        player_id: String,
+        chancellor_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    VoteCast {
+        player_id: String,
+        approved: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    VoteAbstained {
+        player_id: String,
+    },
+    ElectionFinished,
+    #[serde(rename_all = "camelCase")]
+    PolicyDiscarded {
+        player_id: String,
+        policy: Policy,
+    },
+    #[serde(rename_all = "camelCase")]
+    PolicyEnacted {
+        player_id: String,
+        policy: Policy,
+    },
+    #[serde(rename_all = "camelCase")]
+    LoyaltyInvestigated {
+        player_id: String,
+        target_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    SpecialElectionCalled {
+        player_id: String,
+        target_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PoliciesPeeked {
+        player_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerExecuted {
+        player_id: String,
+        target_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerKicked {
+        player_id: String,
+        target_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    AdminHandedOver {
+        player_id: String,
+        target_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PasswordChanged {
+        player_id: String,
+        password_hash: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    LobbyLocked {
+        player_id: String,
+        locked: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    BotAdded {
+        player_id: String,
+        bot_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    PlayerReplacedByBot {
+        player_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    RematchStarted {
+        player_id: String,
+        seed: u64,
+        deck_seed: u64,
+    },
+    #[serde(rename_all = "camelCase")]
+    VetoProposed {
+        player_id: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    VetoAnswered {
+        player_id: String,
+        approved: bool,
+    },
+    #[serde(rename_all = "camelCase")]
+    NameChosen {
+        player_id: String,
+        name: String,
+    },
+}
+
+impl GameEvent {
+    /// Creates the first event of each game, which determines the random seeds for dealing roles and shuffling policies.
+    pub fn game_created(admin_id: &str, token: &str) -> Self {
+        GameEvent::GameCreated {
+            token: String::from(token),
+            admin_id: String::from(admin_id),
+            seed: rand::random(),
+            deck_seed: rand::random(),
+        }
+    }
+
+    /// Starts another game with the same lobby, which needs new random seeds for dealing roles and shuffling policies.
+    pub fn rematch_started(player_id: &str) -> Self {
+        GameEvent::RematchStarted {
+            player_id: String::from(player_id),
+            seed: rand::random(),
+            deck_seed: rand::random(),
+        }
+    }
+}
+
+/// An event together with the time it has been recorded.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedEvent {
+    time: DateTime<Utc>,
+    event: GameEvent,
+}
+
+impl LoggedEvent {
+    pub fn new(event: GameEvent) -> Self {
+        LoggedEvent {
+            time: Utc::now(),
+            event,
+        }
+    }
+
+    pub fn time(&self) -> &DateTime<Utc> {
+        &self.time
+    }
+
+    pub fn event(&self) -> &GameEvent {
+        &self.event
+    }
+
+    /// Returns the events of the last game played with the lobby, which starts with the last rematch if there was any.
+    pub fn latest_game(events: &[LoggedEvent]) -> &[LoggedEvent] {
+        let start = events
+            .iter()
+            .rposition(|logged_event| {
+                matches!(logged_event.event, GameEvent::RematchStarted { .. })
+            })
+            .unwrap_or_default();
+        &events[start..]
+    }
+}
+
+impl From<LoggedEvent> for proto::replay::GameEvent {
+    fn from(logged_event: LoggedEvent) -> Self {
+        let mut event = proto::replay::GameEvent::new();
+        event.set_time(logged_event.time.to_rfc3339());
+        match logged_event.event {
+            GameEvent::GameCreated { admin_id, .. } => {
+                let mut def = proto::replay::GameEvent_GameCreated::new();
+                def.set_admin_id(admin_id);
+                event.set_gameCreated(def);
+            }
+            GameEvent::PlayerJoined { player_id, .. } => {
+                let mut def = proto::replay::GameEvent_PlayerJoined::new();
+                def.set_player_id(player_id);
+                event.set_playerJoined(def);
+            }
+            GameEvent::PlayerLeft { player_id } => {
+                let mut def = proto::replay::GameEvent_PlayerLeft::new();
+                def.set_player_id(player_id);
+                event.set_playerLeft(def);
+            }
+            GameEvent::SettingsUpdated {
+                player_id,
+                settings,
+            } => {
+                let mut def = proto::replay::GameEvent_SettingsUpdated::new();
+                def.set_player_id(player_id);
+                def.set_settings(settings.into());
+                event.set_settingsUpdated(def);
+            }
+            GameEvent::GameStarted => {
+                event.set_gameStarted(proto::replay::GameEvent_GameStarted::new());
+            }
+            GameEvent::ChancellorNominated {
+                player_id,
+                chancellor_id,
+            } => {
+                let mut def = proto::replay::GameEvent_ChancellorNominated::new();
+                def.set_player_id(player_id);
+                def.set_chancellor_id(chancellor_id);
+                event.set_chancellorNominated(def);
+            }
+            GameEvent::VoteCast {
+                player_id,
+                approved,
+            } => {
+                let mut def = proto::replay::GameEvent_VoteCast::new();
+                def.set_player_id(player_id);
+                def.set_approved(approved);
+                event.set_voteCast(def);
+            }
+            GameEvent::VoteAbstained { player_id } => {
+                let mut def = proto::replay::GameEvent_VoteAbstained::new();
+                def.set_player_id(player_id);
+                event.set_voteAbstained(def);
+            }
+            GameEvent::ElectionFinished => {
+                event.set_electionFinished(proto::replay::GameEvent_ElectionFinished::new());
+            }
+            GameEvent::PolicyDiscarded { player_id, policy } => {
+                let mut def = proto::replay::GameEvent_PolicyDiscarded::new();
+                def.set_player_id(player_id);
+                def.set_policy(policy.into());
+                event.set_policyDiscarded(def);
+            }
+            GameEvent::PolicyEnacted { player_id, policy } => {
+                let mut def = proto::replay::GameEvent_PolicyEnacted::new();
+                def.set_player_id(player_id);
+                def.set_policy(policy.into());
+                event.set_policyEnacted(def);
+            }
+            GameEvent::LoyaltyInvestigated {
+                player_id,
+                target_id,
+            } => {
+                let mut def = proto::replay::GameEvent_LoyaltyInvestigated::new();
+                def.set_player_id(player_id);
+                def.set_target_id(target_id);
+                event.set_loyaltyInvestigated(def);
+            }
+            GameEvent::SpecialElectionCalled {
+                player_id,
+                target_id,
+            } => {
+                let mut def = proto::replay::GameEvent_SpecialElectionCalled::new();
+                def.set_player_id(player_id);
+                def.set_target_id(target_id);
+                event.set_specialElectionCalled(def);
+            }
+            GameEvent::PoliciesPeeked { player_id } => {
+                let mut def = proto::replay::GameEvent_PoliciesPeeked::new();
+                def.set_player_id(player_id);
+                event.set_policiesPeeked(def);
+            }
+            GameEvent::PlayerExecuted {
+                player_id,
+                target_id,
+            } => {
+                let mut def = proto::replay::GameEvent_PlayerExecuted::new();
+                def.set_player_id(player_id);
+                def.set_target_id(target_id);
+                event.set_playerExecuted(def);
+            }
+            GameEvent::PlayerKicked {
+                player_id,
+                target_id,
+            } => {
+                let mut def = proto::replay::GameEvent_PlayerKicked::new();
+                def.set_player_id(player_id);
+                def.set_target_id(target_id);
+                event.set_playerKicked(def);
+            }
+            GameEvent::AdminHandedOver {
+                player_id,
+                target_id,
+            } => {
+                let mut def = proto::replay::GameEvent_AdminHandedOver::new();
+                def.set_player_id(player_id);
+                def.set_target_id(target_id);
+                event.set_adminHandedOver(def);
+            }
+            GameEvent::PasswordChanged {
+                player_id,
+                password_hash,
+            } => {
+                // the hash is never exported, only whether the game is protected
+                let mut def = proto::replay::GameEvent_PasswordChanged::new();
+                def.set_player_id(player_id);
+                def.set_password_protected(password_hash.is_some());
+                event.set_passwordChanged(def);
+            }
+            GameEvent::LobbyLocked { player_id, locked } => {
+                let mut def = proto::replay::GameEvent_LobbyLocked::new();
+                def.set_player_id(player_id);
+                def.set_locked(locked);
+                event.set_lobbyLocked(def);
+            }
+            GameEvent::BotAdded { player_id, bot_id } => {
+                let mut def = proto::replay::GameEvent_BotAdded::new();
+                def.set_player_id(player_id);
+                def.set_bot_id(bot_id);
+                event.set_botAdded(def);
+            }
+            GameEvent::PlayerReplacedByBot { player_id } => {
+                let mut def = proto::replay::GameEvent_PlayerReplacedByBot::new();
+                def.set_player_id(player_id);
+                event.set_playerReplacedByBot(def);
+            }
+            GameEvent::RematchStarted { player_id, .. } => {
+                let mut def = proto::replay::GameEvent_RematchStarted::new();
+                def.set_player_id(player_id);
+                event.set_rematchStarted(def);
+            }
+            GameEvent::VetoProposed { player_id } => {
+                let mut def = proto::replay::GameEvent_VetoProposed::new();
+                def.set_player_id(player_id);
+                event.set_vetoProposed(def);
+            }
+            GameEvent::VetoAnswered {
+                player_id,
+                approved,
+            } => {
+                let mut def = proto::replay::GameEvent_VetoAnswered::new();
+                def.set_player_id(player_id);
+                def.set_approved(approved);
+                event.set_vetoAnswered(def);
+            }
+            GameEvent::NameChosen { player_id, name } => {
+                let mut def = proto::replay::GameEvent_NameChosen::new();
+                def.set_player_id(player_id);
+                def.set_name(name);
+                event.set_nameChosen(def);
+            }
+        }
+        event
+    }
+}
+
+impl From<LoggedEvent> for IVec {
+    fn from(event: LoggedEvent) -> Self {
+        IVec::from(bincode::serialize(&event).unwrap())
+    }
+}
+
+impl TryFrom<IVec> for LoggedEvent {
+    type Error = bincode::Error;
+    fn try_from(bytes: IVec) -> Result<Self, Self::Error> {
+        bincode::deserialize(&bytes)
+    }
+}
@@ -1,23 +1,114 @@
+use super::{
+    decision::CollectiveDecision,
+    event::{GameEvent, LoggedEvent},
+    player::{NameRejection, Player},
+    policy::{Policy, PolicyDeck},
+    power::{self, ExecutivePower},
+    role::{self, Party, Role},
+    settings::GameSettings,
+    TaskType,
+};
 use crate::{
     db::Persist,
     model::proto::{self},
 };
 use chrono::{DateTime, Utc};
+use protobuf::RepeatedField;
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_pcg::Pcg64;
 use serde::{Deserialize, Serialize};
 use sled::IVec;
-use std::{collections::HashSet, convert::TryFrom};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::TryFrom,
+    fmt,
+};
+
+const LEGISLATIVE_POLICIES_COUNT: usize = 3;
+const MAX_FAILED_ELECTIONS_COUNT: usize = 3;
+const WINNING_LOYAL_POLICIES_COUNT: usize = 5;
+const WINNING_CLAN_POLICIES_COUNT: usize = 6;
+const CLAN_LEADER_ELECTION_POLICIES_COUNT: usize = 3;
+const MAX_PLAYERS_WITHOUT_PRESIDENT_TERM_LIMIT: usize = 5;
+const MIN_PLAYERS_COUNT: usize = 5;
+const VETO_POLICIES_COUNT: usize = 5;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub enum GameState {
     Initialized,
     Abandoned,
     Started,
+    Finished { winner: Party, reason: WinReason },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub enum WinReason {
+    LoyalPoliciesEnacted,
+    ClanPoliciesEnacted,
+    ClanLeaderExecuted,
+    ClanLeaderElected,
+}
+
+/// The step of the current round, which tells the players who needs to act next.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Phase {
+    Lobby,
+    Nomination,
+    Election,
+    LegislativeSession,
+    ExecutiveAction,
+    Finished,
+}
+
+/// Reasons why the admin can't start a game yet.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum StartRejection {
+    TooFewPlayers,
+    TooManyPlayers,
+    PlayersNotReady,
+}
+
+impl fmt::Display for StartRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StartRejection::TooFewPlayers => {
+                write!(f, "Game needs at least {} players", MIN_PLAYERS_COUNT)
+            }
+            StartRejection::TooManyPlayers => write!(f, "Game has too many players"),
+            StartRejection::PlayersNotReady => write!(f, "Not all players are ready"),
+        }
+    }
+}
+
+/// Reasons why a player can't join a game.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum JoinRejection {
+    AlreadyStarted,
+    LobbyLocked,
+    LobbyFull,
+}
+
+impl fmt::Display for JoinRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JoinRejection::AlreadyStarted => write!(f, "Game has already started"),
+            JoinRejection::LobbyLocked => write!(f, "Game doesn't accept new players"),
+            JoinRejection::LobbyFull => {
+                write!(f, "Game has reached the maximum amount of players")
+            }
+        }
+    }
 }
 
 /// This struct defines a game session. Each valid game needs to have an admin who is responsible for defining game settings.
 /// The admin is also a player but currently not added redundantly to player_ids as well as admin_id.
 ///
 /// Please note that each method here only mutates the struct state but still needs to be persisted to the database separately.
+/// The server never calls these methods directly, but records each change as a `GameEvent` which is applied with `Game::apply`.
+/// This way the whole game can be rebuilt from its event log with `Game::replay`.
 ///
 /// Example:
 /// ```no_run
@@ -30,7 +121,7 @@ pub enum GameState {
 ///     async move {
 ///         let client = Client::new(sender.clone());
 ///         let mut game = Game::new("admin", "GAME");
-///         game.start();
+///         game.start().expect("Starting game has failed");
 ///         let _ = client.persist(&game).await;
 ///     }
 /// });
@@ -43,7 +134,33 @@ pub struct Game {
     last_action_time: DateTime<Utc>,
     admin_id: Option<String>,
     player_ids: HashSet<String>,
+    join_order: Vec<String>,
+    names: HashMap<String, String>,
+    bot_ids: HashSet<String>,
+    password_hash: Option<String>,
+    locked: bool,
     state: GameState,
+    settings: GameSettings,
+    seed: u64,
+    roles: HashMap<String, Role>,
+    deck: PolicyDeck,
+    seats: Vec<String>,
+    president_seat: usize,
+    nominated_chancellor_id: Option<String>,
+    president_id: Option<String>,
+    chancellor_id: Option<String>,
+    decisions: HashMap<TaskType, CollectiveDecision>,
+    legislative_policies: Vec<Policy>,
+    loyal_policies_count: usize,
+    clan_policies_count: usize,
+    last_enacted_policy: Option<Policy>,
+    failed_elections_count: usize,
+    veto_proposed: bool,
+    veto_refused: bool,
+    pending_power: Option<ExecutivePower>,
+    special_candidate_id: Option<String>,
+    investigated_ids: Vec<String>,
+    executed_ids: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
@@ -53,10 +170,16 @@ pub struct GameResponse {
     admin_id: Option<String>,
     player_ids: HashSet<String>,
     state: GameState,
+    password_protected: bool,
+    locked: bool,
 }
 
 impl Game {
     pub fn new(admin_id: &str, token: &str) -> Self {
+        Game::with_seeds(admin_id, token, rand::random(), rand::random())
+    }
+
+    fn with_seeds(admin_id: &str, token: &str, seed: u64, deck_seed: u64) -> Self {
         if token.to_uppercase() != token {
             panic!("Only uppercase characters allowed");
         }
@@ -66,8 +189,155 @@ impl Game {
             last_action_time: Utc::now(),
             admin_id: Some(String::from(admin_id)),
             player_ids: HashSet::with_capacity(10),
+            join_order: vec![String::from(admin_id)],
+            names: HashMap::default(),
+            bot_ids: HashSet::default(),
+            password_hash: None,
+            locked: false,
             state: GameState::Initialized,
+            settings: GameSettings::default(),
+            seed,
+            roles: HashMap::with_capacity(10),
+            deck: PolicyDeck::new(deck_seed),
+            seats: Vec::with_capacity(10),
+            president_seat: 0,
+            nominated_chancellor_id: None,
+            president_id: None,
+            chancellor_id: None,
+            decisions: HashMap::default(),
+            legislative_policies: Vec::with_capacity(LEGISLATIVE_POLICIES_COUNT),
+            loyal_policies_count: 0,
+            clan_policies_count: 0,
+            last_enacted_policy: None,
+            failed_elections_count: 0,
+            veto_proposed: false,
+            veto_refused: false,
+            pending_power: None,
+            special_candidate_id: None,
+            investigated_ids: Vec::default(),
+            executed_ids: Vec::default(),
+        }
+    }
+
+    /// Rebuilds a game by applying all events of its log in order, starting with the creation of the game.
+    pub fn replay(events: &[LoggedEvent]) -> Result<Game, String> {
+        let (created, events) = events
+            .split_first()
+            .ok_or_else(|| String::from("Event log is empty"))?;
+        let mut game = match created.event() {
+            GameEvent::GameCreated {
+                token,
+                admin_id,
+                seed,
+                deck_seed,
+            } => Game::with_seeds(admin_id, token, *seed, *deck_seed),
+            _ => return Err(String::from("Event log doesn't start with a created game")),
+        };
+        game.creation_time = *created.time();
+        game.last_action_time = *created.time();
+
+        for event in events {
+            game.apply(event)?;
+        }
+        Ok(game)
+    }
+
+    /// Applies a single event to the game, which fails if the event violates the rules.
+    pub fn apply(&mut self, logged_event: &LoggedEvent) -> Result<(), String> {
+        match logged_event.event() {
+            GameEvent::GameCreated { .. } => {
+                return Err(format!("Game {} has already been created", self.token));
+            }
+            GameEvent::PlayerJoined {
+                player_id,
+                max_players_count,
+            } => self
+                .join(player_id, *max_players_count)
+                .map_err(|rejection| rejection.to_string())?,
+            GameEvent::PlayerLeft { player_id } => self.remove_player(player_id),
+            GameEvent::SettingsUpdated {
+                player_id,
+                settings,
+            } => {
+                if self.admin_id.as_deref() != Some(player_id.as_str()) {
+                    return Err(format!("Player {} is not the admin", player_id));
+                }
+                self.update_settings(settings.clone())?;
+            }
+            GameEvent::GameStarted => self.start()?,
+            GameEvent::ChancellorNominated {
+                player_id,
+                chancellor_id,
+            } => {
+                if self.presidential_candidate_id() != Some(player_id.as_str()) {
+                    return Err(format!(
+                        "Player {} is not the presidential candidate",
+                        player_id
+                    ));
+                }
+                self.nominate_chancellor(chancellor_id)?;
+            }
+            GameEvent::VoteCast {
+                player_id,
+                approved,
+            } => self.answer_decision(&TaskType::Vote, player_id, *approved)?,
+            GameEvent::VoteAbstained { player_id } => {
+                self.abstain_decision(&TaskType::Vote, player_id)?
+            }
+            GameEvent::ElectionFinished => {
+                if self.finish_election()? && !self.is_finished() {
+                    self.start_legislative_session()?;
+                }
+            }
+            GameEvent::PolicyDiscarded { player_id, policy } => {
+                self.discard_policy(player_id, *policy)?
+            }
+            GameEvent::PolicyEnacted { player_id, policy } => {
+                self.enact_policy(player_id, *policy)?
+            }
+            GameEvent::VetoProposed { player_id } => self.propose_veto(player_id)?,
+            GameEvent::VetoAnswered {
+                player_id,
+                approved,
+            } => self.answer_veto(player_id, *approved)?,
+            GameEvent::NameChosen { player_id, name } => self.choose_name(player_id, name)?,
+            GameEvent::LoyaltyInvestigated {
+                player_id,
+                target_id,
+            } => self.investigate_loyalty(player_id, target_id)?,
+            GameEvent::SpecialElectionCalled {
+                player_id,
+                target_id,
+            } => self.call_special_election(player_id, target_id)?,
+            GameEvent::PoliciesPeeked { player_id } => self.peek_policies(player_id)?,
+            GameEvent::PlayerExecuted {
+                player_id,
+                target_id,
+            } => self.execute_player(player_id, target_id)?,
+            GameEvent::PlayerKicked {
+                player_id,
+                target_id,
+            } => self.kick_player(player_id, target_id)?,
+            GameEvent::AdminHandedOver {
+                player_id,
+                target_id,
+            } => self.hand_over_admin(player_id, target_id)?,
+            GameEvent::PasswordChanged {
+                player_id,
+                password_hash,
+            } => self.change_password(player_id, password_hash.clone())?,
+            GameEvent::LobbyLocked { player_id, locked } => self.lock_lobby(player_id, *locked)?,
+            GameEvent::BotAdded { player_id, bot_id } => self.add_bot(player_id, bot_id)?,
+            GameEvent::PlayerReplacedByBot { player_id } => self.replace_by_bot(player_id)?,
+            GameEvent::RematchStarted {
+                player_id,
+                seed,
+                deck_seed,
+            } => self.start_rematch(player_id, *seed, *deck_seed)?,
         }
+
+        self.last_action_time = *logged_event.time();
+        Ok(())
     }
 
     pub fn token(&self) -> &str {
@@ -93,6 +363,27 @@ impl Game {
         ids
     }
 
+    pub fn players_count(&self) -> usize {
+        self.admin_id.iter().count() + self.player_ids.len()
+    }
+
+    /// Returns whether no more players can join the lobby.
+    pub fn is_full(&self, max_players_count: usize) -> bool {
+        self.players_count() >= max_players_count
+    }
+
+    /// Checks whether the amount of players is within the supported range to start the game.
+    pub fn check_players_count(&self, max_players_count: usize) -> Result<(), StartRejection> {
+        let count = self.players_count();
+        if count < MIN_PLAYERS_COUNT {
+            Err(StartRejection::TooFewPlayers)
+        } else if count > max_players_count {
+            Err(StartRejection::TooManyPlayers)
+        } else {
+            Ok(())
+        }
+    }
+
     pub fn last_action_time(&self) -> &DateTime<Utc> {
         &self.last_action_time
     }
@@ -101,7 +392,536 @@ impl Game {
         &self.state
     }
 
+    pub fn settings(&self) -> &GameSettings {
+        &self.settings
+    }
+
+    /// Replaces the rule variants of the game, which is only possible before the game has started.
+    pub fn update_settings(&mut self, settings: GameSettings) -> Result<(), String> {
+        if self.state != GameState::Initialized && self.state != GameState::Abandoned {
+            return Err(String::from(
+                "Settings can't be changed after the game has started",
+            ));
+        }
+        settings.validate()?;
+
+        self.settings = settings;
+        Ok(())
+    }
+
+    /// Checks whether the game can be started with the current settings and players.
+    pub fn validate_settings(&self) -> Result<(), String> {
+        self.settings.validate_for(self.players_count())
+    }
+
+    pub fn deck(&self) -> &PolicyDeck {
+        &self.deck
+    }
+
+    /// Returns the player IDs in seating order, which is determined randomly at the start of the game.
+    pub fn seats(&self) -> &[String] {
+        &self.seats
+    }
+
+    /// Returns the IDs of all players in seating order, who haven't been executed.
+    pub fn living_player_ids(&self) -> Vec<String> {
+        self.seats
+            .iter()
+            .filter(|id| self.is_alive(id))
+            .map(String::from)
+            .collect()
+    }
+
+    pub fn is_alive(&self, player_id: &str) -> bool {
+        self.seats.iter().any(|id| id == player_id) && !self.is_executed(player_id)
+    }
+
+    /// Executed players can't take part in any decisions for the rest of the game.
+    pub fn is_executed(&self, player_id: &str) -> bool {
+        self.executed_ids.iter().any(|id| id == player_id)
+    }
+
+    pub fn executed_ids(&self) -> &[String] {
+        &self.executed_ids
+    }
+
+    /// Returns the player who is chosen by a special election or otherwise the next player in seating order.
+    pub fn presidential_candidate_id(&self) -> Option<&str> {
+        self.special_candidate_id
+            .as_deref()
+            .or_else(|| self.seats.get(self.president_seat).map(String::as_str))
+    }
+
+    pub fn nominated_chancellor_id(&self) -> Option<&str> {
+        self.nominated_chancellor_id.as_deref()
+    }
+
+    /// Passes the presidential candidacy to the next living player in seating order.
+    /// After a special election the order continues with the player next to the president who has called it.
+    pub fn rotate_presidency(&mut self) {
+        for _ in 0..self.seats.len() {
+            self.president_seat = (self.president_seat + 1) % self.seats.len();
+            if self.is_alive(&self.seats[self.president_seat]) {
+                break;
+            }
+        }
+        self.special_candidate_id = None;
+        self.nominated_chancellor_id = None;
+    }
+
+    /// Returns the IDs of all players the presidential candidate might nominate as chancellor.
+    /// The members of the last elected government are term-limited, but with five or fewer living players only the last chancellor is.
+    pub fn eligible_chancellor_ids(&self) -> Vec<String> {
+        let living_player_ids = self.living_player_ids();
+        let president_term_limited =
+            living_player_ids.len() > MAX_PLAYERS_WITHOUT_PRESIDENT_TERM_LIMIT;
+        let candidate_id = self.presidential_candidate_id();
+
+        living_player_ids
+            .iter()
+            .filter(|id| Some(id.as_str()) != candidate_id)
+            .filter(|id| Some(id.as_str()) != self.chancellor_id())
+            .filter(|id| !president_term_limited || Some(id.as_str()) != self.president_id())
+            .map(String::from)
+            .collect()
+    }
+
+    pub fn nominate_chancellor(&mut self, chancellor_id: &str) -> Result<(), String> {
+        if self.state != GameState::Started {
+            return Err(String::from("Game has not started"));
+        }
+        if self.nominated_chancellor_id.is_some() {
+            return Err(String::from("Chancellor has already been nominated"));
+        }
+        if !self
+            .eligible_chancellor_ids()
+            .iter()
+            .any(|id| id == chancellor_id)
+        {
+            return Err(format!(
+                "Player {} is not eligible as chancellor",
+                chancellor_id
+            ));
+        }
+
+        self.nominated_chancellor_id = Some(String::from(chancellor_id));
+        self.start_decision(TaskType::Vote, self.living_player_ids());
+        Ok(())
+    }
+
+    /// Returns the ID of the president of the last elected government.
+    pub fn president_id(&self) -> Option<&str> {
+        self.president_id.as_deref()
+    }
+
+    /// Returns the ID of the chancellor of the last elected government.
+    pub fn chancellor_id(&self) -> Option<&str> {
+        self.chancellor_id.as_deref()
+    }
+
+    /// Completes the election of the nominated government after all votes have been cast and returns whether it was elected.
+    /// A rejected government passes the presidential candidacy to the next player and advances the election tracker.
+    pub fn finish_election(&mut self) -> Result<bool, String> {
+        let approved = self
+            .decision(&TaskType::Vote)
+            .filter(|decision| decision.is_complete())
+            .map(CollectiveDecision::is_approved)
+            .ok_or_else(|| String::from("Election has not been completed"))?;
+        let chancellor_id = self
+            .nominated_chancellor_id
+            .take()
+            .ok_or_else(|| String::from("No chancellor has been nominated"))?;
+
+        if approved {
+            self.president_id = self.presidential_candidate_id().map(String::from);
+            self.chancellor_id = Some(chancellor_id);
+            if self.clan_policies_count >= CLAN_LEADER_ELECTION_POLICIES_COUNT
+                && self.chancellor_id() == self.clan_leader_id()
+            {
+                self.finish(Party::Clan, WinReason::ClanLeaderElected);
+            }
+        } else {
+            self.failed_elections_count += 1;
+            if self.failed_elections_count >= MAX_FAILED_ELECTIONS_COUNT {
+                self.enact_top_policy();
+            }
+            self.rotate_presidency();
+        }
+        Ok(approved)
+    }
+
+    /// Returns the number of consecutive failed elections since the last enacted policy.
+    pub fn failed_elections_count(&self) -> usize {
+        self.failed_elections_count
+    }
+
+    /// The country is thrown into chaos after too many failed elections, so the top policy is enacted without a government.
+    /// This also lifts the term limits of the last government.
+    fn enact_top_policy(&mut self) {
+        if let Some(policy) = self.deck.draw(1).pop() {
+            self.add_to_board(policy);
+        }
+        self.president_id = None;
+        self.chancellor_id = None;
+    }
+
+    fn add_to_board(&mut self, policy: Policy) {
+        match policy {
+            Policy::Loyal => self.loyal_policies_count += 1,
+            Policy::Clan => self.clan_policies_count += 1,
+        }
+        self.last_enacted_policy = Some(policy);
+        self.failed_elections_count = 0;
+        self.evaluate_winner();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, GameState::Finished { .. })
+    }
+
+    pub fn phase(&self) -> Phase {
+        match self.state {
+            GameState::Initialized | GameState::Abandoned => Phase::Lobby,
+            GameState::Finished { .. } => Phase::Finished,
+            GameState::Started if self.pending_power.is_some() => Phase::ExecutiveAction,
+            GameState::Started if !self.legislative_policies.is_empty() => {
+                Phase::LegislativeSession
+            }
+            GameState::Started if self.nominated_chancellor_id.is_some() => Phase::Election,
+            GameState::Started => Phase::Nomination,
+        }
+    }
+
+    /// Checks the win conditions of the board and the executions and finishes the game as soon as one of them is fulfilled.
+    fn evaluate_winner(&mut self) {
+        let leader_executed = match self.clan_leader_id() {
+            Some(leader_id) => self.executed_ids.iter().any(|id| id == leader_id),
+            None => false,
+        };
+
+        if self.loyal_policies_count >= WINNING_LOYAL_POLICIES_COUNT {
+            self.finish(Party::Loyal, WinReason::LoyalPoliciesEnacted);
+        } else if self.clan_policies_count >= WINNING_CLAN_POLICIES_COUNT {
+            self.finish(Party::Clan, WinReason::ClanPoliciesEnacted);
+        } else if leader_executed {
+            self.finish(Party::Loyal, WinReason::ClanLeaderExecuted);
+        }
+    }
+
+    fn finish(&mut self, winner: Party, reason: WinReason) {
+        if self.state == GameState::Started {
+            self.pending_power = None;
+            self.state = GameState::Finished { winner, reason };
+        }
+    }
+
+    /// Returns the policies currently in the hands of the elected government, which must never be revealed to other players.
+    pub fn legislative_policies(&self) -> &[Policy] {
+        &self.legislative_policies
+    }
+
+    /// Returns the number of enacted policies of the given type on the board.
+    pub fn enacted_policies_count(&self, policy: Policy) -> usize {
+        match policy {
+            Policy::Loyal => self.loyal_policies_count,
+            Policy::Clan => self.clan_policies_count,
+        }
+    }
+
+    pub fn last_enacted_policy(&self) -> Option<Policy> {
+        self.last_enacted_policy
+    }
+
+    /// Draws the policies for the president of the elected government.
+    pub fn start_legislative_session(&mut self) -> Result<(), String> {
+        if self.state != GameState::Started {
+            return Err(String::from("Game has not started"));
+        }
+        if self.president_id.is_none() || self.chancellor_id.is_none() {
+            return Err(String::from("No government has been elected"));
+        }
+        if !self.legislative_policies.is_empty() {
+            return Err(String::from("Legislative session has already started"));
+        }
+
+        self.legislative_policies = self.deck.draw(LEGISLATIVE_POLICIES_COUNT);
+        Ok(())
+    }
+
+    /// The president discards one of the drawn policies and passes the remaining ones to the chancellor.
+    pub fn discard_policy(&mut self, player_id: &str, policy: Policy) -> Result<(), String> {
+        if self.president_id() != Some(player_id) {
+            return Err(format!("Player {} is not the president", player_id));
+        }
+        if self.legislative_policies.len() != LEGISLATIVE_POLICIES_COUNT {
+            return Err(String::from("President can't discard a policy now"));
+        }
+
+        self.take_legislative_policy(policy)?;
+        self.deck.discard(policy);
+        Ok(())
+    }
+
+    /// The chancellor enacts one of the remaining policies, the other one is discarded.
+    /// Afterwards the presidential candidacy passes to the next player.
+    pub fn enact_policy(&mut self, player_id: &str, policy: Policy) -> Result<(), String> {
+        if self.chancellor_id() != Some(player_id) {
+            return Err(format!("Player {} is not the chancellor", player_id));
+        }
+        if self.legislative_policies.len() != LEGISLATIVE_POLICIES_COUNT - 1 || self.veto_proposed {
+            return Err(String::from("Chancellor can't enact a policy now"));
+        }
+
+        self.take_legislative_policy(policy)?;
+        for discarded in self.legislative_policies.drain(..) {
+            self.deck.discard(discarded);
+        }
+        self.veto_refused = false;
+        self.add_to_board(policy);
+        if policy == Policy::Clan && !self.is_finished() {
+            self.pending_power = power::executive_power(self.seats.len(), self.clan_policies_count);
+        }
+        if self.pending_power.is_none() {
+            self.rotate_presidency();
+        }
+        Ok(())
+    }
+
+    /// Returns whether the chancellor may propose to veto the remaining policies,
+    /// which requires enough enacted clan policies and is only possible once per legislative session.
+    pub fn is_veto_allowed(&self) -> bool {
+        self.settings.veto_enabled()
+            && self.clan_policies_count >= VETO_POLICIES_COUNT
+            && self.legislative_policies.len() == LEGISLATIVE_POLICIES_COUNT - 1
+            && !self.veto_proposed
+            && !self.veto_refused
+    }
+
+    pub fn is_veto_proposed(&self) -> bool {
+        self.veto_proposed
+    }
+
+    /// The chancellor proposes to discard the remaining policies instead of enacting one of them.
+    pub fn propose_veto(&mut self, player_id: &str) -> Result<(), String> {
+        if self.chancellor_id() != Some(player_id) {
+            return Err(format!("Player {} is not the chancellor", player_id));
+        }
+        if !self.is_veto_allowed() {
+            return Err(String::from("Chancellor can't propose a veto now"));
+        }
+
+        self.veto_proposed = true;
+        Ok(())
+    }
+
+    /// The president confirms or refuses the veto proposed by the chancellor.
+    /// A confirmed veto discards the remaining policies and counts as a failed election,
+    /// after a refused veto the chancellor has to enact one of the policies.
+    pub fn answer_veto(&mut self, player_id: &str, approved: bool) -> Result<(), String> {
+        if self.president_id() != Some(player_id) {
+            return Err(format!("Player {} is not the president", player_id));
+        }
+        if !self.veto_proposed {
+            return Err(String::from("No veto has been proposed"));
+        }
+
+        self.veto_proposed = false;
+        if !approved {
+            self.veto_refused = true;
+            return Ok(());
+        }
+
+        for discarded in self.legislative_policies.drain(..) {
+            self.deck.discard(discarded);
+        }
+        self.failed_elections_count += 1;
+        if self.failed_elections_count >= MAX_FAILED_ELECTIONS_COUNT {
+            self.enact_top_policy();
+        }
+        self.rotate_presidency();
+        Ok(())
+    }
+
+    /// Returns the executive power the president needs to use before the next round starts.
+    pub fn pending_power(&self) -> Option<ExecutivePower> {
+        self.pending_power
+    }
+
+    /// Returns the IDs of all players who might be chosen for the pending executive power.
+    pub fn eligible_power_target_ids(&self) -> Vec<String> {
+        self.living_player_ids()
+            .into_iter()
+            .filter(|id| Some(id.as_str()) != self.president_id())
+            .filter(|id| {
+                self.pending_power != Some(ExecutivePower::InvestigateLoyalty)
+                    || !self.investigated_ids.contains(id)
+            })
+            .collect()
+    }
+
+    /// The president investigates the party membership of another player, who can't be investigated again.
+    pub fn investigate_loyalty(&mut self, player_id: &str, target_id: &str) -> Result<(), String> {
+        self.check_power_target(player_id, target_id, ExecutivePower::InvestigateLoyalty)?;
+
+        self.investigated_ids.push(String::from(target_id));
+        self.pending_power = None;
+        self.rotate_presidency();
+        Ok(())
+    }
+
+    /// The president chooses the next presidential candidate.
+    pub fn call_special_election(
+        &mut self,
+        player_id: &str,
+        target_id: &str,
+    ) -> Result<(), String> {
+        self.check_power_target(player_id, target_id, ExecutivePower::SpecialElection)?;
+
+        self.pending_power = None;
+        self.nominated_chancellor_id = None;
+        self.special_candidate_id = Some(String::from(target_id));
+        Ok(())
+    }
+
+    /// The president looks at the top policies of the draw pile, which stay in the same order.
+    pub fn peek_policies(&mut self, player_id: &str) -> Result<(), String> {
+        self.check_power(player_id, ExecutivePower::PolicyPeek)?;
+
+        self.pending_power = None;
+        self.rotate_presidency();
+        Ok(())
+    }
+
+    /// The president executes another player, who is out of the game from now on.
+    pub fn execute_player(&mut self, player_id: &str, target_id: &str) -> Result<(), String> {
+        self.check_power_target(player_id, target_id, ExecutivePower::Execution)?;
+
+        self.executed_ids.push(String::from(target_id));
+        self.pending_power = None;
+        self.rotate_presidency();
+        self.evaluate_winner();
+        Ok(())
+    }
+
+    fn check_power(&self, player_id: &str, power: ExecutivePower) -> Result<(), String> {
+        if self.pending_power != Some(power) {
+            return Err(format!("Power {:?} is not available", power));
+        }
+        if self.president_id() != Some(player_id) {
+            return Err(format!("Player {} is not the president", player_id));
+        }
+        Ok(())
+    }
+
+    fn check_power_target(
+        &self,
+        player_id: &str,
+        target_id: &str,
+        power: ExecutivePower,
+    ) -> Result<(), String> {
+        self.check_power(player_id, power)?;
+        if !self
+            .eligible_power_target_ids()
+            .iter()
+            .any(|id| id == target_id)
+        {
+            return Err(format!("Player {} can't be chosen", target_id));
+        }
+        Ok(())
+    }
+
+    fn take_legislative_policy(&mut self, policy: Policy) -> Result<(), String> {
+        match self.legislative_policies.iter().position(|p| *p == policy) {
+            Some(index) => {
+                self.legislative_policies.remove(index);
+                Ok(())
+            }
+            None => Err(format!("Policy {:?} is not available", policy)),
+        }
+    }
+
+    pub fn decision(&self, task_type: &TaskType) -> Option<&CollectiveDecision> {
+        self.decisions.get(task_type)
+    }
+
+    /// Opens a new decision for the given voters and replaces any previous decision of the same type.
+    pub fn start_decision(&mut self, task_type: TaskType, voter_ids: Vec<String>) {
+        self.decisions
+            .insert(task_type, CollectiveDecision::new(voter_ids));
+    }
+
+    pub fn answer_decision(
+        &mut self,
+        task_type: &TaskType,
+        player_id: &str,
+        approved: bool,
+    ) -> Result<(), String> {
+        match self.decisions.get_mut(task_type) {
+            Some(decision) if !decision.is_complete() => decision.answer(player_id, approved),
+            _ => Err(format!("No open decision {:?} found", task_type)),
+        }
+    }
+
+    pub fn abstain_decision(
+        &mut self,
+        task_type: &TaskType,
+        player_id: &str,
+    ) -> Result<(), String> {
+        match self.decisions.get_mut(task_type) {
+            Some(decision) if !decision.is_complete() => decision.abstain(player_id),
+            _ => Err(format!("No open decision {:?} found", task_type)),
+        }
+    }
+
+    /// Checks whether another player may join, a locked lobby is reported before a full one.
+    pub fn check_join(&self, max_players_count: usize) -> Result<(), JoinRejection> {
+        if self.state != GameState::Initialized && self.state != GameState::Abandoned {
+            Err(JoinRejection::AlreadyStarted)
+        } else if self.locked {
+            Err(JoinRejection::LobbyLocked)
+        } else if self.is_full(max_players_count) {
+            Err(JoinRejection::LobbyFull)
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn join(&mut self, player_id: &str, max_players_count: usize) -> Result<(), JoinRejection> {
+        self.check_join(max_players_count)?;
+        self.add_player(player_id);
+        Ok(())
+    }
+
+    /// Checks the chosen name, which must not be used by any other player of the game, ignoring the case.
+    pub fn check_name(&self, player_id: &str, name: &str) -> Result<(), NameRejection> {
+        let name = Player::normalize_name(name)?.to_lowercase();
+        if self
+            .names
+            .iter()
+            .any(|(id, other)| id != player_id && other.to_lowercase() == name)
+        {
+            return Err(NameRejection::AlreadyTaken);
+        }
+        Ok(())
+    }
+
+    /// Reserves the name for the player, so no other player of the game can choose it anymore.
+    pub fn choose_name(&mut self, player_id: &str, name: &str) -> Result<(), String> {
+        if !self.all_player_ids().iter().any(|id| id == player_id) {
+            return Err(format!("Player {} is not part of the game", player_id));
+        }
+        self.check_name(player_id, name)
+            .map_err(|rejection| rejection.to_string())?;
+
+        self.names
+            .insert(String::from(player_id), String::from(name.trim()));
+        Ok(())
+    }
+
     pub fn add_player(&mut self, player_id: &str) {
+        if !self.join_order.iter().any(|id| id == player_id) {
+            self.join_order.push(String::from(player_id));
+        }
         match self.admin_id {
             Some(_) => {
                 self.player_ids.insert(String::from(player_id));
@@ -114,6 +934,8 @@ impl Game {
     }
 
     pub fn remove_player(&mut self, player_id: &str) {
+        self.join_order.retain(|id| id != player_id);
+        self.names.remove(player_id);
         if self.player_ids.contains(player_id) {
             self.player_ids.remove(player_id);
         } else if self
@@ -122,7 +944,8 @@ impl Game {
             .filter(|id| id == player_id)
             .is_some()
         {
-            if let Some(next_player_id) = self.player_ids.iter().next().map(String::from) {
+            // the longest attending player succeeds, which also results in the same admin when replaying the event log
+            if let Some(next_player_id) = self.next_admin_id() {
                 self.admin_id = Some(String::from(&next_player_id));
                 self.player_ids.remove(&next_player_id);
             } else {
@@ -134,18 +957,267 @@ impl Game {
         }
     }
 
+    /// Bots can't administrate the game, so only human players are considered.
+    fn next_admin_id(&self) -> Option<String> {
+        self.join_order
+            .iter()
+            .find(|id| self.player_ids.contains(*id) && !self.bot_ids.contains(*id))
+            .map(String::from)
+    }
+
+    /// The admin fills an empty seat of the lobby with a bot, which is played by the server.
+    pub fn add_bot(&mut self, player_id: &str, bot_id: &str) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        if self.state != GameState::Initialized {
+            return Err(format!("Game {} has already started", self.token));
+        }
+
+        self.add_player(bot_id);
+        self.bot_ids.insert(String::from(bot_id));
+        Ok(())
+    }
+
+    /// A bot takes over the seat of a player who has left a running game, so the game can go on.
+    /// An admin being replaced passes the admin role on to the longest attending human player.
+    pub fn replace_by_bot(&mut self, player_id: &str) -> Result<(), String> {
+        if self.state != GameState::Started {
+            return Err(format!("Game {} isn't running", self.token));
+        }
+        if !self.seats.iter().any(|id| id == player_id) {
+            return Err(format!("Player {} has no seat", player_id));
+        }
+
+        self.bot_ids.insert(String::from(player_id));
+        if self.admin_id.as_deref() == Some(player_id) {
+            if let Some(next_player_id) = self.next_admin_id() {
+                self.player_ids.remove(&next_player_id);
+                self.player_ids.insert(String::from(player_id));
+                self.admin_id = Some(next_player_id);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn is_bot(&self, player_id: &str) -> bool {
+        self.bot_ids.contains(player_id)
+    }
+
+    pub fn bot_ids(&self) -> &HashSet<String> {
+        &self.bot_ids
+    }
+
+    /// The admin removes another player from the lobby, which works the same way as if the player had left.
+    /// Seated players can't be kicked, as the running game still waits for their answers.
+    pub fn kick_player(&mut self, player_id: &str, target_id: &str) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        if self.state != GameState::Initialized {
+            return Err(format!("Game {} has already started", self.token));
+        }
+        if !self.player_ids.contains(target_id) {
+            return Err(format!("Player {} can't be kicked", target_id));
+        }
+
+        self.remove_player(target_id);
+        Ok(())
+    }
+
+    /// The admin passes the admin role on to another player and takes part as a regular player from then on.
+    pub fn hand_over_admin(&mut self, player_id: &str, target_id: &str) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        if !self.player_ids.contains(target_id) {
+            return Err(format!("Player {} can't become the admin", target_id));
+        }
+
+        self.player_ids.remove(target_id);
+        self.player_ids.insert(String::from(player_id));
+        self.admin_id = Some(String::from(target_id));
+        Ok(())
+    }
+
+    /// Only players knowing the password can join, the game only keeps the hash of it.
+    pub fn change_password(
+        &mut self,
+        player_id: &str,
+        password_hash: Option<String>,
+    ) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        self.password_hash = password_hash;
+        Ok(())
+    }
+
+    pub fn password_hash(&self) -> Option<&str> {
+        self.password_hash.as_deref()
+    }
+
+    /// A locked lobby doesn't accept any new players, regardless of the password.
+    pub fn lock_lobby(&mut self, player_id: &str, locked: bool) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        self.locked = locked;
+        Ok(())
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+
     pub fn to_response(&self) -> GameResponse {
         GameResponse {
             admin_id: self.admin_id.to_owned(),
             player_ids: self.player_ids.to_owned(),
             state: self.state.to_owned(),
             token: self.token.to_owned(),
+            password_protected: self.password_hash.is_some(),
+            locked: self.locked,
         }
     }
 
-    pub fn start(&mut self) {
+    /// Returns the secret role of the given player, which is only known after the game has started.
+    pub fn role(&self, player_id: &str) -> Option<Role> {
+        self.roles.get(player_id).copied()
+    }
+
+    pub fn clan_leader_id(&self) -> Option<&str> {
+        self.roles
+            .iter()
+            .find(|(_, role)| **role == Role::ClanLeader)
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Returns the other clan members the given player is allowed to know about.
+    /// Clan members know each other and their leader, while the settings decide whether the leader knows the members.
+    pub fn known_clan_member_ids(&self, player_id: &str) -> Vec<String> {
+        let knows_members = match self.role(player_id) {
+            Some(Role::Clan) => true,
+            Some(Role::ClanLeader) => self.settings.clan_leader_knows_members(self.roles.len()),
+            _ => false,
+        };
+        if !knows_members {
+            return vec![];
+        }
+
+        let mut ids = self
+            .roles
+            .iter()
+            .filter(|(id, role)| **role == Role::Clan && *id != player_id)
+            .map(|(id, _)| String::from(id))
+            .collect::<Vec<_>>();
+        ids.sort();
+        ids
+    }
+
+    /// Deals the roles and seats the players, which is only possible once for each game.
+    /// Further games of the same lobby are started with `Game::start_rematch`.
+    pub fn start(&mut self) -> Result<(), String> {
+        if self.state != GameState::Initialized {
+            return Err(format!("Game {} has already started", self.token));
+        }
+        self.deal();
+        Ok(())
+    }
+
+    fn deal(&mut self) {
+        // sorting makes dealing the roles reproducible for the same seed
+        let mut player_ids = self.all_player_ids();
+        player_ids.sort();
+
+        let mut rng = Pcg64::seed_from_u64(self.seed);
+        let roles = role::deal_roles(
+            player_ids.len(),
+            self.settings.clan_members_count(player_ids.len()),
+            &mut rng,
+        );
+        self.roles = player_ids.iter().cloned().zip(roles).collect();
+        player_ids.shuffle(&mut rng);
+        self.seats = player_ids;
+        self.president_seat = 0;
+        self.nominated_chancellor_id = None;
+        self.president_id = None;
+        self.chancellor_id = None;
+        self.decisions.clear();
+        self.legislative_policies.clear();
+        self.loyal_policies_count = 0;
+        self.clan_policies_count = 0;
+        self.last_enacted_policy = None;
+        self.failed_elections_count = 0;
+        self.veto_proposed = false;
+        self.veto_refused = false;
+        self.pending_power = None;
+        self.special_candidate_id = None;
+        self.investigated_ids.clear();
+        self.executed_ids.clear();
         self.state = GameState::Started;
     }
+
+    /// The admin starts another game with the same lobby and settings once the game has finished.
+    /// Roles and policies are dealt again, but the players keep their seats and the next player becomes the first president.
+    pub fn start_rematch(
+        &mut self,
+        player_id: &str,
+        seed: u64,
+        deck_seed: u64,
+    ) -> Result<(), String> {
+        if self.admin_id.as_deref() != Some(player_id) {
+            return Err(format!("Player {} is not the admin", player_id));
+        }
+        if !self.is_finished() {
+            return Err(format!("Game {} hasn't finished", self.token));
+        }
+
+        let previous_seats = std::mem::take(&mut self.seats);
+        self.seed = seed;
+        self.deck = PolicyDeck::new(deck_seed);
+        self.deal();
+
+        // players who have joined since the last game take the last seats
+        let mut seats = previous_seats
+            .into_iter()
+            .filter(|id| self.seats.contains(id))
+            .collect::<Vec<_>>();
+        if !seats.is_empty() {
+            seats.rotate_left(1);
+        }
+        for id in &self.seats {
+            if !seats.contains(id) {
+                seats.push(String::from(id));
+            }
+        }
+        self.seats = seats;
+        Ok(())
+    }
+}
+
+impl From<WinReason> for proto::game::WinReason {
+    fn from(reason: WinReason) -> Self {
+        match reason {
+            WinReason::LoyalPoliciesEnacted => proto::game::WinReason::LOYAL_POLICIES_ENACTED,
+            WinReason::ClanPoliciesEnacted => proto::game::WinReason::CLAN_POLICIES_ENACTED,
+            WinReason::ClanLeaderExecuted => proto::game::WinReason::CLAN_LEADER_EXECUTED,
+            WinReason::ClanLeaderElected => proto::game::WinReason::CLAN_LEADER_ELECTED,
+        }
+    }
+}
+
+impl From<Phase> for proto::game::Phase {
+    fn from(phase: Phase) -> Self {
+        match phase {
+            Phase::Lobby => proto::game::Phase::LOBBY,
+            Phase::Nomination => proto::game::Phase::NOMINATION,
+            Phase::Election => proto::game::Phase::ELECTION,
+            Phase::LegislativeSession => proto::game::Phase::LEGISLATIVE_SESSION,
+            Phase::ExecutiveAction => proto::game::Phase::EXECUTIVE_ACTION,
+            Phase::Finished => proto::game::Phase::FINISHED,
+        }
+    }
 }
 
 impl Persist for Game {
@@ -172,10 +1244,442 @@ impl TryFrom<IVec> for Game {
 impl Into<proto::game::Game> for Game {
     fn into(self) -> proto::game::Game {
         let mut game = proto::game::Game::new();
+        let eligible_chancellor_ids = if self.state == GameState::Started {
+            self.eligible_chancellor_ids()
+        } else {
+            vec![]
+        };
         game.set_token(String::from(self.token()));
+        game.set_finished(self.is_finished());
+        game.set_password_protected(self.password_hash.is_some());
+        game.set_locked(self.locked);
+        if let Some(id) = self.presidential_candidate_id() {
+            game.set_presidential_candidate_id(String::from(id));
+        }
         if let Some(id) = self.admin_id {
             game.set_admin_id(id);
         }
+        game.set_draw_pile_count(self.deck.draw_pile_count() as u32);
+        game.set_discard_pile_count(self.deck.discard_pile_count() as u32);
+        if let Some(id) = self.nominated_chancellor_id {
+            game.set_nominated_chancellor_id(id);
+        }
+        if let Some(id) = self.president_id {
+            game.set_president_id(id);
+        }
+        if let Some(id) = self.chancellor_id {
+            game.set_chancellor_id(id);
+        }
+        game.set_seat_ids(RepeatedField::from_vec(self.seats));
+        game.set_loyal_policies_count(self.loyal_policies_count as u32);
+        game.set_clan_policies_count(self.clan_policies_count as u32);
+        game.set_failed_elections_count(self.failed_elections_count as u32);
+        game.set_executed_player_ids(RepeatedField::from_vec(self.executed_ids));
+        game.set_eligible_chancellor_ids(RepeatedField::from_vec(eligible_chancellor_ids));
+        game.set_settings(self.settings.into());
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Game, GameSettings, GameState, JoinRejection, NameRejection, Party, Policy, PolicyDeck,
+        WinReason,
+    };
+    use crate::model::{GameEvent, LoggedEvent, TaskType};
+
+    fn init_game(players_count: usize) -> Game {
+        let mut game = Game::new("admin", "GAME");
+        for i in 1..players_count {
+            game.add_player(&format!("player{}", i));
+        }
+        game.start().expect("Starting game has failed");
         game
     }
+
+    fn elect_government(game: &mut Game) -> (String, String) {
+        let president_id = String::from(game.presidential_candidate_id().unwrap());
+        // Nominate the last seat, so the chancellor doesn't become the next presidential candidate
+        let chancellor_id = game.eligible_chancellor_ids().last().unwrap().clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for id in game.living_player_ids() {
+            game.answer_decision(&TaskType::Vote, &id, true)
+                .expect("Voting has failed");
+        }
+        assert_eq!(game.finish_election(), Ok(true));
+        game.rotate_presidency();
+
+        (president_id, chancellor_id)
+    }
+
+    fn replay_with(events: &mut Vec<LoggedEvent>, event: GameEvent) -> Game {
+        events.push(LoggedEvent::new(event));
+        Game::replay(events).expect("Replaying events has failed")
+    }
+
+    #[test]
+    fn should_replay_game_from_events() {
+        let mut events = vec![LoggedEvent::new(GameEvent::game_created("admin", "GAME"))];
+        for i in 1..5 {
+            let player_id = format!("player{}", i);
+            replay_with(
+                &mut events,
+                GameEvent::PlayerJoined {
+                    player_id,
+                    max_players_count: 10,
+                },
+            );
+        }
+        let game = replay_with(&mut events, GameEvent::GameStarted);
+        let candidate_id = String::from(game.presidential_candidate_id().unwrap());
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        let game = replay_with(
+            &mut events,
+            GameEvent::ChancellorNominated {
+                player_id: candidate_id.clone(),
+                chancellor_id: chancellor_id.clone(),
+            },
+        );
+        for player_id in game.living_player_ids() {
+            replay_with(
+                &mut events,
+                GameEvent::VoteCast {
+                    player_id,
+                    approved: true,
+                },
+            );
+        }
+        let game = replay_with(&mut events, GameEvent::ElectionFinished);
+
+        assert_eq!(game.president_id(), Some(candidate_id.as_str()));
+        assert_eq!(game.chancellor_id(), Some(chancellor_id.as_str()));
+        assert_eq!(game.legislative_policies().len(), 3);
+        assert_eq!(Game::replay(&events), Ok(game));
+    }
+
+    #[test]
+    fn should_reject_events_breaking_the_rules() {
+        let created = LoggedEvent::new(GameEvent::game_created("admin", "GAME"));
+        let mut game =
+            Game::replay(std::slice::from_ref(&created)).expect("Replaying events has failed");
+
+        assert!(game.apply(&created).is_err());
+        assert!(game
+            .apply(&LoggedEvent::new(GameEvent::VoteCast {
+                player_id: String::from("admin"),
+                approved: true,
+            }))
+            .is_err());
+        assert!(Game::replay(&[LoggedEvent::new(GameEvent::GameStarted)]).is_err());
+    }
+
+    #[test]
+    fn should_only_start_game_once() {
+        let mut game = init_game(5);
+        let seats = game.seats().to_vec();
+
+        assert!(game.start().is_err());
+        assert!(game
+            .apply(&LoggedEvent::new(GameEvent::GameStarted))
+            .is_err());
+        assert_eq!(game.seats(), &seats[..]);
+    }
+
+    #[test]
+    fn should_only_join_open_lobby() {
+        let joined = |player_id: &str, max_players_count| {
+            LoggedEvent::new(GameEvent::PlayerJoined {
+                player_id: String::from(player_id),
+                max_players_count,
+            })
+        };
+        let mut game = Game::new("admin", "GAME");
+
+        assert!(game.apply(&joined("player1", 2)).is_ok());
+        assert_eq!(game.check_join(2), Err(JoinRejection::LobbyFull));
+        assert!(game.apply(&joined("player2", 2)).is_err());
+        game.lock_lobby("admin", true)
+            .expect("Locking lobby has failed");
+        assert_eq!(game.check_join(2), Err(JoinRejection::LobbyLocked));
+        assert!(game.apply(&joined("player2", 10)).is_err());
+        assert!(!game.player_ids().contains("player2"));
+
+        let mut game = init_game(5);
+        assert_eq!(game.check_join(10), Err(JoinRejection::AlreadyStarted));
+        assert!(game.apply(&joined("player5", 10)).is_err());
+        assert!(!game.player_ids().contains("player5"));
+    }
+
+    #[test]
+    fn should_keep_names_unique() {
+        let mut game = Game::new("admin", "GAME");
+        game.add_player("player1");
+
+        assert!(game.choose_name("admin", "Tom").is_ok());
+        assert!(game.choose_name("admin", "tom").is_ok());
+        assert_eq!(
+            game.check_name("player1", " TOM "),
+            Err(NameRejection::AlreadyTaken)
+        );
+        assert!(game.choose_name("player1", "Tom").is_err());
+        assert!(game.choose_name("player2", "Tim").is_err());
+
+        game.remove_player("admin");
+        assert!(game.choose_name("player1", "Tom").is_ok());
+    }
+
+    #[test]
+    fn should_only_allow_admin_to_kick_players() {
+        let mut game = Game::new("admin", "GAME");
+        game.add_player("player1");
+        game.add_player("player2");
+
+        assert!(game.kick_player("player1", "player2").is_err());
+        assert!(game.kick_player("admin", "admin").is_err());
+        assert!(game.kick_player("admin", "player2").is_ok());
+        assert!(!game.player_ids().contains("player2"));
+        assert!(game.kick_player("admin", "player2").is_err());
+    }
+
+    #[test]
+    fn should_not_kick_players_of_running_game() {
+        let mut game = init_game(5);
+
+        assert!(game.kick_player("admin", "player1").is_err());
+        assert!(game.player_ids().contains("player1"));
+    }
+
+    #[test]
+    fn should_pass_admin_role_on_in_join_order() {
+        let mut game = Game::new("admin", "GAME");
+        for id in &["player3", "player1", "player2"] {
+            game.add_player(id);
+        }
+
+        game.remove_player("admin");
+        assert_eq!(game.admin_id().as_deref(), Some("player3"));
+        game.remove_player("player1");
+        game.remove_player("player3");
+        assert_eq!(game.admin_id().as_deref(), Some("player2"));
+        assert!(game.player_ids().is_empty());
+    }
+
+    #[test]
+    fn should_hand_over_admin_role() {
+        let mut game = Game::new("admin", "GAME");
+        game.add_player("player1");
+        game.add_player("player2");
+
+        assert!(game.hand_over_admin("player1", "player2").is_err());
+        assert!(game.hand_over_admin("admin", "admin").is_err());
+        assert!(game.hand_over_admin("admin", "unknown").is_err());
+        assert!(game.hand_over_admin("admin", "player2").is_ok());
+        assert_eq!(game.admin_id().as_deref(), Some("player2"));
+        assert!(game.player_ids().contains("admin"));
+        assert!(!game.player_ids().contains("player2"));
+
+        // the former admin has joined first, so the role goes back on leaving
+        game.remove_player("player2");
+        assert_eq!(game.admin_id().as_deref(), Some("admin"));
+    }
+
+    #[test]
+    fn should_never_pass_admin_role_on_to_bots() {
+        let mut game = Game::new("admin", "GAME");
+        assert!(game.add_bot("player1", "bot1").is_err());
+        assert!(game.add_bot("admin", "bot1").is_ok());
+        game.add_player("player1");
+
+        game.remove_player("admin");
+        assert_eq!(game.admin_id().as_deref(), Some("player1"));
+        assert!(game.is_bot("bot1"));
+
+        // only bots are left, so nobody can administrate the game anymore
+        game.remove_player("player1");
+        assert_eq!(game.admin_id(), &None);
+        assert_eq!(game.state(), &GameState::Abandoned);
+    }
+
+    #[test]
+    fn should_only_replace_players_of_running_game_by_bots() {
+        let mut game = Game::new("admin", "GAME");
+        for id in &["player1", "player2", "player3", "player4"] {
+            game.add_player(id);
+        }
+        assert!(game.replace_by_bot("player1").is_err());
+        game.start().expect("Starting game has failed");
+
+        assert!(game.replace_by_bot("unknown").is_err());
+        assert!(game.replace_by_bot("admin").is_ok());
+        assert!(game.is_bot("admin"));
+        assert_eq!(game.admin_id().as_deref(), Some("player1"));
+        assert!(game.player_ids().contains("admin"));
+        assert_eq!(game.seats().len(), 5);
+    }
+
+    #[test]
+    fn should_only_allow_admin_to_protect_lobby() {
+        let mut game = Game::new("admin", "GAME");
+        game.add_player("player1");
+
+        assert!(game
+            .change_password("player1", Some(String::from("hash")))
+            .is_err());
+        assert!(game.lock_lobby("player1", true).is_err());
+        assert!(game
+            .change_password("admin", Some(String::from("hash")))
+            .is_ok());
+        assert!(game.lock_lobby("admin", true).is_ok());
+        assert_eq!(game.password_hash(), Some("hash"));
+        assert!(game.is_locked());
+
+        let response = game.to_response();
+        assert!(response.password_protected);
+        assert!(response.locked);
+    }
+
+    #[test]
+    fn should_exclude_last_government() {
+        let mut game = init_game(7);
+        assert_eq!(game.eligible_chancellor_ids().len(), 6);
+
+        let (president_id, chancellor_id) = elect_government(&mut game);
+
+        let eligible_ids = game.eligible_chancellor_ids();
+        assert!(!eligible_ids.contains(&president_id));
+        assert!(!eligible_ids.contains(&chancellor_id));
+        assert_eq!(eligible_ids.len(), 4);
+    }
+
+    #[test]
+    fn should_only_exclude_last_chancellor_in_small_games() {
+        let mut game = init_game(5);
+
+        let (president_id, chancellor_id) = elect_government(&mut game);
+
+        let eligible_ids = game.eligible_chancellor_ids();
+        assert!(eligible_ids.contains(&president_id));
+        assert!(!eligible_ids.contains(&chancellor_id));
+        assert_eq!(eligible_ids.len(), 3);
+    }
+
+    fn pass_policies_to_chancellor(game: &mut Game) -> (String, String) {
+        let (president_id, chancellor_id) = elect_government(game);
+        game.start_legislative_session()
+            .expect("Drawing policies has failed");
+        let policy = game.legislative_policies()[0];
+        game.discard_policy(&president_id, policy)
+            .expect("Discarding policy has failed");
+        (president_id, chancellor_id)
+    }
+
+    #[test]
+    fn should_only_allow_veto_after_five_clan_policies() {
+        let mut game = init_game(5);
+        let (_, chancellor_id) = pass_policies_to_chancellor(&mut game);
+        game.clan_policies_count = 4;
+        assert!(!game.is_veto_allowed());
+        assert!(game.propose_veto(&chancellor_id).is_err());
+
+        game.clan_policies_count = 5;
+        assert!(game.is_veto_allowed());
+        let settings = game.settings().clone();
+        game.settings = GameSettings::new(
+            settings.role_distributions().to_vec(),
+            settings.clan_leader_knowledge(),
+            false,
+            settings.task_timeout_seconds(),
+            settings.chat_audience(),
+        );
+        assert!(!game.is_veto_allowed());
+    }
+
+    #[test]
+    fn should_discard_policies_after_confirmed_veto() {
+        let mut game = init_game(5);
+        let (president_id, chancellor_id) = pass_policies_to_chancellor(&mut game);
+        game.clan_policies_count = 5;
+        let candidate_id = String::from(game.presidential_candidate_id().unwrap());
+
+        assert!(game.propose_veto(&president_id).is_err());
+        assert!(game.answer_veto(&president_id, true).is_err());
+        assert!(game.propose_veto(&chancellor_id).is_ok());
+        assert!(game.propose_veto(&chancellor_id).is_err());
+        let policy = game.legislative_policies()[0];
+        assert!(game.enact_policy(&chancellor_id, policy).is_err());
+        assert!(game.answer_veto(&chancellor_id, true).is_err());
+        assert!(game.answer_veto(&president_id, true).is_ok());
+
+        assert!(game.legislative_policies().is_empty());
+        assert_eq!(game.deck().discard_pile_count(), 3);
+        assert_eq!(game.failed_elections_count(), 1);
+        assert_ne!(
+            game.presidential_candidate_id(),
+            Some(candidate_id.as_str())
+        );
+        assert!(!game.is_veto_proposed());
+    }
+
+    #[test]
+    fn should_enact_policy_after_refused_veto() {
+        let mut game = init_game(5);
+        let (president_id, chancellor_id) = pass_policies_to_chancellor(&mut game);
+        game.clan_policies_count = 5;
+
+        assert!(game.propose_veto(&chancellor_id).is_ok());
+        assert!(game.answer_veto(&president_id, false).is_ok());
+        assert_eq!(game.legislative_policies().len(), 2);
+        assert!(!game.is_veto_allowed());
+        assert!(game.propose_veto(&chancellor_id).is_err());
+
+        let policy = game.legislative_policies()[0];
+        assert!(game.enact_policy(&chancellor_id, policy).is_ok());
+        assert_eq!(game.failed_elections_count(), 0);
+    }
+
+    #[test]
+    fn should_enact_top_policy_after_too_many_vetoes() {
+        let mut game = init_game(5);
+        let (president_id, chancellor_id) = pass_policies_to_chancellor(&mut game);
+        game.clan_policies_count = 5;
+        game.loyal_policies_count = 1;
+        game.failed_elections_count = 2;
+
+        assert!(game.propose_veto(&chancellor_id).is_ok());
+        assert!(game.answer_veto(&president_id, true).is_ok());
+
+        assert_eq!(game.failed_elections_count(), 0);
+        assert_eq!(game.president_id(), None);
+        assert!(game.last_enacted_policy().is_some());
+    }
+
+    #[test]
+    fn should_start_rematch_with_next_president() {
+        let mut game = init_game(5);
+        elect_government(&mut game);
+        assert!(game.start_rematch("admin", 1, 2).is_err());
+
+        game.loyal_policies_count = 5;
+        game.state = GameState::Finished {
+            winner: Party::Loyal,
+            reason: WinReason::LoyalPoliciesEnacted,
+        };
+        let previous_seats = game.seats().to_vec();
+        assert!(game.start_rematch("player1", 1, 2).is_err());
+        assert!(game.start_rematch("admin", 1, 2).is_ok());
+
+        assert_eq!(game.state(), &GameState::Started);
+        assert_eq!(game.seats()[..4], previous_seats[1..]);
+        assert_eq!(game.seats()[4], previous_seats[0]);
+        assert_eq!(
+            game.presidential_candidate_id(),
+            Some(previous_seats[1].as_str())
+        );
+        assert_eq!(game.president_id(), None);
+        assert_eq!(game.enacted_policies_count(Policy::Loyal), 0);
+        assert_eq!(game.deck(), &PolicyDeck::new(2));
+        assert!(game.legislative_policies().is_empty());
+    }
 }
@@ -1,13 +1,39 @@
+mod chat;
+mod decision;
+mod event;
 mod game;
 mod player;
+mod policy;
+mod power;
+mod role;
+mod settings;
 mod task;
 
 pub mod proto;
+pub use chat::ChatMessage;
+pub use decision::CollectiveDecision;
+pub use event::GameEvent;
+pub use event::LoggedEvent;
 pub use game::Game;
 pub use game::GameResponse;
 pub use game::GameState;
+pub use game::JoinRejection;
+pub use game::Phase;
+pub use game::StartRejection;
+pub use game::WinReason;
+pub use player::NameRejection;
 pub use player::Player;
 pub use player::PlayerResponse;
+pub use policy::Policy;
+pub use policy::PolicyDeck;
+pub use power::ExecutivePower;
+pub use role::Party;
+pub use role::Role;
+pub use settings::ChatAudience;
+pub use settings::ClanLeaderKnowledge;
+pub use settings::GameSettings;
+pub use settings::RoleDistribution;
+pub use task::CollectiveTask;
 pub use task::Task;
 pub use task::TaskDefinition;
 pub use task::TaskType;
@@ -10,12 +10,51 @@ use nanoid::nanoid;
 use protobuf::RepeatedField;
 use serde::{Deserialize, Serialize};
 use sled::IVec;
-use std::collections::VecDeque;
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt,
+};
+
+const MIN_NAME_LENGTH: usize = 2;
+const MAX_NAME_LENGTH: usize = 24;
+const NAME_SPECIAL_CHARS: [char; 5] = [' ', '-', '_', '.', '\''];
 
 fn generate_random_name() -> String {
     Generator::default().next().unwrap()
 }
 
+/// Reasons why a player can't pick a name.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum NameRejection {
+    TooShort,
+    TooLong,
+    InvalidCharacters,
+    AlreadyTaken,
+}
+
+impl fmt::Display for NameRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NameRejection::TooShort => {
+                write!(f, "Name needs at least {} characters", MIN_NAME_LENGTH)
+            }
+            NameRejection::TooLong => {
+                write!(
+                    f,
+                    "Name can't have more than {} characters",
+                    MAX_NAME_LENGTH
+                )
+            }
+            NameRejection::InvalidCharacters => write!(
+                f,
+                "Name may only contain letters, digits, spaces and the characters -_.'"
+            ),
+            NameRejection::AlreadyTaken => write!(f, "Name is already taken"),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Derivative)]
 #[derivative(Debug)]
 #[serde(rename_all = "camelCase")]
@@ -28,6 +67,9 @@ pub struct Player {
     creation_time: DateTime<Utc>,
     last_active_time: Option<DateTime<Utc>>,
     open_tasks: VecDeque<TaskDefinition>,
+    task_deadlines: HashMap<TaskType, DateTime<Utc>>,
+    ready: bool,
+    bot: bool,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Derivative)]
@@ -49,9 +91,22 @@ impl Player {
             creation_time: Utc::now(),
             last_active_time: None,
             open_tasks: VecDeque::default(),
+            task_deadlines: HashMap::default(),
+            ready: false,
+            bot: false,
         }
     }
 
+    /// Creates a player without a connection, whose tasks are answered by the server.
+    /// Bots don't need to pick a name, so they are ready right away.
+    pub fn new_bot(game_token: &str) -> Self {
+        let mut player = Player::new(game_token);
+        player.name = format!("Bot {}", player.name);
+        player.ready = true;
+        player.bot = true;
+        player
+    }
+
     pub fn id(&self) -> &str {
         &self.id
     }
@@ -60,6 +115,30 @@ impl Player {
         &self.name
     }
 
+    /// Trims the chosen name and checks its length and characters, the uniqueness within the game is checked separately.
+    pub fn normalize_name(name: &str) -> Result<&str, NameRejection> {
+        let name = name.trim();
+        let length = name.chars().count();
+        if length < MIN_NAME_LENGTH {
+            return Err(NameRejection::TooShort);
+        }
+        if length > MAX_NAME_LENGTH {
+            return Err(NameRejection::TooLong);
+        }
+        if !name
+            .chars()
+            .all(|c| c.is_alphanumeric() || NAME_SPECIAL_CHARS.contains(&c))
+        {
+            return Err(NameRejection::InvalidCharacters);
+        }
+        Ok(name)
+    }
+
+    /// Names are compared ignoring the case, as players couldn't tell such names apart.
+    pub fn has_name(&self, name: &str) -> bool {
+        self.name.to_lowercase() == name.to_lowercase()
+    }
+
     pub fn set_name(&mut self, name: &str) {
         if !name.is_empty() {
             self.name = String::from(name);
@@ -94,6 +173,29 @@ impl Player {
         self.open_tasks.push_back(task);
     }
 
+    /// Assigns a task, which is resolved with a default action if the player hasn't answered until the deadline.
+    /// Tasks without a default action don't get a deadline.
+    pub fn assign_task_until(&mut self, task: TaskDefinition, deadline: DateTime<Utc>) {
+        if task.get_type().has_default_action() {
+            self.task_deadlines.insert(task.get_type(), deadline);
+        }
+        self.assign_task(task);
+    }
+
+    pub fn task_deadline(&self, task: &TaskType) -> Option<&DateTime<Utc>> {
+        self.task_deadlines.get(task)
+    }
+
+    /// Returns whether any open task has passed its deadline.
+    /// Tasks behind a task without a default action don't expire, as the player has to answer that one first.
+    pub fn has_expired_task(&self, now: &DateTime<Utc>) -> bool {
+        self.open_tasks
+            .iter()
+            .take_while(|task| task.get_type().has_default_action())
+            .filter_map(|task| self.task_deadlines.get(&task.get_type()))
+            .any(|deadline| deadline < now)
+    }
+
     pub fn resolve_task(&mut self, task: TaskType) {
         if self
             .open_tasks
@@ -102,6 +204,7 @@ impl Player {
             .is_some()
         {
             self.open_tasks.pop_front();
+            self.task_deadlines.remove(&task);
         } else {
             warn!("Task {:?} not resolved", task);
         }
@@ -111,6 +214,31 @@ impl Player {
         &self.open_tasks
     }
 
+    /// Drops all open tasks, e.g. of an executed player or for a rematch, while the name and readiness are kept.
+    pub fn clear_tasks(&mut self) {
+        self.open_tasks.clear();
+        self.task_deadlines.clear();
+    }
+
+    /// Players mark themselves as ready in the lobby, once they have picked their name.
+    pub fn set_ready(&mut self, ready: bool) {
+        self.ready = ready;
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.ready
+    }
+
+    /// Hands the player over to the server, which answers all remaining tasks from now on.
+    pub fn replace_by_bot(&mut self) {
+        self.bot = true;
+        self.last_active_time = None;
+    }
+
+    pub fn is_bot(&self) -> bool {
+        self.bot
+    }
+
     pub fn to_response(&self) -> PlayerResponse {
         PlayerResponse {
             id: self.id.to_owned(),
@@ -120,6 +248,17 @@ impl Player {
     }
 }
 
+impl From<NameRejection> for proto::player::NameRejection {
+    fn from(rejection: NameRejection) -> Self {
+        match rejection {
+            NameRejection::TooShort => proto::player::NameRejection::TOO_SHORT,
+            NameRejection::TooLong => proto::player::NameRejection::TOO_LONG,
+            NameRejection::InvalidCharacters => proto::player::NameRejection::INVALID_CHARACTERS,
+            NameRejection::AlreadyTaken => proto::player::NameRejection::ALREADY_TAKEN,
+        }
+    }
+}
+
 impl Persist for Player {
     fn id(&self) -> &str {
         self.id()
@@ -144,6 +283,8 @@ impl Into<proto::player::Player> for Player {
         let mut player = proto::player::Player::new();
         player.set_id(self.id);
         player.set_name(self.name);
+        player.set_ready(self.ready);
+        player.set_bot(self.bot);
         player
     }
 }
@@ -155,9 +296,66 @@ impl Into<proto::player::OwnPlayer> for Player {
         player.set_name(self.name);
         let mut open_tasks = RepeatedField::new();
         for t in self.open_tasks {
-            open_tasks.push(t.into());
+            let deadline = self.task_deadlines.get(&t.get_type()).copied();
+            let mut task: proto::task::Task = t.into();
+            if let Some(deadline) = deadline {
+                task.set_deadline(deadline.to_rfc3339());
+            }
+            open_tasks.push(task);
         }
         player.set_open_tasks(open_tasks);
+        player.set_ready(self.ready);
         player
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{NameRejection, Player};
+    use crate::model::{TaskDefinition, TaskType};
+    use chrono::{Duration, Utc};
+
+    #[test]
+    fn should_normalize_names() {
+        assert_eq!(Player::normalize_name("  Tom "), Ok("Tom"));
+        assert_eq!(Player::normalize_name("Zoë O'Neil-2"), Ok("Zoë O'Neil-2"));
+        assert_eq!(Player::normalize_name("   "), Err(NameRejection::TooShort));
+        assert_eq!(Player::normalize_name("T"), Err(NameRejection::TooShort));
+        assert_eq!(
+            Player::normalize_name(&"a".repeat(25)),
+            Err(NameRejection::TooLong)
+        );
+        assert_eq!(
+            Player::normalize_name("Tom\nTim"),
+            Err(NameRejection::InvalidCharacters)
+        );
+        assert_eq!(
+            Player::normalize_name("<b>Tom</b>"),
+            Err(NameRejection::InvalidCharacters)
+        );
+    }
+
+    #[test]
+    fn should_compare_names_ignoring_case() {
+        let mut player = Player::new("GAME");
+        player.set_name("Tom");
+
+        assert!(player.has_name("tom"));
+        assert!(!player.has_name("Tim"));
+    }
+
+    #[test]
+    fn should_only_expire_tasks_with_default_action() {
+        let expired = Utc::now() - Duration::seconds(1);
+        let mut player = Player::new("GAME");
+        player.assign_task_until(TaskDefinition::Settings {}, expired);
+        player.assign_task_until(TaskDefinition::PeekPolicies {}, expired);
+
+        assert_eq!(player.task_deadline(&TaskType::Settings), None);
+        assert!(player.task_deadline(&TaskType::PeekPolicies).is_some());
+        assert!(!player.has_expired_task(&Utc::now()));
+
+        player.resolve_task(TaskType::Settings);
+        assert!(player.has_expired_task(&Utc::now()));
+    }
+}
@@ -0,0 +1,159 @@
+use super::proto::{self};
+use rand::{seq::SliceRandom, SeedableRng};
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+const LOYAL_POLICIES_COUNT: usize = 6;
+const CLAN_POLICIES_COUNT: usize = 11;
+const MIN_DRAW_PILE_COUNT: usize = 3;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Policy {
+    Loyal,
+    Clan,
+}
+
+/// The deck of policies consists of a draw pile and a discard pile.
+/// Shuffling is based on a seeded generator, so the same seed always results in the same order of policies.
+/// The order of the draw pile must never be revealed to the clients, only the sizes of the piles are public.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyDeck {
+    draw_pile: Vec<Policy>,
+    discard_pile: Vec<Policy>,
+    seed: u64,
+    shuffles: u64,
+}
+
+impl PolicyDeck {
+    pub fn new(seed: u64) -> Self {
+        let mut draw_pile = vec![Policy::Loyal; LOYAL_POLICIES_COUNT];
+        draw_pile.extend(vec![Policy::Clan; CLAN_POLICIES_COUNT]);
+
+        let mut deck = PolicyDeck {
+            draw_pile,
+            discard_pile: Vec::with_capacity(LOYAL_POLICIES_COUNT + CLAN_POLICIES_COUNT),
+            seed,
+            shuffles: 0,
+        };
+        deck.shuffle();
+        deck
+    }
+
+    /// Draws up to the given amount of policies from the top of the draw pile.
+    /// The discard pile is shuffled back in as soon as less than three policies remain.
+    pub fn draw(&mut self, count: usize) -> Vec<Policy> {
+        let split_index = self.draw_pile.len().saturating_sub(count);
+        let mut policies = self.draw_pile.split_off(split_index);
+        policies.reverse();
+
+        if self.draw_pile.len() < MIN_DRAW_PILE_COUNT {
+            self.reshuffle();
+        }
+
+        policies
+    }
+
+    /// Returns the policies on top of the draw pile without removing them.
+    pub fn peek(&self, count: usize) -> Vec<Policy> {
+        self.draw_pile.iter().rev().take(count).copied().collect()
+    }
+
+    pub fn discard(&mut self, policy: Policy) {
+        self.discard_pile.push(policy);
+    }
+
+    pub fn draw_pile_count(&self) -> usize {
+        self.draw_pile.len()
+    }
+
+    pub fn discard_pile_count(&self) -> usize {
+        self.discard_pile.len()
+    }
+
+    fn reshuffle(&mut self) {
+        self.draw_pile.append(&mut self.discard_pile);
+        self.shuffle();
+    }
+
+    fn shuffle(&mut self) {
+        let mut rng = Pcg64::seed_from_u64(self.seed.wrapping_add(self.shuffles));
+        self.draw_pile.shuffle(&mut rng);
+        self.shuffles += 1;
+    }
+}
+
+impl From<Policy> for proto::policy::Policy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::Loyal => proto::policy::Policy::LOYAL_POLICY,
+            Policy::Clan => proto::policy::Policy::CLAN_POLICY,
+        }
+    }
+}
+
+impl From<proto::policy::Policy> for Policy {
+    fn from(policy: proto::policy::Policy) -> Self {
+        match policy {
+            proto::policy::Policy::LOYAL_POLICY => Policy::Loyal,
+            proto::policy::Policy::CLAN_POLICY => Policy::Clan,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Policy, PolicyDeck};
+
+    #[test]
+    fn should_create_full_deck() {
+        let deck = PolicyDeck::new(1);
+
+        assert_eq!(deck.draw_pile_count(), 17);
+        assert_eq!(deck.discard_pile_count(), 0);
+        assert_eq!(
+            deck.peek(17)
+                .iter()
+                .filter(|p| **p == Policy::Loyal)
+                .count(),
+            6
+        );
+    }
+
+    #[test]
+    fn should_shuffle_reproducible() {
+        assert_eq!(PolicyDeck::new(42).peek(17), PolicyDeck::new(42).peek(17));
+        assert_ne!(PolicyDeck::new(42).peek(17), PolicyDeck::new(43).peek(17));
+    }
+
+    #[test]
+    fn should_draw_from_top() {
+        let mut deck = PolicyDeck::new(1);
+        let top = deck.peek(3);
+
+        let drawn = deck.draw(3);
+
+        assert_eq!(drawn, top);
+        assert_eq!(deck.draw_pile_count(), 14);
+    }
+
+    #[test]
+    fn should_reshuffle_discards() {
+        let mut deck = PolicyDeck::new(1);
+        for _ in 0..4 {
+            for policy in deck.draw(3) {
+                deck.discard(policy);
+            }
+        }
+        assert_eq!(deck.draw_pile_count(), 5);
+        assert_eq!(deck.discard_pile_count(), 12);
+
+        for policy in deck.draw(3) {
+            deck.discard(policy);
+        }
+
+        assert_eq!(deck.draw_pile_count(), 14);
+        assert_eq!(deck.discard_pile_count(), 3);
+    }
+}
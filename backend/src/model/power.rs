@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+/// A power the president is granted after enacting certain clan policies.
+#[derive(Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ExecutivePower {
+    InvestigateLoyalty,
+    SpecialElection,
+    PolicyPeek,
+    Execution,
+}
+
+/// Returns the power granted by the given count of enacted clan policies, which depends on the amount of players.
+/// The official table covers five to ten players, smaller and bigger games use the closest table.
+pub fn executive_power(player_count: usize, clan_policies_count: usize) -> Option<ExecutivePower> {
+    match (player_count, clan_policies_count) {
+        (_, 4) | (_, 5) => Some(ExecutivePower::Execution),
+        (0..=6, 3) => Some(ExecutivePower::PolicyPeek),
+        (0..=6, _) => None,
+        (_, 3) => Some(ExecutivePower::SpecialElection),
+        (7 | 8, 2) => Some(ExecutivePower::InvestigateLoyalty),
+        (7 | 8, _) => None,
+        (_, 1) | (_, 2) => Some(ExecutivePower::InvestigateLoyalty),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{executive_power, ExecutivePower};
+
+    #[test]
+    fn should_grant_powers_for_small_games() {
+        let powers = (1..=5)
+            .map(|count| executive_power(5, count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            powers,
+            vec![
+                None,
+                None,
+                Some(ExecutivePower::PolicyPeek),
+                Some(ExecutivePower::Execution),
+                Some(ExecutivePower::Execution)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_grant_powers_for_medium_games() {
+        let powers = (1..=5)
+            .map(|count| executive_power(7, count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            powers,
+            vec![
+                None,
+                Some(ExecutivePower::InvestigateLoyalty),
+                Some(ExecutivePower::SpecialElection),
+                Some(ExecutivePower::Execution),
+                Some(ExecutivePower::Execution)
+            ]
+        );
+    }
+
+    #[test]
+    fn should_grant_powers_for_big_games() {
+        let powers = (1..=6)
+            .map(|count| executive_power(10, count))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            powers,
+            vec![
+                Some(ExecutivePower::InvestigateLoyalty),
+                Some(ExecutivePower::InvestigateLoyalty),
+                Some(ExecutivePower::SpecialElection),
+                Some(ExecutivePower::Execution),
+                Some(ExecutivePower::Execution),
+                None
+            ]
+        );
+    }
+}
@@ -28,6 +28,22 @@ pub struct Game {
     // message fields
     pub token: ::std::string::String,
     pub admin_id: ::std::string::String,
+    pub draw_pile_count: u32,
+    pub discard_pile_count: u32,
+    pub seat_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub presidential_candidate_id: ::std::string::String,
+    pub nominated_chancellor_id: ::std::string::String,
+    pub president_id: ::std::string::String,
+    pub chancellor_id: ::std::string::String,
+    pub loyal_policies_count: u32,
+    pub clan_policies_count: u32,
+    pub failed_elections_count: u32,
+    pub executed_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub finished: bool,
+    pub eligible_chancellor_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub settings: ::protobuf::SingularPtrField<super::settings::GameSettings>,
+    pub password_protected: bool,
+    pub locked: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -95,10 +111,347 @@ impl Game {
     pub fn take_admin_id(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.admin_id, ::std::string::String::new())
     }
+
+    // uint32 draw_pile_count = 3;
+
+
+    pub fn get_draw_pile_count(&self) -> u32 {
+        self.draw_pile_count
+    }
+    pub fn clear_draw_pile_count(&mut self) {
+        self.draw_pile_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_draw_pile_count(&mut self, v: u32) {
+        self.draw_pile_count = v;
+    }
+
+    // uint32 discard_pile_count = 4;
+
+
+    pub fn get_discard_pile_count(&self) -> u32 {
+        self.discard_pile_count
+    }
+    pub fn clear_discard_pile_count(&mut self) {
+        self.discard_pile_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_discard_pile_count(&mut self, v: u32) {
+        self.discard_pile_count = v;
+    }
+
+    // repeated string seat_ids = 5;
+
+
+    pub fn get_seat_ids(&self) -> &[::std::string::String] {
+        &self.seat_ids
+    }
+    pub fn clear_seat_ids(&mut self) {
+        self.seat_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seat_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.seat_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_seat_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.seat_ids
+    }
+
+    // Take field
+    pub fn take_seat_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.seat_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // string presidential_candidate_id = 6;
+
+
+    pub fn get_presidential_candidate_id(&self) -> &str {
+        &self.presidential_candidate_id
+    }
+    pub fn clear_presidential_candidate_id(&mut self) {
+        self.presidential_candidate_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_presidential_candidate_id(&mut self, v: ::std::string::String) {
+        self.presidential_candidate_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_presidential_candidate_id(&mut self) -> &mut ::std::string::String {
+        &mut self.presidential_candidate_id
+    }
+
+    // Take field
+    pub fn take_presidential_candidate_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.presidential_candidate_id, ::std::string::String::new())
+    }
+
+    // string nominated_chancellor_id = 7;
+
+
+    pub fn get_nominated_chancellor_id(&self) -> &str {
+        &self.nominated_chancellor_id
+    }
+    pub fn clear_nominated_chancellor_id(&mut self) {
+        self.nominated_chancellor_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nominated_chancellor_id(&mut self, v: ::std::string::String) {
+        self.nominated_chancellor_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nominated_chancellor_id(&mut self) -> &mut ::std::string::String {
+        &mut self.nominated_chancellor_id
+    }
+
+    // Take field
+    pub fn take_nominated_chancellor_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.nominated_chancellor_id, ::std::string::String::new())
+    }
+
+    // string president_id = 8;
+
+
+    pub fn get_president_id(&self) -> &str {
+        &self.president_id
+    }
+    pub fn clear_president_id(&mut self) {
+        self.president_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_president_id(&mut self, v: ::std::string::String) {
+        self.president_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_president_id(&mut self) -> &mut ::std::string::String {
+        &mut self.president_id
+    }
+
+    // Take field
+    pub fn take_president_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.president_id, ::std::string::String::new())
+    }
+
+    // string chancellor_id = 9;
+
+
+    pub fn get_chancellor_id(&self) -> &str {
+        &self.chancellor_id
+    }
+    pub fn clear_chancellor_id(&mut self) {
+        self.chancellor_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chancellor_id(&mut self, v: ::std::string::String) {
+        self.chancellor_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chancellor_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chancellor_id
+    }
+
+    // Take field
+    pub fn take_chancellor_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chancellor_id, ::std::string::String::new())
+    }
+
+    // uint32 loyal_policies_count = 10;
+
+
+    pub fn get_loyal_policies_count(&self) -> u32 {
+        self.loyal_policies_count
+    }
+    pub fn clear_loyal_policies_count(&mut self) {
+        self.loyal_policies_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_loyal_policies_count(&mut self, v: u32) {
+        self.loyal_policies_count = v;
+    }
+
+    // uint32 clan_policies_count = 11;
+
+
+    pub fn get_clan_policies_count(&self) -> u32 {
+        self.clan_policies_count
+    }
+    pub fn clear_clan_policies_count(&mut self) {
+        self.clan_policies_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_policies_count(&mut self, v: u32) {
+        self.clan_policies_count = v;
+    }
+
+    // uint32 failed_elections_count = 12;
+
+
+    pub fn get_failed_elections_count(&self) -> u32 {
+        self.failed_elections_count
+    }
+    pub fn clear_failed_elections_count(&mut self) {
+        self.failed_elections_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_failed_elections_count(&mut self, v: u32) {
+        self.failed_elections_count = v;
+    }
+
+    // repeated string executed_player_ids = 13;
+
+
+    pub fn get_executed_player_ids(&self) -> &[::std::string::String] {
+        &self.executed_player_ids
+    }
+    pub fn clear_executed_player_ids(&mut self) {
+        self.executed_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executed_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.executed_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_executed_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.executed_player_ids
+    }
+
+    // Take field
+    pub fn take_executed_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.executed_player_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // bool finished = 14;
+
+
+    pub fn get_finished(&self) -> bool {
+        self.finished
+    }
+    pub fn clear_finished(&mut self) {
+        self.finished = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_finished(&mut self, v: bool) {
+        self.finished = v;
+    }
+
+    // repeated string eligible_chancellor_ids = 15;
+
+
+    pub fn get_eligible_chancellor_ids(&self) -> &[::std::string::String] {
+        &self.eligible_chancellor_ids
+    }
+    pub fn clear_eligible_chancellor_ids(&mut self) {
+        self.eligible_chancellor_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eligible_chancellor_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.eligible_chancellor_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eligible_chancellor_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.eligible_chancellor_ids
+    }
+
+    // Take field
+    pub fn take_eligible_chancellor_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.eligible_chancellor_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // .GameSettings settings = 16;
+
+
+    pub fn get_settings(&self) -> &super::settings::GameSettings {
+        self.settings.as_ref().unwrap_or_else(|| <super::settings::GameSettings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_settings(&mut self) {
+        self.settings.clear();
+    }
+
+    pub fn has_settings(&self) -> bool {
+        self.settings.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: super::settings::GameSettings) {
+        self.settings = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_settings(&mut self) -> &mut super::settings::GameSettings {
+        if self.settings.is_none() {
+            self.settings.set_default();
+        }
+        self.settings.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> super::settings::GameSettings {
+        self.settings.take().unwrap_or_else(|| super::settings::GameSettings::new())
+    }
+
+    // bool password_protected = 17;
+
+
+    pub fn get_password_protected(&self) -> bool {
+        self.password_protected
+    }
+    pub fn clear_password_protected(&mut self) {
+        self.password_protected = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_protected(&mut self, v: bool) {
+        self.password_protected = v;
+    }
+
+    // bool locked = 18;
+
+
+    pub fn get_locked(&self) -> bool {
+        self.locked
+    }
+    pub fn clear_locked(&mut self) {
+        self.locked = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locked(&mut self, v: bool) {
+        self.locked = v;
+    }
 }
 
 impl ::protobuf::Message for Game {
     fn is_initialized(&self) -> bool {
+        for v in &self.settings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -112,6 +465,86 @@ impl ::protobuf::Message for Game {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.admin_id)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.draw_pile_count = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.discard_pile_count = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.seat_ids)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.presidential_candidate_id)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.nominated_chancellor_id)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.president_id)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chancellor_id)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.loyal_policies_count = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.clan_policies_count = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.failed_elections_count = tmp;
+                },
+                13 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.executed_player_ids)?;
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.finished = tmp;
+                },
+                15 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.eligible_chancellor_ids)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.settings)?;
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.password_protected = tmp;
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.locked = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -130,6 +563,55 @@ impl ::protobuf::Message for Game {
         if !self.admin_id.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.admin_id);
         }
+        if self.draw_pile_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.draw_pile_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.discard_pile_count != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.discard_pile_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.seat_ids {
+            my_size += ::protobuf::rt::string_size(5, &value);
+        };
+        if !self.presidential_candidate_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.presidential_candidate_id);
+        }
+        if !self.nominated_chancellor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.nominated_chancellor_id);
+        }
+        if !self.president_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.president_id);
+        }
+        if !self.chancellor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.chancellor_id);
+        }
+        if self.loyal_policies_count != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.loyal_policies_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.clan_policies_count != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.clan_policies_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.failed_elections_count != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.failed_elections_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.executed_player_ids {
+            my_size += ::protobuf::rt::string_size(13, &value);
+        };
+        if self.finished != false {
+            my_size += 2;
+        }
+        for value in &self.eligible_chancellor_ids {
+            my_size += ::protobuf::rt::string_size(15, &value);
+        };
+        if let Some(ref v) = self.settings.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.password_protected != false {
+            my_size += 3;
+        }
+        if self.locked != false {
+            my_size += 3;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -142,6 +624,56 @@ impl ::protobuf::Message for Game {
         if !self.admin_id.is_empty() {
             os.write_string(2, &self.admin_id)?;
         }
+        if self.draw_pile_count != 0 {
+            os.write_uint32(3, self.draw_pile_count)?;
+        }
+        if self.discard_pile_count != 0 {
+            os.write_uint32(4, self.discard_pile_count)?;
+        }
+        for v in &self.seat_ids {
+            os.write_string(5, &v)?;
+        };
+        if !self.presidential_candidate_id.is_empty() {
+            os.write_string(6, &self.presidential_candidate_id)?;
+        }
+        if !self.nominated_chancellor_id.is_empty() {
+            os.write_string(7, &self.nominated_chancellor_id)?;
+        }
+        if !self.president_id.is_empty() {
+            os.write_string(8, &self.president_id)?;
+        }
+        if !self.chancellor_id.is_empty() {
+            os.write_string(9, &self.chancellor_id)?;
+        }
+        if self.loyal_policies_count != 0 {
+            os.write_uint32(10, self.loyal_policies_count)?;
+        }
+        if self.clan_policies_count != 0 {
+            os.write_uint32(11, self.clan_policies_count)?;
+        }
+        if self.failed_elections_count != 0 {
+            os.write_uint32(12, self.failed_elections_count)?;
+        }
+        for v in &self.executed_player_ids {
+            os.write_string(13, &v)?;
+        };
+        if self.finished != false {
+            os.write_bool(14, self.finished)?;
+        }
+        for v in &self.eligible_chancellor_ids {
+            os.write_string(15, &v)?;
+        };
+        if let Some(ref v) = self.settings.as_ref() {
+            os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.password_protected != false {
+            os.write_bool(17, self.password_protected)?;
+        }
+        if self.locked != false {
+            os.write_bool(18, self.locked)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -190,6 +722,86 @@ impl ::protobuf::Message for Game {
                 |m: &Game| { &m.admin_id },
                 |m: &mut Game| { &mut m.admin_id },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "draw_pile_count",
+                |m: &Game| { &m.draw_pile_count },
+                |m: &mut Game| { &mut m.draw_pile_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "discard_pile_count",
+                |m: &Game| { &m.discard_pile_count },
+                |m: &mut Game| { &mut m.discard_pile_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "seat_ids",
+                |m: &Game| { &m.seat_ids },
+                |m: &mut Game| { &mut m.seat_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "presidential_candidate_id",
+                |m: &Game| { &m.presidential_candidate_id },
+                |m: &mut Game| { &mut m.presidential_candidate_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "nominated_chancellor_id",
+                |m: &Game| { &m.nominated_chancellor_id },
+                |m: &mut Game| { &mut m.nominated_chancellor_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "president_id",
+                |m: &Game| { &m.president_id },
+                |m: &mut Game| { &mut m.president_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chancellor_id",
+                |m: &Game| { &m.chancellor_id },
+                |m: &mut Game| { &mut m.chancellor_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "loyal_policies_count",
+                |m: &Game| { &m.loyal_policies_count },
+                |m: &mut Game| { &mut m.loyal_policies_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "clan_policies_count",
+                |m: &Game| { &m.clan_policies_count },
+                |m: &mut Game| { &mut m.clan_policies_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "failed_elections_count",
+                |m: &Game| { &m.failed_elections_count },
+                |m: &mut Game| { &mut m.failed_elections_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "executed_player_ids",
+                |m: &Game| { &m.executed_player_ids },
+                |m: &mut Game| { &mut m.executed_player_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "finished",
+                |m: &Game| { &m.finished },
+                |m: &mut Game| { &mut m.finished },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "eligible_chancellor_ids",
+                |m: &Game| { &m.eligible_chancellor_ids },
+                |m: &mut Game| { &mut m.eligible_chancellor_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::settings::GameSettings>>(
+                "settings",
+                |m: &Game| { &m.settings },
+                |m: &mut Game| { &mut m.settings },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "password_protected",
+                |m: &Game| { &m.password_protected },
+                |m: &mut Game| { &mut m.password_protected },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "locked",
+                |m: &Game| { &m.locked },
+                |m: &mut Game| { &mut m.locked },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Game>(
                 "Game",
                 fields,
@@ -208,6 +820,22 @@ impl ::protobuf::Clear for Game {
     fn clear(&mut self) {
         self.token.clear();
         self.admin_id.clear();
+        self.draw_pile_count = 0;
+        self.discard_pile_count = 0;
+        self.seat_ids.clear();
+        self.presidential_candidate_id.clear();
+        self.nominated_chancellor_id.clear();
+        self.president_id.clear();
+        self.chancellor_id.clear();
+        self.loyal_policies_count = 0;
+        self.clan_policies_count = 0;
+        self.failed_elections_count = 0;
+        self.executed_player_ids.clear();
+        self.finished = false;
+        self.eligible_chancellor_ids.clear();
+        self.settings.clear();
+        self.password_protected = false;
+        self.locked = false;
         self.unknown_fields.clear();
     }
 }
@@ -224,9 +852,148 @@ impl ::protobuf::reflect::ProtobufValue for Game {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum WinReason {
+    LOYAL_POLICIES_ENACTED = 0,
+    CLAN_POLICIES_ENACTED = 1,
+    CLAN_LEADER_EXECUTED = 2,
+    CLAN_LEADER_ELECTED = 3,
+}
+
+impl ::protobuf::ProtobufEnum for WinReason {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<WinReason> {
+        match value {
+            0 => ::std::option::Option::Some(WinReason::LOYAL_POLICIES_ENACTED),
+            1 => ::std::option::Option::Some(WinReason::CLAN_POLICIES_ENACTED),
+            2 => ::std::option::Option::Some(WinReason::CLAN_LEADER_EXECUTED),
+            3 => ::std::option::Option::Some(WinReason::CLAN_LEADER_ELECTED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [WinReason] = &[
+            WinReason::LOYAL_POLICIES_ENACTED,
+            WinReason::CLAN_POLICIES_ENACTED,
+            WinReason::CLAN_LEADER_EXECUTED,
+            WinReason::CLAN_LEADER_ELECTED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<WinReason>("WinReason", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for WinReason {
+}
+
+impl ::std::default::Default for WinReason {
+    fn default() -> Self {
+        WinReason::LOYAL_POLICIES_ENACTED
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WinReason {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Phase {
+    LOBBY = 0,
+    NOMINATION = 1,
+    ELECTION = 2,
+    LEGISLATIVE_SESSION = 3,
+    EXECUTIVE_ACTION = 4,
+    FINISHED = 5,
+}
+
+impl ::protobuf::ProtobufEnum for Phase {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Phase> {
+        match value {
+            0 => ::std::option::Option::Some(Phase::LOBBY),
+            1 => ::std::option::Option::Some(Phase::NOMINATION),
+            2 => ::std::option::Option::Some(Phase::ELECTION),
+            3 => ::std::option::Option::Some(Phase::LEGISLATIVE_SESSION),
+            4 => ::std::option::Option::Some(Phase::EXECUTIVE_ACTION),
+            5 => ::std::option::Option::Some(Phase::FINISHED),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Phase] = &[
+            Phase::LOBBY,
+            Phase::NOMINATION,
+            Phase::ELECTION,
+            Phase::LEGISLATIVE_SESSION,
+            Phase::EXECUTIVE_ACTION,
+            Phase::FINISHED,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Phase>("Phase", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Phase {
+}
+
+impl ::std::default::Default for Phase {
+    fn default() -> Self {
+        Phase::LOBBY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Phase {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\ngame.proto\"7\n\x04Game\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05to\
-    ken\x12\x19\n\x08admin_id\x18\x02\x20\x01(\tR\x07adminIdb\x06proto3\
+    \n\ngame.proto\x1a\x0esettings.proto\"\xf2\x05\n\x04Game\x12\x14\n\x05to\
+    ken\x18\x01\x20\x01(\tR\x05token\x12\x19\n\x08admin_id\x18\x02\x20\x01(\
+    \tR\x07adminId\x12&\n\x0fdraw_pile_count\x18\x03\x20\x01(\rR\rdrawPileCo\
+    unt\x12,\n\x12discard_pile_count\x18\x04\x20\x01(\rR\x10discardPileCount\
+    \x12\x19\n\x08seat_ids\x18\x05\x20\x03(\tR\x07seatIds\x12:\n\x19presiden\
+    tial_candidate_id\x18\x06\x20\x01(\tR\x17presidentialCandidateId\x126\n\
+    \x17nominated_chancellor_id\x18\x07\x20\x01(\tR\x15nominatedChancellorId\
+    \x12!\n\x0cpresident_id\x18\x08\x20\x01(\tR\x0bpresidentId\x12#\n\rchanc\
+    ellor_id\x18\t\x20\x01(\tR\x0cchancellorId\x120\n\x14loyal_policies_coun\
+    t\x18\n\x20\x01(\rR\x12loyalPoliciesCount\x12.\n\x13clan_policies_count\
+    \x18\x0b\x20\x01(\rR\x11clanPoliciesCount\x124\n\x16failed_elections_cou\
+    nt\x18\x0c\x20\x01(\rR\x14failedElectionsCount\x12.\n\x13executed_player\
+    _ids\x18\r\x20\x03(\tR\x11executedPlayerIds\x12\x1a\n\x08finished\x18\
+    \x0e\x20\x01(\x08R\x08finished\x126\n\x17eligible_chancellor_ids\x18\x0f\
+    \x20\x03(\tR\x15eligibleChancellorIds\x12)\n\x08settings\x18\x10\x20\x01\
+    (\x0b2\r.GameSettingsR\x08settings\x12-\n\x12password_protected\x18\x11\
+    \x20\x01(\x08R\x11passwordProtected\x12\x16\n\x06locked\x18\x12\x20\x01(\
+    \x08R\x06locked*u\n\tWinReason\x12\x1a\n\x16LOYAL_POLICIES_ENACTED\x10\0\
+    \x12\x19\n\x15CLAN_POLICIES_ENACTED\x10\x01\x12\x18\n\x14CLAN_LEADER_EXE\
+    CUTED\x10\x02\x12\x17\n\x13CLAN_LEADER_ELECTED\x10\x03*m\n\x05Phase\x12\
+    \t\n\x05LOBBY\x10\0\x12\x0e\n\nNOMINATION\x10\x01\x12\x0c\n\x08ELECTION\
+    \x10\x02\x12\x17\n\x13LEGISLATIVE_SESSION\x10\x03\x12\x14\n\x10EXECUTIVE\
+    _ACTION\x10\x04\x12\x0c\n\x08FINISHED\x10\x05b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
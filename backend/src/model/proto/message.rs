@@ -42,6 +42,20 @@ impl<'a> ::std::default::Default for &'a Client {
 pub enum Client_oneof_message {
     authConfirmed(Client_AuthConfirmed),
     nameUpdated(Client_NameUpdated),
+    roleDiscovered(Client_RoleDiscovered),
+    chancellorNominated(Client_ChancellorNominated),
+    voteCast(Client_VoteCast),
+    policyDiscarded(Client_PolicyDiscarded),
+    policyEnacted(Client_PolicyEnacted),
+    loyaltyInvestigated(Client_LoyaltyInvestigated),
+    specialElectionCalled(Client_SpecialElectionCalled),
+    policiesPeeked(Client_PoliciesPeeked),
+    playerExecuted(Client_PlayerExecuted),
+    gameSettingsUpdated(Client_GameSettingsUpdated),
+    chatMessage(Client_ChatMessage),
+    readyChanged(Client_ReadyChanged),
+    vetoProposed(Client_VetoProposed),
+    vetoAnswered(Client_VetoAnswered),
 }
 
 impl Client {
@@ -146,20 +160,6695 @@ impl Client {
             Client_NameUpdated::new()
         }
     }
+
+    // .Client.RoleDiscovered roleDiscovered = 3;
+
+
+    pub fn get_roleDiscovered(&self) -> &Client_RoleDiscovered {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::roleDiscovered(ref v)) => v,
+            _ => <Client_RoleDiscovered as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_roleDiscovered(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_roleDiscovered(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::roleDiscovered(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roleDiscovered(&mut self, v: Client_RoleDiscovered) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::roleDiscovered(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_roleDiscovered(&mut self) -> &mut Client_RoleDiscovered {
+        if let ::std::option::Option::Some(Client_oneof_message::roleDiscovered(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::roleDiscovered(Client_RoleDiscovered::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::roleDiscovered(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_roleDiscovered(&mut self) -> Client_RoleDiscovered {
+        if self.has_roleDiscovered() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::roleDiscovered(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_RoleDiscovered::new()
+        }
+    }
+
+    // .Client.ChancellorNominated chancellorNominated = 4;
+
+
+    pub fn get_chancellorNominated(&self) -> &Client_ChancellorNominated {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chancellorNominated(ref v)) => v,
+            _ => <Client_ChancellorNominated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_chancellorNominated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_chancellorNominated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chancellorNominated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chancellorNominated(&mut self, v: Client_ChancellorNominated) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::chancellorNominated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chancellorNominated(&mut self) -> &mut Client_ChancellorNominated {
+        if let ::std::option::Option::Some(Client_oneof_message::chancellorNominated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::chancellorNominated(Client_ChancellorNominated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chancellorNominated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chancellorNominated(&mut self) -> Client_ChancellorNominated {
+        if self.has_chancellorNominated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::chancellorNominated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_ChancellorNominated::new()
+        }
+    }
+
+    // .Client.VoteCast voteCast = 5;
+
+
+    pub fn get_voteCast(&self) -> &Client_VoteCast {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::voteCast(ref v)) => v,
+            _ => <Client_VoteCast as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_voteCast(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_voteCast(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::voteCast(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voteCast(&mut self, v: Client_VoteCast) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::voteCast(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voteCast(&mut self) -> &mut Client_VoteCast {
+        if let ::std::option::Option::Some(Client_oneof_message::voteCast(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::voteCast(Client_VoteCast::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::voteCast(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_voteCast(&mut self) -> Client_VoteCast {
+        if self.has_voteCast() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::voteCast(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_VoteCast::new()
+        }
+    }
+
+    // .Client.PolicyDiscarded policyDiscarded = 6;
+
+
+    pub fn get_policyDiscarded(&self) -> &Client_PolicyDiscarded {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyDiscarded(ref v)) => v,
+            _ => <Client_PolicyDiscarded as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policyDiscarded(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_policyDiscarded(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyDiscarded(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policyDiscarded(&mut self, v: Client_PolicyDiscarded) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::policyDiscarded(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policyDiscarded(&mut self) -> &mut Client_PolicyDiscarded {
+        if let ::std::option::Option::Some(Client_oneof_message::policyDiscarded(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::policyDiscarded(Client_PolicyDiscarded::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyDiscarded(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policyDiscarded(&mut self) -> Client_PolicyDiscarded {
+        if self.has_policyDiscarded() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::policyDiscarded(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_PolicyDiscarded::new()
+        }
+    }
+
+    // .Client.PolicyEnacted policyEnacted = 7;
+
+
+    pub fn get_policyEnacted(&self) -> &Client_PolicyEnacted {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyEnacted(ref v)) => v,
+            _ => <Client_PolicyEnacted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policyEnacted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_policyEnacted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyEnacted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policyEnacted(&mut self, v: Client_PolicyEnacted) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::policyEnacted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policyEnacted(&mut self) -> &mut Client_PolicyEnacted {
+        if let ::std::option::Option::Some(Client_oneof_message::policyEnacted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::policyEnacted(Client_PolicyEnacted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policyEnacted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policyEnacted(&mut self) -> Client_PolicyEnacted {
+        if self.has_policyEnacted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::policyEnacted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_PolicyEnacted::new()
+        }
+    }
+
+    // .Client.LoyaltyInvestigated loyaltyInvestigated = 8;
+
+
+    pub fn get_loyaltyInvestigated(&self) -> &Client_LoyaltyInvestigated {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(ref v)) => v,
+            _ => <Client_LoyaltyInvestigated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_loyaltyInvestigated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_loyaltyInvestigated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_loyaltyInvestigated(&mut self, v: Client_LoyaltyInvestigated) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_loyaltyInvestigated(&mut self) -> &mut Client_LoyaltyInvestigated {
+        if let ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(Client_LoyaltyInvestigated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_loyaltyInvestigated(&mut self) -> Client_LoyaltyInvestigated {
+        if self.has_loyaltyInvestigated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_LoyaltyInvestigated::new()
+        }
+    }
+
+    // .Client.SpecialElectionCalled specialElectionCalled = 9;
+
+
+    pub fn get_specialElectionCalled(&self) -> &Client_SpecialElectionCalled {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(ref v)) => v,
+            _ => <Client_SpecialElectionCalled as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_specialElectionCalled(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_specialElectionCalled(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_specialElectionCalled(&mut self, v: Client_SpecialElectionCalled) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_specialElectionCalled(&mut self) -> &mut Client_SpecialElectionCalled {
+        if let ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(Client_SpecialElectionCalled::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_specialElectionCalled(&mut self) -> Client_SpecialElectionCalled {
+        if self.has_specialElectionCalled() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_SpecialElectionCalled::new()
+        }
+    }
+
+    // .Client.PoliciesPeeked policiesPeeked = 10;
+
+
+    pub fn get_policiesPeeked(&self) -> &Client_PoliciesPeeked {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policiesPeeked(ref v)) => v,
+            _ => <Client_PoliciesPeeked as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policiesPeeked(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_policiesPeeked(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policiesPeeked(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policiesPeeked(&mut self, v: Client_PoliciesPeeked) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::policiesPeeked(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policiesPeeked(&mut self) -> &mut Client_PoliciesPeeked {
+        if let ::std::option::Option::Some(Client_oneof_message::policiesPeeked(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::policiesPeeked(Client_PoliciesPeeked::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::policiesPeeked(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policiesPeeked(&mut self) -> Client_PoliciesPeeked {
+        if self.has_policiesPeeked() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::policiesPeeked(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_PoliciesPeeked::new()
+        }
+    }
+
+    // .Client.PlayerExecuted playerExecuted = 11;
+
+
+    pub fn get_playerExecuted(&self) -> &Client_PlayerExecuted {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::playerExecuted(ref v)) => v,
+            _ => <Client_PlayerExecuted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerExecuted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_playerExecuted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::playerExecuted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerExecuted(&mut self, v: Client_PlayerExecuted) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::playerExecuted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerExecuted(&mut self) -> &mut Client_PlayerExecuted {
+        if let ::std::option::Option::Some(Client_oneof_message::playerExecuted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::playerExecuted(Client_PlayerExecuted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::playerExecuted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerExecuted(&mut self) -> Client_PlayerExecuted {
+        if self.has_playerExecuted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::playerExecuted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_PlayerExecuted::new()
+        }
+    }
+
+    // .Client.GameSettingsUpdated gameSettingsUpdated = 12;
+
+
+    pub fn get_gameSettingsUpdated(&self) -> &Client_GameSettingsUpdated {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(ref v)) => v,
+            _ => <Client_GameSettingsUpdated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameSettingsUpdated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_gameSettingsUpdated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameSettingsUpdated(&mut self, v: Client_GameSettingsUpdated) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameSettingsUpdated(&mut self) -> &mut Client_GameSettingsUpdated {
+        if let ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(Client_GameSettingsUpdated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameSettingsUpdated(&mut self) -> Client_GameSettingsUpdated {
+        if self.has_gameSettingsUpdated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_GameSettingsUpdated::new()
+        }
+    }
+
+    // .Client.ChatMessage chatMessage = 13;
+
+
+    pub fn get_chatMessage(&self) -> &Client_ChatMessage {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chatMessage(ref v)) => v,
+            _ => <Client_ChatMessage as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_chatMessage(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_chatMessage(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chatMessage(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chatMessage(&mut self, v: Client_ChatMessage) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::chatMessage(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chatMessage(&mut self) -> &mut Client_ChatMessage {
+        if let ::std::option::Option::Some(Client_oneof_message::chatMessage(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::chatMessage(Client_ChatMessage::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::chatMessage(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chatMessage(&mut self) -> Client_ChatMessage {
+        if self.has_chatMessage() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::chatMessage(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_ChatMessage::new()
+        }
+    }
+
+    // .Client.ReadyChanged readyChanged = 14;
+
+
+    pub fn get_readyChanged(&self) -> &Client_ReadyChanged {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::readyChanged(ref v)) => v,
+            _ => <Client_ReadyChanged as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_readyChanged(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_readyChanged(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::readyChanged(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_readyChanged(&mut self, v: Client_ReadyChanged) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::readyChanged(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_readyChanged(&mut self) -> &mut Client_ReadyChanged {
+        if let ::std::option::Option::Some(Client_oneof_message::readyChanged(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::readyChanged(Client_ReadyChanged::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::readyChanged(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_readyChanged(&mut self) -> Client_ReadyChanged {
+        if self.has_readyChanged() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::readyChanged(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_ReadyChanged::new()
+        }
+    }
+
+    // .Client.VetoProposed vetoProposed = 15;
+
+
+    pub fn get_vetoProposed(&self) -> &Client_VetoProposed {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoProposed(ref v)) => v,
+            _ => <Client_VetoProposed as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_vetoProposed(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_vetoProposed(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoProposed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vetoProposed(&mut self, v: Client_VetoProposed) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::vetoProposed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vetoProposed(&mut self) -> &mut Client_VetoProposed {
+        if let ::std::option::Option::Some(Client_oneof_message::vetoProposed(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::vetoProposed(Client_VetoProposed::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoProposed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_vetoProposed(&mut self) -> Client_VetoProposed {
+        if self.has_vetoProposed() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::vetoProposed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_VetoProposed::new()
+        }
+    }
+
+    // .Client.VetoAnswered vetoAnswered = 16;
+
+
+    pub fn get_vetoAnswered(&self) -> &Client_VetoAnswered {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoAnswered(ref v)) => v,
+            _ => <Client_VetoAnswered as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_vetoAnswered(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_vetoAnswered(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoAnswered(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vetoAnswered(&mut self, v: Client_VetoAnswered) {
+        self.message = ::std::option::Option::Some(Client_oneof_message::vetoAnswered(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vetoAnswered(&mut self) -> &mut Client_VetoAnswered {
+        if let ::std::option::Option::Some(Client_oneof_message::vetoAnswered(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Client_oneof_message::vetoAnswered(Client_VetoAnswered::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Client_oneof_message::vetoAnswered(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_vetoAnswered(&mut self) -> Client_VetoAnswered {
+        if self.has_vetoAnswered() {
+            match self.message.take() {
+                ::std::option::Option::Some(Client_oneof_message::vetoAnswered(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Client_VetoAnswered::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Client {
+    fn is_initialized(&self) -> bool {
+        if let Some(Client_oneof_message::authConfirmed(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::nameUpdated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::roleDiscovered(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::chancellorNominated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::voteCast(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::policyDiscarded(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::policyEnacted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::loyaltyInvestigated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::specialElectionCalled(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::policiesPeeked(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::playerExecuted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::gameSettingsUpdated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::chatMessage(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::readyChanged(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::vetoProposed(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Client_oneof_message::vetoAnswered(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::authConfirmed(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::nameUpdated(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::roleDiscovered(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::chancellorNominated(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::voteCast(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::policyDiscarded(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::policyEnacted(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::loyaltyInvestigated(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::specialElectionCalled(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::policiesPeeked(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::playerExecuted(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::gameSettingsUpdated(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::chatMessage(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::readyChanged(is.read_message()?));
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::vetoProposed(is.read_message()?));
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Client_oneof_message::vetoAnswered(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &Client_oneof_message::authConfirmed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::nameUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::roleDiscovered(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::chancellorNominated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::voteCast(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::policyDiscarded(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::policyEnacted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::loyaltyInvestigated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::specialElectionCalled(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::policiesPeeked(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::playerExecuted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::gameSettingsUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::chatMessage(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::readyChanged(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::vetoProposed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Client_oneof_message::vetoAnswered(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &Client_oneof_message::authConfirmed(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::nameUpdated(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::roleDiscovered(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::chancellorNominated(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::voteCast(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::policyDiscarded(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::policyEnacted(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::loyaltyInvestigated(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::specialElectionCalled(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::policiesPeeked(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::playerExecuted(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::gameSettingsUpdated(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::chatMessage(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::readyChanged(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::vetoProposed(ref v) => {
+                    os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Client_oneof_message::vetoAnswered(ref v) => {
+                    os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client {
+        Client::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_AuthConfirmed>(
+                "authConfirmed",
+                Client::has_authConfirmed,
+                Client::get_authConfirmed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_NameUpdated>(
+                "nameUpdated",
+                Client::has_nameUpdated,
+                Client::get_nameUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_RoleDiscovered>(
+                "roleDiscovered",
+                Client::has_roleDiscovered,
+                Client::get_roleDiscovered,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_ChancellorNominated>(
+                "chancellorNominated",
+                Client::has_chancellorNominated,
+                Client::get_chancellorNominated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_VoteCast>(
+                "voteCast",
+                Client::has_voteCast,
+                Client::get_voteCast,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_PolicyDiscarded>(
+                "policyDiscarded",
+                Client::has_policyDiscarded,
+                Client::get_policyDiscarded,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_PolicyEnacted>(
+                "policyEnacted",
+                Client::has_policyEnacted,
+                Client::get_policyEnacted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_LoyaltyInvestigated>(
+                "loyaltyInvestigated",
+                Client::has_loyaltyInvestigated,
+                Client::get_loyaltyInvestigated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_SpecialElectionCalled>(
+                "specialElectionCalled",
+                Client::has_specialElectionCalled,
+                Client::get_specialElectionCalled,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_PoliciesPeeked>(
+                "policiesPeeked",
+                Client::has_policiesPeeked,
+                Client::get_policiesPeeked,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_PlayerExecuted>(
+                "playerExecuted",
+                Client::has_playerExecuted,
+                Client::get_playerExecuted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_GameSettingsUpdated>(
+                "gameSettingsUpdated",
+                Client::has_gameSettingsUpdated,
+                Client::get_gameSettingsUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_ChatMessage>(
+                "chatMessage",
+                Client::has_chatMessage,
+                Client::get_chatMessage,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_ReadyChanged>(
+                "readyChanged",
+                Client::has_readyChanged,
+                Client::get_readyChanged,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_VetoProposed>(
+                "vetoProposed",
+                Client::has_vetoProposed,
+                Client::get_vetoProposed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_VetoAnswered>(
+                "vetoAnswered",
+                Client::has_vetoAnswered,
+                Client::get_vetoAnswered,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client>(
+                "Client",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client {
+        static instance: ::protobuf::rt::LazyV2<Client> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client::new)
+    }
+}
+
+impl ::protobuf::Clear for Client {
+    fn clear(&mut self) {
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_AuthConfirmed {
+    // message fields
+    pub token: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_AuthConfirmed {
+    fn default() -> &'a Client_AuthConfirmed {
+        <Client_AuthConfirmed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_AuthConfirmed {
+    pub fn new() -> Client_AuthConfirmed {
+        ::std::default::Default::default()
+    }
+
+    // string token = 1;
+
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_AuthConfirmed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.token);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.token.is_empty() {
+            os.write_string(1, &self.token)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_AuthConfirmed {
+        Client_AuthConfirmed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "token",
+                |m: &Client_AuthConfirmed| { &m.token },
+                |m: &mut Client_AuthConfirmed| { &mut m.token },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_AuthConfirmed>(
+                "Client.AuthConfirmed",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_AuthConfirmed {
+        static instance: ::protobuf::rt::LazyV2<Client_AuthConfirmed> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_AuthConfirmed::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_AuthConfirmed {
+    fn clear(&mut self) {
+        self.token.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_AuthConfirmed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_AuthConfirmed {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_NameUpdated {
+    // message fields
+    pub name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_NameUpdated {
+    fn default() -> &'a Client_NameUpdated {
+        <Client_NameUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_NameUpdated {
+    pub fn new() -> Client_NameUpdated {
+        ::std::default::Default::default()
+    }
+
+    // string name = 1;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_NameUpdated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_NameUpdated {
+        Client_NameUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Client_NameUpdated| { &m.name },
+                |m: &mut Client_NameUpdated| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_NameUpdated>(
+                "Client.NameUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_NameUpdated {
+        static instance: ::protobuf::rt::LazyV2<Client_NameUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_NameUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_NameUpdated {
+    fn clear(&mut self) {
+        self.name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_NameUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_NameUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_RoleDiscovered {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_RoleDiscovered {
+    fn default() -> &'a Client_RoleDiscovered {
+        <Client_RoleDiscovered as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_RoleDiscovered {
+    pub fn new() -> Client_RoleDiscovered {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Client_RoleDiscovered {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_RoleDiscovered {
+        Client_RoleDiscovered::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_RoleDiscovered>(
+                "Client.RoleDiscovered",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_RoleDiscovered {
+        static instance: ::protobuf::rt::LazyV2<Client_RoleDiscovered> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_RoleDiscovered::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_RoleDiscovered {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_RoleDiscovered {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_RoleDiscovered {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_ChancellorNominated {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_ChancellorNominated {
+    fn default() -> &'a Client_ChancellorNominated {
+        <Client_ChancellorNominated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_ChancellorNominated {
+    pub fn new() -> Client_ChancellorNominated {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_ChancellorNominated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_ChancellorNominated {
+        Client_ChancellorNominated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Client_ChancellorNominated| { &m.player_id },
+                |m: &mut Client_ChancellorNominated| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_ChancellorNominated>(
+                "Client.ChancellorNominated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_ChancellorNominated {
+        static instance: ::protobuf::rt::LazyV2<Client_ChancellorNominated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_ChancellorNominated::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_ChancellorNominated {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_ChancellorNominated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_ChancellorNominated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_VoteCast {
+    // message fields
+    pub approved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_VoteCast {
+    fn default() -> &'a Client_VoteCast {
+        <Client_VoteCast as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_VoteCast {
+    pub fn new() -> Client_VoteCast {
+        ::std::default::Default::default()
+    }
+
+    // bool approved = 1;
+
+
+    pub fn get_approved(&self) -> bool {
+        self.approved
+    }
+    pub fn clear_approved(&mut self) {
+        self.approved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved(&mut self, v: bool) {
+        self.approved = v;
+    }
+}
+
+impl ::protobuf::Message for Client_VoteCast {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.approved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.approved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.approved != false {
+            os.write_bool(1, self.approved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_VoteCast {
+        Client_VoteCast::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "approved",
+                |m: &Client_VoteCast| { &m.approved },
+                |m: &mut Client_VoteCast| { &mut m.approved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_VoteCast>(
+                "Client.VoteCast",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_VoteCast {
+        static instance: ::protobuf::rt::LazyV2<Client_VoteCast> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_VoteCast::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_VoteCast {
+    fn clear(&mut self) {
+        self.approved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_VoteCast {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_VoteCast {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_PolicyDiscarded {
+    // message fields
+    pub policy: super::policy::Policy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_PolicyDiscarded {
+    fn default() -> &'a Client_PolicyDiscarded {
+        <Client_PolicyDiscarded as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_PolicyDiscarded {
+    pub fn new() -> Client_PolicyDiscarded {
+        ::std::default::Default::default()
+    }
+
+    // .Policy policy = 1;
+
+
+    pub fn get_policy(&self) -> super::policy::Policy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: super::policy::Policy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for Client_PolicyDiscarded {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            my_size += ::protobuf::rt::enum_size(1, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_PolicyDiscarded {
+        Client_PolicyDiscarded::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policy",
+                |m: &Client_PolicyDiscarded| { &m.policy },
+                |m: &mut Client_PolicyDiscarded| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_PolicyDiscarded>(
+                "Client.PolicyDiscarded",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_PolicyDiscarded {
+        static instance: ::protobuf::rt::LazyV2<Client_PolicyDiscarded> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_PolicyDiscarded::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_PolicyDiscarded {
+    fn clear(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_PolicyDiscarded {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_PolicyDiscarded {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_PolicyEnacted {
+    // message fields
+    pub policy: super::policy::Policy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_PolicyEnacted {
+    fn default() -> &'a Client_PolicyEnacted {
+        <Client_PolicyEnacted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_PolicyEnacted {
+    pub fn new() -> Client_PolicyEnacted {
+        ::std::default::Default::default()
+    }
+
+    // .Policy policy = 1;
+
+
+    pub fn get_policy(&self) -> super::policy::Policy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: super::policy::Policy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for Client_PolicyEnacted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            my_size += ::protobuf::rt::enum_size(1, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_PolicyEnacted {
+        Client_PolicyEnacted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policy",
+                |m: &Client_PolicyEnacted| { &m.policy },
+                |m: &mut Client_PolicyEnacted| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_PolicyEnacted>(
+                "Client.PolicyEnacted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_PolicyEnacted {
+        static instance: ::protobuf::rt::LazyV2<Client_PolicyEnacted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_PolicyEnacted::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_PolicyEnacted {
+    fn clear(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_PolicyEnacted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_PolicyEnacted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_LoyaltyInvestigated {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_LoyaltyInvestigated {
+    fn default() -> &'a Client_LoyaltyInvestigated {
+        <Client_LoyaltyInvestigated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_LoyaltyInvestigated {
+    pub fn new() -> Client_LoyaltyInvestigated {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_LoyaltyInvestigated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_LoyaltyInvestigated {
+        Client_LoyaltyInvestigated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Client_LoyaltyInvestigated| { &m.player_id },
+                |m: &mut Client_LoyaltyInvestigated| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_LoyaltyInvestigated>(
+                "Client.LoyaltyInvestigated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_LoyaltyInvestigated {
+        static instance: ::protobuf::rt::LazyV2<Client_LoyaltyInvestigated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_LoyaltyInvestigated::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_LoyaltyInvestigated {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_LoyaltyInvestigated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_LoyaltyInvestigated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_SpecialElectionCalled {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_SpecialElectionCalled {
+    fn default() -> &'a Client_SpecialElectionCalled {
+        <Client_SpecialElectionCalled as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_SpecialElectionCalled {
+    pub fn new() -> Client_SpecialElectionCalled {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_SpecialElectionCalled {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_SpecialElectionCalled {
+        Client_SpecialElectionCalled::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Client_SpecialElectionCalled| { &m.player_id },
+                |m: &mut Client_SpecialElectionCalled| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_SpecialElectionCalled>(
+                "Client.SpecialElectionCalled",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_SpecialElectionCalled {
+        static instance: ::protobuf::rt::LazyV2<Client_SpecialElectionCalled> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_SpecialElectionCalled::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_SpecialElectionCalled {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_SpecialElectionCalled {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_SpecialElectionCalled {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_PoliciesPeeked {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_PoliciesPeeked {
+    fn default() -> &'a Client_PoliciesPeeked {
+        <Client_PoliciesPeeked as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_PoliciesPeeked {
+    pub fn new() -> Client_PoliciesPeeked {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Client_PoliciesPeeked {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_PoliciesPeeked {
+        Client_PoliciesPeeked::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_PoliciesPeeked>(
+                "Client.PoliciesPeeked",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_PoliciesPeeked {
+        static instance: ::protobuf::rt::LazyV2<Client_PoliciesPeeked> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_PoliciesPeeked::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_PoliciesPeeked {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_PoliciesPeeked {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_PoliciesPeeked {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_PlayerExecuted {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_PlayerExecuted {
+    fn default() -> &'a Client_PlayerExecuted {
+        <Client_PlayerExecuted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_PlayerExecuted {
+    pub fn new() -> Client_PlayerExecuted {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_PlayerExecuted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_PlayerExecuted {
+        Client_PlayerExecuted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Client_PlayerExecuted| { &m.player_id },
+                |m: &mut Client_PlayerExecuted| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_PlayerExecuted>(
+                "Client.PlayerExecuted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_PlayerExecuted {
+        static instance: ::protobuf::rt::LazyV2<Client_PlayerExecuted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_PlayerExecuted::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_PlayerExecuted {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_PlayerExecuted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_PlayerExecuted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_GameSettingsUpdated {
+    // message fields
+    pub settings: ::protobuf::SingularPtrField<super::settings::GameSettings>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_GameSettingsUpdated {
+    fn default() -> &'a Client_GameSettingsUpdated {
+        <Client_GameSettingsUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_GameSettingsUpdated {
+    pub fn new() -> Client_GameSettingsUpdated {
+        ::std::default::Default::default()
+    }
+
+    // .GameSettings settings = 1;
+
+
+    pub fn get_settings(&self) -> &super::settings::GameSettings {
+        self.settings.as_ref().unwrap_or_else(|| <super::settings::GameSettings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_settings(&mut self) {
+        self.settings.clear();
+    }
+
+    pub fn has_settings(&self) -> bool {
+        self.settings.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: super::settings::GameSettings) {
+        self.settings = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_settings(&mut self) -> &mut super::settings::GameSettings {
+        if self.settings.is_none() {
+            self.settings.set_default();
+        }
+        self.settings.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> super::settings::GameSettings {
+        self.settings.take().unwrap_or_else(|| super::settings::GameSettings::new())
+    }
+}
+
+impl ::protobuf::Message for Client_GameSettingsUpdated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.settings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.settings)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.settings.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.settings.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_GameSettingsUpdated {
+        Client_GameSettingsUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::settings::GameSettings>>(
+                "settings",
+                |m: &Client_GameSettingsUpdated| { &m.settings },
+                |m: &mut Client_GameSettingsUpdated| { &mut m.settings },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_GameSettingsUpdated>(
+                "Client.GameSettingsUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_GameSettingsUpdated {
+        static instance: ::protobuf::rt::LazyV2<Client_GameSettingsUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_GameSettingsUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_GameSettingsUpdated {
+    fn clear(&mut self) {
+        self.settings.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_GameSettingsUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_GameSettingsUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_ChatMessage {
+    // message fields
+    pub text: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_ChatMessage {
+    fn default() -> &'a Client_ChatMessage {
+        <Client_ChatMessage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_ChatMessage {
+    pub fn new() -> Client_ChatMessage {
+        ::std::default::Default::default()
+    }
+
+    // string text = 1;
+
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
+    }
+
+    // Take field
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Client_ChatMessage {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.text);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.text.is_empty() {
+            os.write_string(1, &self.text)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_ChatMessage {
+        Client_ChatMessage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &Client_ChatMessage| { &m.text },
+                |m: &mut Client_ChatMessage| { &mut m.text },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_ChatMessage>(
+                "Client.ChatMessage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_ChatMessage {
+        static instance: ::protobuf::rt::LazyV2<Client_ChatMessage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_ChatMessage::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_ChatMessage {
+    fn clear(&mut self) {
+        self.text.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_ChatMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_ChatMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_ReadyChanged {
+    // message fields
+    pub ready: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_ReadyChanged {
+    fn default() -> &'a Client_ReadyChanged {
+        <Client_ReadyChanged as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_ReadyChanged {
+    pub fn new() -> Client_ReadyChanged {
+        ::std::default::Default::default()
+    }
+
+    // bool ready = 1;
+
+
+    pub fn get_ready(&self) -> bool {
+        self.ready
+    }
+    pub fn clear_ready(&mut self) {
+        self.ready = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: bool) {
+        self.ready = v;
+    }
+}
+
+impl ::protobuf::Message for Client_ReadyChanged {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ready = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.ready != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.ready != false {
+            os.write_bool(1, self.ready)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_ReadyChanged {
+        Client_ReadyChanged::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "ready",
+                |m: &Client_ReadyChanged| { &m.ready },
+                |m: &mut Client_ReadyChanged| { &mut m.ready },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_ReadyChanged>(
+                "Client.ReadyChanged",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_ReadyChanged {
+        static instance: ::protobuf::rt::LazyV2<Client_ReadyChanged> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_ReadyChanged::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_ReadyChanged {
+    fn clear(&mut self) {
+        self.ready = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_ReadyChanged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_ReadyChanged {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_VetoProposed {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_VetoProposed {
+    fn default() -> &'a Client_VetoProposed {
+        <Client_VetoProposed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_VetoProposed {
+    pub fn new() -> Client_VetoProposed {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Client_VetoProposed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_VetoProposed {
+        Client_VetoProposed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_VetoProposed>(
+                "Client.VetoProposed",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_VetoProposed {
+        static instance: ::protobuf::rt::LazyV2<Client_VetoProposed> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_VetoProposed::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_VetoProposed {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_VetoProposed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_VetoProposed {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Client_VetoAnswered {
+    // message fields
+    pub approved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Client_VetoAnswered {
+    fn default() -> &'a Client_VetoAnswered {
+        <Client_VetoAnswered as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Client_VetoAnswered {
+    pub fn new() -> Client_VetoAnswered {
+        ::std::default::Default::default()
+    }
+
+    // bool approved = 1;
+
+
+    pub fn get_approved(&self) -> bool {
+        self.approved
+    }
+    pub fn clear_approved(&mut self) {
+        self.approved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved(&mut self, v: bool) {
+        self.approved = v;
+    }
+}
+
+impl ::protobuf::Message for Client_VetoAnswered {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.approved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.approved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.approved != false {
+            os.write_bool(1, self.approved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Client_VetoAnswered {
+        Client_VetoAnswered::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "approved",
+                |m: &Client_VetoAnswered| { &m.approved },
+                |m: &mut Client_VetoAnswered| { &mut m.approved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_VetoAnswered>(
+                "Client.VetoAnswered",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Client_VetoAnswered {
+        static instance: ::protobuf::rt::LazyV2<Client_VetoAnswered> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Client_VetoAnswered::new)
+    }
+}
+
+impl ::protobuf::Clear for Client_VetoAnswered {
+    fn clear(&mut self) {
+        self.approved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Client_VetoAnswered {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Client_VetoAnswered {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server {
+    // message oneof groups
+    pub message: ::std::option::Option<Server_oneof_message>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server {
+    fn default() -> &'a Server {
+        <Server as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum Server_oneof_message {
+    playerUpdated(Server_PlayerUpdated),
+    gameUpdated(Server_GameUpdated),
+    selfUpdated(Server_SelfUpdated),
+    playerEntered(Server_PlayerEntered),
+    playerLeft(Server_PlayerLeft),
+    roleAssigned(Server_RoleAssigned),
+    votesCounted(Server_VotesCounted),
+    chaosPolicyEnacted(Server_ChaosPolicyEnacted),
+    loyaltyRevealed(Server_LoyaltyRevealed),
+    policiesRevealed(Server_PoliciesRevealed),
+    specialElectionCalled(Server_SpecialElectionCalled),
+    playerExecuted(Server_PlayerExecuted),
+    gameFinished(Server_GameFinished),
+    snapshot(Server_Snapshot),
+    chatPosted(Server_ChatPosted),
+    nameRejected(Server_NameRejected),
+}
+
+impl Server {
+    pub fn new() -> Server {
+        ::std::default::Default::default()
+    }
+
+    // .Server.PlayerUpdated playerUpdated = 1;
+
+
+    pub fn get_playerUpdated(&self) -> &Server_PlayerUpdated {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerUpdated(ref v)) => v,
+            _ => <Server_PlayerUpdated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerUpdated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_playerUpdated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerUpdated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerUpdated(&mut self, v: Server_PlayerUpdated) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerUpdated(&mut self) -> &mut Server_PlayerUpdated {
+        if let ::std::option::Option::Some(Server_oneof_message::playerUpdated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(Server_PlayerUpdated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerUpdated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerUpdated(&mut self) -> Server_PlayerUpdated {
+        if self.has_playerUpdated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::playerUpdated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_PlayerUpdated::new()
+        }
+    }
+
+    // .Server.GameUpdated gameUpdated = 2;
+
+
+    pub fn get_gameUpdated(&self) -> &Server_GameUpdated {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameUpdated(ref v)) => v,
+            _ => <Server_GameUpdated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameUpdated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_gameUpdated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameUpdated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameUpdated(&mut self, v: Server_GameUpdated) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameUpdated(&mut self) -> &mut Server_GameUpdated {
+        if let ::std::option::Option::Some(Server_oneof_message::gameUpdated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(Server_GameUpdated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameUpdated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameUpdated(&mut self) -> Server_GameUpdated {
+        if self.has_gameUpdated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::gameUpdated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_GameUpdated::new()
+        }
+    }
+
+    // .Server.SelfUpdated selfUpdated = 3;
+
+
+    pub fn get_selfUpdated(&self) -> &Server_SelfUpdated {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::selfUpdated(ref v)) => v,
+            _ => <Server_SelfUpdated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_selfUpdated(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_selfUpdated(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::selfUpdated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_selfUpdated(&mut self, v: Server_SelfUpdated) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_selfUpdated(&mut self) -> &mut Server_SelfUpdated {
+        if let ::std::option::Option::Some(Server_oneof_message::selfUpdated(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(Server_SelfUpdated::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::selfUpdated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_selfUpdated(&mut self) -> Server_SelfUpdated {
+        if self.has_selfUpdated() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::selfUpdated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_SelfUpdated::new()
+        }
+    }
+
+    // .Server.PlayerEntered playerEntered = 4;
+
+
+    pub fn get_playerEntered(&self) -> &Server_PlayerEntered {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerEntered(ref v)) => v,
+            _ => <Server_PlayerEntered as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerEntered(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_playerEntered(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerEntered(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerEntered(&mut self, v: Server_PlayerEntered) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerEntered(&mut self) -> &mut Server_PlayerEntered {
+        if let ::std::option::Option::Some(Server_oneof_message::playerEntered(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(Server_PlayerEntered::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerEntered(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerEntered(&mut self) -> Server_PlayerEntered {
+        if self.has_playerEntered() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::playerEntered(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_PlayerEntered::new()
+        }
+    }
+
+    // .Server.PlayerLeft playerLeft = 5;
+
+
+    pub fn get_playerLeft(&self) -> &Server_PlayerLeft {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerLeft(ref v)) => v,
+            _ => <Server_PlayerLeft as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerLeft(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_playerLeft(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerLeft(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerLeft(&mut self, v: Server_PlayerLeft) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerLeft(&mut self) -> &mut Server_PlayerLeft {
+        if let ::std::option::Option::Some(Server_oneof_message::playerLeft(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(Server_PlayerLeft::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerLeft(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerLeft(&mut self) -> Server_PlayerLeft {
+        if self.has_playerLeft() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::playerLeft(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_PlayerLeft::new()
+        }
+    }
+
+    // .Server.RoleAssigned roleAssigned = 6;
+
+
+    pub fn get_roleAssigned(&self) -> &Server_RoleAssigned {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::roleAssigned(ref v)) => v,
+            _ => <Server_RoleAssigned as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_roleAssigned(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_roleAssigned(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::roleAssigned(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roleAssigned(&mut self, v: Server_RoleAssigned) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::roleAssigned(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_roleAssigned(&mut self) -> &mut Server_RoleAssigned {
+        if let ::std::option::Option::Some(Server_oneof_message::roleAssigned(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::roleAssigned(Server_RoleAssigned::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::roleAssigned(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_roleAssigned(&mut self) -> Server_RoleAssigned {
+        if self.has_roleAssigned() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::roleAssigned(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_RoleAssigned::new()
+        }
+    }
+
+    // .Server.VotesCounted votesCounted = 7;
+
+
+    pub fn get_votesCounted(&self) -> &Server_VotesCounted {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::votesCounted(ref v)) => v,
+            _ => <Server_VotesCounted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_votesCounted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_votesCounted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::votesCounted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_votesCounted(&mut self, v: Server_VotesCounted) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::votesCounted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_votesCounted(&mut self) -> &mut Server_VotesCounted {
+        if let ::std::option::Option::Some(Server_oneof_message::votesCounted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::votesCounted(Server_VotesCounted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::votesCounted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_votesCounted(&mut self) -> Server_VotesCounted {
+        if self.has_votesCounted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::votesCounted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_VotesCounted::new()
+        }
+    }
+
+    // .Server.ChaosPolicyEnacted chaosPolicyEnacted = 8;
+
+
+    pub fn get_chaosPolicyEnacted(&self) -> &Server_ChaosPolicyEnacted {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(ref v)) => v,
+            _ => <Server_ChaosPolicyEnacted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_chaosPolicyEnacted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_chaosPolicyEnacted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chaosPolicyEnacted(&mut self, v: Server_ChaosPolicyEnacted) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chaosPolicyEnacted(&mut self) -> &mut Server_ChaosPolicyEnacted {
+        if let ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(Server_ChaosPolicyEnacted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chaosPolicyEnacted(&mut self) -> Server_ChaosPolicyEnacted {
+        if self.has_chaosPolicyEnacted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_ChaosPolicyEnacted::new()
+        }
+    }
+
+    // .Server.LoyaltyRevealed loyaltyRevealed = 9;
+
+
+    pub fn get_loyaltyRevealed(&self) -> &Server_LoyaltyRevealed {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(ref v)) => v,
+            _ => <Server_LoyaltyRevealed as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_loyaltyRevealed(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_loyaltyRevealed(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_loyaltyRevealed(&mut self, v: Server_LoyaltyRevealed) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_loyaltyRevealed(&mut self) -> &mut Server_LoyaltyRevealed {
+        if let ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(Server_LoyaltyRevealed::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_loyaltyRevealed(&mut self) -> Server_LoyaltyRevealed {
+        if self.has_loyaltyRevealed() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_LoyaltyRevealed::new()
+        }
+    }
+
+    // .Server.PoliciesRevealed policiesRevealed = 10;
+
+
+    pub fn get_policiesRevealed(&self) -> &Server_PoliciesRevealed {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::policiesRevealed(ref v)) => v,
+            _ => <Server_PoliciesRevealed as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policiesRevealed(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_policiesRevealed(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::policiesRevealed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policiesRevealed(&mut self, v: Server_PoliciesRevealed) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::policiesRevealed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policiesRevealed(&mut self) -> &mut Server_PoliciesRevealed {
+        if let ::std::option::Option::Some(Server_oneof_message::policiesRevealed(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::policiesRevealed(Server_PoliciesRevealed::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::policiesRevealed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policiesRevealed(&mut self) -> Server_PoliciesRevealed {
+        if self.has_policiesRevealed() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::policiesRevealed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_PoliciesRevealed::new()
+        }
+    }
+
+    // .Server.SpecialElectionCalled specialElectionCalled = 11;
+
+
+    pub fn get_specialElectionCalled(&self) -> &Server_SpecialElectionCalled {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(ref v)) => v,
+            _ => <Server_SpecialElectionCalled as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_specialElectionCalled(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_specialElectionCalled(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_specialElectionCalled(&mut self, v: Server_SpecialElectionCalled) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_specialElectionCalled(&mut self) -> &mut Server_SpecialElectionCalled {
+        if let ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(Server_SpecialElectionCalled::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_specialElectionCalled(&mut self) -> Server_SpecialElectionCalled {
+        if self.has_specialElectionCalled() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_SpecialElectionCalled::new()
+        }
+    }
+
+    // .Server.PlayerExecuted playerExecuted = 12;
+
+
+    pub fn get_playerExecuted(&self) -> &Server_PlayerExecuted {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerExecuted(ref v)) => v,
+            _ => <Server_PlayerExecuted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerExecuted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_playerExecuted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerExecuted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerExecuted(&mut self, v: Server_PlayerExecuted) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::playerExecuted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerExecuted(&mut self) -> &mut Server_PlayerExecuted {
+        if let ::std::option::Option::Some(Server_oneof_message::playerExecuted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::playerExecuted(Server_PlayerExecuted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::playerExecuted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerExecuted(&mut self) -> Server_PlayerExecuted {
+        if self.has_playerExecuted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::playerExecuted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_PlayerExecuted::new()
+        }
+    }
+
+    // .Server.GameFinished gameFinished = 13;
+
+
+    pub fn get_gameFinished(&self) -> &Server_GameFinished {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameFinished(ref v)) => v,
+            _ => <Server_GameFinished as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameFinished(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_gameFinished(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameFinished(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameFinished(&mut self, v: Server_GameFinished) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::gameFinished(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameFinished(&mut self) -> &mut Server_GameFinished {
+        if let ::std::option::Option::Some(Server_oneof_message::gameFinished(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::gameFinished(Server_GameFinished::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::gameFinished(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameFinished(&mut self) -> Server_GameFinished {
+        if self.has_gameFinished() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::gameFinished(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_GameFinished::new()
+        }
+    }
+
+    // .Server.Snapshot snapshot = 14;
+
+
+    pub fn get_snapshot(&self) -> &Server_Snapshot {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::snapshot(ref v)) => v,
+            _ => <Server_Snapshot as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_snapshot(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_snapshot(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::snapshot(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_snapshot(&mut self, v: Server_Snapshot) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::snapshot(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_snapshot(&mut self) -> &mut Server_Snapshot {
+        if let ::std::option::Option::Some(Server_oneof_message::snapshot(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::snapshot(Server_Snapshot::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::snapshot(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_snapshot(&mut self) -> Server_Snapshot {
+        if self.has_snapshot() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::snapshot(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_Snapshot::new()
+        }
+    }
+
+    // .Server.ChatPosted chatPosted = 15;
+
+
+    pub fn get_chatPosted(&self) -> &Server_ChatPosted {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chatPosted(ref v)) => v,
+            _ => <Server_ChatPosted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_chatPosted(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_chatPosted(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chatPosted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chatPosted(&mut self, v: Server_ChatPosted) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::chatPosted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chatPosted(&mut self) -> &mut Server_ChatPosted {
+        if let ::std::option::Option::Some(Server_oneof_message::chatPosted(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::chatPosted(Server_ChatPosted::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::chatPosted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chatPosted(&mut self) -> Server_ChatPosted {
+        if self.has_chatPosted() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::chatPosted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_ChatPosted::new()
+        }
+    }
+
+    // .Server.NameRejected nameRejected = 16;
+
+
+    pub fn get_nameRejected(&self) -> &Server_NameRejected {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::nameRejected(ref v)) => v,
+            _ => <Server_NameRejected as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_nameRejected(&mut self) {
+        self.message = ::std::option::Option::None;
+    }
+
+    pub fn has_nameRejected(&self) -> bool {
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::nameRejected(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nameRejected(&mut self, v: Server_NameRejected) {
+        self.message = ::std::option::Option::Some(Server_oneof_message::nameRejected(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nameRejected(&mut self) -> &mut Server_NameRejected {
+        if let ::std::option::Option::Some(Server_oneof_message::nameRejected(_)) = self.message {
+        } else {
+            self.message = ::std::option::Option::Some(Server_oneof_message::nameRejected(Server_NameRejected::new()));
+        }
+        match self.message {
+            ::std::option::Option::Some(Server_oneof_message::nameRejected(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_nameRejected(&mut self) -> Server_NameRejected {
+        if self.has_nameRejected() {
+            match self.message.take() {
+                ::std::option::Option::Some(Server_oneof_message::nameRejected(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Server_NameRejected::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for Server {
+    fn is_initialized(&self) -> bool {
+        if let Some(Server_oneof_message::playerUpdated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::gameUpdated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::selfUpdated(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::playerEntered(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::playerLeft(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::roleAssigned(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::votesCounted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::chaosPolicyEnacted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::loyaltyRevealed(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::policiesRevealed(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::specialElectionCalled(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::playerExecuted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::gameFinished(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::snapshot(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::chatPosted(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Server_oneof_message::nameRejected(ref v)) = self.message {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::roleAssigned(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::votesCounted(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::chaosPolicyEnacted(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::loyaltyRevealed(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::policiesRevealed(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::specialElectionCalled(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::playerExecuted(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::gameFinished(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::snapshot(is.read_message()?));
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::chatPosted(is.read_message()?));
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.message = ::std::option::Option::Some(Server_oneof_message::nameRejected(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &Server_oneof_message::playerUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::gameUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::selfUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::playerEntered(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::playerLeft(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::roleAssigned(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::votesCounted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::chaosPolicyEnacted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::loyaltyRevealed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::policiesRevealed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::specialElectionCalled(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::playerExecuted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::gameFinished(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::snapshot(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::chatPosted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Server_oneof_message::nameRejected(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.message {
+            match v {
+                &Server_oneof_message::playerUpdated(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::gameUpdated(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::selfUpdated(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::playerEntered(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::playerLeft(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::roleAssigned(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::votesCounted(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::chaosPolicyEnacted(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::loyaltyRevealed(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::policiesRevealed(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::specialElectionCalled(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::playerExecuted(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::gameFinished(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::snapshot(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::chatPosted(ref v) => {
+                    os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Server_oneof_message::nameRejected(ref v) => {
+                    os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server {
+        Server::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerUpdated>(
+                "playerUpdated",
+                Server::has_playerUpdated,
+                Server::get_playerUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_GameUpdated>(
+                "gameUpdated",
+                Server::has_gameUpdated,
+                Server::get_gameUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_SelfUpdated>(
+                "selfUpdated",
+                Server::has_selfUpdated,
+                Server::get_selfUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerEntered>(
+                "playerEntered",
+                Server::has_playerEntered,
+                Server::get_playerEntered,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerLeft>(
+                "playerLeft",
+                Server::has_playerLeft,
+                Server::get_playerLeft,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_RoleAssigned>(
+                "roleAssigned",
+                Server::has_roleAssigned,
+                Server::get_roleAssigned,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_VotesCounted>(
+                "votesCounted",
+                Server::has_votesCounted,
+                Server::get_votesCounted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_ChaosPolicyEnacted>(
+                "chaosPolicyEnacted",
+                Server::has_chaosPolicyEnacted,
+                Server::get_chaosPolicyEnacted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_LoyaltyRevealed>(
+                "loyaltyRevealed",
+                Server::has_loyaltyRevealed,
+                Server::get_loyaltyRevealed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PoliciesRevealed>(
+                "policiesRevealed",
+                Server::has_policiesRevealed,
+                Server::get_policiesRevealed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_SpecialElectionCalled>(
+                "specialElectionCalled",
+                Server::has_specialElectionCalled,
+                Server::get_specialElectionCalled,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerExecuted>(
+                "playerExecuted",
+                Server::has_playerExecuted,
+                Server::get_playerExecuted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_GameFinished>(
+                "gameFinished",
+                Server::has_gameFinished,
+                Server::get_gameFinished,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_Snapshot>(
+                "snapshot",
+                Server::has_snapshot,
+                Server::get_snapshot,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_ChatPosted>(
+                "chatPosted",
+                Server::has_chatPosted,
+                Server::get_chatPosted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_NameRejected>(
+                "nameRejected",
+                Server::has_nameRejected,
+                Server::get_nameRejected,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server>(
+                "Server",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server {
+        static instance: ::protobuf::rt::LazyV2<Server> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server::new)
+    }
+}
+
+impl ::protobuf::Clear for Server {
+    fn clear(&mut self) {
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.message = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_PlayerUpdated {
+    // message fields
+    pub player: ::protobuf::SingularPtrField<super::player::Player>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_PlayerUpdated {
+    fn default() -> &'a Server_PlayerUpdated {
+        <Server_PlayerUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_PlayerUpdated {
+    pub fn new() -> Server_PlayerUpdated {
+        ::std::default::Default::default()
+    }
+
+    // .Player player = 1;
+
+
+    pub fn get_player(&self) -> &super::player::Player {
+        self.player.as_ref().unwrap_or_else(|| <super::player::Player as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_player(&mut self) {
+        self.player.clear();
+    }
+
+    pub fn has_player(&self) -> bool {
+        self.player.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player(&mut self, v: super::player::Player) {
+        self.player = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player(&mut self) -> &mut super::player::Player {
+        if self.player.is_none() {
+            self.player.set_default();
+        }
+        self.player.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_player(&mut self) -> super::player::Player {
+        self.player.take().unwrap_or_else(|| super::player::Player::new())
+    }
+}
+
+impl ::protobuf::Message for Server_PlayerUpdated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.player {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.player.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.player.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_PlayerUpdated {
+        Server_PlayerUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::Player>>(
+                "player",
+                |m: &Server_PlayerUpdated| { &m.player },
+                |m: &mut Server_PlayerUpdated| { &mut m.player },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerUpdated>(
+                "Server.PlayerUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_PlayerUpdated {
+        static instance: ::protobuf::rt::LazyV2<Server_PlayerUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_PlayerUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_PlayerUpdated {
+    fn clear(&mut self) {
+        self.player.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_PlayerUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_PlayerUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_SelfUpdated {
+    // message fields
+    pub player: ::protobuf::SingularPtrField<super::player::OwnPlayer>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_SelfUpdated {
+    fn default() -> &'a Server_SelfUpdated {
+        <Server_SelfUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_SelfUpdated {
+    pub fn new() -> Server_SelfUpdated {
+        ::std::default::Default::default()
+    }
+
+    // .OwnPlayer player = 1;
+
+
+    pub fn get_player(&self) -> &super::player::OwnPlayer {
+        self.player.as_ref().unwrap_or_else(|| <super::player::OwnPlayer as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_player(&mut self) {
+        self.player.clear();
+    }
+
+    pub fn has_player(&self) -> bool {
+        self.player.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player(&mut self, v: super::player::OwnPlayer) {
+        self.player = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player(&mut self) -> &mut super::player::OwnPlayer {
+        if self.player.is_none() {
+            self.player.set_default();
+        }
+        self.player.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_player(&mut self) -> super::player::OwnPlayer {
+        self.player.take().unwrap_or_else(|| super::player::OwnPlayer::new())
+    }
+}
+
+impl ::protobuf::Message for Server_SelfUpdated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.player {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.player.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.player.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_SelfUpdated {
+        Server_SelfUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::OwnPlayer>>(
+                "player",
+                |m: &Server_SelfUpdated| { &m.player },
+                |m: &mut Server_SelfUpdated| { &mut m.player },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_SelfUpdated>(
+                "Server.SelfUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_SelfUpdated {
+        static instance: ::protobuf::rt::LazyV2<Server_SelfUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_SelfUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_SelfUpdated {
+    fn clear(&mut self) {
+        self.player.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_SelfUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_SelfUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_GameUpdated {
+    // message fields
+    pub game: ::protobuf::SingularPtrField<super::game::Game>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_GameUpdated {
+    fn default() -> &'a Server_GameUpdated {
+        <Server_GameUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_GameUpdated {
+    pub fn new() -> Server_GameUpdated {
+        ::std::default::Default::default()
+    }
+
+    // .Game game = 1;
+
+
+    pub fn get_game(&self) -> &super::game::Game {
+        self.game.as_ref().unwrap_or_else(|| <super::game::Game as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_game(&mut self) {
+        self.game.clear();
+    }
+
+    pub fn has_game(&self) -> bool {
+        self.game.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_game(&mut self, v: super::game::Game) {
+        self.game = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_game(&mut self) -> &mut super::game::Game {
+        if self.game.is_none() {
+            self.game.set_default();
+        }
+        self.game.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_game(&mut self) -> super::game::Game {
+        self.game.take().unwrap_or_else(|| super::game::Game::new())
+    }
+}
+
+impl ::protobuf::Message for Server_GameUpdated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.game {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.game)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.game.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.game.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_GameUpdated {
+        Server_GameUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::game::Game>>(
+                "game",
+                |m: &Server_GameUpdated| { &m.game },
+                |m: &mut Server_GameUpdated| { &mut m.game },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_GameUpdated>(
+                "Server.GameUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_GameUpdated {
+        static instance: ::protobuf::rt::LazyV2<Server_GameUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_GameUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_GameUpdated {
+    fn clear(&mut self) {
+        self.game.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_GameUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_GameUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_PlayerEntered {
+    // message fields
+    pub player: ::protobuf::SingularPtrField<super::player::Player>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_PlayerEntered {
+    fn default() -> &'a Server_PlayerEntered {
+        <Server_PlayerEntered as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_PlayerEntered {
+    pub fn new() -> Server_PlayerEntered {
+        ::std::default::Default::default()
+    }
+
+    // .Player player = 1;
+
+
+    pub fn get_player(&self) -> &super::player::Player {
+        self.player.as_ref().unwrap_or_else(|| <super::player::Player as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_player(&mut self) {
+        self.player.clear();
+    }
+
+    pub fn has_player(&self) -> bool {
+        self.player.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player(&mut self, v: super::player::Player) {
+        self.player = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player(&mut self) -> &mut super::player::Player {
+        if self.player.is_none() {
+            self.player.set_default();
+        }
+        self.player.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_player(&mut self) -> super::player::Player {
+        self.player.take().unwrap_or_else(|| super::player::Player::new())
+    }
+}
+
+impl ::protobuf::Message for Server_PlayerEntered {
+    fn is_initialized(&self) -> bool {
+        for v in &self.player {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.player.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.player.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_PlayerEntered {
+        Server_PlayerEntered::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::Player>>(
+                "player",
+                |m: &Server_PlayerEntered| { &m.player },
+                |m: &mut Server_PlayerEntered| { &mut m.player },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerEntered>(
+                "Server.PlayerEntered",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_PlayerEntered {
+        static instance: ::protobuf::rt::LazyV2<Server_PlayerEntered> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_PlayerEntered::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_PlayerEntered {
+    fn clear(&mut self) {
+        self.player.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_PlayerEntered {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_PlayerEntered {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_PlayerLeft {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_PlayerLeft {
+    fn default() -> &'a Server_PlayerLeft {
+        <Server_PlayerLeft as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_PlayerLeft {
+    pub fn new() -> Server_PlayerLeft {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Server_PlayerLeft {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_PlayerLeft {
+        Server_PlayerLeft::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Server_PlayerLeft| { &m.player_id },
+                |m: &mut Server_PlayerLeft| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerLeft>(
+                "Server.PlayerLeft",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_PlayerLeft {
+        static instance: ::protobuf::rt::LazyV2<Server_PlayerLeft> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_PlayerLeft::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_PlayerLeft {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_PlayerLeft {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_PlayerLeft {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_RoleAssigned {
+    // message fields
+    pub role: super::role::Role,
+    pub clan_member_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub clan_leader_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_RoleAssigned {
+    fn default() -> &'a Server_RoleAssigned {
+        <Server_RoleAssigned as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_RoleAssigned {
+    pub fn new() -> Server_RoleAssigned {
+        ::std::default::Default::default()
+    }
+
+    // .Role role = 1;
+
+
+    pub fn get_role(&self) -> super::role::Role {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::role::Role::LOYAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::role::Role) {
+        self.role = v;
+    }
+
+    // repeated string clan_member_ids = 2;
+
+
+    pub fn get_clan_member_ids(&self) -> &[::std::string::String] {
+        &self.clan_member_ids
+    }
+    pub fn clear_clan_member_ids(&mut self) {
+        self.clan_member_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_member_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.clan_member_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_clan_member_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.clan_member_ids
+    }
+
+    // Take field
+    pub fn take_clan_member_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.clan_member_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // string clan_leader_id = 3;
+
+
+    pub fn get_clan_leader_id(&self) -> &str {
+        &self.clan_leader_id
+    }
+    pub fn clear_clan_leader_id(&mut self) {
+        self.clan_leader_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_leader_id(&mut self, v: ::std::string::String) {
+        self.clan_leader_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clan_leader_id(&mut self) -> &mut ::std::string::String {
+        &mut self.clan_leader_id
+    }
+
+    // Take field
+    pub fn take_clan_leader_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.clan_leader_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Server_RoleAssigned {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.clan_member_ids)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clan_leader_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.role != super::role::Role::LOYAL {
+            my_size += ::protobuf::rt::enum_size(1, self.role);
+        }
+        for value in &self.clan_member_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if !self.clan_leader_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.clan_leader_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.role != super::role::Role::LOYAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        for v in &self.clan_member_ids {
+            os.write_string(2, &v)?;
+        };
+        if !self.clan_leader_id.is_empty() {
+            os.write_string(3, &self.clan_leader_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_RoleAssigned {
+        Server_RoleAssigned::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Role>>(
+                "role",
+                |m: &Server_RoleAssigned| { &m.role },
+                |m: &mut Server_RoleAssigned| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clan_member_ids",
+                |m: &Server_RoleAssigned| { &m.clan_member_ids },
+                |m: &mut Server_RoleAssigned| { &mut m.clan_member_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clan_leader_id",
+                |m: &Server_RoleAssigned| { &m.clan_leader_id },
+                |m: &mut Server_RoleAssigned| { &mut m.clan_leader_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_RoleAssigned>(
+                "Server.RoleAssigned",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_RoleAssigned {
+        static instance: ::protobuf::rt::LazyV2<Server_RoleAssigned> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_RoleAssigned::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_RoleAssigned {
+    fn clear(&mut self) {
+        self.role = super::role::Role::LOYAL;
+        self.clan_member_ids.clear();
+        self.clan_leader_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_RoleAssigned {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_RoleAssigned {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_VotesCounted {
+    // message fields
+    pub approving_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub rejecting_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub approved: bool,
+    pub abstaining_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_VotesCounted {
+    fn default() -> &'a Server_VotesCounted {
+        <Server_VotesCounted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_VotesCounted {
+    pub fn new() -> Server_VotesCounted {
+        ::std::default::Default::default()
+    }
+
+    // repeated string approving_player_ids = 1;
+
+
+    pub fn get_approving_player_ids(&self) -> &[::std::string::String] {
+        &self.approving_player_ids
+    }
+    pub fn clear_approving_player_ids(&mut self) {
+        self.approving_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approving_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.approving_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_approving_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.approving_player_ids
+    }
+
+    // Take field
+    pub fn take_approving_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.approving_player_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string rejecting_player_ids = 2;
+
+
+    pub fn get_rejecting_player_ids(&self) -> &[::std::string::String] {
+        &self.rejecting_player_ids
+    }
+    pub fn clear_rejecting_player_ids(&mut self) {
+        self.rejecting_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rejecting_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.rejecting_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rejecting_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.rejecting_player_ids
+    }
+
+    // Take field
+    pub fn take_rejecting_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.rejecting_player_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // bool approved = 3;
+
+
+    pub fn get_approved(&self) -> bool {
+        self.approved
+    }
+    pub fn clear_approved(&mut self) {
+        self.approved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved(&mut self, v: bool) {
+        self.approved = v;
+    }
+
+    // repeated string abstaining_player_ids = 4;
+
+
+    pub fn get_abstaining_player_ids(&self) -> &[::std::string::String] {
+        &self.abstaining_player_ids
+    }
+    pub fn clear_abstaining_player_ids(&mut self) {
+        self.abstaining_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_abstaining_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.abstaining_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_abstaining_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.abstaining_player_ids
+    }
+
+    // Take field
+    pub fn take_abstaining_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.abstaining_player_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Server_VotesCounted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.approving_player_ids)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.rejecting_player_ids)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.approved = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.abstaining_player_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.approving_player_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.rejecting_player_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if self.approved != false {
+            my_size += 2;
+        }
+        for value in &self.abstaining_player_ids {
+            my_size += ::protobuf::rt::string_size(4, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.approving_player_ids {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.rejecting_player_ids {
+            os.write_string(2, &v)?;
+        };
+        if self.approved != false {
+            os.write_bool(3, self.approved)?;
+        }
+        for v in &self.abstaining_player_ids {
+            os.write_string(4, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_VotesCounted {
+        Server_VotesCounted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "approving_player_ids",
+                |m: &Server_VotesCounted| { &m.approving_player_ids },
+                |m: &mut Server_VotesCounted| { &mut m.approving_player_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "rejecting_player_ids",
+                |m: &Server_VotesCounted| { &m.rejecting_player_ids },
+                |m: &mut Server_VotesCounted| { &mut m.rejecting_player_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "approved",
+                |m: &Server_VotesCounted| { &m.approved },
+                |m: &mut Server_VotesCounted| { &mut m.approved },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "abstaining_player_ids",
+                |m: &Server_VotesCounted| { &m.abstaining_player_ids },
+                |m: &mut Server_VotesCounted| { &mut m.abstaining_player_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_VotesCounted>(
+                "Server.VotesCounted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_VotesCounted {
+        static instance: ::protobuf::rt::LazyV2<Server_VotesCounted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_VotesCounted::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_VotesCounted {
+    fn clear(&mut self) {
+        self.approving_player_ids.clear();
+        self.rejecting_player_ids.clear();
+        self.approved = false;
+        self.abstaining_player_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_VotesCounted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_VotesCounted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_ChaosPolicyEnacted {
+    // message fields
+    pub policy: super::policy::Policy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_ChaosPolicyEnacted {
+    fn default() -> &'a Server_ChaosPolicyEnacted {
+        <Server_ChaosPolicyEnacted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_ChaosPolicyEnacted {
+    pub fn new() -> Server_ChaosPolicyEnacted {
+        ::std::default::Default::default()
+    }
+
+    // .Policy policy = 1;
+
+
+    pub fn get_policy(&self) -> super::policy::Policy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: super::policy::Policy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for Server_ChaosPolicyEnacted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            my_size += ::protobuf::rt::enum_size(1, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_ChaosPolicyEnacted {
+        Server_ChaosPolicyEnacted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policy",
+                |m: &Server_ChaosPolicyEnacted| { &m.policy },
+                |m: &mut Server_ChaosPolicyEnacted| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_ChaosPolicyEnacted>(
+                "Server.ChaosPolicyEnacted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_ChaosPolicyEnacted {
+        static instance: ::protobuf::rt::LazyV2<Server_ChaosPolicyEnacted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_ChaosPolicyEnacted::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_ChaosPolicyEnacted {
+    fn clear(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_ChaosPolicyEnacted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_ChaosPolicyEnacted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_LoyaltyRevealed {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub party: super::role::Party,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_LoyaltyRevealed {
+    fn default() -> &'a Server_LoyaltyRevealed {
+        <Server_LoyaltyRevealed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_LoyaltyRevealed {
+    pub fn new() -> Server_LoyaltyRevealed {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // .Party party = 2;
+
+
+    pub fn get_party(&self) -> super::role::Party {
+        self.party
+    }
+    pub fn clear_party(&mut self) {
+        self.party = super::role::Party::LOYAL_PARTY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_party(&mut self, v: super::role::Party) {
+        self.party = v;
+    }
+}
+
+impl ::protobuf::Message for Server_LoyaltyRevealed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.party, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.party != super::role::Party::LOYAL_PARTY {
+            my_size += ::protobuf::rt::enum_size(2, self.party);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.party != super::role::Party::LOYAL_PARTY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.party))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_LoyaltyRevealed {
+        Server_LoyaltyRevealed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Server_LoyaltyRevealed| { &m.player_id },
+                |m: &mut Server_LoyaltyRevealed| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Party>>(
+                "party",
+                |m: &Server_LoyaltyRevealed| { &m.party },
+                |m: &mut Server_LoyaltyRevealed| { &mut m.party },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_LoyaltyRevealed>(
+                "Server.LoyaltyRevealed",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_LoyaltyRevealed {
+        static instance: ::protobuf::rt::LazyV2<Server_LoyaltyRevealed> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_LoyaltyRevealed::new)
+    }
 }
 
-impl ::protobuf::Message for Client {
+impl ::protobuf::Clear for Server_LoyaltyRevealed {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.party = super::role::Party::LOYAL_PARTY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_LoyaltyRevealed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_LoyaltyRevealed {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_PoliciesRevealed {
+    // message fields
+    pub policies: ::std::vec::Vec<super::policy::Policy>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_PoliciesRevealed {
+    fn default() -> &'a Server_PoliciesRevealed {
+        <Server_PoliciesRevealed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_PoliciesRevealed {
+    pub fn new() -> Server_PoliciesRevealed {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Policy policies = 1;
+
+
+    pub fn get_policies(&self) -> &[super::policy::Policy] {
+        &self.policies
+    }
+    pub fn clear_policies(&mut self) {
+        self.policies.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policies(&mut self, v: ::std::vec::Vec<super::policy::Policy>) {
+        self.policies = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policies(&mut self) -> &mut ::std::vec::Vec<super::policy::Policy> {
+        &mut self.policies
+    }
+
+    // Take field
+    pub fn take_policies(&mut self) -> ::std::vec::Vec<super::policy::Policy> {
+        ::std::mem::replace(&mut self.policies, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Server_PoliciesRevealed {
     fn is_initialized(&self) -> bool {
-        if let Some(Client_oneof_message::authConfirmed(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Client_oneof_message::nameUpdated(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -168,16 +6857,7 @@ impl ::protobuf::Message for Client {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Client_oneof_message::authConfirmed(is.read_message()?));
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Client_oneof_message::nameUpdated(is.read_message()?));
+                    ::protobuf::rt::read_repeated_enum_with_unknown_fields_into(wire_type, is, &mut self.policies, 1, &mut self.unknown_fields)?
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -191,37 +6871,176 @@ impl ::protobuf::Message for Client {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &Client_oneof_message::authConfirmed(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in &self.policies {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.policies {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(v))?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Server_PoliciesRevealed {
+        Server_PoliciesRevealed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policies",
+                |m: &Server_PoliciesRevealed| { &m.policies },
+                |m: &mut Server_PoliciesRevealed| { &mut m.policies },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PoliciesRevealed>(
+                "Server.PoliciesRevealed",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Server_PoliciesRevealed {
+        static instance: ::protobuf::rt::LazyV2<Server_PoliciesRevealed> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_PoliciesRevealed::new)
+    }
+}
+
+impl ::protobuf::Clear for Server_PoliciesRevealed {
+    fn clear(&mut self) {
+        self.policies.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Server_PoliciesRevealed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Server_PoliciesRevealed {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_SpecialElectionCalled {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Server_SpecialElectionCalled {
+    fn default() -> &'a Server_SpecialElectionCalled {
+        <Server_SpecialElectionCalled as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Server_SpecialElectionCalled {
+    pub fn new() -> Server_SpecialElectionCalled {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Server_SpecialElectionCalled {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
                 },
-                &Client_oneof_message::nameUpdated(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &Client_oneof_message::authConfirmed(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Client_oneof_message::nameUpdated(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -253,106 +7072,100 @@ impl ::protobuf::Message for Client {
         Self::descriptor_static()
     }
 
-    fn new() -> Client {
-        Client::new()
+    fn new() -> Server_SpecialElectionCalled {
+        Server_SpecialElectionCalled::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_AuthConfirmed>(
-                "authConfirmed",
-                Client::has_authConfirmed,
-                Client::get_authConfirmed,
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Client_NameUpdated>(
-                "nameUpdated",
-                Client::has_nameUpdated,
-                Client::get_nameUpdated,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Server_SpecialElectionCalled| { &m.player_id },
+                |m: &mut Server_SpecialElectionCalled| { &mut m.player_id },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client>(
-                "Client",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_SpecialElectionCalled>(
+                "Server.SpecialElectionCalled",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Client {
-        static instance: ::protobuf::rt::LazyV2<Client> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Client::new)
+    fn default_instance() -> &'static Server_SpecialElectionCalled {
+        static instance: ::protobuf::rt::LazyV2<Server_SpecialElectionCalled> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_SpecialElectionCalled::new)
     }
 }
 
-impl ::protobuf::Clear for Client {
-    fn clear(&mut self) {
-        self.message = ::std::option::Option::None;
-        self.message = ::std::option::Option::None;
+impl ::protobuf::Clear for Server_SpecialElectionCalled {
+    fn clear(&mut self) {
+        self.player_id.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Client {
+impl ::std::fmt::Debug for Server_SpecialElectionCalled {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Client {
+impl ::protobuf::reflect::ProtobufValue for Server_SpecialElectionCalled {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Client_AuthConfirmed {
+pub struct Server_PlayerExecuted {
     // message fields
-    pub token: ::std::string::String,
+    pub player_id: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Client_AuthConfirmed {
-    fn default() -> &'a Client_AuthConfirmed {
-        <Client_AuthConfirmed as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_PlayerExecuted {
+    fn default() -> &'a Server_PlayerExecuted {
+        <Server_PlayerExecuted as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Client_AuthConfirmed {
-    pub fn new() -> Client_AuthConfirmed {
+impl Server_PlayerExecuted {
+    pub fn new() -> Server_PlayerExecuted {
         ::std::default::Default::default()
     }
 
-    // string token = 1;
+    // string player_id = 1;
 
 
-    pub fn get_token(&self) -> &str {
-        &self.token
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
     }
-    pub fn clear_token(&mut self) {
-        self.token.clear();
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_token(&mut self, v: ::std::string::String) {
-        self.token = v;
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_token(&mut self) -> &mut ::std::string::String {
-        &mut self.token
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
     }
 
     // Take field
-    pub fn take_token(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for Client_AuthConfirmed {
+impl ::protobuf::Message for Server_PlayerExecuted {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -362,7 +7175,7 @@ impl ::protobuf::Message for Client_AuthConfirmed {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -376,8 +7189,8 @@ impl ::protobuf::Message for Client_AuthConfirmed {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.token.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.token);
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -385,8 +7198,8 @@ impl ::protobuf::Message for Client_AuthConfirmed {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.token.is_empty() {
-            os.write_string(1, &self.token)?;
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -418,8 +7231,8 @@ impl ::protobuf::Message for Client_AuthConfirmed {
         Self::descriptor_static()
     }
 
-    fn new() -> Client_AuthConfirmed {
-        Client_AuthConfirmed::new()
+    fn new() -> Server_PlayerExecuted {
+        Server_PlayerExecuted::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -427,92 +7240,128 @@ impl ::protobuf::Message for Client_AuthConfirmed {
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "token",
-                |m: &Client_AuthConfirmed| { &m.token },
-                |m: &mut Client_AuthConfirmed| { &mut m.token },
+                "player_id",
+                |m: &Server_PlayerExecuted| { &m.player_id },
+                |m: &mut Server_PlayerExecuted| { &mut m.player_id },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_AuthConfirmed>(
-                "Client.AuthConfirmed",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerExecuted>(
+                "Server.PlayerExecuted",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Client_AuthConfirmed {
-        static instance: ::protobuf::rt::LazyV2<Client_AuthConfirmed> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Client_AuthConfirmed::new)
+    fn default_instance() -> &'static Server_PlayerExecuted {
+        static instance: ::protobuf::rt::LazyV2<Server_PlayerExecuted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_PlayerExecuted::new)
     }
 }
 
-impl ::protobuf::Clear for Client_AuthConfirmed {
+impl ::protobuf::Clear for Server_PlayerExecuted {
     fn clear(&mut self) {
-        self.token.clear();
+        self.player_id.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Client_AuthConfirmed {
+impl ::std::fmt::Debug for Server_PlayerExecuted {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Client_AuthConfirmed {
+impl ::protobuf::reflect::ProtobufValue for Server_PlayerExecuted {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Client_NameUpdated {
+pub struct Server_GameFinished {
     // message fields
-    pub name: ::std::string::String,
+    pub winner: super::role::Party,
+    pub reason: super::game::WinReason,
+    pub roles: ::protobuf::RepeatedField<super::role::PlayerRole>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Client_NameUpdated {
-    fn default() -> &'a Client_NameUpdated {
-        <Client_NameUpdated as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_GameFinished {
+    fn default() -> &'a Server_GameFinished {
+        <Server_GameFinished as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Client_NameUpdated {
-    pub fn new() -> Client_NameUpdated {
+impl Server_GameFinished {
+    pub fn new() -> Server_GameFinished {
         ::std::default::Default::default()
     }
 
-    // string name = 1;
+    // .Party winner = 1;
 
 
-    pub fn get_name(&self) -> &str {
-        &self.name
+    pub fn get_winner(&self) -> super::role::Party {
+        self.winner
     }
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_winner(&mut self) {
+        self.winner = super::role::Party::LOYAL_PARTY;
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = v;
+    pub fn set_winner(&mut self, v: super::role::Party) {
+        self.winner = v;
+    }
+
+    // .WinReason reason = 2;
+
+
+    pub fn get_reason(&self) -> super::game::WinReason {
+        self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason = super::game::WinReason::LOYAL_POLICIES_ENACTED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: super::game::WinReason) {
+        self.reason = v;
+    }
+
+    // repeated .PlayerRole roles = 3;
+
+
+    pub fn get_roles(&self) -> &[super::role::PlayerRole] {
+        &self.roles
+    }
+    pub fn clear_roles(&mut self) {
+        self.roles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roles(&mut self, v: ::protobuf::RepeatedField<super::role::PlayerRole>) {
+        self.roles = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        &mut self.name
+    pub fn mut_roles(&mut self) -> &mut ::protobuf::RepeatedField<super::role::PlayerRole> {
+        &mut self.roles
     }
 
     // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    pub fn take_roles(&mut self) -> ::protobuf::RepeatedField<super::role::PlayerRole> {
+        ::std::mem::replace(&mut self.roles, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for Client_NameUpdated {
+impl ::protobuf::Message for Server_GameFinished {
     fn is_initialized(&self) -> bool {
+        for v in &self.roles {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -521,7 +7370,13 @@ impl ::protobuf::Message for Client_NameUpdated {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.winner, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.roles)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -535,18 +7390,33 @@ impl ::protobuf::Message for Client_NameUpdated {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.name.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.name);
+        if self.winner != super::role::Party::LOYAL_PARTY {
+            my_size += ::protobuf::rt::enum_size(1, self.winner);
         }
+        if self.reason != super::game::WinReason::LOYAL_POLICIES_ENACTED {
+            my_size += ::protobuf::rt::enum_size(2, self.reason);
+        }
+        for value in &self.roles {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.name.is_empty() {
-            os.write_string(1, &self.name)?;
+        if self.winner != super::role::Party::LOYAL_PARTY {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.winner))?;
+        }
+        if self.reason != super::game::WinReason::LOYAL_POLICIES_ENACTED {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.reason))?;
         }
+        for v in &self.roles {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -577,354 +7447,167 @@ impl ::protobuf::Message for Client_NameUpdated {
         Self::descriptor_static()
     }
 
-    fn new() -> Client_NameUpdated {
-        Client_NameUpdated::new()
+    fn new() -> Server_GameFinished {
+        Server_GameFinished::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "name",
-                |m: &Client_NameUpdated| { &m.name },
-                |m: &mut Client_NameUpdated| { &mut m.name },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Party>>(
+                "winner",
+                |m: &Server_GameFinished| { &m.winner },
+                |m: &mut Server_GameFinished| { &mut m.winner },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Client_NameUpdated>(
-                "Client.NameUpdated",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::game::WinReason>>(
+                "reason",
+                |m: &Server_GameFinished| { &m.reason },
+                |m: &mut Server_GameFinished| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::role::PlayerRole>>(
+                "roles",
+                |m: &Server_GameFinished| { &m.roles },
+                |m: &mut Server_GameFinished| { &mut m.roles },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_GameFinished>(
+                "Server.GameFinished",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Client_NameUpdated {
-        static instance: ::protobuf::rt::LazyV2<Client_NameUpdated> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Client_NameUpdated::new)
+    fn default_instance() -> &'static Server_GameFinished {
+        static instance: ::protobuf::rt::LazyV2<Server_GameFinished> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_GameFinished::new)
     }
 }
 
-impl ::protobuf::Clear for Client_NameUpdated {
+impl ::protobuf::Clear for Server_GameFinished {
     fn clear(&mut self) {
-        self.name.clear();
+        self.winner = super::role::Party::LOYAL_PARTY;
+        self.reason = super::game::WinReason::LOYAL_POLICIES_ENACTED;
+        self.roles.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Client_NameUpdated {
+impl ::std::fmt::Debug for Server_GameFinished {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
-
-impl ::protobuf::reflect::ProtobufValue for Client_NameUpdated {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
-    }
-}
-
-#[derive(PartialEq,Clone,Default)]
-pub struct Server {
-    // message oneof groups
-    pub message: ::std::option::Option<Server_oneof_message>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
-
-impl<'a> ::std::default::Default for &'a Server {
-    fn default() -> &'a Server {
-        <Server as ::protobuf::Message>::default_instance()
-    }
-}
-
-#[derive(Clone,PartialEq,Debug)]
-pub enum Server_oneof_message {
-    playerUpdated(Server_PlayerUpdated),
-    gameUpdated(Server_GameUpdated),
-    selfUpdated(Server_SelfUpdated),
-    playerEntered(Server_PlayerEntered),
-    playerLeft(Server_PlayerLeft),
-}
-
-impl Server {
-    pub fn new() -> Server {
-        ::std::default::Default::default()
-    }
-
-    // .Server.PlayerUpdated playerUpdated = 1;
-
-
-    pub fn get_playerUpdated(&self) -> &Server_PlayerUpdated {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerUpdated(ref v)) => v,
-            _ => <Server_PlayerUpdated as ::protobuf::Message>::default_instance(),
-        }
-    }
-    pub fn clear_playerUpdated(&mut self) {
-        self.message = ::std::option::Option::None;
-    }
-
-    pub fn has_playerUpdated(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerUpdated(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_playerUpdated(&mut self, v: Server_PlayerUpdated) {
-        self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_playerUpdated(&mut self) -> &mut Server_PlayerUpdated {
-        if let ::std::option::Option::Some(Server_oneof_message::playerUpdated(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(Server_PlayerUpdated::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerUpdated(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_playerUpdated(&mut self) -> Server_PlayerUpdated {
-        if self.has_playerUpdated() {
-            match self.message.take() {
-                ::std::option::Option::Some(Server_oneof_message::playerUpdated(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Server_PlayerUpdated::new()
-        }
-    }
-
-    // .Server.GameUpdated gameUpdated = 2;
-
-
-    pub fn get_gameUpdated(&self) -> &Server_GameUpdated {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::gameUpdated(ref v)) => v,
-            _ => <Server_GameUpdated as ::protobuf::Message>::default_instance(),
-        }
-    }
-    pub fn clear_gameUpdated(&mut self) {
-        self.message = ::std::option::Option::None;
-    }
-
-    pub fn has_gameUpdated(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::gameUpdated(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_gameUpdated(&mut self, v: Server_GameUpdated) {
-        self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_gameUpdated(&mut self) -> &mut Server_GameUpdated {
-        if let ::std::option::Option::Some(Server_oneof_message::gameUpdated(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(Server_GameUpdated::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::gameUpdated(ref mut v)) => v,
-            _ => panic!(),
-        }
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Take field
-    pub fn take_gameUpdated(&mut self) -> Server_GameUpdated {
-        if self.has_gameUpdated() {
-            match self.message.take() {
-                ::std::option::Option::Some(Server_oneof_message::gameUpdated(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Server_GameUpdated::new()
-        }
+impl ::protobuf::reflect::ProtobufValue for Server_GameFinished {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
+}
 
-    // .Server.SelfUpdated selfUpdated = 3;
-
+#[derive(PartialEq,Clone,Default)]
+pub struct Server_ChatPosted {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub text: ::std::string::String,
+    pub time: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
 
-    pub fn get_selfUpdated(&self) -> &Server_SelfUpdated {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::selfUpdated(ref v)) => v,
-            _ => <Server_SelfUpdated as ::protobuf::Message>::default_instance(),
-        }
+impl<'a> ::std::default::Default for &'a Server_ChatPosted {
+    fn default() -> &'a Server_ChatPosted {
+        <Server_ChatPosted as ::protobuf::Message>::default_instance()
     }
-    pub fn clear_selfUpdated(&mut self) {
-        self.message = ::std::option::Option::None;
+}
+
+impl Server_ChatPosted {
+    pub fn new() -> Server_ChatPosted {
+        ::std::default::Default::default()
     }
 
-    pub fn has_selfUpdated(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::selfUpdated(..)) => true,
-            _ => false,
-        }
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_selfUpdated(&mut self, v: Server_SelfUpdated) {
-        self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(v))
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_selfUpdated(&mut self) -> &mut Server_SelfUpdated {
-        if let ::std::option::Option::Some(Server_oneof_message::selfUpdated(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(Server_SelfUpdated::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::selfUpdated(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
     }
 
     // Take field
-    pub fn take_selfUpdated(&mut self) -> Server_SelfUpdated {
-        if self.has_selfUpdated() {
-            match self.message.take() {
-                ::std::option::Option::Some(Server_oneof_message::selfUpdated(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Server_SelfUpdated::new()
-        }
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
     }
 
-    // .Server.PlayerEntered playerEntered = 4;
+    // string text = 2;
 
 
-    pub fn get_playerEntered(&self) -> &Server_PlayerEntered {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerEntered(ref v)) => v,
-            _ => <Server_PlayerEntered as ::protobuf::Message>::default_instance(),
-        }
+    pub fn get_text(&self) -> &str {
+        &self.text
     }
-    pub fn clear_playerEntered(&mut self) {
-        self.message = ::std::option::Option::None;
-    }
-
-    pub fn has_playerEntered(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerEntered(..)) => true,
-            _ => false,
-        }
+    pub fn clear_text(&mut self) {
+        self.text.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_playerEntered(&mut self, v: Server_PlayerEntered) {
-        self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(v))
+    pub fn set_text(&mut self, v: ::std::string::String) {
+        self.text = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_playerEntered(&mut self) -> &mut Server_PlayerEntered {
-        if let ::std::option::Option::Some(Server_oneof_message::playerEntered(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(Server_PlayerEntered::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerEntered(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_text(&mut self) -> &mut ::std::string::String {
+        &mut self.text
     }
 
     // Take field
-    pub fn take_playerEntered(&mut self) -> Server_PlayerEntered {
-        if self.has_playerEntered() {
-            match self.message.take() {
-                ::std::option::Option::Some(Server_oneof_message::playerEntered(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Server_PlayerEntered::new()
-        }
+    pub fn take_text(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.text, ::std::string::String::new())
     }
 
-    // .Server.PlayerLeft playerLeft = 5;
+    // string time = 3;
 
 
-    pub fn get_playerLeft(&self) -> &Server_PlayerLeft {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerLeft(ref v)) => v,
-            _ => <Server_PlayerLeft as ::protobuf::Message>::default_instance(),
-        }
+    pub fn get_time(&self) -> &str {
+        &self.time
     }
-    pub fn clear_playerLeft(&mut self) {
-        self.message = ::std::option::Option::None;
-    }
-
-    pub fn has_playerLeft(&self) -> bool {
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerLeft(..)) => true,
-            _ => false,
-        }
+    pub fn clear_time(&mut self) {
+        self.time.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_playerLeft(&mut self, v: Server_PlayerLeft) {
-        self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(v))
+    pub fn set_time(&mut self, v: ::std::string::String) {
+        self.time = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_playerLeft(&mut self) -> &mut Server_PlayerLeft {
-        if let ::std::option::Option::Some(Server_oneof_message::playerLeft(_)) = self.message {
-        } else {
-            self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(Server_PlayerLeft::new()));
-        }
-        match self.message {
-            ::std::option::Option::Some(Server_oneof_message::playerLeft(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_time(&mut self) -> &mut ::std::string::String {
+        &mut self.time
     }
 
     // Take field
-    pub fn take_playerLeft(&mut self) -> Server_PlayerLeft {
-        if self.has_playerLeft() {
-            match self.message.take() {
-                ::std::option::Option::Some(Server_oneof_message::playerLeft(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            Server_PlayerLeft::new()
-        }
+    pub fn take_time(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.time, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for Server {
+impl ::protobuf::Message for Server_ChatPosted {
     fn is_initialized(&self) -> bool {
-        if let Some(Server_oneof_message::playerUpdated(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Server_oneof_message::gameUpdated(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Server_oneof_message::selfUpdated(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Server_oneof_message::playerEntered(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Server_oneof_message::playerLeft(ref v)) = self.message {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
         true
     }
 
@@ -933,34 +7616,13 @@ impl ::protobuf::Message for Server {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Server_oneof_message::playerUpdated(is.read_message()?));
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Server_oneof_message::gameUpdated(is.read_message()?));
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.text)?;
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Server_oneof_message::selfUpdated(is.read_message()?));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Server_oneof_message::playerEntered(is.read_message()?));
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.message = ::std::option::Option::Some(Server_oneof_message::playerLeft(is.read_message()?));
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.time)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -974,29 +7636,14 @@ impl ::protobuf::Message for Server {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &Server_oneof_message::playerUpdated(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Server_oneof_message::gameUpdated(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Server_oneof_message::selfUpdated(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Server_oneof_message::playerEntered(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &Server_oneof_message::playerLeft(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.text.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.text);
+        }
+        if !self.time.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.time);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1004,34 +7651,14 @@ impl ::protobuf::Message for Server {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.message {
-            match v {
-                &Server_oneof_message::playerUpdated(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Server_oneof_message::gameUpdated(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Server_oneof_message::selfUpdated(ref v) => {
-                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Server_oneof_message::playerEntered(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &Server_oneof_message::playerLeft(ref v) => {
-                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-            };
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.text.is_empty() {
+            os.write_string(2, &self.text)?;
+        }
+        if !self.time.is_empty() {
+            os.write_string(3, &self.time)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1063,137 +7690,129 @@ impl ::protobuf::Message for Server {
         Self::descriptor_static()
     }
 
-    fn new() -> Server {
-        Server::new()
+    fn new() -> Server_ChatPosted {
+        Server_ChatPosted::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerUpdated>(
-                "playerUpdated",
-                Server::has_playerUpdated,
-                Server::get_playerUpdated,
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_GameUpdated>(
-                "gameUpdated",
-                Server::has_gameUpdated,
-                Server::get_gameUpdated,
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_SelfUpdated>(
-                "selfUpdated",
-                Server::has_selfUpdated,
-                Server::get_selfUpdated,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &Server_ChatPosted| { &m.player_id },
+                |m: &mut Server_ChatPosted| { &mut m.player_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerEntered>(
-                "playerEntered",
-                Server::has_playerEntered,
-                Server::get_playerEntered,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "text",
+                |m: &Server_ChatPosted| { &m.text },
+                |m: &mut Server_ChatPosted| { &mut m.text },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Server_PlayerLeft>(
-                "playerLeft",
-                Server::has_playerLeft,
-                Server::get_playerLeft,
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "time",
+                |m: &Server_ChatPosted| { &m.time },
+                |m: &mut Server_ChatPosted| { &mut m.time },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server>(
-                "Server",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_ChatPosted>(
+                "Server.ChatPosted",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Server {
-        static instance: ::protobuf::rt::LazyV2<Server> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server::new)
+    fn default_instance() -> &'static Server_ChatPosted {
+        static instance: ::protobuf::rt::LazyV2<Server_ChatPosted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_ChatPosted::new)
     }
 }
 
-impl ::protobuf::Clear for Server {
+impl ::protobuf::Clear for Server_ChatPosted {
     fn clear(&mut self) {
-        self.message = ::std::option::Option::None;
-        self.message = ::std::option::Option::None;
-        self.message = ::std::option::Option::None;
-        self.message = ::std::option::Option::None;
-        self.message = ::std::option::Option::None;
+        self.player_id.clear();
+        self.text.clear();
+        self.time.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Server {
+impl ::std::fmt::Debug for Server_ChatPosted {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Server {
+impl ::protobuf::reflect::ProtobufValue for Server_ChatPosted {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Server_PlayerUpdated {
+pub struct Server_NameRejected {
     // message fields
-    pub player: ::protobuf::SingularPtrField<super::player::Player>,
+    pub reason: super::player::NameRejection,
+    pub name: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Server_PlayerUpdated {
-    fn default() -> &'a Server_PlayerUpdated {
-        <Server_PlayerUpdated as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_NameRejected {
+    fn default() -> &'a Server_NameRejected {
+        <Server_NameRejected as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Server_PlayerUpdated {
-    pub fn new() -> Server_PlayerUpdated {
+impl Server_NameRejected {
+    pub fn new() -> Server_NameRejected {
         ::std::default::Default::default()
     }
 
-    // .Player player = 1;
+    // .NameRejection reason = 1;
 
 
-    pub fn get_player(&self) -> &super::player::Player {
-        self.player.as_ref().unwrap_or_else(|| <super::player::Player as ::protobuf::Message>::default_instance())
+    pub fn get_reason(&self) -> super::player::NameRejection {
+        self.reason
     }
-    pub fn clear_player(&mut self) {
-        self.player.clear();
+    pub fn clear_reason(&mut self) {
+        self.reason = super::player::NameRejection::TOO_SHORT;
     }
 
-    pub fn has_player(&self) -> bool {
-        self.player.is_some()
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: super::player::NameRejection) {
+        self.reason = v;
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_player(&mut self, v: super::player::Player) {
-        self.player = ::protobuf::SingularPtrField::some(v);
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_player(&mut self) -> &mut super::player::Player {
-        if self.player.is_none() {
-            self.player.set_default();
-        }
-        self.player.as_mut().unwrap()
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
     }
 
     // Take field
-    pub fn take_player(&mut self) -> super::player::Player {
-        self.player.take().unwrap_or_else(|| super::player::Player::new())
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for Server_PlayerUpdated {
+impl ::protobuf::Message for Server_NameRejected {
     fn is_initialized(&self) -> bool {
-        for v in &self.player {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1202,7 +7821,10 @@ impl ::protobuf::Message for Server_PlayerUpdated {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1216,9 +7838,11 @@ impl ::protobuf::Message for Server_PlayerUpdated {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.player.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if self.reason != super::player::NameRejection::TOO_SHORT {
+            my_size += ::protobuf::rt::enum_size(1, self.reason);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1226,10 +7850,11 @@ impl ::protobuf::Message for Server_PlayerUpdated {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.player.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if self.reason != super::player::NameRejection::TOO_SHORT {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.reason))?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1261,287 +7886,275 @@ impl ::protobuf::Message for Server_PlayerUpdated {
         Self::descriptor_static()
     }
 
-    fn new() -> Server_PlayerUpdated {
-        Server_PlayerUpdated::new()
+    fn new() -> Server_NameRejected {
+        Server_NameRejected::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::Player>>(
-                "player",
-                |m: &Server_PlayerUpdated| { &m.player },
-                |m: &mut Server_PlayerUpdated| { &mut m.player },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::player::NameRejection>>(
+                "reason",
+                |m: &Server_NameRejected| { &m.reason },
+                |m: &mut Server_NameRejected| { &mut m.reason },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerUpdated>(
-                "Server.PlayerUpdated",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Server_NameRejected| { &m.name },
+                |m: &mut Server_NameRejected| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_NameRejected>(
+                "Server.NameRejected",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Server_PlayerUpdated {
-        static instance: ::protobuf::rt::LazyV2<Server_PlayerUpdated> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server_PlayerUpdated::new)
+    fn default_instance() -> &'static Server_NameRejected {
+        static instance: ::protobuf::rt::LazyV2<Server_NameRejected> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_NameRejected::new)
     }
 }
 
-impl ::protobuf::Clear for Server_PlayerUpdated {
+impl ::protobuf::Clear for Server_NameRejected {
     fn clear(&mut self) {
-        self.player.clear();
+        self.reason = super::player::NameRejection::TOO_SHORT;
+        self.name.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Server_PlayerUpdated {
+impl ::std::fmt::Debug for Server_NameRejected {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Server_PlayerUpdated {
+impl ::protobuf::reflect::ProtobufValue for Server_NameRejected {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Server_SelfUpdated {
+pub struct Server_Snapshot {
     // message fields
-    pub player: ::protobuf::SingularPtrField<super::player::OwnPlayer>,
+    pub game: ::protobuf::SingularPtrField<super::game::Game>,
+    pub players: ::protobuf::RepeatedField<Server_Snapshot_PlayerPresence>,
+    pub own_player: ::protobuf::SingularPtrField<super::player::OwnPlayer>,
+    pub knowledge: ::protobuf::SingularPtrField<Server_Snapshot_Knowledge>,
+    pub phase: super::game::Phase,
+    pub chat: ::protobuf::RepeatedField<Server_ChatPosted>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Server_SelfUpdated {
-    fn default() -> &'a Server_SelfUpdated {
-        <Server_SelfUpdated as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_Snapshot {
+    fn default() -> &'a Server_Snapshot {
+        <Server_Snapshot as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Server_SelfUpdated {
-    pub fn new() -> Server_SelfUpdated {
+impl Server_Snapshot {
+    pub fn new() -> Server_Snapshot {
         ::std::default::Default::default()
     }
 
-    // .OwnPlayer player = 1;
+    // .Game game = 1;
 
 
-    pub fn get_player(&self) -> &super::player::OwnPlayer {
-        self.player.as_ref().unwrap_or_else(|| <super::player::OwnPlayer as ::protobuf::Message>::default_instance())
+    pub fn get_game(&self) -> &super::game::Game {
+        self.game.as_ref().unwrap_or_else(|| <super::game::Game as ::protobuf::Message>::default_instance())
     }
-    pub fn clear_player(&mut self) {
-        self.player.clear();
+    pub fn clear_game(&mut self) {
+        self.game.clear();
     }
 
-    pub fn has_player(&self) -> bool {
-        self.player.is_some()
+    pub fn has_game(&self) -> bool {
+        self.game.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_player(&mut self, v: super::player::OwnPlayer) {
-        self.player = ::protobuf::SingularPtrField::some(v);
+    pub fn set_game(&mut self, v: super::game::Game) {
+        self.game = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_player(&mut self) -> &mut super::player::OwnPlayer {
-        if self.player.is_none() {
-            self.player.set_default();
+    pub fn mut_game(&mut self) -> &mut super::game::Game {
+        if self.game.is_none() {
+            self.game.set_default();
         }
-        self.player.as_mut().unwrap()
+        self.game.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_player(&mut self) -> super::player::OwnPlayer {
-        self.player.take().unwrap_or_else(|| super::player::OwnPlayer::new())
+    pub fn take_game(&mut self) -> super::game::Game {
+        self.game.take().unwrap_or_else(|| super::game::Game::new())
     }
-}
 
-impl ::protobuf::Message for Server_SelfUpdated {
-    fn is_initialized(&self) -> bool {
-        for v in &self.player {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
-    }
+    // repeated .Server.Snapshot.PlayerPresence players = 2;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(ref v) = self.player.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    pub fn get_players(&self) -> &[Server_Snapshot_PlayerPresence] {
+        &self.players
     }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.player.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    pub fn clear_players(&mut self) {
+        self.players.clear();
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_players(&mut self, v: ::protobuf::RepeatedField<Server_Snapshot_PlayerPresence>) {
+        self.players = v;
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // Mutable pointer to the field.
+    pub fn mut_players(&mut self) -> &mut ::protobuf::RepeatedField<Server_Snapshot_PlayerPresence> {
+        &mut self.players
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Take field
+    pub fn take_players(&mut self) -> ::protobuf::RepeatedField<Server_Snapshot_PlayerPresence> {
+        ::std::mem::replace(&mut self.players, ::protobuf::RepeatedField::new())
     }
 
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
-    }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
-    }
-    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
-    }
+    // .OwnPlayer own_player = 3;
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
-    }
 
-    fn new() -> Server_SelfUpdated {
-        Server_SelfUpdated::new()
+    pub fn get_own_player(&self) -> &super::player::OwnPlayer {
+        self.own_player.as_ref().unwrap_or_else(|| <super::player::OwnPlayer as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_own_player(&mut self) {
+        self.own_player.clear();
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
-        descriptor.get(|| {
-            let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::OwnPlayer>>(
-                "player",
-                |m: &Server_SelfUpdated| { &m.player },
-                |m: &mut Server_SelfUpdated| { &mut m.player },
-            ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_SelfUpdated>(
-                "Server.SelfUpdated",
-                fields,
-                file_descriptor_proto()
-            )
-        })
+    pub fn has_own_player(&self) -> bool {
+        self.own_player.is_some()
     }
 
-    fn default_instance() -> &'static Server_SelfUpdated {
-        static instance: ::protobuf::rt::LazyV2<Server_SelfUpdated> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server_SelfUpdated::new)
+    // Param is passed by value, moved
+    pub fn set_own_player(&mut self, v: super::player::OwnPlayer) {
+        self.own_player = ::protobuf::SingularPtrField::some(v);
     }
-}
 
-impl ::protobuf::Clear for Server_SelfUpdated {
-    fn clear(&mut self) {
-        self.player.clear();
-        self.unknown_fields.clear();
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_own_player(&mut self) -> &mut super::player::OwnPlayer {
+        if self.own_player.is_none() {
+            self.own_player.set_default();
+        }
+        self.own_player.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_own_player(&mut self) -> super::player::OwnPlayer {
+        self.own_player.take().unwrap_or_else(|| super::player::OwnPlayer::new())
     }
-}
 
-impl ::std::fmt::Debug for Server_SelfUpdated {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    // .Server.Snapshot.Knowledge knowledge = 4;
+
+
+    pub fn get_knowledge(&self) -> &Server_Snapshot_Knowledge {
+        self.knowledge.as_ref().unwrap_or_else(|| <Server_Snapshot_Knowledge as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_knowledge(&mut self) {
+        self.knowledge.clear();
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for Server_SelfUpdated {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
+    pub fn has_knowledge(&self) -> bool {
+        self.knowledge.is_some()
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct Server_GameUpdated {
-    // message fields
-    pub game: ::protobuf::SingularPtrField<super::game::Game>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    // Param is passed by value, moved
+    pub fn set_knowledge(&mut self, v: Server_Snapshot_Knowledge) {
+        self.knowledge = ::protobuf::SingularPtrField::some(v);
+    }
 
-impl<'a> ::std::default::Default for &'a Server_GameUpdated {
-    fn default() -> &'a Server_GameUpdated {
-        <Server_GameUpdated as ::protobuf::Message>::default_instance()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_knowledge(&mut self) -> &mut Server_Snapshot_Knowledge {
+        if self.knowledge.is_none() {
+            self.knowledge.set_default();
+        }
+        self.knowledge.as_mut().unwrap()
     }
-}
 
-impl Server_GameUpdated {
-    pub fn new() -> Server_GameUpdated {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_knowledge(&mut self) -> Server_Snapshot_Knowledge {
+        self.knowledge.take().unwrap_or_else(|| Server_Snapshot_Knowledge::new())
     }
 
-    // .Game game = 1;
+    // .Phase phase = 5;
 
 
-    pub fn get_game(&self) -> &super::game::Game {
-        self.game.as_ref().unwrap_or_else(|| <super::game::Game as ::protobuf::Message>::default_instance())
+    pub fn get_phase(&self) -> super::game::Phase {
+        self.phase
     }
-    pub fn clear_game(&mut self) {
-        self.game.clear();
+    pub fn clear_phase(&mut self) {
+        self.phase = super::game::Phase::LOBBY;
     }
 
-    pub fn has_game(&self) -> bool {
-        self.game.is_some()
+    // Param is passed by value, moved
+    pub fn set_phase(&mut self, v: super::game::Phase) {
+        self.phase = v;
+    }
+
+    // repeated .Server.ChatPosted chat = 6;
+
+
+    pub fn get_chat(&self) -> &[Server_ChatPosted] {
+        &self.chat
+    }
+    pub fn clear_chat(&mut self) {
+        self.chat.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_game(&mut self, v: super::game::Game) {
-        self.game = ::protobuf::SingularPtrField::some(v);
+    pub fn set_chat(&mut self, v: ::protobuf::RepeatedField<Server_ChatPosted>) {
+        self.chat = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_game(&mut self) -> &mut super::game::Game {
-        if self.game.is_none() {
-            self.game.set_default();
-        }
-        self.game.as_mut().unwrap()
+    pub fn mut_chat(&mut self) -> &mut ::protobuf::RepeatedField<Server_ChatPosted> {
+        &mut self.chat
     }
 
     // Take field
-    pub fn take_game(&mut self) -> super::game::Game {
-        self.game.take().unwrap_or_else(|| super::game::Game::new())
+    pub fn take_chat(&mut self) -> ::protobuf::RepeatedField<Server_ChatPosted> {
+        ::std::mem::replace(&mut self.chat, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for Server_GameUpdated {
+impl ::protobuf::Message for Server_Snapshot {
     fn is_initialized(&self) -> bool {
         for v in &self.game {
             if !v.is_initialized() {
                 return false;
             }
         };
+        for v in &self.players {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.own_player {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.knowledge {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.chat {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1552,6 +8165,21 @@ impl ::protobuf::Message for Server_GameUpdated {
                 1 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.game)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.players)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.own_player)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.knowledge)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.phase, 5, &mut self.unknown_fields)?
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.chat)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1568,6 +8196,25 @@ impl ::protobuf::Message for Server_GameUpdated {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        for value in &self.players {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.own_player.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.knowledge.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.phase != super::game::Phase::LOBBY {
+            my_size += ::protobuf::rt::enum_size(5, self.phase);
+        }
+        for value in &self.chat {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1579,6 +8226,29 @@ impl ::protobuf::Message for Server_GameUpdated {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        for v in &self.players {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.own_player.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.knowledge.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.phase != super::game::Phase::LOBBY {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.phase))?;
+        }
+        for v in &self.chat {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1609,8 +8279,8 @@ impl ::protobuf::Message for Server_GameUpdated {
         Self::descriptor_static()
     }
 
-    fn new() -> Server_GameUpdated {
-        Server_GameUpdated::new()
+    fn new() -> Server_Snapshot {
+        Server_Snapshot::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1619,59 +8289,90 @@ impl ::protobuf::Message for Server_GameUpdated {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::game::Game>>(
                 "game",
-                |m: &Server_GameUpdated| { &m.game },
-                |m: &mut Server_GameUpdated| { &mut m.game },
+                |m: &Server_Snapshot| { &m.game },
+                |m: &mut Server_Snapshot| { &mut m.game },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_GameUpdated>(
-                "Server.GameUpdated",
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Server_Snapshot_PlayerPresence>>(
+                "players",
+                |m: &Server_Snapshot| { &m.players },
+                |m: &mut Server_Snapshot| { &mut m.players },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::OwnPlayer>>(
+                "own_player",
+                |m: &Server_Snapshot| { &m.own_player },
+                |m: &mut Server_Snapshot| { &mut m.own_player },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Server_Snapshot_Knowledge>>(
+                "knowledge",
+                |m: &Server_Snapshot| { &m.knowledge },
+                |m: &mut Server_Snapshot| { &mut m.knowledge },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::game::Phase>>(
+                "phase",
+                |m: &Server_Snapshot| { &m.phase },
+                |m: &mut Server_Snapshot| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Server_ChatPosted>>(
+                "chat",
+                |m: &Server_Snapshot| { &m.chat },
+                |m: &mut Server_Snapshot| { &mut m.chat },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_Snapshot>(
+                "Server.Snapshot",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Server_GameUpdated {
-        static instance: ::protobuf::rt::LazyV2<Server_GameUpdated> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server_GameUpdated::new)
+    fn default_instance() -> &'static Server_Snapshot {
+        static instance: ::protobuf::rt::LazyV2<Server_Snapshot> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_Snapshot::new)
     }
 }
 
-impl ::protobuf::Clear for Server_GameUpdated {
+impl ::protobuf::Clear for Server_Snapshot {
     fn clear(&mut self) {
         self.game.clear();
+        self.players.clear();
+        self.own_player.clear();
+        self.knowledge.clear();
+        self.phase = super::game::Phase::LOBBY;
+        self.chat.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Server_GameUpdated {
+impl ::std::fmt::Debug for Server_Snapshot {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Server_GameUpdated {
+impl ::protobuf::reflect::ProtobufValue for Server_Snapshot {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Server_PlayerEntered {
+pub struct Server_Snapshot_PlayerPresence {
     // message fields
     pub player: ::protobuf::SingularPtrField<super::player::Player>,
+    pub connected: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Server_PlayerEntered {
-    fn default() -> &'a Server_PlayerEntered {
-        <Server_PlayerEntered as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_Snapshot_PlayerPresence {
+    fn default() -> &'a Server_Snapshot_PlayerPresence {
+        <Server_Snapshot_PlayerPresence as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Server_PlayerEntered {
-    pub fn new() -> Server_PlayerEntered {
+impl Server_Snapshot_PlayerPresence {
+    pub fn new() -> Server_Snapshot_PlayerPresence {
         ::std::default::Default::default()
     }
 
@@ -1707,9 +8408,24 @@ impl Server_PlayerEntered {
     pub fn take_player(&mut self) -> super::player::Player {
         self.player.take().unwrap_or_else(|| super::player::Player::new())
     }
+
+    // bool connected = 2;
+
+
+    pub fn get_connected(&self) -> bool {
+        self.connected
+    }
+    pub fn clear_connected(&mut self) {
+        self.connected = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_connected(&mut self, v: bool) {
+        self.connected = v;
+    }
 }
 
-impl ::protobuf::Message for Server_PlayerEntered {
+impl ::protobuf::Message for Server_Snapshot_PlayerPresence {
     fn is_initialized(&self) -> bool {
         for v in &self.player {
             if !v.is_initialized() {
@@ -1726,6 +8442,13 @@ impl ::protobuf::Message for Server_PlayerEntered {
                 1 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.player)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.connected = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1742,6 +8465,9 @@ impl ::protobuf::Message for Server_PlayerEntered {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if self.connected != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1753,6 +8479,9 @@ impl ::protobuf::Message for Server_PlayerEntered {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if self.connected != false {
+            os.write_bool(2, self.connected)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1783,8 +8512,8 @@ impl ::protobuf::Message for Server_PlayerEntered {
         Self::descriptor_static()
     }
 
-    fn new() -> Server_PlayerEntered {
-        Server_PlayerEntered::new()
+    fn new() -> Server_Snapshot_PlayerPresence {
+        Server_Snapshot_PlayerPresence::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1793,91 +8522,170 @@ impl ::protobuf::Message for Server_PlayerEntered {
             let mut fields = ::std::vec::Vec::new();
             fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::player::Player>>(
                 "player",
-                |m: &Server_PlayerEntered| { &m.player },
-                |m: &mut Server_PlayerEntered| { &mut m.player },
+                |m: &Server_Snapshot_PlayerPresence| { &m.player },
+                |m: &mut Server_Snapshot_PlayerPresence| { &mut m.player },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerEntered>(
-                "Server.PlayerEntered",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "connected",
+                |m: &Server_Snapshot_PlayerPresence| { &m.connected },
+                |m: &mut Server_Snapshot_PlayerPresence| { &mut m.connected },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_Snapshot_PlayerPresence>(
+                "Server.Snapshot.PlayerPresence",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Server_PlayerEntered {
-        static instance: ::protobuf::rt::LazyV2<Server_PlayerEntered> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server_PlayerEntered::new)
+    fn default_instance() -> &'static Server_Snapshot_PlayerPresence {
+        static instance: ::protobuf::rt::LazyV2<Server_Snapshot_PlayerPresence> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_Snapshot_PlayerPresence::new)
     }
 }
 
-impl ::protobuf::Clear for Server_PlayerEntered {
+impl ::protobuf::Clear for Server_Snapshot_PlayerPresence {
     fn clear(&mut self) {
         self.player.clear();
+        self.connected = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Server_PlayerEntered {
+impl ::std::fmt::Debug for Server_Snapshot_PlayerPresence {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Server_PlayerEntered {
+impl ::protobuf::reflect::ProtobufValue for Server_Snapshot_PlayerPresence {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Server_PlayerLeft {
+pub struct Server_Snapshot_Knowledge {
     // message fields
-    pub player_id: ::std::string::String,
+    pub role: super::role::Role,
+    pub clan_member_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub clan_leader_id: ::std::string::String,
+    pub investigations: ::protobuf::RepeatedField<Server_LoyaltyRevealed>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Server_PlayerLeft {
-    fn default() -> &'a Server_PlayerLeft {
-        <Server_PlayerLeft as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Server_Snapshot_Knowledge {
+    fn default() -> &'a Server_Snapshot_Knowledge {
+        <Server_Snapshot_Knowledge as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Server_PlayerLeft {
-    pub fn new() -> Server_PlayerLeft {
+impl Server_Snapshot_Knowledge {
+    pub fn new() -> Server_Snapshot_Knowledge {
         ::std::default::Default::default()
     }
 
-    // string player_id = 1;
+    // .Role role = 1;
 
 
-    pub fn get_player_id(&self) -> &str {
-        &self.player_id
+    pub fn get_role(&self) -> super::role::Role {
+        self.role
     }
-    pub fn clear_player_id(&mut self) {
-        self.player_id.clear();
+    pub fn clear_role(&mut self) {
+        self.role = super::role::Role::LOYAL;
     }
 
     // Param is passed by value, moved
-    pub fn set_player_id(&mut self, v: ::std::string::String) {
-        self.player_id = v;
+    pub fn set_role(&mut self, v: super::role::Role) {
+        self.role = v;
+    }
+
+    // repeated string clan_member_ids = 2;
+
+
+    pub fn get_clan_member_ids(&self) -> &[::std::string::String] {
+        &self.clan_member_ids
+    }
+    pub fn clear_clan_member_ids(&mut self) {
+        self.clan_member_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_member_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.clan_member_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_clan_member_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.clan_member_ids
+    }
+
+    // Take field
+    pub fn take_clan_member_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.clan_member_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // string clan_leader_id = 3;
+
+
+    pub fn get_clan_leader_id(&self) -> &str {
+        &self.clan_leader_id
+    }
+    pub fn clear_clan_leader_id(&mut self) {
+        self.clan_leader_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_leader_id(&mut self, v: ::std::string::String) {
+        self.clan_leader_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
-        &mut self.player_id
+    pub fn mut_clan_leader_id(&mut self) -> &mut ::std::string::String {
+        &mut self.clan_leader_id
     }
 
     // Take field
-    pub fn take_player_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    pub fn take_clan_leader_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.clan_leader_id, ::std::string::String::new())
+    }
+
+    // repeated .Server.LoyaltyRevealed investigations = 4;
+
+
+    pub fn get_investigations(&self) -> &[Server_LoyaltyRevealed] {
+        &self.investigations
+    }
+    pub fn clear_investigations(&mut self) {
+        self.investigations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_investigations(&mut self, v: ::protobuf::RepeatedField<Server_LoyaltyRevealed>) {
+        self.investigations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_investigations(&mut self) -> &mut ::protobuf::RepeatedField<Server_LoyaltyRevealed> {
+        &mut self.investigations
+    }
+
+    // Take field
+    pub fn take_investigations(&mut self) -> ::protobuf::RepeatedField<Server_LoyaltyRevealed> {
+        ::std::mem::replace(&mut self.investigations, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for Server_PlayerLeft {
+impl ::protobuf::Message for Server_Snapshot_Knowledge {
     fn is_initialized(&self) -> bool {
+        for v in &self.investigations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1886,7 +8694,16 @@ impl ::protobuf::Message for Server_PlayerLeft {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.clan_member_ids)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clan_leader_id)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.investigations)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1900,18 +8717,39 @@ impl ::protobuf::Message for Server_PlayerLeft {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.player_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        if self.role != super::role::Role::LOYAL {
+            my_size += ::protobuf::rt::enum_size(1, self.role);
         }
+        for value in &self.clan_member_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if !self.clan_leader_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.clan_leader_id);
+        }
+        for value in &self.investigations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.player_id.is_empty() {
-            os.write_string(1, &self.player_id)?;
+        if self.role != super::role::Role::LOYAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        for v in &self.clan_member_ids {
+            os.write_string(2, &v)?;
+        };
+        if !self.clan_leader_id.is_empty() {
+            os.write_string(3, &self.clan_leader_id)?;
         }
+        for v in &self.investigations {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1942,127 +8780,465 @@ impl ::protobuf::Message for Server_PlayerLeft {
         Self::descriptor_static()
     }
 
-    fn new() -> Server_PlayerLeft {
-        Server_PlayerLeft::new()
+    fn new() -> Server_Snapshot_Knowledge {
+        Server_Snapshot_Knowledge::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Role>>(
+                "role",
+                |m: &Server_Snapshot_Knowledge| { &m.role },
+                |m: &mut Server_Snapshot_Knowledge| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clan_member_ids",
+                |m: &Server_Snapshot_Knowledge| { &m.clan_member_ids },
+                |m: &mut Server_Snapshot_Knowledge| { &mut m.clan_member_ids },
+            ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "player_id",
-                |m: &Server_PlayerLeft| { &m.player_id },
-                |m: &mut Server_PlayerLeft| { &mut m.player_id },
+                "clan_leader_id",
+                |m: &Server_Snapshot_Knowledge| { &m.clan_leader_id },
+                |m: &mut Server_Snapshot_Knowledge| { &mut m.clan_leader_id },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_PlayerLeft>(
-                "Server.PlayerLeft",
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Server_LoyaltyRevealed>>(
+                "investigations",
+                |m: &Server_Snapshot_Knowledge| { &m.investigations },
+                |m: &mut Server_Snapshot_Knowledge| { &mut m.investigations },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Server_Snapshot_Knowledge>(
+                "Server.Snapshot.Knowledge",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Server_PlayerLeft {
-        static instance: ::protobuf::rt::LazyV2<Server_PlayerLeft> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Server_PlayerLeft::new)
+    fn default_instance() -> &'static Server_Snapshot_Knowledge {
+        static instance: ::protobuf::rt::LazyV2<Server_Snapshot_Knowledge> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Server_Snapshot_Knowledge::new)
     }
 }
 
-impl ::protobuf::Clear for Server_PlayerLeft {
+impl ::protobuf::Clear for Server_Snapshot_Knowledge {
     fn clear(&mut self) {
-        self.player_id.clear();
+        self.role = super::role::Role::LOYAL;
+        self.clan_member_ids.clear();
+        self.clan_leader_id.clear();
+        self.investigations.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Server_PlayerLeft {
+impl ::std::fmt::Debug for Server_Snapshot_Knowledge {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Server_PlayerLeft {
+impl ::protobuf::reflect::ProtobufValue for Server_Snapshot_Knowledge {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\rmessage.proto\x1a\x0cplayer.proto\x1a\ngame.proto\"\xd5\x01\n\x06Cli\
-    ent\x12=\n\rauthConfirmed\x18\x01\x20\x01(\x0b2\x15.Client.AuthConfirmed\
-    H\0R\rauthConfirmed\x127\n\x0bnameUpdated\x18\x02\x20\x01(\x0b2\x13.Clie\
-    nt.NameUpdatedH\0R\x0bnameUpdated\x1a%\n\rAuthConfirmed\x12\x14\n\x05tok\
-    en\x18\x01\x20\x01(\tR\x05token\x1a!\n\x0bNameUpdated\x12\x12\n\x04name\
-    \x18\x01\x20\x01(\tR\x04nameB\t\n\x07message\"\xa5\x04\n\x06Server\x12=\
-    \n\rplayerUpdated\x18\x01\x20\x01(\x0b2\x15.Server.PlayerUpdatedH\0R\rpl\
-    ayerUpdated\x127\n\x0bgameUpdated\x18\x02\x20\x01(\x0b2\x13.Server.GameU\
-    pdatedH\0R\x0bgameUpdated\x127\n\x0bselfUpdated\x18\x03\x20\x01(\x0b2\
-    \x13.Server.SelfUpdatedH\0R\x0bselfUpdated\x12=\n\rplayerEntered\x18\x04\
-    \x20\x01(\x0b2\x15.Server.PlayerEnteredH\0R\rplayerEntered\x124\n\nplaye\
-    rLeft\x18\x05\x20\x01(\x0b2\x12.Server.PlayerLeftH\0R\nplayerLeft\x1a0\n\
-    \rPlayerUpdated\x12\x1f\n\x06player\x18\x01\x20\x01(\x0b2\x07.PlayerR\
-    \x06player\x1a1\n\x0bSelfUpdated\x12\"\n\x06player\x18\x01\x20\x01(\x0b2\
-    \n.OwnPlayerR\x06player\x1a(\n\x0bGameUpdated\x12\x19\n\x04game\x18\x01\
-    \x20\x01(\x0b2\x05.GameR\x04game\x1a0\n\rPlayerEntered\x12\x1f\n\x06play\
-    er\x18\x01\x20\x01(\x0b2\x07.PlayerR\x06player\x1a)\n\nPlayerLeft\x12\
-    \x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerIdB\t\n\x07messageJ\x87\t\
-    \n\x06\x12\x04\0\0\x1d\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\
-    \0\x12\x03\x02\0\x16\n\t\n\x02\x03\x01\x12\x03\x03\0\x14\n\n\n\x02\x04\0\
-    \x12\x04\x05\0\r\x01\n\n\n\x03\x04\0\x01\x12\x03\x05\x08\x0e\n\x0b\n\x04\
-    \x04\0\x03\0\x12\x03\x06\x02-\n\x0c\n\x05\x04\0\x03\0\x01\x12\x03\x06\n\
-    \x17\n\r\n\x06\x04\0\x03\0\x02\0\x12\x03\x06\x1a+\n\x0e\n\x07\x04\0\x03\
-    \0\x02\0\x05\x12\x03\x06\x1a\x20\n\x0e\n\x07\x04\0\x03\0\x02\0\x01\x12\
-    \x03\x06!&\n\x0e\n\x07\x04\0\x03\0\x02\0\x03\x12\x03\x06)*\n\x0b\n\x04\
-    \x04\0\x03\x01\x12\x03\x07\x02*\n\x0c\n\x05\x04\0\x03\x01\x01\x12\x03\
-    \x07\n\x15\n\r\n\x06\x04\0\x03\x01\x02\0\x12\x03\x07\x18(\n\x0e\n\x07\
-    \x04\0\x03\x01\x02\0\x05\x12\x03\x07\x18\x1e\n\x0e\n\x07\x04\0\x03\x01\
-    \x02\0\x01\x12\x03\x07\x1f#\n\x0e\n\x07\x04\0\x03\x01\x02\0\x03\x12\x03\
-    \x07&'\n\x0c\n\x04\x04\0\x08\0\x12\x04\t\x02\x0c\x03\n\x0c\n\x05\x04\0\
-    \x08\0\x01\x12\x03\t\x08\x0f\n\x0b\n\x04\x04\0\x02\0\x12\x03\n\x04$\n\
-    \x0c\n\x05\x04\0\x02\0\x06\x12\x03\n\x04\x11\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03\n\x12\x1f\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03\n\"#\n\x0b\n\x04\
-    \x04\0\x02\x01\x12\x03\x0b\x04\x20\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\
-    \x0b\x04\x0f\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\x0b\x10\x1b\n\x0c\n\
-    \x05\x04\0\x02\x01\x03\x12\x03\x0b\x1e\x1f\n\n\n\x02\x04\x01\x12\x04\x0f\
-    \0\x1d\x01\n\n\n\x03\x04\x01\x01\x12\x03\x0f\x08\x0e\n\x0b\n\x04\x04\x01\
-    \x03\0\x12\x03\x10\x02.\n\x0c\n\x05\x04\x01\x03\0\x01\x12\x03\x10\n\x17\
-    \n\r\n\x06\x04\x01\x03\0\x02\0\x12\x03\x10\x1a,\n\x0e\n\x07\x04\x01\x03\
-    \0\x02\0\x06\x12\x03\x10\x1a\x20\n\x0e\n\x07\x04\x01\x03\0\x02\0\x01\x12\
-    \x03\x10!'\n\x0e\n\x07\x04\x01\x03\0\x02\0\x03\x12\x03\x10*+\n\x0b\n\x04\
-    \x04\x01\x03\x01\x12\x03\x11\x02/\n\x0c\n\x05\x04\x01\x03\x01\x01\x12\
-    \x03\x11\n\x15\n\r\n\x06\x04\x01\x03\x01\x02\0\x12\x03\x11\x18-\n\x0e\n\
-    \x07\x04\x01\x03\x01\x02\0\x06\x12\x03\x11\x18!\n\x0e\n\x07\x04\x01\x03\
-    \x01\x02\0\x01\x12\x03\x11\"(\n\x0e\n\x07\x04\x01\x03\x01\x02\0\x03\x12\
-    \x03\x11+,\n\x0b\n\x04\x04\x01\x03\x02\x12\x03\x12\x02(\n\x0c\n\x05\x04\
-    \x01\x03\x02\x01\x12\x03\x12\n\x15\n\r\n\x06\x04\x01\x03\x02\x02\0\x12\
-    \x03\x12\x18&\n\x0e\n\x07\x04\x01\x03\x02\x02\0\x06\x12\x03\x12\x18\x1c\
-    \n\x0e\n\x07\x04\x01\x03\x02\x02\0\x01\x12\x03\x12\x1d!\n\x0e\n\x07\x04\
-    \x01\x03\x02\x02\0\x03\x12\x03\x12$%\n\x0b\n\x04\x04\x01\x03\x03\x12\x03\
-    \x13\x02.\n\x0c\n\x05\x04\x01\x03\x03\x01\x12\x03\x13\n\x17\n\r\n\x06\
-    \x04\x01\x03\x03\x02\0\x12\x03\x13\x1a,\n\x0e\n\x07\x04\x01\x03\x03\x02\
-    \0\x06\x12\x03\x13\x1a\x20\n\x0e\n\x07\x04\x01\x03\x03\x02\0\x01\x12\x03\
-    \x13!'\n\x0e\n\x07\x04\x01\x03\x03\x02\0\x03\x12\x03\x13*+\n\x0b\n\x04\
-    \x04\x01\x03\x04\x12\x03\x14\x02.\n\x0c\n\x05\x04\x01\x03\x04\x01\x12\
-    \x03\x14\n\x14\n\r\n\x06\x04\x01\x03\x04\x02\0\x12\x03\x14\x17,\n\x0e\n\
-    \x07\x04\x01\x03\x04\x02\0\x05\x12\x03\x14\x17\x1d\n\x0e\n\x07\x04\x01\
-    \x03\x04\x02\0\x01\x12\x03\x14\x1e'\n\x0e\n\x07\x04\x01\x03\x04\x02\0\
-    \x03\x12\x03\x14*+\n\x0c\n\x04\x04\x01\x08\0\x12\x04\x16\x02\x1c\x03\n\
-    \x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x16\x08\x0f\n\x0b\n\x04\x04\x01\x02\
-    \0\x12\x03\x17\x04$\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03\x17\x04\x11\n\
-    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x17\x12\x1f\n\x0c\n\x05\x04\x01\x02\
-    \0\x03\x12\x03\x17\"#\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x18\x04\x20\n\
-    \x0c\n\x05\x04\x01\x02\x01\x06\x12\x03\x18\x04\x0f\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\x18\x10\x1b\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\
-    \x18\x1e\x1f\n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x19\x04\x20\n\x0c\n\x05\
-    \x04\x01\x02\x02\x06\x12\x03\x19\x04\x0f\n\x0c\n\x05\x04\x01\x02\x02\x01\
-    \x12\x03\x19\x10\x1b\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03\x19\x1e\x1f\
-    \n\x0b\n\x04\x04\x01\x02\x03\x12\x03\x1a\x04$\n\x0c\n\x05\x04\x01\x02\
-    \x03\x06\x12\x03\x1a\x04\x11\n\x0c\n\x05\x04\x01\x02\x03\x01\x12\x03\x1a\
-    \x12\x1f\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x1a\"#\n\x0b\n\x04\x04\
-    \x01\x02\x04\x12\x03\x1b\x04\x1e\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\
-    \x1b\x04\x0e\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x1b\x0f\x19\n\x0c\n\
-    \x05\x04\x01\x02\x04\x03\x12\x03\x1b\x1c\x1db\x06proto3\
+    \n\rmessage.proto\x1a\x0cplayer.proto\x1a\ngame.proto\x1a\x0cpolicy.prot\
+    o\x1a\nrole.proto\x1a\x0esettings.proto\"\xcc\r\n\x06Client\x12=\n\rauth\
+    Confirmed\x18\x01\x20\x01(\x0b2\x15.Client.AuthConfirmedH\0R\rauthConfir\
+    med\x127\n\x0bnameUpdated\x18\x02\x20\x01(\x0b2\x13.Client.NameUpdatedH\
+    \0R\x0bnameUpdated\x12@\n\x0eroleDiscovered\x18\x03\x20\x01(\x0b2\x16.Cl\
+    ient.RoleDiscoveredH\0R\x0eroleDiscovered\x12O\n\x13chancellorNominated\
+    \x18\x04\x20\x01(\x0b2\x1b.Client.ChancellorNominatedH\0R\x13chancellorN\
+    ominated\x12.\n\x08voteCast\x18\x05\x20\x01(\x0b2\x10.Client.VoteCastH\0\
+    R\x08voteCast\x12C\n\x0fpolicyDiscarded\x18\x06\x20\x01(\x0b2\x17.Client\
+    .PolicyDiscardedH\0R\x0fpolicyDiscarded\x12=\n\rpolicyEnacted\x18\x07\
+    \x20\x01(\x0b2\x15.Client.PolicyEnactedH\0R\rpolicyEnacted\x12O\n\x13loy\
+    altyInvestigated\x18\x08\x20\x01(\x0b2\x1b.Client.LoyaltyInvestigatedH\0\
+    R\x13loyaltyInvestigated\x12U\n\x15specialElectionCalled\x18\t\x20\x01(\
+    \x0b2\x1d.Client.SpecialElectionCalledH\0R\x15specialElectionCalled\x12@\
+    \n\x0epoliciesPeeked\x18\n\x20\x01(\x0b2\x16.Client.PoliciesPeekedH\0R\
+    \x0epoliciesPeeked\x12@\n\x0eplayerExecuted\x18\x0b\x20\x01(\x0b2\x16.Cl\
+    ient.PlayerExecutedH\0R\x0eplayerExecuted\x12O\n\x13gameSettingsUpdated\
+    \x18\x0c\x20\x01(\x0b2\x1b.Client.GameSettingsUpdatedH\0R\x13gameSetting\
+    sUpdated\x127\n\x0bchatMessage\x18\r\x20\x01(\x0b2\x13.Client.ChatMessag\
+    eH\0R\x0bchatMessage\x12:\n\x0creadyChanged\x18\x0e\x20\x01(\x0b2\x14.Cl\
+    ient.ReadyChangedH\0R\x0creadyChanged\x12:\n\x0cvetoProposed\x18\x0f\x20\
+    \x01(\x0b2\x14.Client.VetoProposedH\0R\x0cvetoProposed\x12:\n\x0cvetoAns\
+    wered\x18\x10\x20\x01(\x0b2\x14.Client.VetoAnsweredH\0R\x0cvetoAnswered\
+    \x1a%\n\rAuthConfirmed\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\
+    \x1a!\n\x0bNameUpdated\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x1a\
+    \x10\n\x0eRoleDiscovered\x1a2\n\x13ChancellorNominated\x12\x1b\n\tplayer\
+    _id\x18\x01\x20\x01(\tR\x08playerId\x1a&\n\x08VoteCast\x12\x1a\n\x08appr\
+    oved\x18\x01\x20\x01(\x08R\x08approved\x1a2\n\x0fPolicyDiscarded\x12\x1f\
+    \n\x06policy\x18\x01\x20\x01(\x0e2\x07.PolicyR\x06policy\x1a0\n\rPolicyE\
+    nacted\x12\x1f\n\x06policy\x18\x01\x20\x01(\x0e2\x07.PolicyR\x06policy\
+    \x1a2\n\x13LoyaltyInvestigated\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\
+    \x08playerId\x1a4\n\x15SpecialElectionCalled\x12\x1b\n\tplayer_id\x18\
+    \x01\x20\x01(\tR\x08playerId\x1a\x10\n\x0ePoliciesPeeked\x1a-\n\x0ePlaye\
+    rExecuted\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x1a@\n\
+    \x13GameSettingsUpdated\x12)\n\x08settings\x18\x01\x20\x01(\x0b2\r.GameS\
+    ettingsR\x08settings\x1a!\n\x0bChatMessage\x12\x12\n\x04text\x18\x01\x20\
+    \x01(\tR\x04text\x1a$\n\x0cReadyChanged\x12\x14\n\x05ready\x18\x01\x20\
+    \x01(\x08R\x05ready\x1a\x0e\n\x0cVetoProposed\x1a*\n\x0cVetoAnswered\x12\
+    \x1a\n\x08approved\x18\x01\x20\x01(\x08R\x08approvedB\t\n\x07message\"\
+    \xfd\x14\n\x06Server\x12=\n\rplayerUpdated\x18\x01\x20\x01(\x0b2\x15.Ser\
+    ver.PlayerUpdatedH\0R\rplayerUpdated\x127\n\x0bgameUpdated\x18\x02\x20\
+    \x01(\x0b2\x13.Server.GameUpdatedH\0R\x0bgameUpdated\x127\n\x0bselfUpdat\
+    ed\x18\x03\x20\x01(\x0b2\x13.Server.SelfUpdatedH\0R\x0bselfUpdated\x12=\
+    \n\rplayerEntered\x18\x04\x20\x01(\x0b2\x15.Server.PlayerEnteredH\0R\rpl\
+    ayerEntered\x124\n\nplayerLeft\x18\x05\x20\x01(\x0b2\x12.Server.PlayerLe\
+    ftH\0R\nplayerLeft\x12:\n\x0croleAssigned\x18\x06\x20\x01(\x0b2\x14.Serv\
+    er.RoleAssignedH\0R\x0croleAssigned\x12:\n\x0cvotesCounted\x18\x07\x20\
+    \x01(\x0b2\x14.Server.VotesCountedH\0R\x0cvotesCounted\x12L\n\x12chaosPo\
+    licyEnacted\x18\x08\x20\x01(\x0b2\x1a.Server.ChaosPolicyEnactedH\0R\x12c\
+    haosPolicyEnacted\x12C\n\x0floyaltyRevealed\x18\t\x20\x01(\x0b2\x17.Serv\
+    er.LoyaltyRevealedH\0R\x0floyaltyRevealed\x12F\n\x10policiesRevealed\x18\
+    \n\x20\x01(\x0b2\x18.Server.PoliciesRevealedH\0R\x10policiesRevealed\x12\
+    U\n\x15specialElectionCalled\x18\x0b\x20\x01(\x0b2\x1d.Server.SpecialEle\
+    ctionCalledH\0R\x15specialElectionCalled\x12@\n\x0eplayerExecuted\x18\
+    \x0c\x20\x01(\x0b2\x16.Server.PlayerExecutedH\0R\x0eplayerExecuted\x12:\
+    \n\x0cgameFinished\x18\r\x20\x01(\x0b2\x14.Server.GameFinishedH\0R\x0cga\
+    meFinished\x12.\n\x08snapshot\x18\x0e\x20\x01(\x0b2\x10.Server.SnapshotH\
+    \0R\x08snapshot\x124\n\nchatPosted\x18\x0f\x20\x01(\x0b2\x12.Server.Chat\
+    PostedH\0R\nchatPosted\x12:\n\x0cnameRejected\x18\x10\x20\x01(\x0b2\x14.\
+    Server.NameRejectedH\0R\x0cnameRejected\x1a0\n\rPlayerUpdated\x12\x1f\n\
+    \x06player\x18\x01\x20\x01(\x0b2\x07.PlayerR\x06player\x1a1\n\x0bSelfUpd\
+    ated\x12\"\n\x06player\x18\x01\x20\x01(\x0b2\n.OwnPlayerR\x06player\x1a(\
+    \n\x0bGameUpdated\x12\x19\n\x04game\x18\x01\x20\x01(\x0b2\x05.GameR\x04g\
+    ame\x1a0\n\rPlayerEntered\x12\x1f\n\x06player\x18\x01\x20\x01(\x0b2\x07.\
+    PlayerR\x06player\x1a)\n\nPlayerLeft\x12\x1b\n\tplayer_id\x18\x01\x20\
+    \x01(\tR\x08playerId\x1aw\n\x0cRoleAssigned\x12\x19\n\x04role\x18\x01\
+    \x20\x01(\x0e2\x05.RoleR\x04role\x12&\n\x0fclan_member_ids\x18\x02\x20\
+    \x03(\tR\rclanMemberIds\x12$\n\x0eclan_leader_id\x18\x03\x20\x01(\tR\x0c\
+    clanLeaderId\x1a\xc2\x01\n\x0cVotesCounted\x120\n\x14approving_player_id\
+    s\x18\x01\x20\x03(\tR\x12approvingPlayerIds\x120\n\x14rejecting_player_i\
+    ds\x18\x02\x20\x03(\tR\x12rejectingPlayerIds\x12\x1a\n\x08approved\x18\
+    \x03\x20\x01(\x08R\x08approved\x122\n\x15abstaining_player_ids\x18\x04\
+    \x20\x03(\tR\x13abstainingPlayerIds\x1a5\n\x12ChaosPolicyEnacted\x12\x1f\
+    \n\x06policy\x18\x01\x20\x01(\x0e2\x07.PolicyR\x06policy\x1aL\n\x0fLoyal\
+    tyRevealed\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1c\
+    \n\x05party\x18\x02\x20\x01(\x0e2\x06.PartyR\x05party\x1a7\n\x10Policies\
+    Revealed\x12#\n\x08policies\x18\x01\x20\x03(\x0e2\x07.PolicyR\x08policie\
+    s\x1a4\n\x15SpecialElectionCalled\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\
+    \tR\x08playerId\x1a-\n\x0ePlayerExecuted\x12\x1b\n\tplayer_id\x18\x01\
+    \x20\x01(\tR\x08playerId\x1au\n\x0cGameFinished\x12\x1e\n\x06winner\x18\
+    \x01\x20\x01(\x0e2\x06.PartyR\x06winner\x12\"\n\x06reason\x18\x02\x20\
+    \x01(\x0e2\n.WinReasonR\x06reason\x12!\n\x05roles\x18\x03\x20\x03(\x0b2\
+    \x0b.PlayerRoleR\x05roles\x1aQ\n\nChatPosted\x12\x1b\n\tplayer_id\x18\
+    \x01\x20\x01(\tR\x08playerId\x12\x12\n\x04text\x18\x02\x20\x01(\tR\x04te\
+    xt\x12\x12\n\x04time\x18\x03\x20\x01(\tR\x04time\x1aJ\n\x0cNameRejected\
+    \x12&\n\x06reason\x18\x01\x20\x01(\x0e2\x0e.NameRejectionR\x06reason\x12\
+    \x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x1a\x94\x04\n\x08Snapshot\x12\
+    \x19\n\x04game\x18\x01\x20\x01(\x0b2\x05.GameR\x04game\x129\n\x07players\
+    \x18\x02\x20\x03(\x0b2\x1f.Server.Snapshot.PlayerPresenceR\x07players\
+    \x12)\n\nown_player\x18\x03\x20\x01(\x0b2\n.OwnPlayerR\townPlayer\x128\n\
+    \tknowledge\x18\x04\x20\x01(\x0b2\x1a.Server.Snapshot.KnowledgeR\tknowle\
+    dge\x12\x1c\n\x05phase\x18\x05\x20\x01(\x0e2\x06.PhaseR\x05phase\x12&\n\
+    \x04chat\x18\x06\x20\x03(\x0b2\x12.Server.ChatPostedR\x04chat\x1aO\n\x0e\
+    PlayerPresence\x12\x1f\n\x06player\x18\x01\x20\x01(\x0b2\x07.PlayerR\x06\
+    player\x12\x1c\n\tconnected\x18\x02\x20\x01(\x08R\tconnected\x1a\xb5\x01\
+    \n\tKnowledge\x12\x19\n\x04role\x18\x01\x20\x01(\x0e2\x05.RoleR\x04role\
+    \x12&\n\x0fclan_member_ids\x18\x02\x20\x03(\tR\rclanMemberIds\x12$\n\x0e\
+    clan_leader_id\x18\x03\x20\x01(\tR\x0cclanLeaderId\x12?\n\x0einvestigati\
+    ons\x18\x04\x20\x03(\x0b2\x17.Server.LoyaltyRevealedR\x0einvestigationsB\
+    \t\n\x07messageJ\x991\n\x06\x12\x04\0\0z\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\t\n\x02\x03\0\x12\x03\x02\0\x16\n\t\n\x02\x03\x01\x12\x03\x03\0\
+    \x14\n\t\n\x02\x03\x02\x12\x03\x04\0\x16\n\t\n\x02\x03\x03\x12\x03\x05\0\
+    \x14\n\t\n\x02\x03\x04\x12\x03\x06\0\x18\n\n\n\x02\x04\0\x12\x04\x08\0,\
+    \x01\n\n\n\x03\x04\0\x01\x12\x03\x08\x08\x0e\n\x0b\n\x04\x04\0\x03\0\x12\
+    \x03\t\x02-\n\x0c\n\x05\x04\0\x03\0\x01\x12\x03\t\n\x17\n\r\n\x06\x04\0\
+    \x03\0\x02\0\x12\x03\t\x1a+\n\x0e\n\x07\x04\0\x03\0\x02\0\x05\x12\x03\t\
+    \x1a\x20\n\x0e\n\x07\x04\0\x03\0\x02\0\x01\x12\x03\t!&\n\x0e\n\x07\x04\0\
+    \x03\0\x02\0\x03\x12\x03\t)*\n\x0b\n\x04\x04\0\x03\x01\x12\x03\n\x02*\n\
+    \x0c\n\x05\x04\0\x03\x01\x01\x12\x03\n\n\x15\n\r\n\x06\x04\0\x03\x01\x02\
+    \0\x12\x03\n\x18(\n\x0e\n\x07\x04\0\x03\x01\x02\0\x05\x12\x03\n\x18\x1e\
+    \n\x0e\n\x07\x04\0\x03\x01\x02\0\x01\x12\x03\n\x1f#\n\x0e\n\x07\x04\0\
+    \x03\x01\x02\0\x03\x12\x03\n&'\n\x0b\n\x04\x04\0\x03\x02\x12\x03\x0b\x02\
+    \x1b\n\x0c\n\x05\x04\0\x03\x02\x01\x12\x03\x0b\n\x18\n\x0b\n\x04\x04\0\
+    \x03\x03\x12\x03\x0c\x027\n\x0c\n\x05\x04\0\x03\x03\x01\x12\x03\x0c\n\
+    \x1d\n\r\n\x06\x04\0\x03\x03\x02\0\x12\x03\x0c\x205\n\x0e\n\x07\x04\0\
+    \x03\x03\x02\0\x05\x12\x03\x0c\x20&\n\x0e\n\x07\x04\0\x03\x03\x02\0\x01\
+    \x12\x03\x0c'0\n\x0e\n\x07\x04\0\x03\x03\x02\0\x03\x12\x03\x0c34\n\x0b\n\
+    \x04\x04\0\x03\x04\x12\x03\r\x02)\n\x0c\n\x05\x04\0\x03\x04\x01\x12\x03\
+    \r\n\x12\n\r\n\x06\x04\0\x03\x04\x02\0\x12\x03\r\x15'\n\x0e\n\x07\x04\0\
+    \x03\x04\x02\0\x05\x12\x03\r\x15\x19\n\x0e\n\x07\x04\0\x03\x04\x02\0\x01\
+    \x12\x03\r\x1a\"\n\x0e\n\x07\x04\0\x03\x04\x02\0\x03\x12\x03\r%&\n\x0b\n\
+    \x04\x04\0\x03\x05\x12\x03\x0e\x020\n\x0c\n\x05\x04\0\x03\x05\x01\x12\
+    \x03\x0e\n\x19\n\r\n\x06\x04\0\x03\x05\x02\0\x12\x03\x0e\x1c.\n\x0e\n\
+    \x07\x04\0\x03\x05\x02\0\x06\x12\x03\x0e\x1c\"\n\x0e\n\x07\x04\0\x03\x05\
+    \x02\0\x01\x12\x03\x0e#)\n\x0e\n\x07\x04\0\x03\x05\x02\0\x03\x12\x03\x0e\
+    ,-\n\x0b\n\x04\x04\0\x03\x06\x12\x03\x0f\x02.\n\x0c\n\x05\x04\0\x03\x06\
+    \x01\x12\x03\x0f\n\x17\n\r\n\x06\x04\0\x03\x06\x02\0\x12\x03\x0f\x1a,\n\
+    \x0e\n\x07\x04\0\x03\x06\x02\0\x06\x12\x03\x0f\x1a\x20\n\x0e\n\x07\x04\0\
+    \x03\x06\x02\0\x01\x12\x03\x0f!'\n\x0e\n\x07\x04\0\x03\x06\x02\0\x03\x12\
+    \x03\x0f*+\n\x0b\n\x04\x04\0\x03\x07\x12\x03\x10\x027\n\x0c\n\x05\x04\0\
+    \x03\x07\x01\x12\x03\x10\n\x1d\n\r\n\x06\x04\0\x03\x07\x02\0\x12\x03\x10\
+    \x205\n\x0e\n\x07\x04\0\x03\x07\x02\0\x05\x12\x03\x10\x20&\n\x0e\n\x07\
+    \x04\0\x03\x07\x02\0\x01\x12\x03\x10'0\n\x0e\n\x07\x04\0\x03\x07\x02\0\
+    \x03\x12\x03\x1034\n\x0b\n\x04\x04\0\x03\x08\x12\x03\x11\x029\n\x0c\n\
+    \x05\x04\0\x03\x08\x01\x12\x03\x11\n\x1f\n\r\n\x06\x04\0\x03\x08\x02\0\
+    \x12\x03\x11\"7\n\x0e\n\x07\x04\0\x03\x08\x02\0\x05\x12\x03\x11\"(\n\x0e\
+    \n\x07\x04\0\x03\x08\x02\0\x01\x12\x03\x11)2\n\x0e\n\x07\x04\0\x03\x08\
+    \x02\0\x03\x12\x03\x1156\n\x0b\n\x04\x04\0\x03\t\x12\x03\x12\x02\x1b\n\
+    \x0c\n\x05\x04\0\x03\t\x01\x12\x03\x12\n\x18\n\x0b\n\x04\x04\0\x03\n\x12\
+    \x03\x13\x022\n\x0c\n\x05\x04\0\x03\n\x01\x12\x03\x13\n\x18\n\r\n\x06\
+    \x04\0\x03\n\x02\0\x12\x03\x13\x1b0\n\x0e\n\x07\x04\0\x03\n\x02\0\x05\
+    \x12\x03\x13\x1b!\n\x0e\n\x07\x04\0\x03\n\x02\0\x01\x12\x03\x13\"+\n\x0e\
+    \n\x07\x04\0\x03\n\x02\0\x03\x12\x03\x13./\n\x0b\n\x04\x04\0\x03\x0b\x12\
+    \x03\x14\x02<\n\x0c\n\x05\x04\0\x03\x0b\x01\x12\x03\x14\n\x1d\n\r\n\x06\
+    \x04\0\x03\x0b\x02\0\x12\x03\x14\x20:\n\x0e\n\x07\x04\0\x03\x0b\x02\0\
+    \x06\x12\x03\x14\x20,\n\x0e\n\x07\x04\0\x03\x0b\x02\0\x01\x12\x03\x14-5\
+    \n\x0e\n\x07\x04\0\x03\x0b\x02\0\x03\x12\x03\x1489\n\x0b\n\x04\x04\0\x03\
+    \x0c\x12\x03\x15\x02*\n\x0c\n\x05\x04\0\x03\x0c\x01\x12\x03\x15\n\x15\n\
+    \r\n\x06\x04\0\x03\x0c\x02\0\x12\x03\x15\x18(\n\x0e\n\x07\x04\0\x03\x0c\
+    \x02\0\x05\x12\x03\x15\x18\x1e\n\x0e\n\x07\x04\0\x03\x0c\x02\0\x01\x12\
+    \x03\x15\x1f#\n\x0e\n\x07\x04\0\x03\x0c\x02\0\x03\x12\x03\x15&'\n\x0b\n\
+    \x04\x04\0\x03\r\x12\x03\x16\x02*\n\x0c\n\x05\x04\0\x03\r\x01\x12\x03\
+    \x16\n\x16\n\r\n\x06\x04\0\x03\r\x02\0\x12\x03\x16\x19(\n\x0e\n\x07\x04\
+    \0\x03\r\x02\0\x05\x12\x03\x16\x19\x1d\n\x0e\n\x07\x04\0\x03\r\x02\0\x01\
+    \x12\x03\x16\x1e#\n\x0e\n\x07\x04\0\x03\r\x02\0\x03\x12\x03\x16&'\n\x0b\
+    \n\x04\x04\0\x03\x0e\x12\x03\x17\x02\x19\n\x0c\n\x05\x04\0\x03\x0e\x01\
+    \x12\x03\x17\n\x16\n\x0b\n\x04\x04\0\x03\x0f\x12\x03\x18\x02-\n\x0c\n\
+    \x05\x04\0\x03\x0f\x01\x12\x03\x18\n\x16\n\r\n\x06\x04\0\x03\x0f\x02\0\
+    \x12\x03\x18\x19+\n\x0e\n\x07\x04\0\x03\x0f\x02\0\x05\x12\x03\x18\x19\
+    \x1d\n\x0e\n\x07\x04\0\x03\x0f\x02\0\x01\x12\x03\x18\x1e&\n\x0e\n\x07\
+    \x04\0\x03\x0f\x02\0\x03\x12\x03\x18)*\n\x0c\n\x04\x04\0\x08\0\x12\x04\
+    \x1a\x02+\x03\n\x0c\n\x05\x04\0\x08\0\x01\x12\x03\x1a\x08\x0f\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x03\x1b\x04$\n\x0c\n\x05\x04\0\x02\0\x06\x12\x03\
+    \x1b\x04\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x1b\x12\x1f\n\x0c\n\x05\
+    \x04\0\x02\0\x03\x12\x03\x1b\"#\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x1c\
+    \x04\x20\n\x0c\n\x05\x04\0\x02\x01\x06\x12\x03\x1c\x04\x0f\n\x0c\n\x05\
+    \x04\0\x02\x01\x01\x12\x03\x1c\x10\x1b\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
+    \x03\x1c\x1e\x1f\n\x0b\n\x04\x04\0\x02\x02\x12\x03\x1d\x04&\n\x0c\n\x05\
+    \x04\0\x02\x02\x06\x12\x03\x1d\x04\x12\n\x0c\n\x05\x04\0\x02\x02\x01\x12\
+    \x03\x1d\x13!\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\x1d$%\n\x0b\n\x04\
+    \x04\0\x02\x03\x12\x03\x1e\x040\n\x0c\n\x05\x04\0\x02\x03\x06\x12\x03\
+    \x1e\x04\x17\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x1e\x18+\n\x0c\n\x05\
+    \x04\0\x02\x03\x03\x12\x03\x1e./\n\x0b\n\x04\x04\0\x02\x04\x12\x03\x1f\
+    \x04\x1a\n\x0c\n\x05\x04\0\x02\x04\x06\x12\x03\x1f\x04\x0c\n\x0c\n\x05\
+    \x04\0\x02\x04\x01\x12\x03\x1f\r\x15\n\x0c\n\x05\x04\0\x02\x04\x03\x12\
+    \x03\x1f\x18\x19\n\x0b\n\x04\x04\0\x02\x05\x12\x03\x20\x04(\n\x0c\n\x05\
+    \x04\0\x02\x05\x06\x12\x03\x20\x04\x13\n\x0c\n\x05\x04\0\x02\x05\x01\x12\
+    \x03\x20\x14#\n\x0c\n\x05\x04\0\x02\x05\x03\x12\x03\x20&'\n\x0b\n\x04\
+    \x04\0\x02\x06\x12\x03!\x04$\n\x0c\n\x05\x04\0\x02\x06\x06\x12\x03!\x04\
+    \x11\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03!\x12\x1f\n\x0c\n\x05\x04\0\
+    \x02\x06\x03\x12\x03!\"#\n\x0b\n\x04\x04\0\x02\x07\x12\x03\"\x040\n\x0c\
+    \n\x05\x04\0\x02\x07\x06\x12\x03\"\x04\x17\n\x0c\n\x05\x04\0\x02\x07\x01\
+    \x12\x03\"\x18+\n\x0c\n\x05\x04\0\x02\x07\x03\x12\x03\"./\n\x0b\n\x04\
+    \x04\0\x02\x08\x12\x03#\x044\n\x0c\n\x05\x04\0\x02\x08\x06\x12\x03#\x04\
+    \x19\n\x0c\n\x05\x04\0\x02\x08\x01\x12\x03#\x1a/\n\x0c\n\x05\x04\0\x02\
+    \x08\x03\x12\x03#23\n\x0b\n\x04\x04\0\x02\t\x12\x03$\x04'\n\x0c\n\x05\
+    \x04\0\x02\t\x06\x12\x03$\x04\x12\n\x0c\n\x05\x04\0\x02\t\x01\x12\x03$\
+    \x13!\n\x0c\n\x05\x04\0\x02\t\x03\x12\x03$$&\n\x0b\n\x04\x04\0\x02\n\x12\
+    \x03%\x04'\n\x0c\n\x05\x04\0\x02\n\x06\x12\x03%\x04\x12\n\x0c\n\x05\x04\
+    \0\x02\n\x01\x12\x03%\x13!\n\x0c\n\x05\x04\0\x02\n\x03\x12\x03%$&\n\x0b\
+    \n\x04\x04\0\x02\x0b\x12\x03&\x041\n\x0c\n\x05\x04\0\x02\x0b\x06\x12\x03\
+    &\x04\x17\n\x0c\n\x05\x04\0\x02\x0b\x01\x12\x03&\x18+\n\x0c\n\x05\x04\0\
+    \x02\x0b\x03\x12\x03&.0\n\x0b\n\x04\x04\0\x02\x0c\x12\x03'\x04!\n\x0c\n\
+    \x05\x04\0\x02\x0c\x06\x12\x03'\x04\x0f\n\x0c\n\x05\x04\0\x02\x0c\x01\
+    \x12\x03'\x10\x1b\n\x0c\n\x05\x04\0\x02\x0c\x03\x12\x03'\x1e\x20\n\x0b\n\
+    \x04\x04\0\x02\r\x12\x03(\x04#\n\x0c\n\x05\x04\0\x02\r\x06\x12\x03(\x04\
+    \x10\n\x0c\n\x05\x04\0\x02\r\x01\x12\x03(\x11\x1d\n\x0c\n\x05\x04\0\x02\
+    \r\x03\x12\x03(\x20\"\n\x0b\n\x04\x04\0\x02\x0e\x12\x03)\x04#\n\x0c\n\
+    \x05\x04\0\x02\x0e\x06\x12\x03)\x04\x10\n\x0c\n\x05\x04\0\x02\x0e\x01\
+    \x12\x03)\x11\x1d\n\x0c\n\x05\x04\0\x02\x0e\x03\x12\x03)\x20\"\n\x0b\n\
+    \x04\x04\0\x02\x0f\x12\x03*\x04#\n\x0c\n\x05\x04\0\x02\x0f\x06\x12\x03*\
+    \x04\x10\n\x0c\n\x05\x04\0\x02\x0f\x01\x12\x03*\x11\x1d\n\x0c\n\x05\x04\
+    \0\x02\x0f\x03\x12\x03*\x20\"\n\n\n\x02\x04\x01\x12\x04.\0z\x01\n\n\n\
+    \x03\x04\x01\x01\x12\x03.\x08\x0e\n\x0b\n\x04\x04\x01\x03\0\x12\x03/\x02\
+    .\n\x0c\n\x05\x04\x01\x03\0\x01\x12\x03/\n\x17\n\r\n\x06\x04\x01\x03\0\
+    \x02\0\x12\x03/\x1a,\n\x0e\n\x07\x04\x01\x03\0\x02\0\x06\x12\x03/\x1a\
+    \x20\n\x0e\n\x07\x04\x01\x03\0\x02\0\x01\x12\x03/!'\n\x0e\n\x07\x04\x01\
+    \x03\0\x02\0\x03\x12\x03/*+\n\x0b\n\x04\x04\x01\x03\x01\x12\x030\x02/\n\
+    \x0c\n\x05\x04\x01\x03\x01\x01\x12\x030\n\x15\n\r\n\x06\x04\x01\x03\x01\
+    \x02\0\x12\x030\x18-\n\x0e\n\x07\x04\x01\x03\x01\x02\0\x06\x12\x030\x18!\
+    \n\x0e\n\x07\x04\x01\x03\x01\x02\0\x01\x12\x030\"(\n\x0e\n\x07\x04\x01\
+    \x03\x01\x02\0\x03\x12\x030+,\n\x0b\n\x04\x04\x01\x03\x02\x12\x031\x02(\
+    \n\x0c\n\x05\x04\x01\x03\x02\x01\x12\x031\n\x15\n\r\n\x06\x04\x01\x03\
+    \x02\x02\0\x12\x031\x18&\n\x0e\n\x07\x04\x01\x03\x02\x02\0\x06\x12\x031\
+    \x18\x1c\n\x0e\n\x07\x04\x01\x03\x02\x02\0\x01\x12\x031\x1d!\n\x0e\n\x07\
+    \x04\x01\x03\x02\x02\0\x03\x12\x031$%\n\x0b\n\x04\x04\x01\x03\x03\x12\
+    \x032\x02.\n\x0c\n\x05\x04\x01\x03\x03\x01\x12\x032\n\x17\n\r\n\x06\x04\
+    \x01\x03\x03\x02\0\x12\x032\x1a,\n\x0e\n\x07\x04\x01\x03\x03\x02\0\x06\
+    \x12\x032\x1a\x20\n\x0e\n\x07\x04\x01\x03\x03\x02\0\x01\x12\x032!'\n\x0e\
+    \n\x07\x04\x01\x03\x03\x02\0\x03\x12\x032*+\n\x0b\n\x04\x04\x01\x03\x04\
+    \x12\x033\x02.\n\x0c\n\x05\x04\x01\x03\x04\x01\x12\x033\n\x14\n\r\n\x06\
+    \x04\x01\x03\x04\x02\0\x12\x033\x17,\n\x0e\n\x07\x04\x01\x03\x04\x02\0\
+    \x05\x12\x033\x17\x1d\n\x0e\n\x07\x04\x01\x03\x04\x02\0\x01\x12\x033\x1e\
+    '\n\x0e\n\x07\x04\x01\x03\x04\x02\0\x03\x12\x033*+\n\x0c\n\x04\x04\x01\
+    \x03\x05\x12\x044\x028\x03\n\x0c\n\x05\x04\x01\x03\x05\x01\x12\x034\n\
+    \x16\n\r\n\x06\x04\x01\x03\x05\x02\0\x12\x035\x04\x12\n\x0e\n\x07\x04\
+    \x01\x03\x05\x02\0\x06\x12\x035\x04\x08\n\x0e\n\x07\x04\x01\x03\x05\x02\
+    \0\x01\x12\x035\t\r\n\x0e\n\x07\x04\x01\x03\x05\x02\0\x03\x12\x035\x10\
+    \x11\n\r\n\x06\x04\x01\x03\x05\x02\x01\x12\x036\x04(\n\x0e\n\x07\x04\x01\
+    \x03\x05\x02\x01\x04\x12\x036\x04\x0c\n\x0e\n\x07\x04\x01\x03\x05\x02\
+    \x01\x05\x12\x036\r\x13\n\x0e\n\x07\x04\x01\x03\x05\x02\x01\x01\x12\x036\
+    \x14#\n\x0e\n\x07\x04\x01\x03\x05\x02\x01\x03\x12\x036&'\n\r\n\x06\x04\
+    \x01\x03\x05\x02\x02\x12\x037\x04\x1e\n\x0e\n\x07\x04\x01\x03\x05\x02\
+    \x02\x05\x12\x037\x04\n\n\x0e\n\x07\x04\x01\x03\x05\x02\x02\x01\x12\x037\
+    \x0b\x19\n\x0e\n\x07\x04\x01\x03\x05\x02\x02\x03\x12\x037\x1c\x1d\n\x0c\
+    \n\x04\x04\x01\x03\x06\x12\x049\x02>\x03\n\x0c\n\x05\x04\x01\x03\x06\x01\
+    \x12\x039\n\x16\n\r\n\x06\x04\x01\x03\x06\x02\0\x12\x03:\x04-\n\x0e\n\
+    \x07\x04\x01\x03\x06\x02\0\x04\x12\x03:\x04\x0c\n\x0e\n\x07\x04\x01\x03\
+    \x06\x02\0\x05\x12\x03:\r\x13\n\x0e\n\x07\x04\x01\x03\x06\x02\0\x01\x12\
+    \x03:\x14(\n\x0e\n\x07\x04\x01\x03\x06\x02\0\x03\x12\x03:+,\n\r\n\x06\
+    \x04\x01\x03\x06\x02\x01\x12\x03;\x04-\n\x0e\n\x07\x04\x01\x03\x06\x02\
+    \x01\x04\x12\x03;\x04\x0c\n\x0e\n\x07\x04\x01\x03\x06\x02\x01\x05\x12\
+    \x03;\r\x13\n\x0e\n\x07\x04\x01\x03\x06\x02\x01\x01\x12\x03;\x14(\n\x0e\
+    \n\x07\x04\x01\x03\x06\x02\x01\x03\x12\x03;+,\n\r\n\x06\x04\x01\x03\x06\
+    \x02\x02\x12\x03<\x04\x16\n\x0e\n\x07\x04\x01\x03\x06\x02\x02\x05\x12\
+    \x03<\x04\x08\n\x0e\n\x07\x04\x01\x03\x06\x02\x02\x01\x12\x03<\t\x11\n\
+    \x0e\n\x07\x04\x01\x03\x06\x02\x02\x03\x12\x03<\x14\x15\n\r\n\x06\x04\
+    \x01\x03\x06\x02\x03\x12\x03=\x04.\n\x0e\n\x07\x04\x01\x03\x06\x02\x03\
+    \x04\x12\x03=\x04\x0c\n\x0e\n\x07\x04\x01\x03\x06\x02\x03\x05\x12\x03=\r\
+    \x13\n\x0e\n\x07\x04\x01\x03\x06\x02\x03\x01\x12\x03=\x14)\n\x0e\n\x07\
+    \x04\x01\x03\x06\x02\x03\x03\x12\x03=,-\n\x0b\n\x04\x04\x01\x03\x07\x12\
+    \x03?\x023\n\x0c\n\x05\x04\x01\x03\x07\x01\x12\x03?\n\x1c\n\r\n\x06\x04\
+    \x01\x03\x07\x02\0\x12\x03?\x1f1\n\x0e\n\x07\x04\x01\x03\x07\x02\0\x06\
+    \x12\x03?\x1f%\n\x0e\n\x07\x04\x01\x03\x07\x02\0\x01\x12\x03?&,\n\x0e\n\
+    \x07\x04\x01\x03\x07\x02\0\x03\x12\x03?/0\n\x0c\n\x04\x04\x01\x03\x08\
+    \x12\x04@\x02C\x03\n\x0c\n\x05\x04\x01\x03\x08\x01\x12\x03@\n\x19\n\r\n\
+    \x06\x04\x01\x03\x08\x02\0\x12\x03A\x04\x19\n\x0e\n\x07\x04\x01\x03\x08\
+    \x02\0\x05\x12\x03A\x04\n\n\x0e\n\x07\x04\x01\x03\x08\x02\0\x01\x12\x03A\
+    \x0b\x14\n\x0e\n\x07\x04\x01\x03\x08\x02\0\x03\x12\x03A\x17\x18\n\r\n\
+    \x06\x04\x01\x03\x08\x02\x01\x12\x03B\x04\x14\n\x0e\n\x07\x04\x01\x03\
+    \x08\x02\x01\x06\x12\x03B\x04\t\n\x0e\n\x07\x04\x01\x03\x08\x02\x01\x01\
+    \x12\x03B\n\x0f\n\x0e\n\x07\x04\x01\x03\x08\x02\x01\x03\x12\x03B\x12\x13\
+    \n\x0b\n\x04\x04\x01\x03\t\x12\x03D\x02<\n\x0c\n\x05\x04\x01\x03\t\x01\
+    \x12\x03D\n\x1a\n\r\n\x06\x04\x01\x03\t\x02\0\x12\x03D\x1d:\n\x0e\n\x07\
+    \x04\x01\x03\t\x02\0\x04\x12\x03D\x1d%\n\x0e\n\x07\x04\x01\x03\t\x02\0\
+    \x06\x12\x03D&,\n\x0e\n\x07\x04\x01\x03\t\x02\0\x01\x12\x03D-5\n\x0e\n\
+    \x07\x04\x01\x03\t\x02\0\x03\x12\x03D89\n\x0b\n\x04\x04\x01\x03\n\x12\
+    \x03E\x029\n\x0c\n\x05\x04\x01\x03\n\x01\x12\x03E\n\x1f\n\r\n\x06\x04\
+    \x01\x03\n\x02\0\x12\x03E\"7\n\x0e\n\x07\x04\x01\x03\n\x02\0\x05\x12\x03\
+    E\"(\n\x0e\n\x07\x04\x01\x03\n\x02\0\x01\x12\x03E)2\n\x0e\n\x07\x04\x01\
+    \x03\n\x02\0\x03\x12\x03E56\n\x0b\n\x04\x04\x01\x03\x0b\x12\x03F\x022\n\
+    \x0c\n\x05\x04\x01\x03\x0b\x01\x12\x03F\n\x18\n\r\n\x06\x04\x01\x03\x0b\
+    \x02\0\x12\x03F\x1b0\n\x0e\n\x07\x04\x01\x03\x0b\x02\0\x05\x12\x03F\x1b!\
+    \n\x0e\n\x07\x04\x01\x03\x0b\x02\0\x01\x12\x03F\"+\n\x0e\n\x07\x04\x01\
+    \x03\x0b\x02\0\x03\x12\x03F./\n\x0c\n\x04\x04\x01\x03\x0c\x12\x04G\x02K\
+    \x03\n\x0c\n\x05\x04\x01\x03\x0c\x01\x12\x03G\n\x16\n\r\n\x06\x04\x01\
+    \x03\x0c\x02\0\x12\x03H\x04\x15\n\x0e\n\x07\x04\x01\x03\x0c\x02\0\x06\
+    \x12\x03H\x04\t\n\x0e\n\x07\x04\x01\x03\x0c\x02\0\x01\x12\x03H\n\x10\n\
+    \x0e\n\x07\x04\x01\x03\x0c\x02\0\x03\x12\x03H\x13\x14\n\r\n\x06\x04\x01\
+    \x03\x0c\x02\x01\x12\x03I\x04\x19\n\x0e\n\x07\x04\x01\x03\x0c\x02\x01\
+    \x06\x12\x03I\x04\r\n\x0e\n\x07\x04\x01\x03\x0c\x02\x01\x01\x12\x03I\x0e\
+    \x14\n\x0e\n\x07\x04\x01\x03\x0c\x02\x01\x03\x12\x03I\x17\x18\n\r\n\x06\
+    \x04\x01\x03\x0c\x02\x02\x12\x03J\x04\"\n\x0e\n\x07\x04\x01\x03\x0c\x02\
+    \x02\x04\x12\x03J\x04\x0c\n\x0e\n\x07\x04\x01\x03\x0c\x02\x02\x06\x12\
+    \x03J\r\x17\n\x0e\n\x07\x04\x01\x03\x0c\x02\x02\x01\x12\x03J\x18\x1d\n\
+    \x0e\n\x07\x04\x01\x03\x0c\x02\x02\x03\x12\x03J\x20!\n\x0c\n\x04\x04\x01\
+    \x03\r\x12\x04L\x02P\x03\n\x0c\n\x05\x04\x01\x03\r\x01\x12\x03L\n\x14\n\
+    \r\n\x06\x04\x01\x03\r\x02\0\x12\x03M\x04\x19\n\x0e\n\x07\x04\x01\x03\r\
+    \x02\0\x05\x12\x03M\x04\n\n\x0e\n\x07\x04\x01\x03\r\x02\0\x01\x12\x03M\
+    \x0b\x14\n\x0e\n\x07\x04\x01\x03\r\x02\0\x03\x12\x03M\x17\x18\n\r\n\x06\
+    \x04\x01\x03\r\x02\x01\x12\x03N\x04\x14\n\x0e\n\x07\x04\x01\x03\r\x02\
+    \x01\x05\x12\x03N\x04\n\n\x0e\n\x07\x04\x01\x03\r\x02\x01\x01\x12\x03N\
+    \x0b\x0f\n\x0e\n\x07\x04\x01\x03\r\x02\x01\x03\x12\x03N\x12\x13\n\r\n\
+    \x06\x04\x01\x03\r\x02\x02\x12\x03O\x04\x14\n\x0e\n\x07\x04\x01\x03\r\
+    \x02\x02\x05\x12\x03O\x04\n\n\x0e\n\x07\x04\x01\x03\r\x02\x02\x01\x12\
+    \x03O\x0b\x0f\n\x0e\n\x07\x04\x01\x03\r\x02\x02\x03\x12\x03O\x12\x13\n\
+    \x0c\n\x04\x04\x01\x03\x0e\x12\x04Q\x02T\x03\n\x0c\n\x05\x04\x01\x03\x0e\
+    \x01\x12\x03Q\n\x16\n\r\n\x06\x04\x01\x03\x0e\x02\0\x12\x03R\x04\x1d\n\
+    \x0e\n\x07\x04\x01\x03\x0e\x02\0\x06\x12\x03R\x04\x11\n\x0e\n\x07\x04\
+    \x01\x03\x0e\x02\0\x01\x12\x03R\x12\x18\n\x0e\n\x07\x04\x01\x03\x0e\x02\
+    \0\x03\x12\x03R\x1b\x1c\n\r\n\x06\x04\x01\x03\x0e\x02\x01\x12\x03S\x04\
+    \x14\n\x0e\n\x07\x04\x01\x03\x0e\x02\x01\x05\x12\x03S\x04\n\n\x0e\n\x07\
+    \x04\x01\x03\x0e\x02\x01\x01\x12\x03S\x0b\x0f\n\x0e\n\x07\x04\x01\x03\
+    \x0e\x02\x01\x03\x12\x03S\x12\x13\n\x0c\n\x04\x04\x01\x03\x0f\x12\x04U\
+    \x02f\x03\n\x0c\n\x05\x04\x01\x03\x0f\x01\x12\x03U\n\x12\n\x0e\n\x06\x04\
+    \x01\x03\x0f\x03\0\x12\x04V\x04Y\x05\n\x0e\n\x07\x04\x01\x03\x0f\x03\0\
+    \x01\x12\x03V\x0c\x1a\n\x0f\n\x08\x04\x01\x03\x0f\x03\0\x02\0\x12\x03W\
+    \x06\x18\n\x10\n\t\x04\x01\x03\x0f\x03\0\x02\0\x06\x12\x03W\x06\x0c\n\
+    \x10\n\t\x04\x01\x03\x0f\x03\0\x02\0\x01\x12\x03W\r\x13\n\x10\n\t\x04\
+    \x01\x03\x0f\x03\0\x02\0\x03\x12\x03W\x16\x17\n\x0f\n\x08\x04\x01\x03\
+    \x0f\x03\0\x02\x01\x12\x03X\x06\x19\n\x10\n\t\x04\x01\x03\x0f\x03\0\x02\
+    \x01\x05\x12\x03X\x06\n\n\x10\n\t\x04\x01\x03\x0f\x03\0\x02\x01\x01\x12\
+    \x03X\x0b\x14\n\x10\n\t\x04\x01\x03\x0f\x03\0\x02\x01\x03\x12\x03X\x17\
+    \x18\n\x0e\n\x06\x04\x01\x03\x0f\x03\x01\x12\x04Z\x04_\x05\n\x0e\n\x07\
+    \x04\x01\x03\x0f\x03\x01\x01\x12\x03Z\x0c\x15\n\x0f\n\x08\x04\x01\x03\
+    \x0f\x03\x01\x02\0\x12\x03[\x06\x14\n\x10\n\t\x04\x01\x03\x0f\x03\x01\
+    \x02\0\x06\x12\x03[\x06\n\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\0\x01\
+    \x12\x03[\x0b\x0f\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\0\x03\x12\x03[\
+    \x12\x13\n\x0f\n\x08\x04\x01\x03\x0f\x03\x01\x02\x01\x12\x03\\\x06*\n\
+    \x10\n\t\x04\x01\x03\x0f\x03\x01\x02\x01\x04\x12\x03\\\x06\x0e\n\x10\n\t\
+    \x04\x01\x03\x0f\x03\x01\x02\x01\x05\x12\x03\\\x0f\x15\n\x10\n\t\x04\x01\
+    \x03\x0f\x03\x01\x02\x01\x01\x12\x03\\\x16%\n\x10\n\t\x04\x01\x03\x0f\
+    \x03\x01\x02\x01\x03\x12\x03\\()\n\x0f\n\x08\x04\x01\x03\x0f\x03\x01\x02\
+    \x02\x12\x03]\x06\x20\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\x02\x05\x12\
+    \x03]\x06\x0c\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\x02\x01\x12\x03]\r\
+    \x1b\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\x02\x03\x12\x03]\x1e\x1f\n\
+    \x0f\n\x08\x04\x01\x03\x0f\x03\x01\x02\x03\x12\x03^\x062\n\x10\n\t\x04\
+    \x01\x03\x0f\x03\x01\x02\x03\x04\x12\x03^\x06\x0e\n\x10\n\t\x04\x01\x03\
+    \x0f\x03\x01\x02\x03\x06\x12\x03^\x0f\x1e\n\x10\n\t\x04\x01\x03\x0f\x03\
+    \x01\x02\x03\x01\x12\x03^\x1f-\n\x10\n\t\x04\x01\x03\x0f\x03\x01\x02\x03\
+    \x03\x12\x03^01\n\r\n\x06\x04\x01\x03\x0f\x02\0\x12\x03`\x04\x12\n\x0e\n\
+    \x07\x04\x01\x03\x0f\x02\0\x06\x12\x03`\x04\x08\n\x0e\n\x07\x04\x01\x03\
+    \x0f\x02\0\x01\x12\x03`\t\r\n\x0e\n\x07\x04\x01\x03\x0f\x02\0\x03\x12\
+    \x03`\x10\x11\n\r\n\x06\x04\x01\x03\x0f\x02\x01\x12\x03a\x04(\n\x0e\n\
+    \x07\x04\x01\x03\x0f\x02\x01\x04\x12\x03a\x04\x0c\n\x0e\n\x07\x04\x01\
+    \x03\x0f\x02\x01\x06\x12\x03a\r\x1b\n\x0e\n\x07\x04\x01\x03\x0f\x02\x01\
+    \x01\x12\x03a\x1c#\n\x0e\n\x07\x04\x01\x03\x0f\x02\x01\x03\x12\x03a&'\n\
+    \r\n\x06\x04\x01\x03\x0f\x02\x02\x12\x03b\x04\x1d\n\x0e\n\x07\x04\x01\
+    \x03\x0f\x02\x02\x06\x12\x03b\x04\r\n\x0e\n\x07\x04\x01\x03\x0f\x02\x02\
+    \x01\x12\x03b\x0e\x18\n\x0e\n\x07\x04\x01\x03\x0f\x02\x02\x03\x12\x03b\
+    \x1b\x1c\n\r\n\x06\x04\x01\x03\x0f\x02\x03\x12\x03c\x04\x1c\n\x0e\n\x07\
+    \x04\x01\x03\x0f\x02\x03\x06\x12\x03c\x04\r\n\x0e\n\x07\x04\x01\x03\x0f\
+    \x02\x03\x01\x12\x03c\x0e\x17\n\x0e\n\x07\x04\x01\x03\x0f\x02\x03\x03\
+    \x12\x03c\x1a\x1b\n\r\n\x06\x04\x01\x03\x0f\x02\x04\x12\x03d\x04\x14\n\
+    \x0e\n\x07\x04\x01\x03\x0f\x02\x04\x06\x12\x03d\x04\t\n\x0e\n\x07\x04\
+    \x01\x03\x0f\x02\x04\x01\x12\x03d\n\x0f\n\x0e\n\x07\x04\x01\x03\x0f\x02\
+    \x04\x03\x12\x03d\x12\x13\n\r\n\x06\x04\x01\x03\x0f\x02\x05\x12\x03e\x04\
+    !\n\x0e\n\x07\x04\x01\x03\x0f\x02\x05\x04\x12\x03e\x04\x0c\n\x0e\n\x07\
+    \x04\x01\x03\x0f\x02\x05\x06\x12\x03e\r\x17\n\x0e\n\x07\x04\x01\x03\x0f\
+    \x02\x05\x01\x12\x03e\x18\x1c\n\x0e\n\x07\x04\x01\x03\x0f\x02\x05\x03\
+    \x12\x03e\x1f\x20\n\x0c\n\x04\x04\x01\x08\0\x12\x04h\x02y\x03\n\x0c\n\
+    \x05\x04\x01\x08\0\x01\x12\x03h\x08\x0f\n\x0b\n\x04\x04\x01\x02\0\x12\
+    \x03i\x04$\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03i\x04\x11\n\x0c\n\x05\
+    \x04\x01\x02\0\x01\x12\x03i\x12\x1f\n\x0c\n\x05\x04\x01\x02\0\x03\x12\
+    \x03i\"#\n\x0b\n\x04\x04\x01\x02\x01\x12\x03j\x04\x20\n\x0c\n\x05\x04\
+    \x01\x02\x01\x06\x12\x03j\x04\x0f\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\
+    \x03j\x10\x1b\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03j\x1e\x1f\n\x0b\n\
+    \x04\x04\x01\x02\x02\x12\x03k\x04\x20\n\x0c\n\x05\x04\x01\x02\x02\x06\
+    \x12\x03k\x04\x0f\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03k\x10\x1b\n\x0c\
+    \n\x05\x04\x01\x02\x02\x03\x12\x03k\x1e\x1f\n\x0b\n\x04\x04\x01\x02\x03\
+    \x12\x03l\x04$\n\x0c\n\x05\x04\x01\x02\x03\x06\x12\x03l\x04\x11\n\x0c\n\
+    \x05\x04\x01\x02\x03\x01\x12\x03l\x12\x1f\n\x0c\n\x05\x04\x01\x02\x03\
+    \x03\x12\x03l\"#\n\x0b\n\x04\x04\x01\x02\x04\x12\x03m\x04\x1e\n\x0c\n\
+    \x05\x04\x01\x02\x04\x06\x12\x03m\x04\x0e\n\x0c\n\x05\x04\x01\x02\x04\
+    \x01\x12\x03m\x0f\x19\n\x0c\n\x05\x04\x01\x02\x04\x03\x12\x03m\x1c\x1d\n\
+    \x0b\n\x04\x04\x01\x02\x05\x12\x03n\x04\"\n\x0c\n\x05\x04\x01\x02\x05\
+    \x06\x12\x03n\x04\x10\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03n\x11\x1d\n\
+    \x0c\n\x05\x04\x01\x02\x05\x03\x12\x03n\x20!\n\x0b\n\x04\x04\x01\x02\x06\
+    \x12\x03o\x04\"\n\x0c\n\x05\x04\x01\x02\x06\x06\x12\x03o\x04\x10\n\x0c\n\
+    \x05\x04\x01\x02\x06\x01\x12\x03o\x11\x1d\n\x0c\n\x05\x04\x01\x02\x06\
+    \x03\x12\x03o\x20!\n\x0b\n\x04\x04\x01\x02\x07\x12\x03p\x04.\n\x0c\n\x05\
+    \x04\x01\x02\x07\x06\x12\x03p\x04\x16\n\x0c\n\x05\x04\x01\x02\x07\x01\
+    \x12\x03p\x17)\n\x0c\n\x05\x04\x01\x02\x07\x03\x12\x03p,-\n\x0b\n\x04\
+    \x04\x01\x02\x08\x12\x03q\x04(\n\x0c\n\x05\x04\x01\x02\x08\x06\x12\x03q\
+    \x04\x13\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03q\x14#\n\x0c\n\x05\x04\
+    \x01\x02\x08\x03\x12\x03q&'\n\x0b\n\x04\x04\x01\x02\t\x12\x03r\x04+\n\
+    \x0c\n\x05\x04\x01\x02\t\x06\x12\x03r\x04\x14\n\x0c\n\x05\x04\x01\x02\t\
+    \x01\x12\x03r\x15%\n\x0c\n\x05\x04\x01\x02\t\x03\x12\x03r(*\n\x0b\n\x04\
+    \x04\x01\x02\n\x12\x03s\x045\n\x0c\n\x05\x04\x01\x02\n\x06\x12\x03s\x04\
+    \x19\n\x0c\n\x05\x04\x01\x02\n\x01\x12\x03s\x1a/\n\x0c\n\x05\x04\x01\x02\
+    \n\x03\x12\x03s24\n\x0b\n\x04\x04\x01\x02\x0b\x12\x03t\x04'\n\x0c\n\x05\
+    \x04\x01\x02\x0b\x06\x12\x03t\x04\x12\n\x0c\n\x05\x04\x01\x02\x0b\x01\
+    \x12\x03t\x13!\n\x0c\n\x05\x04\x01\x02\x0b\x03\x12\x03t$&\n\x0b\n\x04\
+    \x04\x01\x02\x0c\x12\x03u\x04#\n\x0c\n\x05\x04\x01\x02\x0c\x06\x12\x03u\
+    \x04\x10\n\x0c\n\x05\x04\x01\x02\x0c\x01\x12\x03u\x11\x1d\n\x0c\n\x05\
+    \x04\x01\x02\x0c\x03\x12\x03u\x20\"\n\x0b\n\x04\x04\x01\x02\r\x12\x03v\
+    \x04\x1b\n\x0c\n\x05\x04\x01\x02\r\x06\x12\x03v\x04\x0c\n\x0c\n\x05\x04\
+    \x01\x02\r\x01\x12\x03v\r\x15\n\x0c\n\x05\x04\x01\x02\r\x03\x12\x03v\x18\
+    \x1a\n\x0b\n\x04\x04\x01\x02\x0e\x12\x03w\x04\x1f\n\x0c\n\x05\x04\x01\
+    \x02\x0e\x06\x12\x03w\x04\x0e\n\x0c\n\x05\x04\x01\x02\x0e\x01\x12\x03w\
+    \x0f\x19\n\x0c\n\x05\x04\x01\x02\x0e\x03\x12\x03w\x1c\x1e\n\x0b\n\x04\
+    \x04\x01\x02\x0f\x12\x03x\x04#\n\x0c\n\x05\x04\x01\x02\x0f\x06\x12\x03x\
+    \x04\x10\n\x0c\n\x05\x04\x01\x02\x0f\x01\x12\x03x\x11\x1d\n\x0c\n\x05\
+    \x04\x01\x02\x0f\x03\x12\x03x\x20\"b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
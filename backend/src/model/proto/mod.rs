@@ -1,4 +1,8 @@
 pub mod game;
 pub mod message;
 pub mod player;
+pub mod policy;
+pub mod replay;
+pub mod role;
+pub mod settings;
 pub mod task;
@@ -28,6 +28,8 @@ pub struct Player {
     // message fields
     pub id: ::std::string::String,
     pub name: ::std::string::String,
+    pub ready: bool,
+    pub bot: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -95,6 +97,36 @@ impl Player {
     pub fn take_name(&mut self) -> ::std::string::String {
         ::std::mem::replace(&mut self.name, ::std::string::String::new())
     }
+
+    // bool ready = 4;
+
+
+    pub fn get_ready(&self) -> bool {
+        self.ready
+    }
+    pub fn clear_ready(&mut self) {
+        self.ready = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: bool) {
+        self.ready = v;
+    }
+
+    // bool bot = 5;
+
+
+    pub fn get_bot(&self) -> bool {
+        self.bot
+    }
+    pub fn clear_bot(&mut self) {
+        self.bot = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bot(&mut self, v: bool) {
+        self.bot = v;
+    }
 }
 
 impl ::protobuf::Message for Player {
@@ -112,6 +144,20 @@ impl ::protobuf::Message for Player {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ready = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.bot = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -130,6 +176,12 @@ impl ::protobuf::Message for Player {
         if !self.name.is_empty() {
             my_size += ::protobuf::rt::string_size(2, &self.name);
         }
+        if self.ready != false {
+            my_size += 2;
+        }
+        if self.bot != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -142,6 +194,12 @@ impl ::protobuf::Message for Player {
         if !self.name.is_empty() {
             os.write_string(2, &self.name)?;
         }
+        if self.ready != false {
+            os.write_bool(4, self.ready)?;
+        }
+        if self.bot != false {
+            os.write_bool(5, self.bot)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -190,6 +248,16 @@ impl ::protobuf::Message for Player {
                 |m: &Player| { &m.name },
                 |m: &mut Player| { &mut m.name },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "ready",
+                |m: &Player| { &m.ready },
+                |m: &mut Player| { &mut m.ready },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "bot",
+                |m: &Player| { &m.bot },
+                |m: &mut Player| { &mut m.bot },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<Player>(
                 "Player",
                 fields,
@@ -208,6 +276,8 @@ impl ::protobuf::Clear for Player {
     fn clear(&mut self) {
         self.id.clear();
         self.name.clear();
+        self.ready = false;
+        self.bot = false;
         self.unknown_fields.clear();
     }
 }
@@ -230,6 +300,7 @@ pub struct OwnPlayer {
     pub id: ::std::string::String,
     pub name: ::std::string::String,
     pub open_tasks: ::protobuf::RepeatedField<super::task::Task>,
+    pub ready: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -322,6 +393,21 @@ impl OwnPlayer {
     pub fn take_open_tasks(&mut self) -> ::protobuf::RepeatedField<super::task::Task> {
         ::std::mem::replace(&mut self.open_tasks, ::protobuf::RepeatedField::new())
     }
+
+    // bool ready = 5;
+
+
+    pub fn get_ready(&self) -> bool {
+        self.ready
+    }
+    pub fn clear_ready(&mut self) {
+        self.ready = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ready(&mut self, v: bool) {
+        self.ready = v;
+    }
 }
 
 impl ::protobuf::Message for OwnPlayer {
@@ -347,6 +433,13 @@ impl ::protobuf::Message for OwnPlayer {
                 3 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.open_tasks)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.ready = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -369,6 +462,9 @@ impl ::protobuf::Message for OwnPlayer {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.ready != false {
+            my_size += 2;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -386,6 +482,9 @@ impl ::protobuf::Message for OwnPlayer {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.ready != false {
+            os.write_bool(5, self.ready)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -439,6 +538,11 @@ impl ::protobuf::Message for OwnPlayer {
                 |m: &OwnPlayer| { &m.open_tasks },
                 |m: &mut OwnPlayer| { &mut m.open_tasks },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "ready",
+                |m: &OwnPlayer| { &m.ready },
+                |m: &mut OwnPlayer| { &mut m.ready },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<OwnPlayer>(
                 "OwnPlayer",
                 fields,
@@ -458,6 +562,7 @@ impl ::protobuf::Clear for OwnPlayer {
         self.id.clear();
         self.name.clear();
         self.open_tasks.clear();
+        self.ready = false;
         self.unknown_fields.clear();
     }
 }
@@ -474,12 +579,73 @@ impl ::protobuf::reflect::ProtobufValue for OwnPlayer {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum NameRejection {
+    TOO_SHORT = 0,
+    TOO_LONG = 1,
+    INVALID_CHARACTERS = 2,
+    ALREADY_TAKEN = 3,
+}
+
+impl ::protobuf::ProtobufEnum for NameRejection {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<NameRejection> {
+        match value {
+            0 => ::std::option::Option::Some(NameRejection::TOO_SHORT),
+            1 => ::std::option::Option::Some(NameRejection::TOO_LONG),
+            2 => ::std::option::Option::Some(NameRejection::INVALID_CHARACTERS),
+            3 => ::std::option::Option::Some(NameRejection::ALREADY_TAKEN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [NameRejection] = &[
+            NameRejection::TOO_SHORT,
+            NameRejection::TOO_LONG,
+            NameRejection::INVALID_CHARACTERS,
+            NameRejection::ALREADY_TAKEN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<NameRejection>("NameRejection", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for NameRejection {
+}
+
+impl ::std::default::Default for NameRejection {
+    fn default() -> Self {
+        NameRejection::TOO_SHORT
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for NameRejection {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x0cplayer.proto\x1a\ntask.proto\",\n\x06Player\x12\x0e\n\x02id\x18\
-    \x01\x20\x01(\tR\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\"U\
-    \n\tOwnPlayer\x12\x0e\n\x02id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04nam\
-    e\x18\x02\x20\x01(\tR\x04name\x12$\n\nopen_tasks\x18\x03\x20\x03(\x0b2\
-    \x05.TaskR\topenTasksb\x06proto3\
+    \n\x0cplayer.proto\x1a\ntask.proto\"Z\n\x06Player\x12\x0e\n\x02id\x18\
+    \x01\x20\x01(\tR\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\
+    \x14\n\x05ready\x18\x04\x20\x01(\x08R\x05ready\x12\x10\n\x03bot\x18\x05\
+    \x20\x01(\x08R\x03botJ\x04\x08\x03\x10\x04\"q\n\tOwnPlayer\x12\x0e\n\x02\
+    id\x18\x01\x20\x01(\tR\x02id\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04na\
+    me\x12$\n\nopen_tasks\x18\x03\x20\x03(\x0b2\x05.TaskR\topenTasks\x12\x14\
+    \n\x05ready\x18\x05\x20\x01(\x08R\x05readyJ\x04\x08\x04\x10\x05*W\n\rNam\
+    eRejection\x12\r\n\tTOO_SHORT\x10\0\x12\x0c\n\x08TOO_LONG\x10\x01\x12\
+    \x16\n\x12INVALID_CHARACTERS\x10\x02\x12\x11\n\rALREADY_TAKEN\x10\x03b\
+    \x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,91 @@
+// This file is generated by rust-protobuf 2.20.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `policy.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_20_0;
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Policy {
+    LOYAL_POLICY = 0,
+    CLAN_POLICY = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Policy {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Policy> {
+        match value {
+            0 => ::std::option::Option::Some(Policy::LOYAL_POLICY),
+            1 => ::std::option::Option::Some(Policy::CLAN_POLICY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Policy] = &[
+            Policy::LOYAL_POLICY,
+            Policy::CLAN_POLICY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Policy>("Policy", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Policy {
+}
+
+impl ::std::default::Default for Policy {
+    fn default() -> Self {
+        Policy::LOYAL_POLICY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Policy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0cpolicy.proto*+\n\x06Policy\x12\x10\n\x0cLOYAL_POLICY\x10\0\x12\x0f\
+    \n\x0bCLAN_POLICY\x10\x01b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,6994 @@
+// This file is generated by rust-protobuf 2.20.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `replay.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_20_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent {
+    // message fields
+    pub time: ::std::string::String,
+    // message oneof groups
+    pub event: ::std::option::Option<GameEvent_oneof_event>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent {
+    fn default() -> &'a GameEvent {
+        <GameEvent as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum GameEvent_oneof_event {
+    gameCreated(GameEvent_GameCreated),
+    playerJoined(GameEvent_PlayerJoined),
+    playerLeft(GameEvent_PlayerLeft),
+    settingsUpdated(GameEvent_SettingsUpdated),
+    gameStarted(GameEvent_GameStarted),
+    chancellorNominated(GameEvent_ChancellorNominated),
+    voteCast(GameEvent_VoteCast),
+    electionFinished(GameEvent_ElectionFinished),
+    policyDiscarded(GameEvent_PolicyDiscarded),
+    policyEnacted(GameEvent_PolicyEnacted),
+    loyaltyInvestigated(GameEvent_LoyaltyInvestigated),
+    specialElectionCalled(GameEvent_SpecialElectionCalled),
+    policiesPeeked(GameEvent_PoliciesPeeked),
+    playerExecuted(GameEvent_PlayerExecuted),
+    playerKicked(GameEvent_PlayerKicked),
+    adminHandedOver(GameEvent_AdminHandedOver),
+    passwordChanged(GameEvent_PasswordChanged),
+    lobbyLocked(GameEvent_LobbyLocked),
+    voteAbstained(GameEvent_VoteAbstained),
+    botAdded(GameEvent_BotAdded),
+    playerReplacedByBot(GameEvent_PlayerReplacedByBot),
+    rematchStarted(GameEvent_RematchStarted),
+    vetoProposed(GameEvent_VetoProposed),
+    vetoAnswered(GameEvent_VetoAnswered),
+    nameChosen(GameEvent_NameChosen),
+}
+
+impl GameEvent {
+    pub fn new() -> GameEvent {
+        ::std::default::Default::default()
+    }
+
+    // string time = 1;
+
+
+    pub fn get_time(&self) -> &str {
+        &self.time
+    }
+    pub fn clear_time(&mut self) {
+        self.time.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_time(&mut self, v: ::std::string::String) {
+        self.time = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_time(&mut self) -> &mut ::std::string::String {
+        &mut self.time
+    }
+
+    // Take field
+    pub fn take_time(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.time, ::std::string::String::new())
+    }
+
+    // .GameEvent.GameCreated gameCreated = 2;
+
+
+    pub fn get_gameCreated(&self) -> &GameEvent_GameCreated {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(ref v)) => v,
+            _ => <GameEvent_GameCreated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameCreated(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_gameCreated(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameCreated(&mut self, v: GameEvent_GameCreated) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameCreated(&mut self) -> &mut GameEvent_GameCreated {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(GameEvent_GameCreated::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameCreated(&mut self) -> GameEvent_GameCreated {
+        if self.has_gameCreated() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_GameCreated::new()
+        }
+    }
+
+    // .GameEvent.PlayerJoined playerJoined = 3;
+
+
+    pub fn get_playerJoined(&self) -> &GameEvent_PlayerJoined {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(ref v)) => v,
+            _ => <GameEvent_PlayerJoined as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerJoined(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_playerJoined(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerJoined(&mut self, v: GameEvent_PlayerJoined) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerJoined(&mut self) -> &mut GameEvent_PlayerJoined {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(GameEvent_PlayerJoined::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerJoined(&mut self) -> GameEvent_PlayerJoined {
+        if self.has_playerJoined() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PlayerJoined::new()
+        }
+    }
+
+    // .GameEvent.PlayerLeft playerLeft = 4;
+
+
+    pub fn get_playerLeft(&self) -> &GameEvent_PlayerLeft {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(ref v)) => v,
+            _ => <GameEvent_PlayerLeft as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerLeft(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_playerLeft(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerLeft(&mut self, v: GameEvent_PlayerLeft) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerLeft(&mut self) -> &mut GameEvent_PlayerLeft {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(GameEvent_PlayerLeft::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerLeft(&mut self) -> GameEvent_PlayerLeft {
+        if self.has_playerLeft() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PlayerLeft::new()
+        }
+    }
+
+    // .GameEvent.SettingsUpdated settingsUpdated = 5;
+
+
+    pub fn get_settingsUpdated(&self) -> &GameEvent_SettingsUpdated {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(ref v)) => v,
+            _ => <GameEvent_SettingsUpdated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_settingsUpdated(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_settingsUpdated(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settingsUpdated(&mut self, v: GameEvent_SettingsUpdated) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_settingsUpdated(&mut self) -> &mut GameEvent_SettingsUpdated {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(GameEvent_SettingsUpdated::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_settingsUpdated(&mut self) -> GameEvent_SettingsUpdated {
+        if self.has_settingsUpdated() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_SettingsUpdated::new()
+        }
+    }
+
+    // .GameEvent.GameStarted gameStarted = 6;
+
+
+    pub fn get_gameStarted(&self) -> &GameEvent_GameStarted {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(ref v)) => v,
+            _ => <GameEvent_GameStarted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameStarted(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_gameStarted(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameStarted(&mut self, v: GameEvent_GameStarted) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameStarted(&mut self) -> &mut GameEvent_GameStarted {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(GameEvent_GameStarted::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameStarted(&mut self) -> GameEvent_GameStarted {
+        if self.has_gameStarted() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_GameStarted::new()
+        }
+    }
+
+    // .GameEvent.ChancellorNominated chancellorNominated = 7;
+
+
+    pub fn get_chancellorNominated(&self) -> &GameEvent_ChancellorNominated {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(ref v)) => v,
+            _ => <GameEvent_ChancellorNominated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_chancellorNominated(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_chancellorNominated(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chancellorNominated(&mut self, v: GameEvent_ChancellorNominated) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_chancellorNominated(&mut self) -> &mut GameEvent_ChancellorNominated {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(GameEvent_ChancellorNominated::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_chancellorNominated(&mut self) -> GameEvent_ChancellorNominated {
+        if self.has_chancellorNominated() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_ChancellorNominated::new()
+        }
+    }
+
+    // .GameEvent.VoteCast voteCast = 8;
+
+
+    pub fn get_voteCast(&self) -> &GameEvent_VoteCast {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteCast(ref v)) => v,
+            _ => <GameEvent_VoteCast as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_voteCast(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_voteCast(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteCast(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voteCast(&mut self, v: GameEvent_VoteCast) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteCast(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voteCast(&mut self) -> &mut GameEvent_VoteCast {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::voteCast(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteCast(GameEvent_VoteCast::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteCast(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_voteCast(&mut self) -> GameEvent_VoteCast {
+        if self.has_voteCast() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::voteCast(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_VoteCast::new()
+        }
+    }
+
+    // .GameEvent.ElectionFinished electionFinished = 9;
+
+
+    pub fn get_electionFinished(&self) -> &GameEvent_ElectionFinished {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(ref v)) => v,
+            _ => <GameEvent_ElectionFinished as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_electionFinished(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_electionFinished(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_electionFinished(&mut self, v: GameEvent_ElectionFinished) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_electionFinished(&mut self) -> &mut GameEvent_ElectionFinished {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(GameEvent_ElectionFinished::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_electionFinished(&mut self) -> GameEvent_ElectionFinished {
+        if self.has_electionFinished() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_ElectionFinished::new()
+        }
+    }
+
+    // .GameEvent.PolicyDiscarded policyDiscarded = 10;
+
+
+    pub fn get_policyDiscarded(&self) -> &GameEvent_PolicyDiscarded {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(ref v)) => v,
+            _ => <GameEvent_PolicyDiscarded as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policyDiscarded(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_policyDiscarded(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policyDiscarded(&mut self, v: GameEvent_PolicyDiscarded) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policyDiscarded(&mut self) -> &mut GameEvent_PolicyDiscarded {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(GameEvent_PolicyDiscarded::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policyDiscarded(&mut self) -> GameEvent_PolicyDiscarded {
+        if self.has_policyDiscarded() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PolicyDiscarded::new()
+        }
+    }
+
+    // .GameEvent.PolicyEnacted policyEnacted = 11;
+
+
+    pub fn get_policyEnacted(&self) -> &GameEvent_PolicyEnacted {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(ref v)) => v,
+            _ => <GameEvent_PolicyEnacted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policyEnacted(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_policyEnacted(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policyEnacted(&mut self, v: GameEvent_PolicyEnacted) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policyEnacted(&mut self) -> &mut GameEvent_PolicyEnacted {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(GameEvent_PolicyEnacted::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policyEnacted(&mut self) -> GameEvent_PolicyEnacted {
+        if self.has_policyEnacted() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PolicyEnacted::new()
+        }
+    }
+
+    // .GameEvent.LoyaltyInvestigated loyaltyInvestigated = 12;
+
+
+    pub fn get_loyaltyInvestigated(&self) -> &GameEvent_LoyaltyInvestigated {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(ref v)) => v,
+            _ => <GameEvent_LoyaltyInvestigated as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_loyaltyInvestigated(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_loyaltyInvestigated(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_loyaltyInvestigated(&mut self, v: GameEvent_LoyaltyInvestigated) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_loyaltyInvestigated(&mut self) -> &mut GameEvent_LoyaltyInvestigated {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(GameEvent_LoyaltyInvestigated::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_loyaltyInvestigated(&mut self) -> GameEvent_LoyaltyInvestigated {
+        if self.has_loyaltyInvestigated() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_LoyaltyInvestigated::new()
+        }
+    }
+
+    // .GameEvent.SpecialElectionCalled specialElectionCalled = 13;
+
+
+    pub fn get_specialElectionCalled(&self) -> &GameEvent_SpecialElectionCalled {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(ref v)) => v,
+            _ => <GameEvent_SpecialElectionCalled as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_specialElectionCalled(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_specialElectionCalled(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_specialElectionCalled(&mut self, v: GameEvent_SpecialElectionCalled) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_specialElectionCalled(&mut self) -> &mut GameEvent_SpecialElectionCalled {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(GameEvent_SpecialElectionCalled::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_specialElectionCalled(&mut self) -> GameEvent_SpecialElectionCalled {
+        if self.has_specialElectionCalled() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_SpecialElectionCalled::new()
+        }
+    }
+
+    // .GameEvent.PoliciesPeeked policiesPeeked = 14;
+
+
+    pub fn get_policiesPeeked(&self) -> &GameEvent_PoliciesPeeked {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(ref v)) => v,
+            _ => <GameEvent_PoliciesPeeked as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_policiesPeeked(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_policiesPeeked(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policiesPeeked(&mut self, v: GameEvent_PoliciesPeeked) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policiesPeeked(&mut self) -> &mut GameEvent_PoliciesPeeked {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(GameEvent_PoliciesPeeked::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_policiesPeeked(&mut self) -> GameEvent_PoliciesPeeked {
+        if self.has_policiesPeeked() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PoliciesPeeked::new()
+        }
+    }
+
+    // .GameEvent.PlayerExecuted playerExecuted = 15;
+
+
+    pub fn get_playerExecuted(&self) -> &GameEvent_PlayerExecuted {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(ref v)) => v,
+            _ => <GameEvent_PlayerExecuted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerExecuted(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_playerExecuted(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerExecuted(&mut self, v: GameEvent_PlayerExecuted) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerExecuted(&mut self) -> &mut GameEvent_PlayerExecuted {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(GameEvent_PlayerExecuted::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerExecuted(&mut self) -> GameEvent_PlayerExecuted {
+        if self.has_playerExecuted() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PlayerExecuted::new()
+        }
+    }
+
+    // .GameEvent.PlayerKicked playerKicked = 16;
+
+
+    pub fn get_playerKicked(&self) -> &GameEvent_PlayerKicked {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(ref v)) => v,
+            _ => <GameEvent_PlayerKicked as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerKicked(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_playerKicked(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerKicked(&mut self, v: GameEvent_PlayerKicked) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerKicked(&mut self) -> &mut GameEvent_PlayerKicked {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(GameEvent_PlayerKicked::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerKicked(&mut self) -> GameEvent_PlayerKicked {
+        if self.has_playerKicked() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PlayerKicked::new()
+        }
+    }
+
+    // .GameEvent.AdminHandedOver adminHandedOver = 17;
+
+
+    pub fn get_adminHandedOver(&self) -> &GameEvent_AdminHandedOver {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(ref v)) => v,
+            _ => <GameEvent_AdminHandedOver as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_adminHandedOver(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_adminHandedOver(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_adminHandedOver(&mut self, v: GameEvent_AdminHandedOver) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_adminHandedOver(&mut self) -> &mut GameEvent_AdminHandedOver {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(GameEvent_AdminHandedOver::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_adminHandedOver(&mut self) -> GameEvent_AdminHandedOver {
+        if self.has_adminHandedOver() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_AdminHandedOver::new()
+        }
+    }
+
+    // .GameEvent.PasswordChanged passwordChanged = 18;
+
+
+    pub fn get_passwordChanged(&self) -> &GameEvent_PasswordChanged {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(ref v)) => v,
+            _ => <GameEvent_PasswordChanged as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_passwordChanged(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_passwordChanged(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_passwordChanged(&mut self, v: GameEvent_PasswordChanged) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_passwordChanged(&mut self) -> &mut GameEvent_PasswordChanged {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(GameEvent_PasswordChanged::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_passwordChanged(&mut self) -> GameEvent_PasswordChanged {
+        if self.has_passwordChanged() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PasswordChanged::new()
+        }
+    }
+
+    // .GameEvent.LobbyLocked lobbyLocked = 19;
+
+
+    pub fn get_lobbyLocked(&self) -> &GameEvent_LobbyLocked {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(ref v)) => v,
+            _ => <GameEvent_LobbyLocked as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_lobbyLocked(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_lobbyLocked(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lobbyLocked(&mut self, v: GameEvent_LobbyLocked) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_lobbyLocked(&mut self) -> &mut GameEvent_LobbyLocked {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(GameEvent_LobbyLocked::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_lobbyLocked(&mut self) -> GameEvent_LobbyLocked {
+        if self.has_lobbyLocked() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_LobbyLocked::new()
+        }
+    }
+
+    // .GameEvent.VoteAbstained voteAbstained = 20;
+
+
+    pub fn get_voteAbstained(&self) -> &GameEvent_VoteAbstained {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(ref v)) => v,
+            _ => <GameEvent_VoteAbstained as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_voteAbstained(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_voteAbstained(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voteAbstained(&mut self, v: GameEvent_VoteAbstained) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voteAbstained(&mut self) -> &mut GameEvent_VoteAbstained {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(GameEvent_VoteAbstained::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_voteAbstained(&mut self) -> GameEvent_VoteAbstained {
+        if self.has_voteAbstained() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_VoteAbstained::new()
+        }
+    }
+
+    // .GameEvent.BotAdded botAdded = 21;
+
+
+    pub fn get_botAdded(&self) -> &GameEvent_BotAdded {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::botAdded(ref v)) => v,
+            _ => <GameEvent_BotAdded as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_botAdded(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_botAdded(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::botAdded(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_botAdded(&mut self, v: GameEvent_BotAdded) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::botAdded(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_botAdded(&mut self) -> &mut GameEvent_BotAdded {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::botAdded(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::botAdded(GameEvent_BotAdded::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::botAdded(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_botAdded(&mut self) -> GameEvent_BotAdded {
+        if self.has_botAdded() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::botAdded(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_BotAdded::new()
+        }
+    }
+
+    // .GameEvent.PlayerReplacedByBot playerReplacedByBot = 22;
+
+
+    pub fn get_playerReplacedByBot(&self) -> &GameEvent_PlayerReplacedByBot {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(ref v)) => v,
+            _ => <GameEvent_PlayerReplacedByBot as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_playerReplacedByBot(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_playerReplacedByBot(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_playerReplacedByBot(&mut self, v: GameEvent_PlayerReplacedByBot) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_playerReplacedByBot(&mut self) -> &mut GameEvent_PlayerReplacedByBot {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(GameEvent_PlayerReplacedByBot::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_playerReplacedByBot(&mut self) -> GameEvent_PlayerReplacedByBot {
+        if self.has_playerReplacedByBot() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_PlayerReplacedByBot::new()
+        }
+    }
+
+    // .GameEvent.RematchStarted rematchStarted = 23;
+
+
+    pub fn get_rematchStarted(&self) -> &GameEvent_RematchStarted {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(ref v)) => v,
+            _ => <GameEvent_RematchStarted as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_rematchStarted(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_rematchStarted(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rematchStarted(&mut self, v: GameEvent_RematchStarted) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_rematchStarted(&mut self) -> &mut GameEvent_RematchStarted {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(GameEvent_RematchStarted::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_rematchStarted(&mut self) -> GameEvent_RematchStarted {
+        if self.has_rematchStarted() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_RematchStarted::new()
+        }
+    }
+
+    // .GameEvent.VetoProposed vetoProposed = 24;
+
+
+    pub fn get_vetoProposed(&self) -> &GameEvent_VetoProposed {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(ref v)) => v,
+            _ => <GameEvent_VetoProposed as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_vetoProposed(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_vetoProposed(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vetoProposed(&mut self, v: GameEvent_VetoProposed) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vetoProposed(&mut self) -> &mut GameEvent_VetoProposed {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(GameEvent_VetoProposed::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_vetoProposed(&mut self) -> GameEvent_VetoProposed {
+        if self.has_vetoProposed() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_VetoProposed::new()
+        }
+    }
+
+    // .GameEvent.VetoAnswered vetoAnswered = 25;
+
+
+    pub fn get_vetoAnswered(&self) -> &GameEvent_VetoAnswered {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(ref v)) => v,
+            _ => <GameEvent_VetoAnswered as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_vetoAnswered(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_vetoAnswered(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vetoAnswered(&mut self, v: GameEvent_VetoAnswered) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vetoAnswered(&mut self) -> &mut GameEvent_VetoAnswered {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(GameEvent_VetoAnswered::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_vetoAnswered(&mut self) -> GameEvent_VetoAnswered {
+        if self.has_vetoAnswered() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_VetoAnswered::new()
+        }
+    }
+
+    // .GameEvent.NameChosen nameChosen = 26;
+
+
+    pub fn get_nameChosen(&self) -> &GameEvent_NameChosen {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(ref v)) => v,
+            _ => <GameEvent_NameChosen as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_nameChosen(&mut self) {
+        self.event = ::std::option::Option::None;
+    }
+
+    pub fn has_nameChosen(&self) -> bool {
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nameChosen(&mut self, v: GameEvent_NameChosen) {
+        self.event = ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nameChosen(&mut self) -> &mut GameEvent_NameChosen {
+        if let ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(_)) = self.event {
+        } else {
+            self.event = ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(GameEvent_NameChosen::new()));
+        }
+        match self.event {
+            ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_nameChosen(&mut self) -> GameEvent_NameChosen {
+        if self.has_nameChosen() {
+            match self.event.take() {
+                ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            GameEvent_NameChosen::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for GameEvent {
+    fn is_initialized(&self) -> bool {
+        if let Some(GameEvent_oneof_event::gameCreated(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::playerJoined(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::playerLeft(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::settingsUpdated(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::gameStarted(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::chancellorNominated(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::voteCast(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::electionFinished(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::policyDiscarded(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::policyEnacted(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::loyaltyInvestigated(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::specialElectionCalled(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::policiesPeeked(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::playerExecuted(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::playerKicked(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::adminHandedOver(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::passwordChanged(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::lobbyLocked(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::voteAbstained(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::botAdded(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::playerReplacedByBot(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::rematchStarted(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::vetoProposed(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::vetoAnswered(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(GameEvent_oneof_event::nameChosen(ref v)) = self.event {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.time)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameCreated(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerJoined(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerLeft(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::settingsUpdated(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::gameStarted(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::chancellorNominated(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteCast(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::electionFinished(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyDiscarded(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::policyEnacted(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::loyaltyInvestigated(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::specialElectionCalled(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::policiesPeeked(is.read_message()?));
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerExecuted(is.read_message()?));
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerKicked(is.read_message()?));
+                },
+                17 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::adminHandedOver(is.read_message()?));
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::passwordChanged(is.read_message()?));
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::lobbyLocked(is.read_message()?));
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::voteAbstained(is.read_message()?));
+                },
+                21 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::botAdded(is.read_message()?));
+                },
+                22 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::playerReplacedByBot(is.read_message()?));
+                },
+                23 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::rematchStarted(is.read_message()?));
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoProposed(is.read_message()?));
+                },
+                25 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::vetoAnswered(is.read_message()?));
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.event = ::std::option::Option::Some(GameEvent_oneof_event::nameChosen(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.time.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.time);
+        }
+        if let ::std::option::Option::Some(ref v) = self.event {
+            match v {
+                &GameEvent_oneof_event::gameCreated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::playerJoined(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::playerLeft(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::settingsUpdated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::gameStarted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::chancellorNominated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::voteCast(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::electionFinished(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::policyDiscarded(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::policyEnacted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::loyaltyInvestigated(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::specialElectionCalled(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::policiesPeeked(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::playerExecuted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::playerKicked(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::adminHandedOver(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::passwordChanged(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::lobbyLocked(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::voteAbstained(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::botAdded(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::playerReplacedByBot(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::rematchStarted(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::vetoProposed(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::vetoAnswered(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &GameEvent_oneof_event::nameChosen(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.time.is_empty() {
+            os.write_string(1, &self.time)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.event {
+            match v {
+                &GameEvent_oneof_event::gameCreated(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::playerJoined(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::playerLeft(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::settingsUpdated(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::gameStarted(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::chancellorNominated(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::voteCast(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::electionFinished(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::policyDiscarded(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::policyEnacted(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::loyaltyInvestigated(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::specialElectionCalled(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::policiesPeeked(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::playerExecuted(ref v) => {
+                    os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::playerKicked(ref v) => {
+                    os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::adminHandedOver(ref v) => {
+                    os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::passwordChanged(ref v) => {
+                    os.write_tag(18, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::lobbyLocked(ref v) => {
+                    os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::voteAbstained(ref v) => {
+                    os.write_tag(20, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::botAdded(ref v) => {
+                    os.write_tag(21, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::playerReplacedByBot(ref v) => {
+                    os.write_tag(22, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::rematchStarted(ref v) => {
+                    os.write_tag(23, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::vetoProposed(ref v) => {
+                    os.write_tag(24, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::vetoAnswered(ref v) => {
+                    os.write_tag(25, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &GameEvent_oneof_event::nameChosen(ref v) => {
+                    os.write_tag(26, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent {
+        GameEvent::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "time",
+                |m: &GameEvent| { &m.time },
+                |m: &mut GameEvent| { &mut m.time },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_GameCreated>(
+                "gameCreated",
+                GameEvent::has_gameCreated,
+                GameEvent::get_gameCreated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PlayerJoined>(
+                "playerJoined",
+                GameEvent::has_playerJoined,
+                GameEvent::get_playerJoined,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PlayerLeft>(
+                "playerLeft",
+                GameEvent::has_playerLeft,
+                GameEvent::get_playerLeft,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_SettingsUpdated>(
+                "settingsUpdated",
+                GameEvent::has_settingsUpdated,
+                GameEvent::get_settingsUpdated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_GameStarted>(
+                "gameStarted",
+                GameEvent::has_gameStarted,
+                GameEvent::get_gameStarted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_ChancellorNominated>(
+                "chancellorNominated",
+                GameEvent::has_chancellorNominated,
+                GameEvent::get_chancellorNominated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_VoteCast>(
+                "voteCast",
+                GameEvent::has_voteCast,
+                GameEvent::get_voteCast,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_ElectionFinished>(
+                "electionFinished",
+                GameEvent::has_electionFinished,
+                GameEvent::get_electionFinished,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PolicyDiscarded>(
+                "policyDiscarded",
+                GameEvent::has_policyDiscarded,
+                GameEvent::get_policyDiscarded,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PolicyEnacted>(
+                "policyEnacted",
+                GameEvent::has_policyEnacted,
+                GameEvent::get_policyEnacted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_LoyaltyInvestigated>(
+                "loyaltyInvestigated",
+                GameEvent::has_loyaltyInvestigated,
+                GameEvent::get_loyaltyInvestigated,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_SpecialElectionCalled>(
+                "specialElectionCalled",
+                GameEvent::has_specialElectionCalled,
+                GameEvent::get_specialElectionCalled,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PoliciesPeeked>(
+                "policiesPeeked",
+                GameEvent::has_policiesPeeked,
+                GameEvent::get_policiesPeeked,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PlayerExecuted>(
+                "playerExecuted",
+                GameEvent::has_playerExecuted,
+                GameEvent::get_playerExecuted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PlayerKicked>(
+                "playerKicked",
+                GameEvent::has_playerKicked,
+                GameEvent::get_playerKicked,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_AdminHandedOver>(
+                "adminHandedOver",
+                GameEvent::has_adminHandedOver,
+                GameEvent::get_adminHandedOver,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PasswordChanged>(
+                "passwordChanged",
+                GameEvent::has_passwordChanged,
+                GameEvent::get_passwordChanged,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_LobbyLocked>(
+                "lobbyLocked",
+                GameEvent::has_lobbyLocked,
+                GameEvent::get_lobbyLocked,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_VoteAbstained>(
+                "voteAbstained",
+                GameEvent::has_voteAbstained,
+                GameEvent::get_voteAbstained,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_BotAdded>(
+                "botAdded",
+                GameEvent::has_botAdded,
+                GameEvent::get_botAdded,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_PlayerReplacedByBot>(
+                "playerReplacedByBot",
+                GameEvent::has_playerReplacedByBot,
+                GameEvent::get_playerReplacedByBot,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_RematchStarted>(
+                "rematchStarted",
+                GameEvent::has_rematchStarted,
+                GameEvent::get_rematchStarted,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_VetoProposed>(
+                "vetoProposed",
+                GameEvent::has_vetoProposed,
+                GameEvent::get_vetoProposed,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_VetoAnswered>(
+                "vetoAnswered",
+                GameEvent::has_vetoAnswered,
+                GameEvent::get_vetoAnswered,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, GameEvent_NameChosen>(
+                "nameChosen",
+                GameEvent::has_nameChosen,
+                GameEvent::get_nameChosen,
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent>(
+                "GameEvent",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent {
+        static instance: ::protobuf::rt::LazyV2<GameEvent> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent {
+    fn clear(&mut self) {
+        self.time.clear();
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.event = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_GameCreated {
+    // message fields
+    pub admin_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_GameCreated {
+    fn default() -> &'a GameEvent_GameCreated {
+        <GameEvent_GameCreated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_GameCreated {
+    pub fn new() -> GameEvent_GameCreated {
+        ::std::default::Default::default()
+    }
+
+    // string admin_id = 1;
+
+
+    pub fn get_admin_id(&self) -> &str {
+        &self.admin_id
+    }
+    pub fn clear_admin_id(&mut self) {
+        self.admin_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_admin_id(&mut self, v: ::std::string::String) {
+        self.admin_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_admin_id(&mut self) -> &mut ::std::string::String {
+        &mut self.admin_id
+    }
+
+    // Take field
+    pub fn take_admin_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.admin_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_GameCreated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.admin_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.admin_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.admin_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.admin_id.is_empty() {
+            os.write_string(1, &self.admin_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_GameCreated {
+        GameEvent_GameCreated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "admin_id",
+                |m: &GameEvent_GameCreated| { &m.admin_id },
+                |m: &mut GameEvent_GameCreated| { &mut m.admin_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_GameCreated>(
+                "GameEvent.GameCreated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_GameCreated {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_GameCreated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_GameCreated::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_GameCreated {
+    fn clear(&mut self) {
+        self.admin_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_GameCreated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_GameCreated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PlayerJoined {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PlayerJoined {
+    fn default() -> &'a GameEvent_PlayerJoined {
+        <GameEvent_PlayerJoined as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PlayerJoined {
+    pub fn new() -> GameEvent_PlayerJoined {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PlayerJoined {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PlayerJoined {
+        GameEvent_PlayerJoined::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PlayerJoined| { &m.player_id },
+                |m: &mut GameEvent_PlayerJoined| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PlayerJoined>(
+                "GameEvent.PlayerJoined",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PlayerJoined {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PlayerJoined> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PlayerJoined::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PlayerJoined {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PlayerJoined {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PlayerJoined {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PlayerLeft {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PlayerLeft {
+    fn default() -> &'a GameEvent_PlayerLeft {
+        <GameEvent_PlayerLeft as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PlayerLeft {
+    pub fn new() -> GameEvent_PlayerLeft {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PlayerLeft {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PlayerLeft {
+        GameEvent_PlayerLeft::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PlayerLeft| { &m.player_id },
+                |m: &mut GameEvent_PlayerLeft| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PlayerLeft>(
+                "GameEvent.PlayerLeft",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PlayerLeft {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PlayerLeft> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PlayerLeft::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PlayerLeft {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PlayerLeft {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PlayerLeft {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_SettingsUpdated {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub settings: ::protobuf::SingularPtrField<super::settings::GameSettings>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_SettingsUpdated {
+    fn default() -> &'a GameEvent_SettingsUpdated {
+        <GameEvent_SettingsUpdated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_SettingsUpdated {
+    pub fn new() -> GameEvent_SettingsUpdated {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // .GameSettings settings = 2;
+
+
+    pub fn get_settings(&self) -> &super::settings::GameSettings {
+        self.settings.as_ref().unwrap_or_else(|| <super::settings::GameSettings as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_settings(&mut self) {
+        self.settings.clear();
+    }
+
+    pub fn has_settings(&self) -> bool {
+        self.settings.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_settings(&mut self, v: super::settings::GameSettings) {
+        self.settings = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_settings(&mut self) -> &mut super::settings::GameSettings {
+        if self.settings.is_none() {
+            self.settings.set_default();
+        }
+        self.settings.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_settings(&mut self) -> super::settings::GameSettings {
+        self.settings.take().unwrap_or_else(|| super::settings::GameSettings::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_SettingsUpdated {
+    fn is_initialized(&self) -> bool {
+        for v in &self.settings {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.settings)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if let Some(ref v) = self.settings.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if let Some(ref v) = self.settings.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_SettingsUpdated {
+        GameEvent_SettingsUpdated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_SettingsUpdated| { &m.player_id },
+                |m: &mut GameEvent_SettingsUpdated| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::settings::GameSettings>>(
+                "settings",
+                |m: &GameEvent_SettingsUpdated| { &m.settings },
+                |m: &mut GameEvent_SettingsUpdated| { &mut m.settings },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_SettingsUpdated>(
+                "GameEvent.SettingsUpdated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_SettingsUpdated {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_SettingsUpdated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_SettingsUpdated::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_SettingsUpdated {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.settings.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_SettingsUpdated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_SettingsUpdated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_GameStarted {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_GameStarted {
+    fn default() -> &'a GameEvent_GameStarted {
+        <GameEvent_GameStarted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_GameStarted {
+    pub fn new() -> GameEvent_GameStarted {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for GameEvent_GameStarted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_GameStarted {
+        GameEvent_GameStarted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_GameStarted>(
+                "GameEvent.GameStarted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_GameStarted {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_GameStarted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_GameStarted::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_GameStarted {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_GameStarted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_GameStarted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_ChancellorNominated {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub chancellor_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_ChancellorNominated {
+    fn default() -> &'a GameEvent_ChancellorNominated {
+        <GameEvent_ChancellorNominated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_ChancellorNominated {
+    pub fn new() -> GameEvent_ChancellorNominated {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string chancellor_id = 2;
+
+
+    pub fn get_chancellor_id(&self) -> &str {
+        &self.chancellor_id
+    }
+    pub fn clear_chancellor_id(&mut self) {
+        self.chancellor_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chancellor_id(&mut self, v: ::std::string::String) {
+        self.chancellor_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chancellor_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chancellor_id
+    }
+
+    // Take field
+    pub fn take_chancellor_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chancellor_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_ChancellorNominated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chancellor_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.chancellor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.chancellor_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.chancellor_id.is_empty() {
+            os.write_string(2, &self.chancellor_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_ChancellorNominated {
+        GameEvent_ChancellorNominated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_ChancellorNominated| { &m.player_id },
+                |m: &mut GameEvent_ChancellorNominated| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chancellor_id",
+                |m: &GameEvent_ChancellorNominated| { &m.chancellor_id },
+                |m: &mut GameEvent_ChancellorNominated| { &mut m.chancellor_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_ChancellorNominated>(
+                "GameEvent.ChancellorNominated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_ChancellorNominated {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_ChancellorNominated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_ChancellorNominated::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_ChancellorNominated {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.chancellor_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_ChancellorNominated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_ChancellorNominated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_VoteCast {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub approved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_VoteCast {
+    fn default() -> &'a GameEvent_VoteCast {
+        <GameEvent_VoteCast as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_VoteCast {
+    pub fn new() -> GameEvent_VoteCast {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // bool approved = 2;
+
+
+    pub fn get_approved(&self) -> bool {
+        self.approved
+    }
+    pub fn clear_approved(&mut self) {
+        self.approved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved(&mut self, v: bool) {
+        self.approved = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_VoteCast {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.approved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.approved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.approved != false {
+            os.write_bool(2, self.approved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_VoteCast {
+        GameEvent_VoteCast::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_VoteCast| { &m.player_id },
+                |m: &mut GameEvent_VoteCast| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "approved",
+                |m: &GameEvent_VoteCast| { &m.approved },
+                |m: &mut GameEvent_VoteCast| { &mut m.approved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_VoteCast>(
+                "GameEvent.VoteCast",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_VoteCast {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_VoteCast> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_VoteCast::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_VoteCast {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.approved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_VoteCast {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_VoteCast {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_VoteAbstained {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_VoteAbstained {
+    fn default() -> &'a GameEvent_VoteAbstained {
+        <GameEvent_VoteAbstained as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_VoteAbstained {
+    pub fn new() -> GameEvent_VoteAbstained {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_VoteAbstained {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_VoteAbstained {
+        GameEvent_VoteAbstained::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_VoteAbstained| { &m.player_id },
+                |m: &mut GameEvent_VoteAbstained| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_VoteAbstained>(
+                "GameEvent.VoteAbstained",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_VoteAbstained {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_VoteAbstained> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_VoteAbstained::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_VoteAbstained {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_VoteAbstained {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_VoteAbstained {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_ElectionFinished {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_ElectionFinished {
+    fn default() -> &'a GameEvent_ElectionFinished {
+        <GameEvent_ElectionFinished as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_ElectionFinished {
+    pub fn new() -> GameEvent_ElectionFinished {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for GameEvent_ElectionFinished {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_ElectionFinished {
+        GameEvent_ElectionFinished::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_ElectionFinished>(
+                "GameEvent.ElectionFinished",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_ElectionFinished {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_ElectionFinished> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_ElectionFinished::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_ElectionFinished {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_ElectionFinished {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_ElectionFinished {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PolicyDiscarded {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub policy: super::policy::Policy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PolicyDiscarded {
+    fn default() -> &'a GameEvent_PolicyDiscarded {
+        <GameEvent_PolicyDiscarded as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PolicyDiscarded {
+    pub fn new() -> GameEvent_PolicyDiscarded {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // .Policy policy = 2;
+
+
+    pub fn get_policy(&self) -> super::policy::Policy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: super::policy::Policy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PolicyDiscarded {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            my_size += ::protobuf::rt::enum_size(2, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PolicyDiscarded {
+        GameEvent_PolicyDiscarded::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PolicyDiscarded| { &m.player_id },
+                |m: &mut GameEvent_PolicyDiscarded| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policy",
+                |m: &GameEvent_PolicyDiscarded| { &m.policy },
+                |m: &mut GameEvent_PolicyDiscarded| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PolicyDiscarded>(
+                "GameEvent.PolicyDiscarded",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PolicyDiscarded {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PolicyDiscarded> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PolicyDiscarded::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PolicyDiscarded {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PolicyDiscarded {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PolicyDiscarded {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PolicyEnacted {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub policy: super::policy::Policy,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PolicyEnacted {
+    fn default() -> &'a GameEvent_PolicyEnacted {
+        <GameEvent_PolicyEnacted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PolicyEnacted {
+    pub fn new() -> GameEvent_PolicyEnacted {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // .Policy policy = 2;
+
+
+    pub fn get_policy(&self) -> super::policy::Policy {
+        self.policy
+    }
+    pub fn clear_policy(&mut self) {
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policy(&mut self, v: super::policy::Policy) {
+        self.policy = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PolicyEnacted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.policy, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            my_size += ::protobuf::rt::enum_size(2, self.policy);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.policy != super::policy::Policy::LOYAL_POLICY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.policy))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PolicyEnacted {
+        GameEvent_PolicyEnacted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PolicyEnacted| { &m.player_id },
+                |m: &mut GameEvent_PolicyEnacted| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policy",
+                |m: &GameEvent_PolicyEnacted| { &m.policy },
+                |m: &mut GameEvent_PolicyEnacted| { &mut m.policy },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PolicyEnacted>(
+                "GameEvent.PolicyEnacted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PolicyEnacted {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PolicyEnacted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PolicyEnacted::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PolicyEnacted {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.policy = super::policy::Policy::LOYAL_POLICY;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PolicyEnacted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PolicyEnacted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_LoyaltyInvestigated {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub target_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_LoyaltyInvestigated {
+    fn default() -> &'a GameEvent_LoyaltyInvestigated {
+        <GameEvent_LoyaltyInvestigated as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_LoyaltyInvestigated {
+    pub fn new() -> GameEvent_LoyaltyInvestigated {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string target_id = 2;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_LoyaltyInvestigated {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(2, &self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_LoyaltyInvestigated {
+        GameEvent_LoyaltyInvestigated::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_LoyaltyInvestigated| { &m.player_id },
+                |m: &mut GameEvent_LoyaltyInvestigated| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &GameEvent_LoyaltyInvestigated| { &m.target_id },
+                |m: &mut GameEvent_LoyaltyInvestigated| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_LoyaltyInvestigated>(
+                "GameEvent.LoyaltyInvestigated",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_LoyaltyInvestigated {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_LoyaltyInvestigated> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_LoyaltyInvestigated::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_LoyaltyInvestigated {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.target_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_LoyaltyInvestigated {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_LoyaltyInvestigated {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_SpecialElectionCalled {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub target_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_SpecialElectionCalled {
+    fn default() -> &'a GameEvent_SpecialElectionCalled {
+        <GameEvent_SpecialElectionCalled as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_SpecialElectionCalled {
+    pub fn new() -> GameEvent_SpecialElectionCalled {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string target_id = 2;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_SpecialElectionCalled {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(2, &self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_SpecialElectionCalled {
+        GameEvent_SpecialElectionCalled::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_SpecialElectionCalled| { &m.player_id },
+                |m: &mut GameEvent_SpecialElectionCalled| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &GameEvent_SpecialElectionCalled| { &m.target_id },
+                |m: &mut GameEvent_SpecialElectionCalled| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_SpecialElectionCalled>(
+                "GameEvent.SpecialElectionCalled",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_SpecialElectionCalled {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_SpecialElectionCalled> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_SpecialElectionCalled::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_SpecialElectionCalled {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.target_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_SpecialElectionCalled {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_SpecialElectionCalled {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PoliciesPeeked {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PoliciesPeeked {
+    fn default() -> &'a GameEvent_PoliciesPeeked {
+        <GameEvent_PoliciesPeeked as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PoliciesPeeked {
+    pub fn new() -> GameEvent_PoliciesPeeked {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PoliciesPeeked {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PoliciesPeeked {
+        GameEvent_PoliciesPeeked::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PoliciesPeeked| { &m.player_id },
+                |m: &mut GameEvent_PoliciesPeeked| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PoliciesPeeked>(
+                "GameEvent.PoliciesPeeked",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PoliciesPeeked {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PoliciesPeeked> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PoliciesPeeked::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PoliciesPeeked {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PoliciesPeeked {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PoliciesPeeked {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PlayerExecuted {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub target_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PlayerExecuted {
+    fn default() -> &'a GameEvent_PlayerExecuted {
+        <GameEvent_PlayerExecuted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PlayerExecuted {
+    pub fn new() -> GameEvent_PlayerExecuted {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string target_id = 2;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PlayerExecuted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(2, &self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PlayerExecuted {
+        GameEvent_PlayerExecuted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PlayerExecuted| { &m.player_id },
+                |m: &mut GameEvent_PlayerExecuted| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &GameEvent_PlayerExecuted| { &m.target_id },
+                |m: &mut GameEvent_PlayerExecuted| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PlayerExecuted>(
+                "GameEvent.PlayerExecuted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PlayerExecuted {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PlayerExecuted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PlayerExecuted::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PlayerExecuted {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.target_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PlayerExecuted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PlayerExecuted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PlayerKicked {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub target_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PlayerKicked {
+    fn default() -> &'a GameEvent_PlayerKicked {
+        <GameEvent_PlayerKicked as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PlayerKicked {
+    pub fn new() -> GameEvent_PlayerKicked {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string target_id = 2;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PlayerKicked {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(2, &self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PlayerKicked {
+        GameEvent_PlayerKicked::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PlayerKicked| { &m.player_id },
+                |m: &mut GameEvent_PlayerKicked| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &GameEvent_PlayerKicked| { &m.target_id },
+                |m: &mut GameEvent_PlayerKicked| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PlayerKicked>(
+                "GameEvent.PlayerKicked",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PlayerKicked {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PlayerKicked> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PlayerKicked::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PlayerKicked {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.target_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PlayerKicked {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PlayerKicked {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_AdminHandedOver {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub target_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_AdminHandedOver {
+    fn default() -> &'a GameEvent_AdminHandedOver {
+        <GameEvent_AdminHandedOver as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_AdminHandedOver {
+    pub fn new() -> GameEvent_AdminHandedOver {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string target_id = 2;
+
+
+    pub fn get_target_id(&self) -> &str {
+        &self.target_id
+    }
+    pub fn clear_target_id(&mut self) {
+        self.target_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target_id(&mut self, v: ::std::string::String) {
+        self.target_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target_id(&mut self) -> &mut ::std::string::String {
+        &mut self.target_id
+    }
+
+    // Take field
+    pub fn take_target_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.target_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_AdminHandedOver {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.target_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.target_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.target_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.target_id.is_empty() {
+            os.write_string(2, &self.target_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_AdminHandedOver {
+        GameEvent_AdminHandedOver::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_AdminHandedOver| { &m.player_id },
+                |m: &mut GameEvent_AdminHandedOver| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "target_id",
+                |m: &GameEvent_AdminHandedOver| { &m.target_id },
+                |m: &mut GameEvent_AdminHandedOver| { &mut m.target_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_AdminHandedOver>(
+                "GameEvent.AdminHandedOver",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_AdminHandedOver {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_AdminHandedOver> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_AdminHandedOver::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_AdminHandedOver {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.target_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_AdminHandedOver {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_AdminHandedOver {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PasswordChanged {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub password_protected: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PasswordChanged {
+    fn default() -> &'a GameEvent_PasswordChanged {
+        <GameEvent_PasswordChanged as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PasswordChanged {
+    pub fn new() -> GameEvent_PasswordChanged {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // bool password_protected = 2;
+
+
+    pub fn get_password_protected(&self) -> bool {
+        self.password_protected
+    }
+    pub fn clear_password_protected(&mut self) {
+        self.password_protected = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_password_protected(&mut self, v: bool) {
+        self.password_protected = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PasswordChanged {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.password_protected = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.password_protected != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.password_protected != false {
+            os.write_bool(2, self.password_protected)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PasswordChanged {
+        GameEvent_PasswordChanged::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PasswordChanged| { &m.player_id },
+                |m: &mut GameEvent_PasswordChanged| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "password_protected",
+                |m: &GameEvent_PasswordChanged| { &m.password_protected },
+                |m: &mut GameEvent_PasswordChanged| { &mut m.password_protected },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PasswordChanged>(
+                "GameEvent.PasswordChanged",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PasswordChanged {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PasswordChanged> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PasswordChanged::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PasswordChanged {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.password_protected = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PasswordChanged {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PasswordChanged {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_LobbyLocked {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub locked: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_LobbyLocked {
+    fn default() -> &'a GameEvent_LobbyLocked {
+        <GameEvent_LobbyLocked as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_LobbyLocked {
+    pub fn new() -> GameEvent_LobbyLocked {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // bool locked = 2;
+
+
+    pub fn get_locked(&self) -> bool {
+        self.locked
+    }
+    pub fn clear_locked(&mut self) {
+        self.locked = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_locked(&mut self, v: bool) {
+        self.locked = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_LobbyLocked {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.locked = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.locked != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.locked != false {
+            os.write_bool(2, self.locked)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_LobbyLocked {
+        GameEvent_LobbyLocked::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_LobbyLocked| { &m.player_id },
+                |m: &mut GameEvent_LobbyLocked| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "locked",
+                |m: &GameEvent_LobbyLocked| { &m.locked },
+                |m: &mut GameEvent_LobbyLocked| { &mut m.locked },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_LobbyLocked>(
+                "GameEvent.LobbyLocked",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_LobbyLocked {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_LobbyLocked> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_LobbyLocked::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_LobbyLocked {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.locked = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_LobbyLocked {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_LobbyLocked {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_BotAdded {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub bot_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_BotAdded {
+    fn default() -> &'a GameEvent_BotAdded {
+        <GameEvent_BotAdded as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_BotAdded {
+    pub fn new() -> GameEvent_BotAdded {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string bot_id = 2;
+
+
+    pub fn get_bot_id(&self) -> &str {
+        &self.bot_id
+    }
+    pub fn clear_bot_id(&mut self) {
+        self.bot_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bot_id(&mut self, v: ::std::string::String) {
+        self.bot_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_bot_id(&mut self) -> &mut ::std::string::String {
+        &mut self.bot_id
+    }
+
+    // Take field
+    pub fn take_bot_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.bot_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_BotAdded {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.bot_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.bot_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.bot_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.bot_id.is_empty() {
+            os.write_string(2, &self.bot_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_BotAdded {
+        GameEvent_BotAdded::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_BotAdded| { &m.player_id },
+                |m: &mut GameEvent_BotAdded| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "bot_id",
+                |m: &GameEvent_BotAdded| { &m.bot_id },
+                |m: &mut GameEvent_BotAdded| { &mut m.bot_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_BotAdded>(
+                "GameEvent.BotAdded",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_BotAdded {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_BotAdded> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_BotAdded::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_BotAdded {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.bot_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_BotAdded {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_BotAdded {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_PlayerReplacedByBot {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_PlayerReplacedByBot {
+    fn default() -> &'a GameEvent_PlayerReplacedByBot {
+        <GameEvent_PlayerReplacedByBot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_PlayerReplacedByBot {
+    pub fn new() -> GameEvent_PlayerReplacedByBot {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_PlayerReplacedByBot {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_PlayerReplacedByBot {
+        GameEvent_PlayerReplacedByBot::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_PlayerReplacedByBot| { &m.player_id },
+                |m: &mut GameEvent_PlayerReplacedByBot| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_PlayerReplacedByBot>(
+                "GameEvent.PlayerReplacedByBot",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_PlayerReplacedByBot {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_PlayerReplacedByBot> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_PlayerReplacedByBot::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_PlayerReplacedByBot {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_PlayerReplacedByBot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_PlayerReplacedByBot {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_RematchStarted {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_RematchStarted {
+    fn default() -> &'a GameEvent_RematchStarted {
+        <GameEvent_RematchStarted as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_RematchStarted {
+    pub fn new() -> GameEvent_RematchStarted {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_RematchStarted {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_RematchStarted {
+        GameEvent_RematchStarted::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_RematchStarted| { &m.player_id },
+                |m: &mut GameEvent_RematchStarted| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_RematchStarted>(
+                "GameEvent.RematchStarted",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_RematchStarted {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_RematchStarted> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_RematchStarted::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_RematchStarted {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_RematchStarted {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_RematchStarted {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_VetoProposed {
+    // message fields
+    pub player_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_VetoProposed {
+    fn default() -> &'a GameEvent_VetoProposed {
+        <GameEvent_VetoProposed as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_VetoProposed {
+    pub fn new() -> GameEvent_VetoProposed {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_VetoProposed {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_VetoProposed {
+        GameEvent_VetoProposed::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_VetoProposed| { &m.player_id },
+                |m: &mut GameEvent_VetoProposed| { &mut m.player_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_VetoProposed>(
+                "GameEvent.VetoProposed",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_VetoProposed {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_VetoProposed> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_VetoProposed::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_VetoProposed {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_VetoProposed {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_VetoProposed {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_VetoAnswered {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub approved: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_VetoAnswered {
+    fn default() -> &'a GameEvent_VetoAnswered {
+        <GameEvent_VetoAnswered as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_VetoAnswered {
+    pub fn new() -> GameEvent_VetoAnswered {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // bool approved = 2;
+
+
+    pub fn get_approved(&self) -> bool {
+        self.approved
+    }
+    pub fn clear_approved(&mut self) {
+        self.approved = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_approved(&mut self, v: bool) {
+        self.approved = v;
+    }
+}
+
+impl ::protobuf::Message for GameEvent_VetoAnswered {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.approved = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.approved != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.approved != false {
+            os.write_bool(2, self.approved)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_VetoAnswered {
+        GameEvent_VetoAnswered::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_VetoAnswered| { &m.player_id },
+                |m: &mut GameEvent_VetoAnswered| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "approved",
+                |m: &GameEvent_VetoAnswered| { &m.approved },
+                |m: &mut GameEvent_VetoAnswered| { &mut m.approved },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_VetoAnswered>(
+                "GameEvent.VetoAnswered",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_VetoAnswered {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_VetoAnswered> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_VetoAnswered::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_VetoAnswered {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.approved = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_VetoAnswered {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_VetoAnswered {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameEvent_NameChosen {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub name: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameEvent_NameChosen {
+    fn default() -> &'a GameEvent_NameChosen {
+        <GameEvent_NameChosen as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameEvent_NameChosen {
+    pub fn new() -> GameEvent_NameChosen {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // string name = 2;
+
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        &mut self.name
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.name, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for GameEvent_NameChosen {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.name);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(2, &self.name)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameEvent_NameChosen {
+        GameEvent_NameChosen::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &GameEvent_NameChosen| { &m.player_id },
+                |m: &mut GameEvent_NameChosen| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &GameEvent_NameChosen| { &m.name },
+                |m: &mut GameEvent_NameChosen| { &mut m.name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameEvent_NameChosen>(
+                "GameEvent.NameChosen",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameEvent_NameChosen {
+        static instance: ::protobuf::rt::LazyV2<GameEvent_NameChosen> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameEvent_NameChosen::new)
+    }
+}
+
+impl ::protobuf::Clear for GameEvent_NameChosen {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.name.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameEvent_NameChosen {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameEvent_NameChosen {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Replay {
+    // message fields
+    pub token: ::std::string::String,
+    pub winner: super::role::Party,
+    pub reason: super::game::WinReason,
+    pub roles: ::protobuf::RepeatedField<super::role::PlayerRole>,
+    pub events: ::protobuf::RepeatedField<GameEvent>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Replay {
+    fn default() -> &'a Replay {
+        <Replay as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Replay {
+    pub fn new() -> Replay {
+        ::std::default::Default::default()
+    }
+
+    // string token = 1;
+
+
+    pub fn get_token(&self) -> &str {
+        &self.token
+    }
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        &mut self.token
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.token, ::std::string::String::new())
+    }
+
+    // .Party winner = 2;
+
+
+    pub fn get_winner(&self) -> super::role::Party {
+        self.winner
+    }
+    pub fn clear_winner(&mut self) {
+        self.winner = super::role::Party::LOYAL_PARTY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_winner(&mut self, v: super::role::Party) {
+        self.winner = v;
+    }
+
+    // .WinReason reason = 3;
+
+
+    pub fn get_reason(&self) -> super::game::WinReason {
+        self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason = super::game::WinReason::LOYAL_POLICIES_ENACTED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: super::game::WinReason) {
+        self.reason = v;
+    }
+
+    // repeated .PlayerRole roles = 4;
+
+
+    pub fn get_roles(&self) -> &[super::role::PlayerRole] {
+        &self.roles
+    }
+    pub fn clear_roles(&mut self) {
+        self.roles.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roles(&mut self, v: ::protobuf::RepeatedField<super::role::PlayerRole>) {
+        self.roles = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_roles(&mut self) -> &mut ::protobuf::RepeatedField<super::role::PlayerRole> {
+        &mut self.roles
+    }
+
+    // Take field
+    pub fn take_roles(&mut self) -> ::protobuf::RepeatedField<super::role::PlayerRole> {
+        ::std::mem::replace(&mut self.roles, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .GameEvent events = 5;
+
+
+    pub fn get_events(&self) -> &[GameEvent] {
+        &self.events
+    }
+    pub fn clear_events(&mut self) {
+        self.events.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_events(&mut self, v: ::protobuf::RepeatedField<GameEvent>) {
+        self.events = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_events(&mut self) -> &mut ::protobuf::RepeatedField<GameEvent> {
+        &mut self.events
+    }
+
+    // Take field
+    pub fn take_events(&mut self) -> ::protobuf::RepeatedField<GameEvent> {
+        ::std::mem::replace(&mut self.events, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Replay {
+    fn is_initialized(&self) -> bool {
+        for v in &self.roles {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.events {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.token)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.winner, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 3, &mut self.unknown_fields)?
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.roles)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.events)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.token.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.token);
+        }
+        if self.winner != super::role::Party::LOYAL_PARTY {
+            my_size += ::protobuf::rt::enum_size(2, self.winner);
+        }
+        if self.reason != super::game::WinReason::LOYAL_POLICIES_ENACTED {
+            my_size += ::protobuf::rt::enum_size(3, self.reason);
+        }
+        for value in &self.roles {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.events {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.token.is_empty() {
+            os.write_string(1, &self.token)?;
+        }
+        if self.winner != super::role::Party::LOYAL_PARTY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.winner))?;
+        }
+        if self.reason != super::game::WinReason::LOYAL_POLICIES_ENACTED {
+            os.write_enum(3, ::protobuf::ProtobufEnum::value(&self.reason))?;
+        }
+        for v in &self.roles {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.events {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Replay {
+        Replay::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "token",
+                |m: &Replay| { &m.token },
+                |m: &mut Replay| { &mut m.token },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Party>>(
+                "winner",
+                |m: &Replay| { &m.winner },
+                |m: &mut Replay| { &mut m.winner },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::game::WinReason>>(
+                "reason",
+                |m: &Replay| { &m.reason },
+                |m: &mut Replay| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::role::PlayerRole>>(
+                "roles",
+                |m: &Replay| { &m.roles },
+                |m: &mut Replay| { &mut m.roles },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GameEvent>>(
+                "events",
+                |m: &Replay| { &m.events },
+                |m: &mut Replay| { &mut m.events },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Replay>(
+                "Replay",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Replay {
+        static instance: ::protobuf::rt::LazyV2<Replay> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Replay::new)
+    }
+}
+
+impl ::protobuf::Clear for Replay {
+    fn clear(&mut self) {
+        self.token.clear();
+        self.winner = super::role::Party::LOYAL_PARTY;
+        self.reason = super::game::WinReason::LOYAL_POLICIES_ENACTED;
+        self.roles.clear();
+        self.events.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Replay {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Replay {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0creplay.proto\x1a\ngame.proto\x1a\x0cpolicy.proto\x1a\nrole.proto\
+    \x1a\x0esettings.proto\"\xe1\x19\n\tGameEvent\x12\x12\n\x04time\x18\x01\
+    \x20\x01(\tR\x04time\x12:\n\x0bgameCreated\x18\x02\x20\x01(\x0b2\x16.Gam\
+    eEvent.GameCreatedH\0R\x0bgameCreated\x12=\n\x0cplayerJoined\x18\x03\x20\
+    \x01(\x0b2\x17.GameEvent.PlayerJoinedH\0R\x0cplayerJoined\x127\n\nplayer\
+    Left\x18\x04\x20\x01(\x0b2\x15.GameEvent.PlayerLeftH\0R\nplayerLeft\x12F\
+    \n\x0fsettingsUpdated\x18\x05\x20\x01(\x0b2\x1a.GameEvent.SettingsUpdate\
+    dH\0R\x0fsettingsUpdated\x12:\n\x0bgameStarted\x18\x06\x20\x01(\x0b2\x16\
+    .GameEvent.GameStartedH\0R\x0bgameStarted\x12R\n\x13chancellorNominated\
+    \x18\x07\x20\x01(\x0b2\x1e.GameEvent.ChancellorNominatedH\0R\x13chancell\
+    orNominated\x121\n\x08voteCast\x18\x08\x20\x01(\x0b2\x13.GameEvent.VoteC\
+    astH\0R\x08voteCast\x12I\n\x10electionFinished\x18\t\x20\x01(\x0b2\x1b.G\
+    ameEvent.ElectionFinishedH\0R\x10electionFinished\x12F\n\x0fpolicyDiscar\
+    ded\x18\n\x20\x01(\x0b2\x1a.GameEvent.PolicyDiscardedH\0R\x0fpolicyDisca\
+    rded\x12@\n\rpolicyEnacted\x18\x0b\x20\x01(\x0b2\x18.GameEvent.PolicyEna\
+    ctedH\0R\rpolicyEnacted\x12R\n\x13loyaltyInvestigated\x18\x0c\x20\x01(\
+    \x0b2\x1e.GameEvent.LoyaltyInvestigatedH\0R\x13loyaltyInvestigated\x12X\
+    \n\x15specialElectionCalled\x18\r\x20\x01(\x0b2\x20.GameEvent.SpecialEle\
+    ctionCalledH\0R\x15specialElectionCalled\x12C\n\x0epoliciesPeeked\x18\
+    \x0e\x20\x01(\x0b2\x19.GameEvent.PoliciesPeekedH\0R\x0epoliciesPeeked\
+    \x12C\n\x0eplayerExecuted\x18\x0f\x20\x01(\x0b2\x19.GameEvent.PlayerExec\
+    utedH\0R\x0eplayerExecuted\x12=\n\x0cplayerKicked\x18\x10\x20\x01(\x0b2\
+    \x17.GameEvent.PlayerKickedH\0R\x0cplayerKicked\x12F\n\x0fadminHandedOve\
+    r\x18\x11\x20\x01(\x0b2\x1a.GameEvent.AdminHandedOverH\0R\x0fadminHanded\
+    Over\x12F\n\x0fpasswordChanged\x18\x12\x20\x01(\x0b2\x1a.GameEvent.Passw\
+    ordChangedH\0R\x0fpasswordChanged\x12:\n\x0blobbyLocked\x18\x13\x20\x01(\
+    \x0b2\x16.GameEvent.LobbyLockedH\0R\x0blobbyLocked\x12@\n\rvoteAbstained\
+    \x18\x14\x20\x01(\x0b2\x18.GameEvent.VoteAbstainedH\0R\rvoteAbstained\
+    \x121\n\x08botAdded\x18\x15\x20\x01(\x0b2\x13.GameEvent.BotAddedH\0R\x08\
+    botAdded\x12R\n\x13playerReplacedByBot\x18\x16\x20\x01(\x0b2\x1e.GameEve\
+    nt.PlayerReplacedByBotH\0R\x13playerReplacedByBot\x12C\n\x0erematchStart\
+    ed\x18\x17\x20\x01(\x0b2\x19.GameEvent.RematchStartedH\0R\x0erematchStar\
+    ted\x12=\n\x0cvetoProposed\x18\x18\x20\x01(\x0b2\x17.GameEvent.VetoPropo\
+    sedH\0R\x0cvetoProposed\x12=\n\x0cvetoAnswered\x18\x19\x20\x01(\x0b2\x17\
+    .GameEvent.VetoAnsweredH\0R\x0cvetoAnswered\x127\n\nnameChosen\x18\x1a\
+    \x20\x01(\x0b2\x15.GameEvent.NameChosenH\0R\nnameChosen\x1a(\n\x0bGameCr\
+    eated\x12\x19\n\x08admin_id\x18\x01\x20\x01(\tR\x07adminId\x1a+\n\x0cPla\
+    yerJoined\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x1a)\n\nP\
+    layerLeft\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x1aY\n\
+    \x0fSettingsUpdated\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\
+    \x12)\n\x08settings\x18\x02\x20\x01(\x0b2\r.GameSettingsR\x08settings\
+    \x1a\r\n\x0bGameStarted\x1aW\n\x13ChancellorNominated\x12\x1b\n\tplayer_\
+    id\x18\x01\x20\x01(\tR\x08playerId\x12#\n\rchancellor_id\x18\x02\x20\x01\
+    (\tR\x0cchancellorId\x1aC\n\x08VoteCast\x12\x1b\n\tplayer_id\x18\x01\x20\
+    \x01(\tR\x08playerId\x12\x1a\n\x08approved\x18\x02\x20\x01(\x08R\x08appr\
+    oved\x1a,\n\rVoteAbstained\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08p\
+    layerId\x1a\x12\n\x10ElectionFinished\x1aO\n\x0fPolicyDiscarded\x12\x1b\
+    \n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1f\n\x06policy\x18\
+    \x02\x20\x01(\x0e2\x07.PolicyR\x06policy\x1aM\n\rPolicyEnacted\x12\x1b\n\
+    \tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1f\n\x06policy\x18\x02\
+    \x20\x01(\x0e2\x07.PolicyR\x06policy\x1aO\n\x13LoyaltyInvestigated\x12\
+    \x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1b\n\ttarget_id\
+    \x18\x02\x20\x01(\tR\x08targetId\x1aQ\n\x15SpecialElectionCalled\x12\x1b\
+    \n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1b\n\ttarget_id\x18\
+    \x02\x20\x01(\tR\x08targetId\x1a-\n\x0ePoliciesPeeked\x12\x1b\n\tplayer_\
+    id\x18\x01\x20\x01(\tR\x08playerId\x1aJ\n\x0ePlayerExecuted\x12\x1b\n\tp\
+    layer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x1b\n\ttarget_id\x18\x02\
+    \x20\x01(\tR\x08targetId\x1aH\n\x0cPlayerKicked\x12\x1b\n\tplayer_id\x18\
+    \x01\x20\x01(\tR\x08playerId\x12\x1b\n\ttarget_id\x18\x02\x20\x01(\tR\
+    \x08targetId\x1aK\n\x0fAdminHandedOver\x12\x1b\n\tplayer_id\x18\x01\x20\
+    \x01(\tR\x08playerId\x12\x1b\n\ttarget_id\x18\x02\x20\x01(\tR\x08targetI\
+    d\x1a]\n\x0fPasswordChanged\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08\
+    playerId\x12-\n\x12password_protected\x18\x02\x20\x01(\x08R\x11passwordP\
+    rotected\x1aB\n\x0bLobbyLocked\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\
+    \x08playerId\x12\x16\n\x06locked\x18\x02\x20\x01(\x08R\x06locked\x1a>\n\
+    \x08BotAdded\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\
+    \x15\n\x06bot_id\x18\x02\x20\x01(\tR\x05botId\x1a2\n\x13PlayerReplacedBy\
+    Bot\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x1a-\n\x0eRemat\
+    chStarted\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x1a+\n\
+    \x0cVetoProposed\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\
+    \x1aG\n\x0cVetoAnswered\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08play\
+    erId\x12\x1a\n\x08approved\x18\x02\x20\x01(\x08R\x08approved\x1a=\n\nNam\
+    eChosen\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\x08playerId\x12\x12\n\
+    \x04name\x18\x02\x20\x01(\tR\x04nameB\x07\n\x05event\"\xa9\x01\n\x06Repl\
+    ay\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\x12\x1e\n\x06winner\
+    \x18\x02\x20\x01(\x0e2\x06.PartyR\x06winner\x12\"\n\x06reason\x18\x03\
+    \x20\x01(\x0e2\n.WinReasonR\x06reason\x12!\n\x05roles\x18\x04\x20\x03(\
+    \x0b2\x0b.PlayerRoleR\x05roles\x12\"\n\x06events\x18\x05\x20\x03(\x0b2\n\
+    .GameEventR\x06eventsb\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
@@ -0,0 +1,337 @@
+// This file is generated by rust-protobuf 2.20.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `role.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_20_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PlayerRole {
+    // message fields
+    pub player_id: ::std::string::String,
+    pub role: Role,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PlayerRole {
+    fn default() -> &'a PlayerRole {
+        <PlayerRole as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PlayerRole {
+    pub fn new() -> PlayerRole {
+        ::std::default::Default::default()
+    }
+
+    // string player_id = 1;
+
+
+    pub fn get_player_id(&self) -> &str {
+        &self.player_id
+    }
+    pub fn clear_player_id(&mut self) {
+        self.player_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_id(&mut self, v: ::std::string::String) {
+        self.player_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_player_id(&mut self) -> &mut ::std::string::String {
+        &mut self.player_id
+    }
+
+    // Take field
+    pub fn take_player_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.player_id, ::std::string::String::new())
+    }
+
+    // .Role role = 2;
+
+
+    pub fn get_role(&self) -> Role {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = Role::LOYAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: Role) {
+        self.role = v;
+    }
+}
+
+impl ::protobuf::Message for PlayerRole {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.player_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 2, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.player_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.player_id);
+        }
+        if self.role != Role::LOYAL {
+            my_size += ::protobuf::rt::enum_size(2, self.role);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.player_id.is_empty() {
+            os.write_string(1, &self.player_id)?;
+        }
+        if self.role != Role::LOYAL {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PlayerRole {
+        PlayerRole::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "player_id",
+                |m: &PlayerRole| { &m.player_id },
+                |m: &mut PlayerRole| { &mut m.player_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Role>>(
+                "role",
+                |m: &PlayerRole| { &m.role },
+                |m: &mut PlayerRole| { &mut m.role },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PlayerRole>(
+                "PlayerRole",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PlayerRole {
+        static instance: ::protobuf::rt::LazyV2<PlayerRole> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PlayerRole::new)
+    }
+}
+
+impl ::protobuf::Clear for PlayerRole {
+    fn clear(&mut self) {
+        self.player_id.clear();
+        self.role = Role::LOYAL;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PlayerRole {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PlayerRole {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Role {
+    LOYAL = 0,
+    CLAN = 1,
+    CLAN_LEADER = 2,
+}
+
+impl ::protobuf::ProtobufEnum for Role {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Role> {
+        match value {
+            0 => ::std::option::Option::Some(Role::LOYAL),
+            1 => ::std::option::Option::Some(Role::CLAN),
+            2 => ::std::option::Option::Some(Role::CLAN_LEADER),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Role] = &[
+            Role::LOYAL,
+            Role::CLAN,
+            Role::CLAN_LEADER,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Role>("Role", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Role {
+}
+
+impl ::std::default::Default for Role {
+    fn default() -> Self {
+        Role::LOYAL
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Role {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum Party {
+    LOYAL_PARTY = 0,
+    CLAN_PARTY = 1,
+}
+
+impl ::protobuf::ProtobufEnum for Party {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<Party> {
+        match value {
+            0 => ::std::option::Option::Some(Party::LOYAL_PARTY),
+            1 => ::std::option::Option::Some(Party::CLAN_PARTY),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [Party] = &[
+            Party::LOYAL_PARTY,
+            Party::CLAN_PARTY,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<Party>("Party", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for Party {
+}
+
+impl ::std::default::Default for Party {
+    fn default() -> Self {
+        Party::LOYAL_PARTY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Party {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\nrole.proto\"D\n\nPlayerRole\x12\x1b\n\tplayer_id\x18\x01\x20\x01(\tR\
+    \x08playerId\x12\x19\n\x04role\x18\x02\x20\x01(\x0e2\x05.RoleR\x04role*,\
+    \n\x04Role\x12\t\n\x05LOYAL\x10\0\x12\x08\n\x04CLAN\x10\x01\x12\x0f\n\
+    \x0bCLAN_LEADER\x10\x02*(\n\x05Party\x12\x0f\n\x0bLOYAL_PARTY\x10\0\x12\
+    \x0e\n\nCLAN_PARTY\x10\x01b\x06proto3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
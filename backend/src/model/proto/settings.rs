@@ -0,0 +1,643 @@
+// This file is generated by rust-protobuf 2.20.0. Do not edit
+// @generated
+
+// https://github.com/rust-lang/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy::all)]
+
+#![allow(unused_attributes)]
+#![rustfmt::skip]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+//! Generated file from `settings.proto`
+
+/// Generated files are compatible only with the same version
+/// of protobuf runtime.
+// const _PROTOBUF_VERSION_CHECK: () = ::protobuf::VERSION_2_20_0;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RoleDistribution {
+    // message fields
+    pub player_count: u32,
+    pub clan_members_count: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RoleDistribution {
+    fn default() -> &'a RoleDistribution {
+        <RoleDistribution as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RoleDistribution {
+    pub fn new() -> RoleDistribution {
+        ::std::default::Default::default()
+    }
+
+    // uint32 player_count = 1;
+
+
+    pub fn get_player_count(&self) -> u32 {
+        self.player_count
+    }
+    pub fn clear_player_count(&mut self) {
+        self.player_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_player_count(&mut self, v: u32) {
+        self.player_count = v;
+    }
+
+    // uint32 clan_members_count = 2;
+
+
+    pub fn get_clan_members_count(&self) -> u32 {
+        self.clan_members_count
+    }
+    pub fn clear_clan_members_count(&mut self) {
+        self.clan_members_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_members_count(&mut self, v: u32) {
+        self.clan_members_count = v;
+    }
+}
+
+impl ::protobuf::Message for RoleDistribution {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.player_count = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.clan_members_count = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.player_count != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.player_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.clan_members_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.clan_members_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.player_count != 0 {
+            os.write_uint32(1, self.player_count)?;
+        }
+        if self.clan_members_count != 0 {
+            os.write_uint32(2, self.clan_members_count)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RoleDistribution {
+        RoleDistribution::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "player_count",
+                |m: &RoleDistribution| { &m.player_count },
+                |m: &mut RoleDistribution| { &mut m.player_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "clan_members_count",
+                |m: &RoleDistribution| { &m.clan_members_count },
+                |m: &mut RoleDistribution| { &mut m.clan_members_count },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RoleDistribution>(
+                "RoleDistribution",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RoleDistribution {
+        static instance: ::protobuf::rt::LazyV2<RoleDistribution> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RoleDistribution::new)
+    }
+}
+
+impl ::protobuf::Clear for RoleDistribution {
+    fn clear(&mut self) {
+        self.player_count = 0;
+        self.clan_members_count = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RoleDistribution {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RoleDistribution {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct GameSettings {
+    // message fields
+    pub role_distributions: ::protobuf::RepeatedField<RoleDistribution>,
+    pub clan_leader_knowledge: ClanLeaderKnowledge,
+    pub veto_enabled: bool,
+    pub task_timeout_seconds: u32,
+    pub chat_audience: ChatAudience,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a GameSettings {
+    fn default() -> &'a GameSettings {
+        <GameSettings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl GameSettings {
+    pub fn new() -> GameSettings {
+        ::std::default::Default::default()
+    }
+
+    // repeated .RoleDistribution role_distributions = 1;
+
+
+    pub fn get_role_distributions(&self) -> &[RoleDistribution] {
+        &self.role_distributions
+    }
+    pub fn clear_role_distributions(&mut self) {
+        self.role_distributions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role_distributions(&mut self, v: ::protobuf::RepeatedField<RoleDistribution>) {
+        self.role_distributions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_role_distributions(&mut self) -> &mut ::protobuf::RepeatedField<RoleDistribution> {
+        &mut self.role_distributions
+    }
+
+    // Take field
+    pub fn take_role_distributions(&mut self) -> ::protobuf::RepeatedField<RoleDistribution> {
+        ::std::mem::replace(&mut self.role_distributions, ::protobuf::RepeatedField::new())
+    }
+
+    // .ClanLeaderKnowledge clan_leader_knowledge = 2;
+
+
+    pub fn get_clan_leader_knowledge(&self) -> ClanLeaderKnowledge {
+        self.clan_leader_knowledge
+    }
+    pub fn clear_clan_leader_knowledge(&mut self) {
+        self.clan_leader_knowledge = ClanLeaderKnowledge::SMALL_GAMES_ONLY;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_leader_knowledge(&mut self, v: ClanLeaderKnowledge) {
+        self.clan_leader_knowledge = v;
+    }
+
+    // bool veto_enabled = 3;
+
+
+    pub fn get_veto_enabled(&self) -> bool {
+        self.veto_enabled
+    }
+    pub fn clear_veto_enabled(&mut self) {
+        self.veto_enabled = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_veto_enabled(&mut self, v: bool) {
+        self.veto_enabled = v;
+    }
+
+    // uint32 task_timeout_seconds = 4;
+
+
+    pub fn get_task_timeout_seconds(&self) -> u32 {
+        self.task_timeout_seconds
+    }
+    pub fn clear_task_timeout_seconds(&mut self) {
+        self.task_timeout_seconds = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_task_timeout_seconds(&mut self, v: u32) {
+        self.task_timeout_seconds = v;
+    }
+
+    // .ChatAudience chat_audience = 5;
+
+
+    pub fn get_chat_audience(&self) -> ChatAudience {
+        self.chat_audience
+    }
+    pub fn clear_chat_audience(&mut self) {
+        self.chat_audience = ChatAudience::LIVING_PLAYERS;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chat_audience(&mut self, v: ChatAudience) {
+        self.chat_audience = v;
+    }
+}
+
+impl ::protobuf::Message for GameSettings {
+    fn is_initialized(&self) -> bool {
+        for v in &self.role_distributions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.role_distributions)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.clan_leader_knowledge, 2, &mut self.unknown_fields)?
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.veto_enabled = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.task_timeout_seconds = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.chat_audience, 5, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.role_distributions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.clan_leader_knowledge != ClanLeaderKnowledge::SMALL_GAMES_ONLY {
+            my_size += ::protobuf::rt::enum_size(2, self.clan_leader_knowledge);
+        }
+        if self.veto_enabled != false {
+            my_size += 2;
+        }
+        if self.task_timeout_seconds != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.task_timeout_seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.chat_audience != ChatAudience::LIVING_PLAYERS {
+            my_size += ::protobuf::rt::enum_size(5, self.chat_audience);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.role_distributions {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.clan_leader_knowledge != ClanLeaderKnowledge::SMALL_GAMES_ONLY {
+            os.write_enum(2, ::protobuf::ProtobufEnum::value(&self.clan_leader_knowledge))?;
+        }
+        if self.veto_enabled != false {
+            os.write_bool(3, self.veto_enabled)?;
+        }
+        if self.task_timeout_seconds != 0 {
+            os.write_uint32(4, self.task_timeout_seconds)?;
+        }
+        if self.chat_audience != ChatAudience::LIVING_PLAYERS {
+            os.write_enum(5, ::protobuf::ProtobufEnum::value(&self.chat_audience))?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> GameSettings {
+        GameSettings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RoleDistribution>>(
+                "role_distributions",
+                |m: &GameSettings| { &m.role_distributions },
+                |m: &mut GameSettings| { &mut m.role_distributions },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ClanLeaderKnowledge>>(
+                "clan_leader_knowledge",
+                |m: &GameSettings| { &m.clan_leader_knowledge },
+                |m: &mut GameSettings| { &mut m.clan_leader_knowledge },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "veto_enabled",
+                |m: &GameSettings| { &m.veto_enabled },
+                |m: &mut GameSettings| { &mut m.veto_enabled },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "task_timeout_seconds",
+                |m: &GameSettings| { &m.task_timeout_seconds },
+                |m: &mut GameSettings| { &mut m.task_timeout_seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<ChatAudience>>(
+                "chat_audience",
+                |m: &GameSettings| { &m.chat_audience },
+                |m: &mut GameSettings| { &mut m.chat_audience },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<GameSettings>(
+                "GameSettings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static GameSettings {
+        static instance: ::protobuf::rt::LazyV2<GameSettings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(GameSettings::new)
+    }
+}
+
+impl ::protobuf::Clear for GameSettings {
+    fn clear(&mut self) {
+        self.role_distributions.clear();
+        self.clan_leader_knowledge = ClanLeaderKnowledge::SMALL_GAMES_ONLY;
+        self.veto_enabled = false;
+        self.task_timeout_seconds = 0;
+        self.chat_audience = ChatAudience::LIVING_PLAYERS;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for GameSettings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for GameSettings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ClanLeaderKnowledge {
+    SMALL_GAMES_ONLY = 0,
+    ALWAYS_KNOWN = 1,
+    NEVER_KNOWN = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ClanLeaderKnowledge {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ClanLeaderKnowledge> {
+        match value {
+            0 => ::std::option::Option::Some(ClanLeaderKnowledge::SMALL_GAMES_ONLY),
+            1 => ::std::option::Option::Some(ClanLeaderKnowledge::ALWAYS_KNOWN),
+            2 => ::std::option::Option::Some(ClanLeaderKnowledge::NEVER_KNOWN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ClanLeaderKnowledge] = &[
+            ClanLeaderKnowledge::SMALL_GAMES_ONLY,
+            ClanLeaderKnowledge::ALWAYS_KNOWN,
+            ClanLeaderKnowledge::NEVER_KNOWN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ClanLeaderKnowledge>("ClanLeaderKnowledge", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ClanLeaderKnowledge {
+}
+
+impl ::std::default::Default for ClanLeaderKnowledge {
+    fn default() -> Self {
+        ClanLeaderKnowledge::SMALL_GAMES_ONLY
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ClanLeaderKnowledge {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum ChatAudience {
+    LIVING_PLAYERS = 0,
+    ALL_PLAYERS = 1,
+    EVERYONE = 2,
+}
+
+impl ::protobuf::ProtobufEnum for ChatAudience {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<ChatAudience> {
+        match value {
+            0 => ::std::option::Option::Some(ChatAudience::LIVING_PLAYERS),
+            1 => ::std::option::Option::Some(ChatAudience::ALL_PLAYERS),
+            2 => ::std::option::Option::Some(ChatAudience::EVERYONE),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [ChatAudience] = &[
+            ChatAudience::LIVING_PLAYERS,
+            ChatAudience::ALL_PLAYERS,
+            ChatAudience::EVERYONE,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<ChatAudience>("ChatAudience", file_descriptor_proto())
+        })
+    }
+}
+
+impl ::std::marker::Copy for ChatAudience {
+}
+
+impl ::std::default::Default for ChatAudience {
+    fn default() -> Self {
+        ChatAudience::LIVING_PLAYERS
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ChatAudience {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x0esettings.proto\"c\n\x10RoleDistribution\x12!\n\x0cplayer_count\x18\
+    \x01\x20\x01(\rR\x0bplayerCount\x12,\n\x12clan_members_count\x18\x02\x20\
+    \x01(\rR\x10clanMembersCount\"\xa3\x02\n\x0cGameSettings\x12@\n\x12role_\
+    distributions\x18\x01\x20\x03(\x0b2\x11.RoleDistributionR\x11roleDistrib\
+    utions\x12H\n\x15clan_leader_knowledge\x18\x02\x20\x01(\x0e2\x14.ClanLea\
+    derKnowledgeR\x13clanLeaderKnowledge\x12!\n\x0cveto_enabled\x18\x03\x20\
+    \x01(\x08R\x0bvetoEnabled\x120\n\x14task_timeout_seconds\x18\x04\x20\x01\
+    (\rR\x12taskTimeoutSeconds\x122\n\rchat_audience\x18\x05\x20\x01(\x0e2\r\
+    .ChatAudienceR\x0cchatAudience*N\n\x13ClanLeaderKnowledge\x12\x14\n\x10S\
+    MALL_GAMES_ONLY\x10\0\x12\x10\n\x0cALWAYS_KNOWN\x10\x01\x12\x0f\n\x0bNEV\
+    ER_KNOWN\x10\x02*A\n\x0cChatAudience\x12\x12\n\x0eLIVING_PLAYERS\x10\0\
+    \x12\x0f\n\x0bALL_PLAYERS\x10\x01\x12\x0c\n\x08EVERYONE\x10\x02b\x06prot\
+    o3\
+";
+
+static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::Message::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
+}
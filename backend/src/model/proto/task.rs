@@ -25,6 +25,8 @@
 
 #[derive(PartialEq,Clone,Default)]
 pub struct Task {
+    // message fields
+    pub deadline: ::std::string::String,
     // message oneof groups
     pub definition: ::std::option::Option<Task_oneof_definition>,
     // special fields
@@ -41,6 +43,17 @@ impl<'a> ::std::default::Default for &'a Task {
 #[derive(Clone,PartialEq,Debug)]
 pub enum Task_oneof_definition {
     settings(Task_Settings),
+    discoverRole(Task_DiscoverRole),
+    nominateChancellor(Task_NominateChancellor),
+    vote(Task_Vote),
+    discardPolicy(Task_DiscardPolicy),
+    enactPolicy(Task_EnactPolicy),
+    investigateLoyalty(Task_InvestigateLoyalty),
+    callSpecialElection(Task_CallSpecialElection),
+    peekPolicies(Task_PeekPolicies),
+    executePlayer(Task_ExecutePlayer),
+    gameSettings(Task_GameSettings),
+    answerVeto(Task_AnswerVeto),
 }
 
 impl Task {
@@ -96,15 +109,2384 @@ impl Task {
             Task_Settings::new()
         }
     }
+
+    // .Task.DiscoverRole discoverRole = 2;
+
+
+    pub fn get_discoverRole(&self) -> &Task_DiscoverRole {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discoverRole(ref v)) => v,
+            _ => <Task_DiscoverRole as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_discoverRole(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_discoverRole(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discoverRole(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_discoverRole(&mut self, v: Task_DiscoverRole) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::discoverRole(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_discoverRole(&mut self) -> &mut Task_DiscoverRole {
+        if let ::std::option::Option::Some(Task_oneof_definition::discoverRole(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::discoverRole(Task_DiscoverRole::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discoverRole(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_discoverRole(&mut self) -> Task_DiscoverRole {
+        if self.has_discoverRole() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::discoverRole(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_DiscoverRole::new()
+        }
+    }
+
+    // .Task.NominateChancellor nominateChancellor = 3;
+
+
+    pub fn get_nominateChancellor(&self) -> &Task_NominateChancellor {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(ref v)) => v,
+            _ => <Task_NominateChancellor as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_nominateChancellor(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_nominateChancellor(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nominateChancellor(&mut self, v: Task_NominateChancellor) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nominateChancellor(&mut self) -> &mut Task_NominateChancellor {
+        if let ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(Task_NominateChancellor::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_nominateChancellor(&mut self) -> Task_NominateChancellor {
+        if self.has_nominateChancellor() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_NominateChancellor::new()
+        }
+    }
+
+    // .Task.Vote vote = 4;
+
+
+    pub fn get_vote(&self) -> &Task_Vote {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::vote(ref v)) => v,
+            _ => <Task_Vote as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_vote(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_vote(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::vote(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote(&mut self, v: Task_Vote) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::vote(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vote(&mut self) -> &mut Task_Vote {
+        if let ::std::option::Option::Some(Task_oneof_definition::vote(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::vote(Task_Vote::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::vote(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_vote(&mut self) -> Task_Vote {
+        if self.has_vote() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::vote(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_Vote::new()
+        }
+    }
+
+    // .Task.DiscardPolicy discardPolicy = 5;
+
+
+    pub fn get_discardPolicy(&self) -> &Task_DiscardPolicy {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discardPolicy(ref v)) => v,
+            _ => <Task_DiscardPolicy as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_discardPolicy(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_discardPolicy(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discardPolicy(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_discardPolicy(&mut self, v: Task_DiscardPolicy) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::discardPolicy(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_discardPolicy(&mut self) -> &mut Task_DiscardPolicy {
+        if let ::std::option::Option::Some(Task_oneof_definition::discardPolicy(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::discardPolicy(Task_DiscardPolicy::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::discardPolicy(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_discardPolicy(&mut self) -> Task_DiscardPolicy {
+        if self.has_discardPolicy() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::discardPolicy(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_DiscardPolicy::new()
+        }
+    }
+
+    // .Task.EnactPolicy enactPolicy = 6;
+
+
+    pub fn get_enactPolicy(&self) -> &Task_EnactPolicy {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::enactPolicy(ref v)) => v,
+            _ => <Task_EnactPolicy as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_enactPolicy(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_enactPolicy(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::enactPolicy(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enactPolicy(&mut self, v: Task_EnactPolicy) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::enactPolicy(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_enactPolicy(&mut self) -> &mut Task_EnactPolicy {
+        if let ::std::option::Option::Some(Task_oneof_definition::enactPolicy(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::enactPolicy(Task_EnactPolicy::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::enactPolicy(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_enactPolicy(&mut self) -> Task_EnactPolicy {
+        if self.has_enactPolicy() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::enactPolicy(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_EnactPolicy::new()
+        }
+    }
+
+    // .Task.InvestigateLoyalty investigateLoyalty = 7;
+
+
+    pub fn get_investigateLoyalty(&self) -> &Task_InvestigateLoyalty {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(ref v)) => v,
+            _ => <Task_InvestigateLoyalty as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_investigateLoyalty(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_investigateLoyalty(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_investigateLoyalty(&mut self, v: Task_InvestigateLoyalty) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_investigateLoyalty(&mut self) -> &mut Task_InvestigateLoyalty {
+        if let ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(Task_InvestigateLoyalty::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_investigateLoyalty(&mut self) -> Task_InvestigateLoyalty {
+        if self.has_investigateLoyalty() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_InvestigateLoyalty::new()
+        }
+    }
+
+    // .Task.CallSpecialElection callSpecialElection = 8;
+
+
+    pub fn get_callSpecialElection(&self) -> &Task_CallSpecialElection {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(ref v)) => v,
+            _ => <Task_CallSpecialElection as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_callSpecialElection(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_callSpecialElection(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_callSpecialElection(&mut self, v: Task_CallSpecialElection) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_callSpecialElection(&mut self) -> &mut Task_CallSpecialElection {
+        if let ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(Task_CallSpecialElection::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_callSpecialElection(&mut self) -> Task_CallSpecialElection {
+        if self.has_callSpecialElection() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_CallSpecialElection::new()
+        }
+    }
+
+    // .Task.PeekPolicies peekPolicies = 9;
+
+
+    pub fn get_peekPolicies(&self) -> &Task_PeekPolicies {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::peekPolicies(ref v)) => v,
+            _ => <Task_PeekPolicies as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_peekPolicies(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_peekPolicies(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::peekPolicies(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_peekPolicies(&mut self, v: Task_PeekPolicies) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::peekPolicies(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_peekPolicies(&mut self) -> &mut Task_PeekPolicies {
+        if let ::std::option::Option::Some(Task_oneof_definition::peekPolicies(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::peekPolicies(Task_PeekPolicies::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::peekPolicies(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_peekPolicies(&mut self) -> Task_PeekPolicies {
+        if self.has_peekPolicies() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::peekPolicies(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_PeekPolicies::new()
+        }
+    }
+
+    // .Task.ExecutePlayer executePlayer = 10;
+
+
+    pub fn get_executePlayer(&self) -> &Task_ExecutePlayer {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::executePlayer(ref v)) => v,
+            _ => <Task_ExecutePlayer as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_executePlayer(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_executePlayer(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::executePlayer(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executePlayer(&mut self, v: Task_ExecutePlayer) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::executePlayer(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_executePlayer(&mut self) -> &mut Task_ExecutePlayer {
+        if let ::std::option::Option::Some(Task_oneof_definition::executePlayer(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::executePlayer(Task_ExecutePlayer::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::executePlayer(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_executePlayer(&mut self) -> Task_ExecutePlayer {
+        if self.has_executePlayer() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::executePlayer(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_ExecutePlayer::new()
+        }
+    }
+
+    // .Task.GameSettings gameSettings = 11;
+
+
+    pub fn get_gameSettings(&self) -> &Task_GameSettings {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::gameSettings(ref v)) => v,
+            _ => <Task_GameSettings as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_gameSettings(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_gameSettings(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::gameSettings(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gameSettings(&mut self, v: Task_GameSettings) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::gameSettings(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_gameSettings(&mut self) -> &mut Task_GameSettings {
+        if let ::std::option::Option::Some(Task_oneof_definition::gameSettings(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::gameSettings(Task_GameSettings::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::gameSettings(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_gameSettings(&mut self) -> Task_GameSettings {
+        if self.has_gameSettings() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::gameSettings(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_GameSettings::new()
+        }
+    }
+
+    // .Task.AnswerVeto answerVeto = 13;
+
+
+    pub fn get_answerVeto(&self) -> &Task_AnswerVeto {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::answerVeto(ref v)) => v,
+            _ => <Task_AnswerVeto as ::protobuf::Message>::default_instance(),
+        }
+    }
+    pub fn clear_answerVeto(&mut self) {
+        self.definition = ::std::option::Option::None;
+    }
+
+    pub fn has_answerVeto(&self) -> bool {
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::answerVeto(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_answerVeto(&mut self, v: Task_AnswerVeto) {
+        self.definition = ::std::option::Option::Some(Task_oneof_definition::answerVeto(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_answerVeto(&mut self) -> &mut Task_AnswerVeto {
+        if let ::std::option::Option::Some(Task_oneof_definition::answerVeto(_)) = self.definition {
+        } else {
+            self.definition = ::std::option::Option::Some(Task_oneof_definition::answerVeto(Task_AnswerVeto::new()));
+        }
+        match self.definition {
+            ::std::option::Option::Some(Task_oneof_definition::answerVeto(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_answerVeto(&mut self) -> Task_AnswerVeto {
+        if self.has_answerVeto() {
+            match self.definition.take() {
+                ::std::option::Option::Some(Task_oneof_definition::answerVeto(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            Task_AnswerVeto::new()
+        }
+    }
+
+    // string deadline = 12;
+
+
+    pub fn get_deadline(&self) -> &str {
+        &self.deadline
+    }
+    pub fn clear_deadline(&mut self) {
+        self.deadline.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deadline(&mut self, v: ::std::string::String) {
+        self.deadline = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_deadline(&mut self) -> &mut ::std::string::String {
+        &mut self.deadline
+    }
+
+    // Take field
+    pub fn take_deadline(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.deadline, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Task {
+    fn is_initialized(&self) -> bool {
+        if let Some(Task_oneof_definition::settings(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::discoverRole(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::nominateChancellor(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::vote(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::discardPolicy(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::enactPolicy(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::investigateLoyalty(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::callSpecialElection(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::peekPolicies(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::executePlayer(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::gameSettings(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(Task_oneof_definition::answerVeto(ref v)) = self.definition {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::settings(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::discoverRole(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::nominateChancellor(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::vote(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::discardPolicy(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::enactPolicy(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::investigateLoyalty(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::callSpecialElection(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::peekPolicies(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::executePlayer(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::gameSettings(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.definition = ::std::option::Option::Some(Task_oneof_definition::answerVeto(is.read_message()?));
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.deadline)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.deadline.is_empty() {
+            my_size += ::protobuf::rt::string_size(12, &self.deadline);
+        }
+        if let ::std::option::Option::Some(ref v) = self.definition {
+            match v {
+                &Task_oneof_definition::settings(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::discoverRole(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::nominateChancellor(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::vote(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::discardPolicy(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::enactPolicy(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::investigateLoyalty(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::callSpecialElection(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::peekPolicies(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::executePlayer(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::gameSettings(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Task_oneof_definition::answerVeto(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.deadline.is_empty() {
+            os.write_string(12, &self.deadline)?;
+        }
+        if let ::std::option::Option::Some(ref v) = self.definition {
+            match v {
+                &Task_oneof_definition::settings(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::discoverRole(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::nominateChancellor(ref v) => {
+                    os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::vote(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::discardPolicy(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::enactPolicy(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::investigateLoyalty(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::callSpecialElection(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::peekPolicies(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::executePlayer(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::gameSettings(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &Task_oneof_definition::answerVeto(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task {
+        Task::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_Settings>(
+                "settings",
+                Task::has_settings,
+                Task::get_settings,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_DiscoverRole>(
+                "discoverRole",
+                Task::has_discoverRole,
+                Task::get_discoverRole,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_NominateChancellor>(
+                "nominateChancellor",
+                Task::has_nominateChancellor,
+                Task::get_nominateChancellor,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_Vote>(
+                "vote",
+                Task::has_vote,
+                Task::get_vote,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_DiscardPolicy>(
+                "discardPolicy",
+                Task::has_discardPolicy,
+                Task::get_discardPolicy,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_EnactPolicy>(
+                "enactPolicy",
+                Task::has_enactPolicy,
+                Task::get_enactPolicy,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_InvestigateLoyalty>(
+                "investigateLoyalty",
+                Task::has_investigateLoyalty,
+                Task::get_investigateLoyalty,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_CallSpecialElection>(
+                "callSpecialElection",
+                Task::has_callSpecialElection,
+                Task::get_callSpecialElection,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_PeekPolicies>(
+                "peekPolicies",
+                Task::has_peekPolicies,
+                Task::get_peekPolicies,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_ExecutePlayer>(
+                "executePlayer",
+                Task::has_executePlayer,
+                Task::get_executePlayer,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_GameSettings>(
+                "gameSettings",
+                Task::has_gameSettings,
+                Task::get_gameSettings,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_AnswerVeto>(
+                "answerVeto",
+                Task::has_answerVeto,
+                Task::get_answerVeto,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "deadline",
+                |m: &Task| { &m.deadline },
+                |m: &mut Task| { &mut m.deadline },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task>(
+                "Task",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task {
+        static instance: ::protobuf::rt::LazyV2<Task> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task::new)
+    }
+}
+
+impl ::protobuf::Clear for Task {
+    fn clear(&mut self) {
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.definition = ::std::option::Option::None;
+        self.deadline.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_Settings {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_Settings {
+    fn default() -> &'a Task_Settings {
+        <Task_Settings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_Settings {
+    pub fn new() -> Task_Settings {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Task_Settings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_Settings {
+        Task_Settings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_Settings>(
+                "Task.Settings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_Settings {
+        static instance: ::protobuf::rt::LazyV2<Task_Settings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_Settings::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_Settings {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_Settings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_Settings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_GameSettings {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_GameSettings {
+    fn default() -> &'a Task_GameSettings {
+        <Task_GameSettings as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_GameSettings {
+    pub fn new() -> Task_GameSettings {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Task_GameSettings {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_GameSettings {
+        Task_GameSettings::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_GameSettings>(
+                "Task.GameSettings",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_GameSettings {
+        static instance: ::protobuf::rt::LazyV2<Task_GameSettings> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_GameSettings::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_GameSettings {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_GameSettings {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_GameSettings {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_DiscoverRole {
+    // message fields
+    pub role: super::role::Role,
+    pub clan_member_ids: ::protobuf::RepeatedField<::std::string::String>,
+    pub clan_leader_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_DiscoverRole {
+    fn default() -> &'a Task_DiscoverRole {
+        <Task_DiscoverRole as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_DiscoverRole {
+    pub fn new() -> Task_DiscoverRole {
+        ::std::default::Default::default()
+    }
+
+    // .Role role = 1;
+
+
+    pub fn get_role(&self) -> super::role::Role {
+        self.role
+    }
+    pub fn clear_role(&mut self) {
+        self.role = super::role::Role::LOYAL;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_role(&mut self, v: super::role::Role) {
+        self.role = v;
+    }
+
+    // repeated string clan_member_ids = 2;
+
+
+    pub fn get_clan_member_ids(&self) -> &[::std::string::String] {
+        &self.clan_member_ids
+    }
+    pub fn clear_clan_member_ids(&mut self) {
+        self.clan_member_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_member_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.clan_member_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_clan_member_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.clan_member_ids
+    }
+
+    // Take field
+    pub fn take_clan_member_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.clan_member_ids, ::protobuf::RepeatedField::new())
+    }
+
+    // string clan_leader_id = 3;
+
+
+    pub fn get_clan_leader_id(&self) -> &str {
+        &self.clan_leader_id
+    }
+    pub fn clear_clan_leader_id(&mut self) {
+        self.clan_leader_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_clan_leader_id(&mut self, v: ::std::string::String) {
+        self.clan_leader_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_clan_leader_id(&mut self) -> &mut ::std::string::String {
+        &mut self.clan_leader_id
+    }
+
+    // Take field
+    pub fn take_clan_leader_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.clan_leader_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Task_DiscoverRole {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.role, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.clan_member_ids)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.clan_leader_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.role != super::role::Role::LOYAL {
+            my_size += ::protobuf::rt::enum_size(1, self.role);
+        }
+        for value in &self.clan_member_ids {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        if !self.clan_leader_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.clan_leader_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.role != super::role::Role::LOYAL {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.role))?;
+        }
+        for v in &self.clan_member_ids {
+            os.write_string(2, &v)?;
+        };
+        if !self.clan_leader_id.is_empty() {
+            os.write_string(3, &self.clan_leader_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_DiscoverRole {
+        Task_DiscoverRole::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::role::Role>>(
+                "role",
+                |m: &Task_DiscoverRole| { &m.role },
+                |m: &mut Task_DiscoverRole| { &mut m.role },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clan_member_ids",
+                |m: &Task_DiscoverRole| { &m.clan_member_ids },
+                |m: &mut Task_DiscoverRole| { &mut m.clan_member_ids },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "clan_leader_id",
+                |m: &Task_DiscoverRole| { &m.clan_leader_id },
+                |m: &mut Task_DiscoverRole| { &mut m.clan_leader_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_DiscoverRole>(
+                "Task.DiscoverRole",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_DiscoverRole {
+        static instance: ::protobuf::rt::LazyV2<Task_DiscoverRole> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_DiscoverRole::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_DiscoverRole {
+    fn clear(&mut self) {
+        self.role = super::role::Role::LOYAL;
+        self.clan_member_ids.clear();
+        self.clan_leader_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_DiscoverRole {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_DiscoverRole {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_NominateChancellor {
+    // message fields
+    pub eligible_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_NominateChancellor {
+    fn default() -> &'a Task_NominateChancellor {
+        <Task_NominateChancellor as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_NominateChancellor {
+    pub fn new() -> Task_NominateChancellor {
+        ::std::default::Default::default()
+    }
+
+    // repeated string eligible_player_ids = 1;
+
+
+    pub fn get_eligible_player_ids(&self) -> &[::std::string::String] {
+        &self.eligible_player_ids
+    }
+    pub fn clear_eligible_player_ids(&mut self) {
+        self.eligible_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eligible_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.eligible_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eligible_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.eligible_player_ids
+    }
+
+    // Take field
+    pub fn take_eligible_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.eligible_player_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Task_NominateChancellor {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.eligible_player_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.eligible_player_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.eligible_player_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_NominateChancellor {
+        Task_NominateChancellor::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "eligible_player_ids",
+                |m: &Task_NominateChancellor| { &m.eligible_player_ids },
+                |m: &mut Task_NominateChancellor| { &mut m.eligible_player_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_NominateChancellor>(
+                "Task.NominateChancellor",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_NominateChancellor {
+        static instance: ::protobuf::rt::LazyV2<Task_NominateChancellor> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_NominateChancellor::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_NominateChancellor {
+    fn clear(&mut self) {
+        self.eligible_player_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_NominateChancellor {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_NominateChancellor {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_Vote {
+    // message fields
+    pub president_id: ::std::string::String,
+    pub chancellor_id: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_Vote {
+    fn default() -> &'a Task_Vote {
+        <Task_Vote as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_Vote {
+    pub fn new() -> Task_Vote {
+        ::std::default::Default::default()
+    }
+
+    // string president_id = 1;
+
+
+    pub fn get_president_id(&self) -> &str {
+        &self.president_id
+    }
+    pub fn clear_president_id(&mut self) {
+        self.president_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_president_id(&mut self, v: ::std::string::String) {
+        self.president_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_president_id(&mut self) -> &mut ::std::string::String {
+        &mut self.president_id
+    }
+
+    // Take field
+    pub fn take_president_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.president_id, ::std::string::String::new())
+    }
+
+    // string chancellor_id = 2;
+
+
+    pub fn get_chancellor_id(&self) -> &str {
+        &self.chancellor_id
+    }
+    pub fn clear_chancellor_id(&mut self) {
+        self.chancellor_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_chancellor_id(&mut self, v: ::std::string::String) {
+        self.chancellor_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_chancellor_id(&mut self) -> &mut ::std::string::String {
+        &mut self.chancellor_id
+    }
+
+    // Take field
+    pub fn take_chancellor_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.chancellor_id, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for Task_Vote {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.president_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.chancellor_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.president_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.president_id);
+        }
+        if !self.chancellor_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.chancellor_id);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.president_id.is_empty() {
+            os.write_string(1, &self.president_id)?;
+        }
+        if !self.chancellor_id.is_empty() {
+            os.write_string(2, &self.chancellor_id)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_Vote {
+        Task_Vote::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "president_id",
+                |m: &Task_Vote| { &m.president_id },
+                |m: &mut Task_Vote| { &mut m.president_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "chancellor_id",
+                |m: &Task_Vote| { &m.chancellor_id },
+                |m: &mut Task_Vote| { &mut m.chancellor_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_Vote>(
+                "Task.Vote",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_Vote {
+        static instance: ::protobuf::rt::LazyV2<Task_Vote> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_Vote::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_Vote {
+    fn clear(&mut self) {
+        self.president_id.clear();
+        self.chancellor_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_Vote {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_Vote {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_DiscardPolicy {
+    // message fields
+    pub policies: ::std::vec::Vec<super::policy::Policy>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_DiscardPolicy {
+    fn default() -> &'a Task_DiscardPolicy {
+        <Task_DiscardPolicy as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_DiscardPolicy {
+    pub fn new() -> Task_DiscardPolicy {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Policy policies = 1;
+
+
+    pub fn get_policies(&self) -> &[super::policy::Policy] {
+        &self.policies
+    }
+    pub fn clear_policies(&mut self) {
+        self.policies.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policies(&mut self, v: ::std::vec::Vec<super::policy::Policy>) {
+        self.policies = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policies(&mut self) -> &mut ::std::vec::Vec<super::policy::Policy> {
+        &mut self.policies
+    }
+
+    // Take field
+    pub fn take_policies(&mut self) -> ::std::vec::Vec<super::policy::Policy> {
+        ::std::mem::replace(&mut self.policies, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for Task_DiscardPolicy {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_enum_with_unknown_fields_into(wire_type, is, &mut self.policies, 1, &mut self.unknown_fields)?
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.policies {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.policies {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(v))?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_DiscardPolicy {
+        Task_DiscardPolicy::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policies",
+                |m: &Task_DiscardPolicy| { &m.policies },
+                |m: &mut Task_DiscardPolicy| { &mut m.policies },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_DiscardPolicy>(
+                "Task.DiscardPolicy",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_DiscardPolicy {
+        static instance: ::protobuf::rt::LazyV2<Task_DiscardPolicy> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_DiscardPolicy::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_DiscardPolicy {
+    fn clear(&mut self) {
+        self.policies.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_DiscardPolicy {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_DiscardPolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_EnactPolicy {
+    // message fields
+    pub policies: ::std::vec::Vec<super::policy::Policy>,
+    pub veto_allowed: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_EnactPolicy {
+    fn default() -> &'a Task_EnactPolicy {
+        <Task_EnactPolicy as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_EnactPolicy {
+    pub fn new() -> Task_EnactPolicy {
+        ::std::default::Default::default()
+    }
+
+    // repeated .Policy policies = 1;
+
+
+    pub fn get_policies(&self) -> &[super::policy::Policy] {
+        &self.policies
+    }
+    pub fn clear_policies(&mut self) {
+        self.policies.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_policies(&mut self, v: ::std::vec::Vec<super::policy::Policy>) {
+        self.policies = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_policies(&mut self) -> &mut ::std::vec::Vec<super::policy::Policy> {
+        &mut self.policies
+    }
+
+    // Take field
+    pub fn take_policies(&mut self) -> ::std::vec::Vec<super::policy::Policy> {
+        ::std::mem::replace(&mut self.policies, ::std::vec::Vec::new())
+    }
+
+    // bool veto_allowed = 2;
+
+
+    pub fn get_veto_allowed(&self) -> bool {
+        self.veto_allowed
+    }
+    pub fn clear_veto_allowed(&mut self) {
+        self.veto_allowed = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_veto_allowed(&mut self, v: bool) {
+        self.veto_allowed = v;
+    }
 }
 
-impl ::protobuf::Message for Task {
+impl ::protobuf::Message for Task_EnactPolicy {
     fn is_initialized(&self) -> bool {
-        if let Some(Task_oneof_definition::settings(ref v)) = self.definition {
-            if !v.is_initialized() {
-                return false;
-            }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_enum_with_unknown_fields_into(wire_type, is, &mut self.policies, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.veto_allowed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.policies {
+            my_size += ::protobuf::rt::enum_size(1, *value);
+        };
+        if self.veto_allowed != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.policies {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(v))?;
+        };
+        if self.veto_allowed != false {
+            os.write_bool(2, self.veto_allowed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_EnactPolicy {
+        Task_EnactPolicy::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::policy::Policy>>(
+                "policies",
+                |m: &Task_EnactPolicy| { &m.policies },
+                |m: &mut Task_EnactPolicy| { &mut m.policies },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "veto_allowed",
+                |m: &Task_EnactPolicy| { &m.veto_allowed },
+                |m: &mut Task_EnactPolicy| { &mut m.veto_allowed },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_EnactPolicy>(
+                "Task.EnactPolicy",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_EnactPolicy {
+        static instance: ::protobuf::rt::LazyV2<Task_EnactPolicy> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_EnactPolicy::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_EnactPolicy {
+    fn clear(&mut self) {
+        self.policies.clear();
+        self.veto_allowed = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_EnactPolicy {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_EnactPolicy {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_InvestigateLoyalty {
+    // message fields
+    pub eligible_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_InvestigateLoyalty {
+    fn default() -> &'a Task_InvestigateLoyalty {
+        <Task_InvestigateLoyalty as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_InvestigateLoyalty {
+    pub fn new() -> Task_InvestigateLoyalty {
+        ::std::default::Default::default()
+    }
+
+    // repeated string eligible_player_ids = 1;
+
+
+    pub fn get_eligible_player_ids(&self) -> &[::std::string::String] {
+        &self.eligible_player_ids
+    }
+    pub fn clear_eligible_player_ids(&mut self) {
+        self.eligible_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eligible_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.eligible_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eligible_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.eligible_player_ids
+    }
+
+    // Take field
+    pub fn take_eligible_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.eligible_player_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Task_InvestigateLoyalty {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.eligible_player_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.eligible_player_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.eligible_player_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_InvestigateLoyalty {
+        Task_InvestigateLoyalty::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "eligible_player_ids",
+                |m: &Task_InvestigateLoyalty| { &m.eligible_player_ids },
+                |m: &mut Task_InvestigateLoyalty| { &mut m.eligible_player_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_InvestigateLoyalty>(
+                "Task.InvestigateLoyalty",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_InvestigateLoyalty {
+        static instance: ::protobuf::rt::LazyV2<Task_InvestigateLoyalty> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_InvestigateLoyalty::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_InvestigateLoyalty {
+    fn clear(&mut self) {
+        self.eligible_player_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_InvestigateLoyalty {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_InvestigateLoyalty {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_CallSpecialElection {
+    // message fields
+    pub eligible_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_CallSpecialElection {
+    fn default() -> &'a Task_CallSpecialElection {
+        <Task_CallSpecialElection as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_CallSpecialElection {
+    pub fn new() -> Task_CallSpecialElection {
+        ::std::default::Default::default()
+    }
+
+    // repeated string eligible_player_ids = 1;
+
+
+    pub fn get_eligible_player_ids(&self) -> &[::std::string::String] {
+        &self.eligible_player_ids
+    }
+    pub fn clear_eligible_player_ids(&mut self) {
+        self.eligible_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eligible_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.eligible_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eligible_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.eligible_player_ids
+    }
+
+    // Take field
+    pub fn take_eligible_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.eligible_player_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Task_CallSpecialElection {
+    fn is_initialized(&self) -> bool {
         true
     }
 
@@ -113,10 +2495,7 @@ impl ::protobuf::Message for Task {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.definition = ::std::option::Option::Some(Task_oneof_definition::settings(is.read_message()?));
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.eligible_player_ids)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -130,29 +2509,292 @@ impl ::protobuf::Message for Task {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.definition {
-            match v {
-                &Task_oneof_definition::settings(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in &self.eligible_player_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.eligible_player_ids {
+            os.write_string(1, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_CallSpecialElection {
+        Task_CallSpecialElection::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "eligible_player_ids",
+                |m: &Task_CallSpecialElection| { &m.eligible_player_ids },
+                |m: &mut Task_CallSpecialElection| { &mut m.eligible_player_ids },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_CallSpecialElection>(
+                "Task.CallSpecialElection",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_CallSpecialElection {
+        static instance: ::protobuf::rt::LazyV2<Task_CallSpecialElection> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_CallSpecialElection::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_CallSpecialElection {
+    fn clear(&mut self) {
+        self.eligible_player_ids.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_CallSpecialElection {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_CallSpecialElection {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_PeekPolicies {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_PeekPolicies {
+    fn default() -> &'a Task_PeekPolicies {
+        <Task_PeekPolicies as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_PeekPolicies {
+    pub fn new() -> Task_PeekPolicies {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for Task_PeekPolicies {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.definition {
-            match v {
-                &Task_oneof_definition::settings(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Task_PeekPolicies {
+        Task_PeekPolicies::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let fields = ::std::vec::Vec::new();
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_PeekPolicies>(
+                "Task.PeekPolicies",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Task_PeekPolicies {
+        static instance: ::protobuf::rt::LazyV2<Task_PeekPolicies> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_PeekPolicies::new)
+    }
+}
+
+impl ::protobuf::Clear for Task_PeekPolicies {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Task_PeekPolicies {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Task_PeekPolicies {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Task_ExecutePlayer {
+    // message fields
+    pub eligible_player_ids: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a Task_ExecutePlayer {
+    fn default() -> &'a Task_ExecutePlayer {
+        <Task_ExecutePlayer as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl Task_ExecutePlayer {
+    pub fn new() -> Task_ExecutePlayer {
+        ::std::default::Default::default()
+    }
+
+    // repeated string eligible_player_ids = 1;
+
+
+    pub fn get_eligible_player_ids(&self) -> &[::std::string::String] {
+        &self.eligible_player_ids
+    }
+    pub fn clear_eligible_player_ids(&mut self) {
+        self.eligible_player_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_eligible_player_ids(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.eligible_player_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_eligible_player_ids(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.eligible_player_ids
+    }
+
+    // Take field
+    pub fn take_eligible_player_ids(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.eligible_player_ids, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for Task_ExecutePlayer {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.eligible_player_ids)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.eligible_player_ids {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.eligible_player_ids {
+            os.write_string(1, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -183,72 +2825,72 @@ impl ::protobuf::Message for Task {
         Self::descriptor_static()
     }
 
-    fn new() -> Task {
-        Task::new()
+    fn new() -> Task_ExecutePlayer {
+        Task_ExecutePlayer::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Task_Settings>(
-                "settings",
-                Task::has_settings,
-                Task::get_settings,
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "eligible_player_ids",
+                |m: &Task_ExecutePlayer| { &m.eligible_player_ids },
+                |m: &mut Task_ExecutePlayer| { &mut m.eligible_player_ids },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task>(
-                "Task",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_ExecutePlayer>(
+                "Task.ExecutePlayer",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Task {
-        static instance: ::protobuf::rt::LazyV2<Task> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Task::new)
+    fn default_instance() -> &'static Task_ExecutePlayer {
+        static instance: ::protobuf::rt::LazyV2<Task_ExecutePlayer> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_ExecutePlayer::new)
     }
 }
 
-impl ::protobuf::Clear for Task {
+impl ::protobuf::Clear for Task_ExecutePlayer {
     fn clear(&mut self) {
-        self.definition = ::std::option::Option::None;
+        self.eligible_player_ids.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Task {
+impl ::std::fmt::Debug for Task_ExecutePlayer {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Task {
+impl ::protobuf::reflect::ProtobufValue for Task_ExecutePlayer {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Task_Settings {
+pub struct Task_AnswerVeto {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Task_Settings {
-    fn default() -> &'a Task_Settings {
-        <Task_Settings as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Task_AnswerVeto {
+    fn default() -> &'a Task_AnswerVeto {
+        <Task_AnswerVeto as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Task_Settings {
-    pub fn new() -> Task_Settings {
+impl Task_AnswerVeto {
+    pub fn new() -> Task_AnswerVeto {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for Task_Settings {
+impl ::protobuf::Message for Task_AnswerVeto {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -305,50 +2947,79 @@ impl ::protobuf::Message for Task_Settings {
         Self::descriptor_static()
     }
 
-    fn new() -> Task_Settings {
-        Task_Settings::new()
+    fn new() -> Task_AnswerVeto {
+        Task_AnswerVeto::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let fields = ::std::vec::Vec::new();
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_Settings>(
-                "Task.Settings",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Task_AnswerVeto>(
+                "Task.AnswerVeto",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static Task_Settings {
-        static instance: ::protobuf::rt::LazyV2<Task_Settings> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(Task_Settings::new)
+    fn default_instance() -> &'static Task_AnswerVeto {
+        static instance: ::protobuf::rt::LazyV2<Task_AnswerVeto> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Task_AnswerVeto::new)
     }
 }
 
-impl ::protobuf::Clear for Task_Settings {
+impl ::protobuf::Clear for Task_AnswerVeto {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Task_Settings {
+impl ::std::fmt::Debug for Task_AnswerVeto {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Task_Settings {
+impl ::protobuf::reflect::ProtobufValue for Task_AnswerVeto {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\ntask.proto\"N\n\x04Task\x12,\n\x08settings\x18\x01\x20\x01(\x0b2\x0e\
-    .Task.SettingsH\0R\x08settings\x1a\n\n\x08SettingsB\x0c\n\ndefinitionb\
-    \x06proto3\
+    \n\ntask.proto\x1a\x0cpolicy.proto\x1a\nrole.proto\"\x9e\x0b\n\x04Task\
+    \x12,\n\x08settings\x18\x01\x20\x01(\x0b2\x0e.Task.SettingsH\0R\x08setti\
+    ngs\x128\n\x0cdiscoverRole\x18\x02\x20\x01(\x0b2\x12.Task.DiscoverRoleH\
+    \0R\x0cdiscoverRole\x12J\n\x12nominateChancellor\x18\x03\x20\x01(\x0b2\
+    \x18.Task.NominateChancellorH\0R\x12nominateChancellor\x12\x20\n\x04vote\
+    \x18\x04\x20\x01(\x0b2\n.Task.VoteH\0R\x04vote\x12;\n\rdiscardPolicy\x18\
+    \x05\x20\x01(\x0b2\x13.Task.DiscardPolicyH\0R\rdiscardPolicy\x125\n\x0be\
+    nactPolicy\x18\x06\x20\x01(\x0b2\x11.Task.EnactPolicyH\0R\x0benactPolicy\
+    \x12J\n\x12investigateLoyalty\x18\x07\x20\x01(\x0b2\x18.Task.Investigate\
+    LoyaltyH\0R\x12investigateLoyalty\x12M\n\x13callSpecialElection\x18\x08\
+    \x20\x01(\x0b2\x19.Task.CallSpecialElectionH\0R\x13callSpecialElection\
+    \x128\n\x0cpeekPolicies\x18\t\x20\x01(\x0b2\x12.Task.PeekPoliciesH\0R\
+    \x0cpeekPolicies\x12;\n\rexecutePlayer\x18\n\x20\x01(\x0b2\x13.Task.Exec\
+    utePlayerH\0R\rexecutePlayer\x128\n\x0cgameSettings\x18\x0b\x20\x01(\x0b\
+    2\x12.Task.GameSettingsH\0R\x0cgameSettings\x122\n\nanswerVeto\x18\r\x20\
+    \x01(\x0b2\x10.Task.AnswerVetoH\0R\nanswerVeto\x12\x1a\n\x08deadline\x18\
+    \x0c\x20\x01(\tR\x08deadline\x1a\n\n\x08Settings\x1a\x0e\n\x0cGameSettin\
+    gs\x1aw\n\x0cDiscoverRole\x12\x19\n\x04role\x18\x01\x20\x01(\x0e2\x05.Ro\
+    leR\x04role\x12&\n\x0fclan_member_ids\x18\x02\x20\x03(\tR\rclanMemberIds\
+    \x12$\n\x0eclan_leader_id\x18\x03\x20\x01(\tR\x0cclanLeaderId\x1aD\n\x12\
+    NominateChancellor\x12.\n\x13eligible_player_ids\x18\x01\x20\x03(\tR\x11\
+    eligiblePlayerIds\x1aN\n\x04Vote\x12!\n\x0cpresident_id\x18\x01\x20\x01(\
+    \tR\x0bpresidentId\x12#\n\rchancellor_id\x18\x02\x20\x01(\tR\x0cchancell\
+    orId\x1a4\n\rDiscardPolicy\x12#\n\x08policies\x18\x01\x20\x03(\x0e2\x07.\
+    PolicyR\x08policies\x1aU\n\x0bEnactPolicy\x12#\n\x08policies\x18\x01\x20\
+    \x03(\x0e2\x07.PolicyR\x08policies\x12!\n\x0cveto_allowed\x18\x02\x20\
+    \x01(\x08R\x0bvetoAllowed\x1aD\n\x12InvestigateLoyalty\x12.\n\x13eligibl\
+    e_player_ids\x18\x01\x20\x03(\tR\x11eligiblePlayerIds\x1aE\n\x13CallSpec\
+    ialElection\x12.\n\x13eligible_player_ids\x18\x01\x20\x03(\tR\x11eligibl\
+    ePlayerIds\x1a\x0e\n\x0cPeekPolicies\x1a?\n\rExecutePlayer\x12.\n\x13eli\
+    gible_player_ids\x18\x01\x20\x03(\tR\x11eligiblePlayerIds\x1a\x0c\n\nAns\
+    werVetoB\x0c\n\ndefinitionb\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
@@ -0,0 +1,99 @@
+use super::proto::{self};
+use rand::seq::SliceRandom;
+use rand_pcg::Pcg64;
+use serde::{Deserialize, Serialize};
+
+/// The secret identity of a player which is dealt at the start of a game.
+#[derive(Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Role {
+    Loyal,
+    Clan,
+    ClanLeader,
+}
+
+/// The party a role belongs to, which is revealed on investigations and decides about the winner.
+#[derive(Serialize, Deserialize, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Party {
+    Loyal,
+    Clan,
+}
+
+impl Role {
+    pub fn is_clan(&self) -> bool {
+        match self {
+            Role::Loyal => false,
+            Role::Clan | Role::ClanLeader => true,
+        }
+    }
+
+    pub fn party(&self) -> Party {
+        if self.is_clan() {
+            Party::Clan
+        } else {
+            Party::Loyal
+        }
+    }
+}
+
+/// Returns the amount of clan members (without the clan leader) for the given player count.
+/// The official table covers five to ten players, smaller and bigger games are interpolated.
+pub fn clan_members_count(player_count: usize) -> usize {
+    match player_count {
+        5 | 6 => 1,
+        7 | 8 => 2,
+        9 | 10 => 3,
+        _ => (player_count.saturating_sub(1) / 2).saturating_sub(1),
+    }
+}
+
+/// The clan leader only knows the other clan members in small games.
+pub fn clan_leader_knows_members(player_count: usize) -> bool {
+    player_count <= 6
+}
+
+/// Deals the roles for the given amount of players in random order.
+/// There is always exactly one clan leader, the remaining roles follow the distribution of the game settings.
+pub fn deal_roles(player_count: usize, clan_members: usize, rng: &mut Pcg64) -> Vec<Role> {
+    if player_count == 0 {
+        return vec![];
+    }
+
+    let clan_members = clan_members.min(player_count - 1);
+    let mut roles = vec![Role::ClanLeader];
+    roles.extend(vec![Role::Clan; clan_members]);
+    roles.extend(vec![Role::Loyal; player_count - clan_members - 1]);
+    roles.shuffle(rng);
+
+    roles
+}
+
+impl From<Role> for proto::role::Role {
+    fn from(role: Role) -> Self {
+        match role {
+            Role::Loyal => proto::role::Role::LOYAL,
+            Role::Clan => proto::role::Role::CLAN,
+            Role::ClanLeader => proto::role::Role::CLAN_LEADER,
+        }
+    }
+}
+
+impl From<Party> for proto::role::Party {
+    fn from(party: Party) -> Self {
+        match party {
+            Party::Loyal => proto::role::Party::LOYAL_PARTY,
+            Party::Clan => proto::role::Party::CLAN_PARTY,
+        }
+    }
+}
+
+impl From<proto::role::Role> for Role {
+    fn from(role: proto::role::Role) -> Self {
+        match role {
+            proto::role::Role::LOYAL => Role::Loyal,
+            proto::role::Role::CLAN => Role::Clan,
+            proto::role::Role::CLAN_LEADER => Role::ClanLeader,
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use super::{
+    proto::{self},
+    role,
+};
+use protobuf::RepeatedField;
+use serde::{Deserialize, Serialize};
+
+const MIN_TASK_TIMEOUT_SECONDS: u32 = 10;
+const MAX_TASK_TIMEOUT_SECONDS: u32 = 600;
+const OFFICIAL_PLAYER_COUNTS: [usize; 6] = [5, 6, 7, 8, 9, 10];
+
+/// Overrides the amount of clan members (without the clan leader) for a specific player count.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RoleDistribution {
+    pub player_count: usize,
+    pub clan_members_count: usize,
+}
+
+/// Decides whether the clan leader gets to know the other clan members at the start of the game.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ClanLeaderKnowledge {
+    SmallGamesOnly,
+    AlwaysKnown,
+    NeverKnown,
+}
+
+/// Decides who takes part in the chat, dead players and spectators are excluded by default.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ChatAudience {
+    LivingPlayers,
+    AllPlayers,
+    Everyone,
+}
+
+/// The rule variants of a game, which are chosen by the admin in the lobby.
+/// The defaults follow the official rules.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GameSettings {
+    role_distributions: Vec<RoleDistribution>,
+    clan_leader_knowledge: ClanLeaderKnowledge,
+    veto_enabled: bool,
+    task_timeout_seconds: Option<u32>,
+    chat_audience: ChatAudience,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        GameSettings {
+            role_distributions: OFFICIAL_PLAYER_COUNTS
+                .iter()
+                .map(|player_count| RoleDistribution {
+                    player_count: *player_count,
+                    clan_members_count: role::clan_members_count(*player_count),
+                })
+                .collect(),
+            clan_leader_knowledge: ClanLeaderKnowledge::SmallGamesOnly,
+            veto_enabled: true,
+            task_timeout_seconds: None,
+            chat_audience: ChatAudience::LivingPlayers,
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn new(
+        role_distributions: Vec<RoleDistribution>,
+        clan_leader_knowledge: ClanLeaderKnowledge,
+        veto_enabled: bool,
+        task_timeout_seconds: Option<u32>,
+        chat_audience: ChatAudience,
+    ) -> Self {
+        GameSettings {
+            role_distributions,
+            clan_leader_knowledge,
+            veto_enabled,
+            task_timeout_seconds,
+            chat_audience,
+        }
+    }
+
+    pub fn role_distributions(&self) -> &[RoleDistribution] {
+        &self.role_distributions
+    }
+
+    pub fn clan_leader_knowledge(&self) -> ClanLeaderKnowledge {
+        self.clan_leader_knowledge
+    }
+
+    pub fn veto_enabled(&self) -> bool {
+        self.veto_enabled
+    }
+
+    /// Returns the time each player has to resolve a task, no timers are used without it.
+    pub fn task_timeout_seconds(&self) -> Option<u32> {
+        self.task_timeout_seconds
+    }
+
+    pub fn chat_audience(&self) -> ChatAudience {
+        self.chat_audience
+    }
+
+    /// Returns whether dead players can still read and write chat messages.
+    pub fn dead_players_chat(&self) -> bool {
+        self.chat_audience != ChatAudience::LivingPlayers
+    }
+
+    /// Returns whether spectators can read chat messages, they are never allowed to write any.
+    pub fn spectators_chat(&self) -> bool {
+        self.chat_audience == ChatAudience::Everyone
+    }
+
+    /// Returns the amount of clan members (without the clan leader) for the given player count.
+    /// Player counts without a distribution fall back to the official table.
+    pub fn clan_members_count(&self, player_count: usize) -> usize {
+        self.role_distributions
+            .iter()
+            .find(|distribution| distribution.player_count == player_count)
+            .map(|distribution| distribution.clan_members_count)
+            .unwrap_or_else(|| role::clan_members_count(player_count))
+    }
+
+    pub fn clan_leader_knows_members(&self, player_count: usize) -> bool {
+        match self.clan_leader_knowledge {
+            ClanLeaderKnowledge::SmallGamesOnly => role::clan_leader_knows_members(player_count),
+            ClanLeaderKnowledge::AlwaysKnown => true,
+            ClanLeaderKnowledge::NeverKnown => false,
+        }
+    }
+
+    /// Checks that the settings are playable at all, independent of the current player count.
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, distribution) in self.role_distributions.iter().enumerate() {
+            if self.role_distributions[..index]
+                .iter()
+                .any(|other| other.player_count == distribution.player_count)
+            {
+                return Err(format!(
+                    "Role distribution for {} players is defined twice",
+                    distribution.player_count
+                ));
+            }
+            validate_distribution(distribution.player_count, distribution.clan_members_count)?;
+        }
+
+        match self.task_timeout_seconds {
+            Some(seconds)
+                if !(MIN_TASK_TIMEOUT_SECONDS..=MAX_TASK_TIMEOUT_SECONDS).contains(&seconds) =>
+            {
+                Err(format!(
+                    "Task timeout must be between {} and {} seconds",
+                    MIN_TASK_TIMEOUT_SECONDS, MAX_TASK_TIMEOUT_SECONDS
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the settings are playable with the given amount of players.
+    pub fn validate_for(&self, player_count: usize) -> Result<(), String> {
+        self.validate()?;
+        validate_distribution(player_count, self.clan_members_count(player_count))
+    }
+}
+
+/// The clan including its leader must always be a minority, only a clan leader without members is allowed in tiny games.
+fn validate_distribution(player_count: usize, clan_members_count: usize) -> Result<(), String> {
+    if clan_members_count > 0 && (clan_members_count + 1) * 2 >= player_count {
+        return Err(format!(
+            "{} clan members are too many for {} players",
+            clan_members_count, player_count
+        ));
+    }
+    Ok(())
+}
+
+impl From<ClanLeaderKnowledge> for proto::settings::ClanLeaderKnowledge {
+    fn from(knowledge: ClanLeaderKnowledge) -> Self {
+        match knowledge {
+            ClanLeaderKnowledge::SmallGamesOnly => {
+                proto::settings::ClanLeaderKnowledge::SMALL_GAMES_ONLY
+            }
+            ClanLeaderKnowledge::AlwaysKnown => proto::settings::ClanLeaderKnowledge::ALWAYS_KNOWN,
+            ClanLeaderKnowledge::NeverKnown => proto::settings::ClanLeaderKnowledge::NEVER_KNOWN,
+        }
+    }
+}
+
+impl From<proto::settings::ClanLeaderKnowledge> for ClanLeaderKnowledge {
+    fn from(knowledge: proto::settings::ClanLeaderKnowledge) -> Self {
+        match knowledge {
+            proto::settings::ClanLeaderKnowledge::SMALL_GAMES_ONLY => {
+                ClanLeaderKnowledge::SmallGamesOnly
+            }
+            proto::settings::ClanLeaderKnowledge::ALWAYS_KNOWN => ClanLeaderKnowledge::AlwaysKnown,
+            proto::settings::ClanLeaderKnowledge::NEVER_KNOWN => ClanLeaderKnowledge::NeverKnown,
+        }
+    }
+}
+
+impl From<ChatAudience> for proto::settings::ChatAudience {
+    fn from(audience: ChatAudience) -> Self {
+        match audience {
+            ChatAudience::LivingPlayers => proto::settings::ChatAudience::LIVING_PLAYERS,
+            ChatAudience::AllPlayers => proto::settings::ChatAudience::ALL_PLAYERS,
+            ChatAudience::Everyone => proto::settings::ChatAudience::EVERYONE,
+        }
+    }
+}
+
+impl From<proto::settings::ChatAudience> for ChatAudience {
+    fn from(audience: proto::settings::ChatAudience) -> Self {
+        match audience {
+            proto::settings::ChatAudience::LIVING_PLAYERS => ChatAudience::LivingPlayers,
+            proto::settings::ChatAudience::ALL_PLAYERS => ChatAudience::AllPlayers,
+            proto::settings::ChatAudience::EVERYONE => ChatAudience::Everyone,
+        }
+    }
+}
+
+impl From<GameSettings> for proto::settings::GameSettings {
+    fn from(settings: GameSettings) -> Self {
+        let mut proto_settings = proto::settings::GameSettings::new();
+        proto_settings.set_role_distributions(RepeatedField::from_vec(
+            settings
+                .role_distributions
+                .iter()
+                .map(|distribution| {
+                    let mut proto_distribution = proto::settings::RoleDistribution::new();
+                    proto_distribution.set_player_count(distribution.player_count as u32);
+                    proto_distribution
+                        .set_clan_members_count(distribution.clan_members_count as u32);
+                    proto_distribution
+                })
+                .collect(),
+        ));
+        proto_settings.set_clan_leader_knowledge(settings.clan_leader_knowledge.into());
+        proto_settings.set_veto_enabled(settings.veto_enabled);
+        proto_settings.set_task_timeout_seconds(settings.task_timeout_seconds.unwrap_or(0));
+        proto_settings.set_chat_audience(settings.chat_audience.into());
+        proto_settings
+    }
+}
+
+impl From<proto::settings::GameSettings> for GameSettings {
+    fn from(settings: proto::settings::GameSettings) -> Self {
+        GameSettings {
+            role_distributions: settings
+                .get_role_distributions()
+                .iter()
+                .map(|distribution| RoleDistribution {
+                    player_count: distribution.get_player_count() as usize,
+                    clan_members_count: distribution.get_clan_members_count() as usize,
+                })
+                .collect(),
+            clan_leader_knowledge: settings.get_clan_leader_knowledge().into(),
+            veto_enabled: settings.get_veto_enabled(),
+            task_timeout_seconds: Some(settings.get_task_timeout_seconds())
+                .filter(|seconds| *seconds > 0),
+            chat_audience: settings.get_chat_audience().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChatAudience, ClanLeaderKnowledge, GameSettings, RoleDistribution};
+
+    #[test]
+    fn should_follow_official_rules_by_default() {
+        let settings = GameSettings::default();
+
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.clan_members_count(5), 1);
+        assert_eq!(settings.clan_members_count(10), 3);
+        assert!(settings.clan_leader_knows_members(6));
+        assert!(!settings.clan_leader_knows_members(7));
+        assert!(!settings.dead_players_chat());
+        assert!(!settings.spectators_chat());
+    }
+
+    #[test]
+    fn should_use_custom_role_distribution() {
+        let settings = GameSettings::new(
+            vec![RoleDistribution {
+                player_count: 8,
+                clan_members_count: 1,
+            }],
+            ClanLeaderKnowledge::AlwaysKnown,
+            false,
+            None,
+            ChatAudience::LivingPlayers,
+        );
+
+        assert!(settings.validate_for(8).is_ok());
+        assert_eq!(settings.clan_members_count(8), 1);
+        assert_eq!(settings.clan_members_count(7), 2);
+        assert!(settings.clan_leader_knows_members(10));
+    }
+
+    #[test]
+    fn should_reject_invalid_settings() {
+        let clan_majority = GameSettings::new(
+            vec![RoleDistribution {
+                player_count: 6,
+                clan_members_count: 2,
+            }],
+            ClanLeaderKnowledge::SmallGamesOnly,
+            true,
+            None,
+            ChatAudience::LivingPlayers,
+        );
+        assert!(clan_majority.validate().is_err());
+
+        let duplicate = RoleDistribution {
+            player_count: 7,
+            clan_members_count: 1,
+        };
+        let duplicates = GameSettings::new(
+            vec![duplicate, duplicate],
+            ClanLeaderKnowledge::SmallGamesOnly,
+            true,
+            None,
+            ChatAudience::LivingPlayers,
+        );
+        assert!(duplicates.validate().is_err());
+
+        let short_timeout = GameSettings::new(
+            vec![],
+            ClanLeaderKnowledge::SmallGamesOnly,
+            true,
+            Some(1),
+            ChatAudience::LivingPlayers,
+        );
+        assert!(short_timeout.validate().is_err());
+        assert!(short_timeout.validate_for(7).is_err());
+    }
+}
@@ -1,27 +1,88 @@
 use super::{
     proto::{self},
-    Player,
+    CollectiveDecision, Game, GameEvent, Player, Policy, Role,
 };
 use crate::server::app_context::AppContext;
 use async_trait::async_trait;
+use protobuf::RepeatedField;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Clone, Hash, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum TaskType {
     Settings,
+    GameSettings,
+    DiscoverRole,
+    NominateChancellor,
+    Vote,
+    DiscardPolicy,
+    EnactPolicy,
+    InvestigateLoyalty,
+    CallSpecialElection,
+    PeekPolicies,
+    ExecutePlayer,
+    AnswerVeto,
+}
+
+impl TaskType {
+    /// Settings are up to the player, so they can't be answered by the server and never expire.
+    pub fn has_default_action(&self) -> bool {
+        !matches!(self, TaskType::Settings | TaskType::GameSettings)
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub enum TaskDefinition {
     Settings {},
+    GameSettings {},
+    DiscoverRole {
+        role: Role,
+        clan_member_ids: Vec<String>,
+        clan_leader_id: Option<String>,
+    },
+    NominateChancellor {
+        eligible_player_ids: Vec<String>,
+    },
+    Vote {
+        president_id: String,
+        chancellor_id: String,
+    },
+    DiscardPolicy {
+        policies: Vec<Policy>,
+    },
+    EnactPolicy {
+        policies: Vec<Policy>,
+        veto_allowed: bool,
+    },
+    InvestigateLoyalty {
+        eligible_player_ids: Vec<String>,
+    },
+    CallSpecialElection {
+        eligible_player_ids: Vec<String>,
+    },
+    PeekPolicies {},
+    ExecutePlayer {
+        eligible_player_ids: Vec<String>,
+    },
+    AnswerVeto {},
 }
 
 impl TaskDefinition {
     pub fn get_type(&self) -> TaskType {
         match self {
             TaskDefinition::Settings {} => TaskType::Settings,
+            TaskDefinition::GameSettings {} => TaskType::GameSettings,
+            TaskDefinition::DiscoverRole { .. } => TaskType::DiscoverRole,
+            TaskDefinition::NominateChancellor { .. } => TaskType::NominateChancellor,
+            TaskDefinition::Vote { .. } => TaskType::Vote,
+            TaskDefinition::DiscardPolicy { .. } => TaskType::DiscardPolicy,
+            TaskDefinition::EnactPolicy { .. } => TaskType::EnactPolicy,
+            TaskDefinition::InvestigateLoyalty { .. } => TaskType::InvestigateLoyalty,
+            TaskDefinition::CallSpecialElection { .. } => TaskType::CallSpecialElection,
+            TaskDefinition::PeekPolicies {} => TaskType::PeekPolicies,
+            TaskDefinition::ExecutePlayer { .. } => TaskType::ExecutePlayer,
+            TaskDefinition::AnswerVeto {} => TaskType::AnswerVeto,
         }
     }
 }
@@ -38,11 +99,78 @@ pub trait Task {
     fn resolve_after_first_answer(&self) -> bool;
 }
 
+/// Tasks which are assigned to multiple players at once and only have an effect after each of them has answered.
+#[async_trait]
+pub trait CollectiveTask {
+    /// Returns the type of this task to connect them with open tasks and the decision of the game.
+    fn get_type(&self) -> TaskType;
+
+    /// Returns the event which records the answer of the given player, which stays hidden until the decision is complete.
+    fn answer_event(&self, player_id: &str) -> GameEvent;
+
+    /// Applies the outcome of the complete decision. This is only called once, after the last answer.
+    async fn apply_decision(
+        &self,
+        game: &Game,
+        decision: &CollectiveDecision,
+        ctx: &AppContext,
+    ) -> Result<(), String>;
+}
+
 impl From<proto::task::Task> for TaskDefinition {
     fn from(proto_task: proto::task::Task) -> Self {
         match proto_task.definition {
             Some(def) => match def {
                 proto::task::Task_oneof_definition::settings(_) => TaskDefinition::Settings {},
+                proto::task::Task_oneof_definition::gameSettings(_) => {
+                    TaskDefinition::GameSettings {}
+                }
+                proto::task::Task_oneof_definition::discoverRole(def) => {
+                    TaskDefinition::DiscoverRole {
+                        role: def.role.into(),
+                        clan_member_ids: def.clan_member_ids.into_vec(),
+                        clan_leader_id: Some(def.clan_leader_id).filter(|id| !id.is_empty()),
+                    }
+                }
+                proto::task::Task_oneof_definition::nominateChancellor(def) => {
+                    TaskDefinition::NominateChancellor {
+                        eligible_player_ids: def.eligible_player_ids.into_vec(),
+                    }
+                }
+                proto::task::Task_oneof_definition::vote(def) => TaskDefinition::Vote {
+                    president_id: def.president_id,
+                    chancellor_id: def.chancellor_id,
+                },
+                proto::task::Task_oneof_definition::discardPolicy(def) => {
+                    TaskDefinition::DiscardPolicy {
+                        policies: def.policies.into_iter().map(Policy::from).collect(),
+                    }
+                }
+                proto::task::Task_oneof_definition::enactPolicy(def) => {
+                    TaskDefinition::EnactPolicy {
+                        policies: def.policies.into_iter().map(Policy::from).collect(),
+                        veto_allowed: def.veto_allowed,
+                    }
+                }
+                proto::task::Task_oneof_definition::investigateLoyalty(def) => {
+                    TaskDefinition::InvestigateLoyalty {
+                        eligible_player_ids: def.eligible_player_ids.into_vec(),
+                    }
+                }
+                proto::task::Task_oneof_definition::callSpecialElection(def) => {
+                    TaskDefinition::CallSpecialElection {
+                        eligible_player_ids: def.eligible_player_ids.into_vec(),
+                    }
+                }
+                proto::task::Task_oneof_definition::peekPolicies(_) => {
+                    TaskDefinition::PeekPolicies {}
+                }
+                proto::task::Task_oneof_definition::executePlayer(def) => {
+                    TaskDefinition::ExecutePlayer {
+                        eligible_player_ids: def.eligible_player_ids.into_vec(),
+                    }
+                }
+                proto::task::Task_oneof_definition::answerVeto(_) => TaskDefinition::AnswerVeto {},
             },
             None => TaskDefinition::Settings {},
         }
@@ -56,6 +184,79 @@ impl Into<proto::task::Task> for TaskDefinition {
             TaskDefinition::Settings {} => {
                 task.set_settings(proto::task::Task_Settings::new());
             }
+            TaskDefinition::GameSettings {} => {
+                task.set_gameSettings(proto::task::Task_GameSettings::new());
+            }
+            TaskDefinition::DiscoverRole {
+                role,
+                clan_member_ids,
+                clan_leader_id,
+            } => {
+                let mut def = proto::task::Task_DiscoverRole::new();
+                def.set_role(role.into());
+                def.set_clan_member_ids(RepeatedField::from_vec(clan_member_ids));
+                if let Some(id) = clan_leader_id {
+                    def.set_clan_leader_id(id);
+                }
+                task.set_discoverRole(def);
+            }
+            TaskDefinition::NominateChancellor {
+                eligible_player_ids,
+            } => {
+                let mut def = proto::task::Task_NominateChancellor::new();
+                def.set_eligible_player_ids(RepeatedField::from_vec(eligible_player_ids));
+                task.set_nominateChancellor(def);
+            }
+            TaskDefinition::Vote {
+                president_id,
+                chancellor_id,
+            } => {
+                let mut def = proto::task::Task_Vote::new();
+                def.set_president_id(president_id);
+                def.set_chancellor_id(chancellor_id);
+                task.set_vote(def);
+            }
+            TaskDefinition::DiscardPolicy { policies } => {
+                let mut def = proto::task::Task_DiscardPolicy::new();
+                def.set_policies(policies.into_iter().map(|p| p.into()).collect());
+                task.set_discardPolicy(def);
+            }
+            TaskDefinition::EnactPolicy {
+                policies,
+                veto_allowed,
+            } => {
+                let mut def = proto::task::Task_EnactPolicy::new();
+                def.set_policies(policies.into_iter().map(|p| p.into()).collect());
+                def.set_veto_allowed(veto_allowed);
+                task.set_enactPolicy(def);
+            }
+            TaskDefinition::InvestigateLoyalty {
+                eligible_player_ids,
+            } => {
+                let mut def = proto::task::Task_InvestigateLoyalty::new();
+                def.set_eligible_player_ids(RepeatedField::from_vec(eligible_player_ids));
+                task.set_investigateLoyalty(def);
+            }
+            TaskDefinition::CallSpecialElection {
+                eligible_player_ids,
+            } => {
+                let mut def = proto::task::Task_CallSpecialElection::new();
+                def.set_eligible_player_ids(RepeatedField::from_vec(eligible_player_ids));
+                task.set_callSpecialElection(def);
+            }
+            TaskDefinition::PeekPolicies {} => {
+                task.set_peekPolicies(proto::task::Task_PeekPolicies::new());
+            }
+            TaskDefinition::ExecutePlayer {
+                eligible_player_ids,
+            } => {
+                let mut def = proto::task::Task_ExecutePlayer::new();
+                def.set_eligible_player_ids(RepeatedField::from_vec(eligible_player_ids));
+                task.set_executePlayer(def);
+            }
+            TaskDefinition::AnswerVeto {} => {
+                task.set_answerVeto(proto::task::Task_AnswerVeto::new());
+            }
         }
         task
     }
@@ -1,8 +1,8 @@
 use super::{logger::init_logger, ws::WsClient};
 use crate::{
     config::AppConfig,
-    db::{ChangeListener, Client, Database},
-    model::{Game, Player},
+    db::{ChangeListener, ChatLog, Client, Database, EventLog, QueryError},
+    model::{Game, GameEvent, LoggedEvent, Player},
 };
 use envconfig::Envconfig;
 use tokio::sync::mpsc;
@@ -10,10 +10,12 @@ use tokio::sync::mpsc;
 pub struct DbClients {
     games: Client<Game>,
     players: Client<Player>,
+    events: EventLog,
+    chat: ChatLog,
 }
 
 impl DbClients {
-    pub fn init_with_changes() -> (DbClients, ChangeListener) {
+    pub fn init_with_changes(config: &AppConfig) -> (DbClients, ChangeListener) {
         let (mut games_repo, games_sender) = Database::init("games");
         let (mut players_repo, players_sender) = Database::init("players");
 
@@ -27,12 +29,17 @@ impl DbClients {
         });
 
         (
-            DbClients { games, players },
+            DbClients {
+                games,
+                players,
+                events: EventLog::init("events"),
+                chat: ChatLog::init("chat", config.chat_history_length),
+            },
             ChangeListener::new(player_changes, game_changes),
         )
     }
 
-    pub fn init() -> DbClients {
+    pub fn init(config: &AppConfig) -> DbClients {
         let (mut games_repo, games_sender) = Database::init("games");
         let (mut players_repo, players_sender) = Database::init("players");
 
@@ -43,6 +50,25 @@ impl DbClients {
         DbClients {
             games: Client::new(games_sender),
             players: Client::new(players_sender),
+            events: EventLog::init("events"),
+            chat: ChatLog::init("chat", config.chat_history_length),
+        }
+    }
+
+    /// Keeps all data in memory only, so simulations neither touch nor purge the databases of a server.
+    pub fn init_in_memory(config: &AppConfig) -> DbClients {
+        let (mut games_repo, games_sender) = Database::init_in_memory("games");
+        let (mut players_repo, players_sender) = Database::init_in_memory("players");
+
+        tokio::task::spawn(async move {
+            tokio::join!(players_repo.start_listening(), games_repo.start_listening(),);
+        });
+
+        DbClients {
+            games: Client::new(games_sender),
+            players: Client::new(players_sender),
+            events: EventLog::init_in_memory("events"),
+            chat: ChatLog::init_in_memory("chat", config.chat_history_length),
         }
     }
 
@@ -53,6 +79,57 @@ impl DbClients {
     pub fn players(&self) -> &Client<Player> {
         &self.players
     }
+
+    pub fn events(&self) -> &EventLog {
+        &self.events
+    }
+
+    pub fn chat(&self) -> &ChatLog {
+        &self.chat
+    }
+
+    /// Persists a new game and starts its event log, the game is removed again if the log can't be started.
+    pub async fn create_game(&self, admin_id: &str, token: &str) -> Result<Game, QueryError> {
+        let created = LoggedEvent::new(GameEvent::game_created(admin_id, token));
+        let game =
+            Game::replay(std::slice::from_ref(&created)).map_err(|err| QueryError::new(&err))?;
+        self.games.persist(&game).await?;
+        if let Err(err) = self.events.append(token, &[created]) {
+            self.games.remove(token).await?;
+            return Err(QueryError::from_sled(err));
+        }
+
+        Ok(game)
+    }
+
+    /// Applies the events to the game and appends them to its event log, which is the only way to change a game.
+    /// The stored game is what all readers rely on, while the log records how it came about and rebuilds it with `Game::replay`.
+    /// Events are only appended once the game has been persisted, and the game is restored if appending fails.
+    /// This way the log never contains events which the game rejected or which failed to be persisted.
+    pub async fn apply_events(
+        &self,
+        game_token: &str,
+        events: Vec<GameEvent>,
+    ) -> Result<Game, QueryError> {
+        let log = self.events.clone();
+        let events = events.into_iter().map(LoggedEvent::new).collect::<Vec<_>>();
+        let applied_events = events.clone();
+        self.games
+            .update_and_then(
+                game_token,
+                Box::new(move |game| {
+                    for event in &applied_events {
+                        game.apply(event)?;
+                    }
+                    Ok(())
+                }),
+                Box::new(move |game| {
+                    log.append(game.token(), &events)
+                        .map_err(|err| err.to_string())
+                }),
+            )
+            .await
+    }
 }
 
 /// The AppContext is the central place for crosscutting topics like Database acess or reading configuration values.
@@ -67,14 +144,22 @@ pub struct AppContext {
 impl AppContext {
     pub fn init() -> AppContext {
         let (config, ws) = AppContext::init_config_and_ws();
-        let db = DbClients::init();
+        let db = DbClients::init(&config);
+
+        AppContext { config, ws, db }
+    }
+
+    /// Creates a context without any persistent storage, messages to players are dropped as nobody is connected.
+    pub fn init_in_memory() -> AppContext {
+        let (config, ws) = AppContext::init_config_and_ws();
+        let db = DbClients::init_in_memory(&config);
 
         AppContext { config, ws, db }
     }
 
     pub fn init_with_changes() -> (AppContext, ChangeListener) {
         let (config, ws) = AppContext::init_config_and_ws();
-        let (db, changes) = DbClients::init_with_changes();
+        let (db, changes) = DbClients::init_with_changes(&config);
 
         (AppContext { config, ws, db }, changes)
     }
@@ -1,7 +1,20 @@
 use crate::{
     model::proto::{self},
     server::{
-        app_context::AppContext, endpoints::tasks::apply_task, tasks::settings::SettingsTask,
+        app_context::AppContext,
+        endpoints::{
+            chat::post_chat_message, collective_tasks::apply_collective_task, lobby::change_ready,
+            tasks::apply_task,
+        },
+        tasks::{
+            answer_veto::AnswerVetoTask, discard_policy::DiscardPolicyTask,
+            discover_role::DiscoverRoleTask, enact_policy::EnactPolicyTask,
+            execute_player::ExecutePlayerTask, game_settings::GameSettingsTask,
+            investigate_loyalty::InvestigateLoyaltyTask,
+            nominate_chancellor::NominateChancellorTask, peek_policies::PeekPoliciesTask,
+            propose_veto::ProposeVetoTask, settings::SettingsTask,
+            special_election::SpecialElectionTask, vote::VoteTask,
+        },
     },
 };
 use futures::{stream::SplitSink, StreamExt};
@@ -139,6 +152,111 @@ async fn handle_incoming_message(
         proto::message::Client_oneof_message::nameUpdated(ev) => {
             apply_task(SettingsTask { name: ev.name }, peer_id, ctx).await
         }
+        proto::message::Client_oneof_message::gameSettingsUpdated(ev) => {
+            apply_task(
+                GameSettingsTask {
+                    settings: ev.get_settings().clone().into(),
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::roleDiscovered(_) => {
+            apply_task(DiscoverRoleTask {}, peer_id, ctx).await
+        }
+        proto::message::Client_oneof_message::chancellorNominated(ev) => {
+            apply_task(
+                NominateChancellorTask {
+                    player_id: ev.player_id,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::voteCast(ev) => {
+            apply_collective_task(
+                VoteTask {
+                    approved: ev.approved,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::policyDiscarded(ev) => {
+            apply_task(
+                DiscardPolicyTask {
+                    policy: ev.policy.into(),
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::policyEnacted(ev) => {
+            apply_task(
+                EnactPolicyTask {
+                    policy: ev.policy.into(),
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::loyaltyInvestigated(ev) => {
+            apply_task(
+                InvestigateLoyaltyTask {
+                    player_id: ev.player_id,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::specialElectionCalled(ev) => {
+            apply_task(
+                SpecialElectionTask {
+                    player_id: ev.player_id,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::policiesPeeked(_) => {
+            apply_task(PeekPoliciesTask {}, peer_id, ctx).await
+        }
+        proto::message::Client_oneof_message::playerExecuted(ev) => {
+            apply_task(
+                ExecutePlayerTask {
+                    player_id: ev.player_id,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::vetoProposed(_) => {
+            apply_task(ProposeVetoTask {}, peer_id, ctx).await
+        }
+        proto::message::Client_oneof_message::vetoAnswered(ev) => {
+            apply_task(
+                AnswerVetoTask {
+                    approved: ev.approved,
+                },
+                peer_id,
+                ctx,
+            )
+            .await
+        }
+        proto::message::Client_oneof_message::chatMessage(ev) => {
+            post_chat_message(&ev.text, peer_id, ctx).await
+        }
+        proto::message::Client_oneof_message::readyChanged(ev) => {
+            change_ready(ev.ready, peer_id, ctx).await
+        }
     }
 }
 
@@ -148,9 +266,12 @@ mod tests {
     use crate::{
         model::{
             proto::{self},
-            Player, TaskDefinition,
+            Game, Player, TaskDefinition,
+        },
+        server::{
+            app_context::AppContext,
+            auth::{generate_jwt_token, generate_spectator_token},
         },
-        server::{app_context::AppContext, auth::generate_jwt_token},
     };
 
     #[tokio::test]
@@ -214,6 +335,54 @@ mod tests {
         assert!(reply.is_ok());
     }
 
+    #[tokio::test]
+    async fn should_handle_auth_message_of_spectator() {
+        let ctx = AppContext::init();
+        let mut admin = Player::new("GAME");
+        admin.assign_task(TaskDefinition::Settings {});
+        ctx.db()
+            .players()
+            .persist(&admin)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(&Game::new(admin.id(), "GAME"))
+            .await
+            .expect("Persisting game has failed");
+        let token = generate_spectator_token("spectator", "GAME", &ctx.config().auth_secret);
+
+        let reply = handle_incoming_message(
+            proto::message::Client_oneof_message::authConfirmed(
+                proto::message::Client_AuthConfirmed {
+                    token,
+                    ..Default::default()
+                },
+            ),
+            &ctx,
+            "peer-id",
+        )
+        .await;
+        assert!(reply.is_ok());
+        assert!(ctx
+            .ws()
+            .get_authenticated_player_for_peer("peer-id")
+            .await
+            .is_none());
+
+        // spectators never resolve tasks
+        let reply = handle_incoming_message(
+            proto::message::Client_oneof_message::nameUpdated(proto::message::Client_NameUpdated {
+                name: String::from("Spectator"),
+                ..Default::default()
+            }),
+            &ctx,
+            "peer-id",
+        )
+        .await;
+        assert!(reply.is_err());
+    }
+
     #[tokio::test]
     async fn should_handle_auth_message_with_invalid_token() {
         let ctx = AppContext::init();
@@ -0,0 +1,186 @@
+use crate::{
+    model::{
+        proto::{self},
+        ChatMessage, Game,
+    },
+    server::app_context::AppContext,
+};
+use chrono::{Duration, Utc};
+use log::{debug, error};
+
+const MAX_MESSAGE_LENGTH: usize = 300;
+const RATE_LIMIT_MESSAGES_COUNT: usize = 5;
+const RATE_LIMIT_SECONDS: i64 = 10;
+
+/// Posts the message of a player to everyone in the game, who takes part in the chat according to the game settings.
+pub async fn post_chat_message(text: &str, peer_id: &str, ctx: &AppContext) -> Result<(), String> {
+    let player_id = ctx
+        .ws()
+        .get_authenticated_player_for_peer(peer_id)
+        .await
+        .ok_or_else(|| String::from("Unauthorized user"))?;
+    let player = ctx
+        .db()
+        .players()
+        .get(&player_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("Player not found"))?;
+    let game = ctx
+        .db()
+        .games()
+        .get(player.game_token())
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("Game not found"))?;
+
+    if !takes_part_in_chat(&game, player.id()) {
+        return Err(format!("Player {} can't chat anymore", player.id()));
+    }
+    let text = text.trim();
+    if text.is_empty() {
+        return Err(String::from("Chat message is empty"));
+    }
+    if text.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(format!(
+            "Chat message is longer than {} characters",
+            MAX_MESSAGE_LENGTH
+        ));
+    }
+    let history = ctx
+        .db()
+        .chat()
+        .read(game.token())
+        .map_err(|err| err.to_string())?;
+    check_rate_limit(player.id(), &history)?;
+
+    let message = ChatMessage::new(player.id(), text);
+    ctx.db()
+        .chat()
+        .append(game.token(), message.clone())
+        .map_err(|err| err.to_string())?;
+    debug!("Player {} posted a chat message", player.id());
+
+    for receiver_id in game
+        .all_player_ids()
+        .into_iter()
+        .filter(|id| takes_part_in_chat(&game, id))
+    {
+        let mut msg = proto::message::Server::new();
+        msg.set_chatPosted(message.clone().into());
+        if let Err(err) = ctx.ws().send_message(receiver_id, msg).await {
+            error!("Sending ChatPosted has failed: {}", &err);
+        }
+    }
+    if game.settings().spectators_chat() {
+        let mut msg = proto::message::Server::new();
+        msg.set_chatPosted(message.into());
+        if let Err(err) = ctx.ws().broadcast_to_spectators(game.token(), msg).await {
+            error!("Sending ChatPosted to spectators has failed: {}", &err);
+        }
+    }
+    Ok(())
+}
+
+/// Executed players are only allowed to read and write messages if the game settings allow it.
+pub fn takes_part_in_chat(game: &Game, player_id: &str) -> bool {
+    game.settings().dead_players_chat() || !game.executed_ids().iter().any(|id| id == player_id)
+}
+
+fn check_rate_limit(player_id: &str, history: &[ChatMessage]) -> Result<(), String> {
+    let threshold = Utc::now() - Duration::seconds(RATE_LIMIT_SECONDS);
+    let recent_count = history
+        .iter()
+        .filter(|message| message.player_id() == player_id && message.time() > &threshold)
+        .count();
+    if recent_count >= RATE_LIMIT_MESSAGES_COUNT {
+        return Err(format!(
+            "Player {} has posted too many chat messages",
+            player_id
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{post_chat_message, MAX_MESSAGE_LENGTH, RATE_LIMIT_MESSAGES_COUNT};
+    use crate::{
+        model::{Game, Player},
+        server::app_context::AppContext,
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_lobby(ctx: &AppContext) -> Player {
+        let admin = Player::new(GAME_TOKEN);
+        ctx.db()
+            .players()
+            .persist(&admin)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(&Game::new(admin.id(), GAME_TOKEN))
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(admin.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+        admin
+    }
+
+    #[tokio::test]
+    async fn should_post_chat_message() {
+        let ctx = AppContext::init();
+        let admin = init_lobby(&ctx).await;
+
+        let res = post_chat_message("  Hello there!  ", "peer", &ctx).await;
+        assert!(res.is_ok());
+
+        let history = ctx
+            .db()
+            .chat()
+            .read(GAME_TOKEN)
+            .expect("Reading chat has failed");
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].player_id(), admin.id());
+        assert_eq!(history[0].text(), "Hello there!");
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_chat_messages() {
+        let ctx = AppContext::init();
+        init_lobby(&ctx).await;
+
+        assert!(post_chat_message("   ", "peer", &ctx).await.is_err());
+        assert!(
+            post_chat_message(&"a".repeat(MAX_MESSAGE_LENGTH + 1), "peer", &ctx)
+                .await
+                .is_err()
+        );
+        assert!(post_chat_message("Hello", "unknown-peer", &ctx)
+            .await
+            .is_err());
+        assert!(ctx
+            .db()
+            .chat()
+            .read(GAME_TOKEN)
+            .expect("Reading chat has failed")
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_limit_chat_message_rate() {
+        let ctx = AppContext::init();
+        init_lobby(&ctx).await;
+
+        for i in 0..RATE_LIMIT_MESSAGES_COUNT {
+            let res = post_chat_message(&format!("Message {}", i), "peer", &ctx).await;
+            assert!(res.is_ok());
+        }
+        let res = post_chat_message("One message too many", "peer", &ctx).await;
+        assert!(res.is_err());
+    }
+}
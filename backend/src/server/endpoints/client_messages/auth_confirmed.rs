@@ -1,6 +1,10 @@
+use super::snapshot::send_snapshot;
 use crate::{
     model::proto::{self},
-    server::{app_context::AppContext, auth::extract_verified_player},
+    server::{
+        app_context::AppContext,
+        auth::{extract_verified_player, extract_verified_spectator},
+    },
 };
 use log::error;
 
@@ -27,46 +31,87 @@ pub async fn handle_auth_confirmation(
                 .await
                 .expect("Reading game has failed")
             {
-                let mut game_updated_msg = proto::message::Server_GameUpdated::new();
-                game_updated_msg.set_game(game.clone().into());
-                let mut msg = proto::message::Server::new();
-                msg.set_gameUpdated(game_updated_msg);
-                ctx.ws()
-                    .send_message(String::from(new_player.id()), msg)
-                    .await?;
+                send_snapshot(&new_player, &game, ctx).await?;
 
+                // inform other players about new player
                 for other_player_id in game.all_player_ids() {
-                    if let Some(other_player) = ctx
-                        .db()
-                        .players()
-                        .get(&other_player_id)
-                        .await
-                        .expect("Reading player has failed")
-                    {
-                        // inform new player about existing players
-                        if other_player_id != new_player.id() {
-                            let mut update_msg = proto::message::Server_PlayerEntered::new();
-                            update_msg.set_player(other_player.clone().into());
-                            let mut msg = proto::message::Server::new();
-                            msg.set_playerEntered(update_msg);
-                            ctx.ws()
-                                .send_message(String::from(new_player.id()), msg)
-                                .await?;
-                        }
-
-                        // inform other players about new player
-                        let mut player_msg = proto::message::Server_PlayerEntered::new();
-                        player_msg.set_player(new_player.clone().into());
-                        let mut msg = proto::message::Server::new();
-                        msg.set_playerEntered(player_msg);
-                        ctx.ws()
-                            .send_message(String::from(other_player.id()), msg)
-                            .await?;
+                    if other_player_id == new_player.id() {
+                        continue;
                     }
+                    let mut player_msg = proto::message::Server_PlayerEntered::new();
+                    player_msg.set_player(new_player.clone().into());
+                    let mut msg = proto::message::Server::new();
+                    msg.set_playerEntered(player_msg);
+                    ctx.ws().send_message(other_player_id, msg).await?;
                 }
             }
             Ok(())
         }
-        None => Err(String::from("Unauthorized user")),
+        None => match extract_verified_spectator(token, ctx) {
+            Some((spectator_id, game_token)) => {
+                handle_spectator_confirmation(&spectator_id, &game_token, peer_id, ctx).await
+            }
+            None => Err(String::from("Unauthorized user")),
+        },
+    }
+}
+
+/// Spectators only receive the public state of the game and its players.
+async fn handle_spectator_confirmation(
+    spectator_id: &str,
+    game_token: &str,
+    peer_id: &str,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let game = ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| format!("Game {} doesn't exist", game_token))?;
+    ctx.ws()
+        .register_spectator(spectator_id, game_token, peer_id)
+        .await?;
+
+    let mut game_updated_msg = proto::message::Server_GameUpdated::new();
+    game_updated_msg.set_game(game.clone().into());
+    let mut msg = proto::message::Server::new();
+    msg.set_gameUpdated(game_updated_msg);
+    ctx.ws()
+        .send_spectator_message(String::from(spectator_id), msg)
+        .await?;
+
+    for player in ctx
+        .db()
+        .players()
+        .get_batch(&game.all_player_ids())
+        .await
+        .map_err(|err| err.to_string())?
+        .into_values()
+    {
+        let mut player_msg = proto::message::Server_PlayerEntered::new();
+        player_msg.set_player(player.into());
+        let mut msg = proto::message::Server::new();
+        msg.set_playerEntered(player_msg);
+        ctx.ws()
+            .send_spectator_message(String::from(spectator_id), msg)
+            .await?;
+    }
+
+    if game.settings().spectators_chat() {
+        for message in ctx
+            .db()
+            .chat()
+            .read(game_token)
+            .map_err(|err| err.to_string())?
+        {
+            let mut msg = proto::message::Server::new();
+            msg.set_chatPosted(message.into());
+            ctx.ws()
+                .send_spectator_message(String::from(spectator_id), msg)
+                .await?;
+        }
     }
+    Ok(())
 }
@@ -1,3 +1,4 @@
 mod auth_confirmed;
+mod snapshot;
 
 pub use auth_confirmed::handle_auth_confirmation;
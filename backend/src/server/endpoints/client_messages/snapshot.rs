@@ -0,0 +1,221 @@
+use crate::{
+    model::{
+        proto::{self},
+        ChatMessage, Game, GameEvent, LoggedEvent, Player, Role,
+    },
+    server::{app_context::AppContext, endpoints::chat::takes_part_in_chat},
+};
+use protobuf::RepeatedField;
+use std::collections::HashMap;
+
+/// Sends the whole state of the game as seen by the given player, so a reconnecting client is in sync after a single message.
+pub async fn send_snapshot(player: &Player, game: &Game, ctx: &AppContext) -> Result<(), String> {
+    let players = ctx
+        .db()
+        .players()
+        .get_batch(&game.all_player_ids())
+        .await
+        .map_err(|err| err.to_string())?;
+    let events = ctx
+        .db()
+        .events()
+        .read(game.token())
+        .map_err(|err| err.to_string())?;
+    let chat = if takes_part_in_chat(game, player.id()) {
+        ctx.db()
+            .chat()
+            .read(game.token())
+            .map_err(|err| err.to_string())?
+    } else {
+        vec![]
+    };
+
+    let mut msg = proto::message::Server::new();
+    msg.set_snapshot(create_snapshot(player, game, &players, &events, chat));
+    ctx.ws().send_message(String::from(player.id()), msg).await
+}
+
+fn create_snapshot(
+    player: &Player,
+    game: &Game,
+    players: &HashMap<String, Player>,
+    events: &[LoggedEvent],
+    chat: Vec<ChatMessage>,
+) -> proto::message::Server_Snapshot {
+    let mut snapshot = proto::message::Server_Snapshot::new();
+    snapshot.set_game(game.clone().into());
+    snapshot.set_players(RepeatedField::from_vec(
+        game.all_player_ids()
+            .iter()
+            .filter_map(|id| players.get(id))
+            .map(|other_player| {
+                let mut presence = proto::message::Server_Snapshot_PlayerPresence::new();
+                presence.set_connected(other_player.last_active_time().is_none());
+                presence.set_player(other_player.clone().into());
+                presence
+            })
+            .collect(),
+    ));
+    snapshot.set_own_player(player.clone().into());
+    if let Some(knowledge) = create_knowledge(player.id(), game, events) {
+        snapshot.set_knowledge(knowledge);
+    }
+    snapshot.set_phase(game.phase().into());
+    snapshot.set_chat(RepeatedField::from_vec(
+        chat.into_iter().map(|message| message.into()).collect(),
+    ));
+    snapshot
+}
+
+/// Collects everything the player has learned in private: the own role, the known clan members and the investigated loyalties.
+/// Investigations of games before a rematch are left out, as the roles have been dealt again.
+fn create_knowledge(
+    player_id: &str,
+    game: &Game,
+    events: &[LoggedEvent],
+) -> Option<proto::message::Server_Snapshot_Knowledge> {
+    let role = game.role(player_id)?;
+
+    let mut knowledge = proto::message::Server_Snapshot_Knowledge::new();
+    knowledge.set_role(role.into());
+    knowledge.set_clan_member_ids(RepeatedField::from_vec(
+        game.known_clan_member_ids(player_id),
+    ));
+    if let (Role::Clan, Some(id)) = (role, game.clan_leader_id()) {
+        knowledge.set_clan_leader_id(String::from(id));
+    }
+    knowledge.set_investigations(RepeatedField::from_vec(
+        LoggedEvent::latest_game(events)
+            .iter()
+            .filter_map(|logged_event| match logged_event.event() {
+                GameEvent::LoyaltyInvestigated {
+                    player_id: investigator_id,
+                    target_id,
+                } if investigator_id == player_id => game.role(target_id).map(|target_role| {
+                    let mut loyalty = proto::message::Server_LoyaltyRevealed::new();
+                    loyalty.set_player_id(target_id.clone());
+                    loyalty.set_party(target_role.party().into());
+                    loyalty
+                }),
+                _ => None,
+            })
+            .collect(),
+    ));
+    Some(knowledge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::create_snapshot;
+    use crate::model::{
+        proto::{self},
+        ChatMessage, Game, GameEvent, LoggedEvent, Player,
+    };
+    use std::collections::HashMap;
+
+    fn init_players(count: usize) -> HashMap<String, Player> {
+        (0..count)
+            .map(|_| {
+                let player = Player::new("GAME");
+                (String::from(player.id()), player)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_create_snapshot_of_lobby() {
+        let mut players = init_players(2);
+        let ids = players.keys().cloned().collect::<Vec<_>>();
+        let mut game = Game::new(&ids[0], "GAME");
+        game.add_player(&ids[1]);
+        players.get_mut(&ids[1]).unwrap().set_inactive();
+
+        let snapshot = create_snapshot(
+            &players[&ids[0]],
+            &game,
+            &players,
+            &[],
+            vec![ChatMessage::new(&ids[1], "Hello")],
+        );
+
+        assert_eq!(snapshot.get_phase(), proto::game::Phase::LOBBY);
+        assert_eq!(snapshot.get_own_player().get_id(), ids[0]);
+        assert!(!snapshot.has_knowledge());
+        assert_eq!(snapshot.get_chat()[0].get_text(), "Hello");
+        let presence = snapshot
+            .get_players()
+            .iter()
+            .map(|presence| (presence.get_player().get_id(), presence.get_connected()))
+            .collect::<HashMap<_, _>>();
+        assert_eq!(presence.len(), 2);
+        assert!(presence[ids[0].as_str()]);
+        assert!(!presence[ids[1].as_str()]);
+    }
+
+    #[test]
+    fn should_include_private_knowledge() {
+        let players = init_players(5);
+        let ids = players.keys().cloned().collect::<Vec<_>>();
+        let mut game = Game::new(&ids[0], "GAME");
+        for id in &ids[1..] {
+            game.add_player(id);
+        }
+        game.start().expect("Starting game has failed");
+        let investigator_id = &ids[0];
+        let target_id = &ids[1];
+        let events = vec![
+            LoggedEvent::new(GameEvent::LoyaltyInvestigated {
+                player_id: investigator_id.clone(),
+                target_id: target_id.clone(),
+            }),
+            LoggedEvent::new(GameEvent::LoyaltyInvestigated {
+                player_id: target_id.clone(),
+                target_id: investigator_id.clone(),
+            }),
+        ];
+
+        let snapshot = create_snapshot(&players[investigator_id], &game, &players, &events, vec![]);
+
+        assert_eq!(snapshot.get_phase(), proto::game::Phase::NOMINATION);
+        let knowledge = snapshot.get_knowledge();
+        assert_eq!(
+            knowledge.get_role(),
+            game.role(investigator_id).unwrap().into()
+        );
+        assert_eq!(knowledge.get_investigations().len(), 1);
+        assert_eq!(knowledge.get_investigations()[0].get_player_id(), target_id);
+        assert_eq!(
+            knowledge.get_investigations()[0].get_party(),
+            game.role(target_id).unwrap().party().into()
+        );
+    }
+
+    #[test]
+    fn should_only_include_investigations_of_latest_game() {
+        let players = init_players(5);
+        let ids = players.keys().cloned().collect::<Vec<_>>();
+        let mut game = Game::new(&ids[0], "GAME");
+        for id in &ids[1..] {
+            game.add_player(id);
+        }
+        game.start().expect("Starting game has failed");
+        let investigator_id = &ids[0];
+        let events = vec![
+            LoggedEvent::new(GameEvent::LoyaltyInvestigated {
+                player_id: investigator_id.clone(),
+                target_id: ids[1].clone(),
+            }),
+            LoggedEvent::new(GameEvent::rematch_started(investigator_id)),
+            LoggedEvent::new(GameEvent::LoyaltyInvestigated {
+                player_id: investigator_id.clone(),
+                target_id: ids[2].clone(),
+            }),
+        ];
+
+        let snapshot = create_snapshot(&players[investigator_id], &game, &players, &events, vec![]);
+
+        let investigations = snapshot.get_knowledge().get_investigations();
+        assert_eq!(investigations.len(), 1);
+        assert_eq!(investigations[0].get_player_id(), ids[2]);
+    }
+}
@@ -0,0 +1,262 @@
+use crate::{
+    model::{
+        proto::{self},
+        CollectiveDecision, CollectiveTask, Game,
+    },
+    server::{app_context::AppContext, endpoints::tasks::is_executed},
+};
+use log::{error, warn};
+use protobuf::RepeatedField;
+
+/// Collects the answer of a player for a task assigned to multiple players.
+/// The answer is stored atomically within the decision of the game, so only the last answer completes the decision.
+/// All players are informed about the votes afterwards and the task is applied once.
+pub async fn apply_collective_task<T: CollectiveTask>(
+    task: T,
+    peer_id: &str,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    match ctx.ws().get_authenticated_player_for_peer(peer_id).await {
+        Some(player_id) => apply_player_collective_task(task, &player_id, ctx).await,
+        None => Err(String::from("Player not authenticated")),
+    }
+}
+
+/// Collects the answer in the name of the given player, which is also used to resolve tasks without a connection.
+pub async fn apply_player_collective_task<T: CollectiveTask>(
+    task: T,
+    player_id: &str,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let mut player = match ctx
+        .db()
+        .players()
+        .get(player_id)
+        .await
+        .expect("Reading player has failed")
+    {
+        Some(player) => player,
+        None => return Err(String::from("Player not found")),
+    };
+    if is_executed(&player, ctx).await {
+        return Err(String::from("Dead players can't resolve tasks"));
+    }
+
+    // Check if task is assigned
+    if player
+        .open_tasks()
+        .front()
+        .filter(|def| def.get_type() == task.get_type())
+        .is_none()
+    {
+        // Prevent leaking information about assigned tasks of other players by sending still OK
+        warn!(
+            "Player {} doesn't have task {:?} to resolve",
+            player.id(),
+            task.get_type()
+        );
+        return Ok(());
+    }
+
+    let game = ctx
+        .db()
+        .apply_events(player.game_token(), vec![task.answer_event(player_id)])
+        .await
+        .map_err(|err| err.to_string())?;
+
+    player.resolve_task(task.get_type());
+    if ctx.db().players().persist(&player).await.is_err() {
+        return Err(String::from("Updating player has failed"));
+    }
+
+    match game
+        .decision(&task.get_type())
+        .filter(|decision| decision.is_complete())
+    {
+        Some(decision) => {
+            inform_about_votes(&game, decision, ctx).await;
+            task.apply_decision(&game, decision, ctx).await
+        }
+        None => Ok(()),
+    }
+}
+
+async fn inform_about_votes(game: &Game, decision: &CollectiveDecision, ctx: &AppContext) {
+    for player_id in game.all_player_ids() {
+        let mut votes_msg = proto::message::Server_VotesCounted::new();
+        votes_msg.set_approving_player_ids(RepeatedField::from_vec(decision.approving_ids()));
+        votes_msg.set_rejecting_player_ids(RepeatedField::from_vec(decision.rejecting_ids()));
+        votes_msg.set_abstaining_player_ids(RepeatedField::from_vec(decision.abstaining_ids()));
+        votes_msg.set_approved(decision.is_approved());
+        let mut msg = proto::message::Server::new();
+        msg.set_votesCounted(votes_msg);
+
+        if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+            error!("Sending VotesCounted has failed: {}", &err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Game, Player, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext, endpoints::collective_tasks::apply_collective_task,
+            tasks::vote::VoteTask,
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_election(ctx: &AppContext) -> Game {
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+
+        for player in players.iter_mut() {
+            player.assign_task(TaskDefinition::Vote {
+                president_id: String::from(game.presidential_candidate_id().unwrap()),
+                chancellor_id: chancellor_id.clone(),
+            });
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        for (seat, id) in game.seats().iter().enumerate() {
+            ctx.ws()
+                .register_active_player(id, &format!("peer{}", seat))
+                .await
+                .expect("Setting peer connection failed");
+        }
+
+        game
+    }
+
+    async fn get_game(ctx: &AppContext) -> Game {
+        ctx.db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_resolve_after_last_vote() {
+        let ctx = AppContext::init();
+        let game = init_election(&ctx).await;
+
+        for i in 0..4 {
+            let res =
+                apply_collective_task(VoteTask { approved: i < 3 }, &format!("peer{}", i), &ctx)
+                    .await;
+            assert!(res.is_ok());
+
+            let updated_game = get_game(&ctx).await;
+            assert!(!updated_game
+                .decision(&TaskType::Vote)
+                .unwrap()
+                .is_complete());
+            assert!(updated_game.chancellor_id().is_none());
+        }
+
+        let res = apply_collective_task(VoteTask { approved: false }, "peer4", &ctx).await;
+        assert!(res.is_ok());
+
+        let updated_game = get_game(&ctx).await;
+        let decision = updated_game.decision(&TaskType::Vote).unwrap();
+        assert!(decision.is_complete());
+        assert_eq!(decision.approving_ids().len(), 3);
+        assert_eq!(decision.rejecting_ids().len(), 2);
+        assert_eq!(
+            updated_game.president_id(),
+            game.presidential_candidate_id()
+        );
+        assert_eq!(updated_game.chancellor_id(), game.nominated_chancellor_id());
+
+        let players = ctx
+            .db()
+            .players()
+            .get_batch(game.seats())
+            .await
+            .expect("Reading players has failed");
+        for (id, player) in players {
+            if Some(id.as_str()) == updated_game.president_id() {
+                assert_eq!(
+                    player.open_tasks().front(),
+                    Some(&TaskDefinition::DiscardPolicy {
+                        policies: updated_game.legislative_policies().to_vec()
+                    })
+                );
+            } else {
+                assert!(player.open_tasks().is_empty());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn should_ignore_second_vote() {
+        let ctx = AppContext::init();
+        init_election(&ctx).await;
+
+        let res = apply_collective_task(VoteTask { approved: true }, "peer0", &ctx).await;
+        assert!(res.is_ok());
+        let res = apply_collective_task(VoteTask { approved: false }, "peer0", &ctx).await;
+        assert!(res.is_ok());
+
+        let updated_game = get_game(&ctx).await;
+        let decision = updated_game.decision(&TaskType::Vote).unwrap();
+        assert_eq!(decision.approving_ids().len(), 1);
+        assert!(decision.rejecting_ids().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_pass_candidacy_after_rejection() {
+        let ctx = AppContext::init();
+        let game = init_election(&ctx).await;
+
+        for i in 0..5 {
+            let res =
+                apply_collective_task(VoteTask { approved: i < 2 }, &format!("peer{}", i), &ctx)
+                    .await;
+            assert!(res.is_ok());
+        }
+
+        let updated_game = get_game(&ctx).await;
+        assert!(updated_game.chancellor_id().is_none());
+        assert!(updated_game.nominated_chancellor_id().is_none());
+        assert_eq!(
+            updated_game.presidential_candidate_id(),
+            Some(game.seats()[1].as_str())
+        );
+
+        let candidate = ctx
+            .db()
+            .players()
+            .get(&game.seats()[1])
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            candidate.open_tasks().front().map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+    }
+}
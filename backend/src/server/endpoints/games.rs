@@ -1,16 +1,28 @@
 use crate::{
-    model::{Game, GameResponse, GameState, Player, TaskDefinition, TaskType},
+    model::{
+        proto::{self},
+        Game, GameEvent, GameResponse, GameState, JoinRejection, Player, StartRejection,
+        TaskDefinition,
+    },
     server::{
         app_context::AppContext,
-        auth::{extract_verified_id, generate_jwt_token},
-        reply::{reply_error, reply_error_with_details, reply_success},
+        auth::{
+            extract_verified_id, generate_jwt_token, generate_spectator_token, hash_password,
+            verify_password,
+        },
+        flow::{start_game, start_rematch},
+        reply::{reply_error, reply_error_with_details, reply_error_with_reason, reply_success},
     },
 };
-use log::debug;
+use log::{debug, warn};
+use nanoid::nanoid;
 use rand::{distributions::Alphanumeric, thread_rng, Rng};
-use serde::Serialize;
-use std::{convert::Infallible, iter};
-use warp::hyper::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, convert::Infallible, iter};
+use warp::{
+    hyper::StatusCode,
+    reply::{Json, WithStatus},
+};
 
 // this value determines the findability of a game and is a tradeoff between security and user friendliness
 // 5 tokens mean a chance of finding a random game of 1:60466176.
@@ -22,12 +34,37 @@ struct AttendGameReponse {
     token: String,
 }
 
+#[derive(Deserialize, Default)]
+pub struct AttendGameRequest {
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ChangePasswordRequest {
+    /// An empty or missing password removes the protection of the game.
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct LockLobbyRequest {
+    pub locked: bool,
+}
+
+#[derive(Deserialize)]
+pub struct StartGameQuery {
+    /// The admin may start the game although not all players are ready yet.
+    #[serde(default)]
+    pub force: bool,
+}
+
 pub async fn get_game_filter(
     game_token: &str,
     authorization: &str,
     ctx: &AppContext,
 ) -> Result<impl warp::Reply, Infallible> {
-    match extract_verified_id(authorization, ctx) {
+    match extract_verified_id(authorization, ctx).await {
         Some(_) => match ctx
             .db()
             .games()
@@ -78,8 +115,14 @@ pub async fn create_game_filter(ctx: &AppContext) -> Result<impl warp::Reply, In
     }
 
     let game_token = generate_game_token();
-    let player = create_new_player(&game_token, ctx).await;
+    let mut player = create_new_player(&game_token, ctx).await;
     let new_game = create_new_game(player.id(), &game_token, ctx).await;
+    player.assign_task(TaskDefinition::GameSettings {});
+    ctx.db()
+        .players()
+        .persist(&player)
+        .await
+        .expect("Assigning game settings failed");
 
     Ok(warp::reply::with_status(
         warp::reply::json(&AttendGameReponse {
@@ -92,31 +135,109 @@ pub async fn create_game_filter(ctx: &AppContext) -> Result<impl warp::Reply, In
 
 pub async fn attend_game_filter(
     game_token: &str,
+    password: Option<&str>,
     ctx: &AppContext,
 ) -> Result<impl warp::Reply, Infallible> {
-    match ctx
+    let max_players_count = ctx.config().max_players_count;
+    let game = match ctx
         .db()
         .games()
         .get(&game_token)
         .await
         .expect("Reading game has failed")
-        .filter(|game| game.state() != &GameState::Started)
+        .filter(|game| {
+            game.state() == &GameState::Initialized || game.state() == &GameState::Abandoned
+        }) {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    if let Err(rejection) = game.check_join(max_players_count) {
+        return Ok(reply_join_rejection(rejection));
+    }
+    if game
+        .password_hash()
+        .filter(|hash| !verify_password(password.unwrap_or_default(), hash))
+        .is_some()
     {
-        Some(mut game) => {
-            let player = create_new_player(&game_token, ctx).await;
-
-            game.add_player(player.id());
+        return Ok(reply_error_with_reason(
+            StatusCode::UNAUTHORIZED,
+            if password.is_some() {
+                "wrongPassword"
+            } else {
+                "passwordRequired"
+            },
+            "Game is protected by a password",
+        ));
+    }
 
-            match ctx.db().games().persist(&game).await {
-                Ok(_) => Ok(warp::reply::with_status(
-                    warp::reply::json(&AttendGameReponse {
-                        game: game.to_response(),
-                        token: String::from(player.user_token()),
-                    }),
-                    StatusCode::OK,
-                )),
-                Err(_) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR)),
+    let player = create_new_player(&game_token, ctx).await;
+    // the game may have changed since it has been read, so the event checks again whether the player may join
+    let player_joined = GameEvent::PlayerJoined {
+        player_id: String::from(player.id()),
+        max_players_count,
+    };
+    match ctx.db().apply_events(game_token, vec![player_joined]).await {
+        Ok(game) => Ok(warp::reply::with_status(
+            warp::reply::json(&AttendGameReponse {
+                game: game.to_response(),
+                token: String::from(player.user_token()),
+            }),
+            StatusCode::OK,
+        )),
+        Err(_) => {
+            if let Err(err) = ctx.db().players().remove(player.id()).await {
+                warn!("Removing rejected player has failed: {:?}", err);
             }
+            match ctx
+                .db()
+                .games()
+                .get(game_token)
+                .await
+                .expect("Reading game has failed")
+                .map(|game| game.check_join(max_players_count))
+            {
+                Some(Err(rejection)) => Ok(reply_join_rejection(rejection)),
+                Some(Ok(_)) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR)),
+                None => Ok(reply_error(StatusCode::NOT_FOUND)),
+            }
+        }
+    }
+}
+
+fn reply_join_rejection(rejection: JoinRejection) -> WithStatus<Json> {
+    let status = match rejection {
+        JoinRejection::LobbyLocked => StatusCode::LOCKED,
+        JoinRejection::AlreadyStarted | JoinRejection::LobbyFull => StatusCode::CONFLICT,
+    };
+    reply_error_with_reason(status, rejection, &rejection.to_string())
+}
+
+/// Spectators watch a game without taking a seat, so they are possible in every state of the game.
+pub async fn spectate_game_filter(
+    game_token: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => {
+            let spectator_id = nanoid!();
+            debug!("Spectator {} watches game {}", spectator_id, game_token);
+            Ok(warp::reply::with_status(
+                warp::reply::json(&AttendGameReponse {
+                    game: game.to_response(),
+                    token: generate_spectator_token(
+                        &spectator_id,
+                        game_token,
+                        &ctx.config().auth_secret,
+                    ),
+                }),
+                StatusCode::OK,
+            ))
         }
         None => Ok(reply_error(StatusCode::NOT_FOUND)),
     }
@@ -134,10 +255,13 @@ pub async fn leave_game_filter(
         .await
         .expect("Reading game has failed")
     {
-        Some(mut game) => match extract_verified_id(authorization, ctx) {
+        Some(_) => match extract_verified_id(authorization, ctx).await {
             Some(player_id) => {
-                game.remove_player(&player_id);
-                match ctx.db().games().persist(&game).await {
+                match ctx
+                    .db()
+                    .apply_events(game_token, vec![GameEvent::PlayerLeft { player_id }])
+                    .await
+                {
                     Ok(_) => Ok(reply_success(StatusCode::OK)),
                     Err(_) => Ok(reply_error_with_details(
                         StatusCode::INTERNAL_SERVER_ERROR,
@@ -151,9 +275,280 @@ pub async fn leave_game_filter(
     }
 }
 
+/// The admin removes another player from the game, whose token is invalidated by removing the player.
+pub async fn kick_player_filter(
+    game_token: &str,
+    player_id: &str,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    let game = match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    let admin_id = match extract_verified_id(authorization, ctx)
+        .await
+        .filter(|id| game.admin_id().as_deref() == Some(id.as_str()))
+    {
+        Some(admin_id) => admin_id,
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED)),
+    };
+    if game.state() != &GameState::Initialized {
+        return Ok(reply_error_with_details(
+            StatusCode::CONFLICT,
+            "Game has already started",
+        ));
+    }
+
+    let kicked = GameEvent::PlayerKicked {
+        player_id: admin_id,
+        target_id: String::from(player_id),
+    };
+    let game = match ctx.db().apply_events(game_token, vec![kicked]).await {
+        Ok(game) => game,
+        Err(err) => {
+            return Ok(reply_error_with_details(
+                StatusCode::BAD_REQUEST,
+                &err.to_string(),
+            ))
+        }
+    };
+    debug!(
+        "Player {} has been kicked from game {}",
+        player_id, game_token
+    );
+
+    if let Err(err) = ctx.db().players().remove(player_id).await {
+        warn!("Removing kicked player has failed: {:?}", err);
+    }
+    if let Err(err) = ctx.ws().close_connection(player_id).await {
+        warn!("Closing connection of kicked player failed: {:?}", err);
+    }
+    for other_player_id in game.all_player_ids() {
+        let mut msg = proto::message::Server::new();
+        msg.set_playerLeft(player_left_message(player_id));
+        if let Err(err) = ctx.ws().send_message(other_player_id, msg).await {
+            warn!("Informing about kicked player failed: {:?}", err);
+        }
+    }
+    let mut msg = proto::message::Server::new();
+    msg.set_playerLeft(player_left_message(player_id));
+    if let Err(err) = ctx.ws().broadcast_to_spectators(game_token, msg).await {
+        warn!("Informing spectators about kicked player failed: {:?}", err);
+    }
+
+    Ok(reply_success(StatusCode::OK))
+}
+
+/// The admin passes the admin role on to another player, everyone is informed by the updated game.
+pub async fn hand_over_admin_filter(
+    game_token: &str,
+    player_id: &str,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    let game = match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    let admin_id = match extract_verified_id(authorization, ctx)
+        .await
+        .filter(|id| game.admin_id().as_deref() == Some(id.as_str()))
+    {
+        Some(admin_id) => admin_id,
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED)),
+    };
+
+    let handed_over = GameEvent::AdminHandedOver {
+        player_id: admin_id,
+        target_id: String::from(player_id),
+    };
+    match ctx.db().apply_events(game_token, vec![handed_over]).await {
+        Ok(_) => {
+            debug!("Player {} is the admin of game {}", player_id, game_token);
+            Ok(reply_success(StatusCode::OK))
+        }
+        Err(err) => Ok(reply_error_with_details(
+            StatusCode::BAD_REQUEST,
+            &err.to_string(),
+        )),
+    }
+}
+
+/// The admin fills an empty seat of the lobby with a bot, which answers its tasks without a connection.
+pub async fn add_bot_filter(
+    game_token: &str,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    let game = match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    let admin_id = match extract_verified_id(authorization, ctx)
+        .await
+        .filter(|id| game.admin_id().as_deref() == Some(id.as_str()))
+    {
+        Some(admin_id) => admin_id,
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED)),
+    };
+    if game.is_full(ctx.config().max_players_count) {
+        return Ok(reply_error_with_reason(
+            StatusCode::CONFLICT,
+            "lobbyFull",
+            "Game has reached the maximum amount of players",
+        ));
+    }
+
+    let bot = Player::new_bot(game_token);
+    ctx.db()
+        .players()
+        .persist(&bot)
+        .await
+        .expect("Creating bot failed");
+    let bot_added = GameEvent::BotAdded {
+        player_id: admin_id,
+        bot_id: String::from(bot.id()),
+    };
+    let game = match ctx.db().apply_events(game_token, vec![bot_added]).await {
+        Ok(game) => game,
+        Err(err) => {
+            if let Err(err) = ctx.db().players().remove(bot.id()).await {
+                warn!("Removing rejected bot has failed: {:?}", err);
+            }
+            return Ok(reply_error_with_details(
+                StatusCode::BAD_REQUEST,
+                &err.to_string(),
+            ));
+        }
+    };
+    debug!("Bot {} has been added to game {}", bot.id(), game_token);
+
+    for other_player_id in game.all_player_ids() {
+        if other_player_id == bot.id() {
+            continue;
+        }
+        let mut msg = proto::message::Server::new();
+        msg.set_playerEntered(player_entered_message(&bot));
+        if let Err(err) = ctx.ws().send_message(other_player_id, msg).await {
+            warn!("Informing about added bot failed: {:?}", err);
+        }
+    }
+    let mut msg = proto::message::Server::new();
+    msg.set_playerEntered(player_entered_message(&bot));
+    if let Err(err) = ctx.ws().broadcast_to_spectators(game_token, msg).await {
+        warn!("Informing spectators about added bot failed: {:?}", err);
+    }
+
+    Ok(warp::reply::with_status(
+        warp::reply::json(&bot.to_response()),
+        StatusCode::CREATED,
+    ))
+}
+
+/// The admin protects the game with a password or removes it again.
+pub async fn change_password_filter(
+    game_token: &str,
+    password: Option<&str>,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    let password_hash = password
+        .filter(|password| !password.is_empty())
+        .map(hash_password);
+    update_lobby_access(game_token, authorization, ctx, |player_id| {
+        GameEvent::PasswordChanged {
+            player_id,
+            password_hash,
+        }
+    })
+    .await
+}
+
+/// The admin locks the lobby against new players or opens it again.
+pub async fn lock_lobby_filter(
+    game_token: &str,
+    locked: bool,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    update_lobby_access(game_token, authorization, ctx, |player_id| {
+        GameEvent::LobbyLocked { player_id, locked }
+    })
+    .await
+}
+
+async fn update_lobby_access(
+    game_token: &str,
+    authorization: &str,
+    ctx: &AppContext,
+    create_event: impl FnOnce(String) -> GameEvent,
+) -> Result<WithStatus<Json>, Infallible> {
+    let game = match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    let admin_id = match extract_verified_id(authorization, ctx)
+        .await
+        .filter(|id| game.admin_id().as_deref() == Some(id.as_str()))
+    {
+        Some(admin_id) => admin_id,
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED)),
+    };
+
+    match ctx
+        .db()
+        .apply_events(game_token, vec![create_event(admin_id)])
+        .await
+    {
+        Ok(_) => Ok(reply_success(StatusCode::OK)),
+        Err(err) => Ok(reply_error_with_details(
+            StatusCode::BAD_REQUEST,
+            &err.to_string(),
+        )),
+    }
+}
+
+fn player_left_message(player_id: &str) -> proto::message::Server_PlayerLeft {
+    let mut player_msg = proto::message::Server_PlayerLeft::new();
+    player_msg.set_player_id(String::from(player_id));
+    player_msg
+}
+
+fn player_entered_message(player: &Player) -> proto::message::Server_PlayerEntered {
+    let mut player_msg = proto::message::Server_PlayerEntered::new();
+    player_msg.set_player(player.clone().into());
+    player_msg
+}
+
 pub async fn start_game_filter(
     game_token: &str,
     authorization: &str,
+    force: bool,
     ctx: &AppContext,
 ) -> Result<impl warp::Reply, Infallible> {
     match ctx
@@ -163,29 +558,33 @@ pub async fn start_game_filter(
         .await
         .expect("Reading game has failed")
     {
-        Some(mut game) => match extract_verified_id(authorization, ctx)
+        Some(game) => match extract_verified_id(authorization, ctx)
+            .await
             .filter(|_| game.admin_id().is_some())
             .filter(|id| id == game.admin_id().as_ref().unwrap())
         {
+            Some(_) if game.state() != &GameState::Initialized => Ok(reply_error_with_details(
+                StatusCode::CONFLICT,
+                "Game has already started",
+            )),
             Some(_) => match ctx.db().players().get_batch(&game.all_player_ids()).await {
-                Ok(mut players) => {
-                    game.start();
-                    let players = players
-                        .values_mut()
-                        .map(|p| {
-                            p.resolve_task(TaskType::Settings);
-                            p.clone()
-                        })
-                        .collect::<Vec<_>>();
-                    let (persist_players, persist_game) = tokio::join!(
-                        ctx.db().players().persist_batch(&players),
-                        ctx.db().games().persist(&game)
-                    );
-                    match persist_players.and(persist_game) {
+                Ok(players) => match (
+                    check_lobby(&game, &players, ctx.config().max_players_count, force),
+                    game.validate_settings(),
+                ) {
+                    (Err(rejection), _) => Ok(reply_error_with_reason(
+                        StatusCode::CONFLICT,
+                        rejection,
+                        &rejection.to_string(),
+                    )),
+                    (Ok(_), Err(err)) => {
+                        Ok(reply_error_with_details(StatusCode::BAD_REQUEST, &err))
+                    }
+                    (Ok(_), Ok(_)) => match start_game(game_token, players, ctx).await {
                         Ok(_) => Ok(reply_success(StatusCode::OK)),
                         Err(_) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR)),
-                    }
-                }
+                    },
+                },
                 Err(_) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR)),
             },
             None => Ok(reply_error(StatusCode::UNAUTHORIZED)),
@@ -194,14 +593,88 @@ pub async fn start_game_filter(
     }
 }
 
-async fn create_new_game(admin_id: &str, token: &str, ctx: &AppContext) -> Game {
-    let new_game = Game::new(admin_id, token);
-    let new_token = new_game.token();
-    ctx.db()
+/// Starts another game with the same token, players and settings after the game has finished.
+/// The players have been ready for the last game already, so only their connection is checked again.
+pub async fn rematch_filter(
+    game_token: &str,
+    authorization: &str,
+    ctx: &AppContext,
+) -> Result<impl warp::Reply, Infallible> {
+    let game = match ctx
+        .db()
         .games()
-        .persist(&new_game)
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND)),
+    };
+    let admin_id = match extract_verified_id(authorization, ctx)
+        .await
+        .filter(|id| game.admin_id().as_deref() == Some(id.as_str()))
+    {
+        Some(id) => id,
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED)),
+    };
+    if !game.is_finished() {
+        return Ok(reply_error_with_details(
+            StatusCode::CONFLICT,
+            "Game has not finished",
+        ));
+    }
+
+    match ctx.db().players().get_batch(&game.all_player_ids()).await {
+        Ok(players) => match check_lobby(&game, &players, ctx.config().max_players_count, true) {
+            Err(rejection) => Ok(reply_error_with_reason(
+                StatusCode::CONFLICT,
+                rejection,
+                &rejection.to_string(),
+            )),
+            Ok(_) => match start_rematch(game_token, &admin_id, players, ctx).await {
+                Ok(_) => Ok(reply_success(StatusCode::OK)),
+                Err(err) => Ok(reply_error_with_details(StatusCode::BAD_REQUEST, &err)),
+            },
+        },
+        Err(_) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR)),
+    }
+}
+
+/// Checks whether the lobby is complete, so the admin can start the game.
+/// Forcing the start skips waiting for players who haven't marked themselves as ready.
+fn check_lobby(
+    game: &Game,
+    players: &HashMap<String, Player>,
+    max_players_count: usize,
+    force: bool,
+) -> Result<(), StartRejection> {
+    game.check_players_count(max_players_count)?;
+    // players who have lost their connection can't take part in the game
+    if players
+        .values()
+        .any(|player| player.last_active_time().is_some())
+    {
+        return Err(StartRejection::PlayersNotReady);
+    }
+    // the admin is ready by starting the game
+    if !force
+        && players
+            .values()
+            .filter(|player| game.admin_id().as_deref() != Some(player.id()))
+            .any(|player| !player.is_ready())
+    {
+        return Err(StartRejection::PlayersNotReady);
+    }
+    Ok(())
+}
+
+async fn create_new_game(admin_id: &str, token: &str, ctx: &AppContext) -> Game {
+    let new_game = ctx
+        .db()
+        .create_game(admin_id, token)
         .await
         .expect("Creating game failed");
+    let new_token = new_game.token();
     debug!("Created game with token {}", new_token);
 
     new_game
@@ -226,17 +699,61 @@ async fn create_new_player(game_token: &str, ctx: &AppContext) -> Player {
 #[cfg(test)]
 mod tests {
     use super::{
-        attend_game_filter, create_game_filter, get_game_filter, leave_game_filter,
-        start_game_filter,
+        add_bot_filter, attend_game_filter, change_password_filter, create_game_filter,
+        get_game_filter, hand_over_admin_filter, kick_player_filter, leave_game_filter,
+        lock_lobby_filter, rematch_filter, spectate_game_filter, start_game_filter,
     };
     use crate::{
-        model::{Game, GameState, Player},
-        server::{app_context::AppContext, auth::generate_jwt_token},
+        model::{
+            ChatAudience, ClanLeaderKnowledge, Game, GameSettings, GameState, Player, Role,
+            RoleDistribution, TaskDefinition, TaskType,
+        },
+        server::{
+            app_context::AppContext,
+            auth::{extract_verified_spectator, generate_jwt_token},
+        },
     };
-    use warp::{hyper::StatusCode, Reply};
+    use warp::{hyper::StatusCode, reply::Response, Reply};
 
     const GAME_TOKEN: &str = "ACDEF";
 
+    async fn init_lobby(ctx: &AppContext, mut game: Game, players_count: usize) -> Vec<Player> {
+        let mut players = vec![];
+        for _ in 0..players_count {
+            let mut player = Player::new(GAME_TOKEN);
+            player.set_ready(true);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Writing players failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Writing game failed");
+        players
+    }
+
+    async fn persist_player(ctx: &AppContext, player: &Player) {
+        ctx.db()
+            .players()
+            .persist(player)
+            .await
+            .expect("Writing player failed");
+    }
+
+    async fn read_reason(reply: Response) -> String {
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("Parsing body failed");
+        String::from(json["reason"].as_str().unwrap_or_default())
+    }
+
     #[tokio::test]
     async fn should_not_get_game_unauthorized() {
         let ctx = AppContext::init();
@@ -252,7 +769,9 @@ mod tests {
     async fn should_not_get_unknown_game() {
         let ctx = AppContext::init();
 
-        let token = generate_jwt_token(&Player::new("game"), &ctx.config().auth_secret);
+        let player = Player::new("game");
+        persist_player(&ctx, &player).await;
+        let token = generate_jwt_token(&player, &ctx.config().auth_secret);
 
         let reply = get_game_filter("game", &token, &ctx).await;
         assert_eq!(
@@ -266,6 +785,7 @@ mod tests {
         let ctx = AppContext::init();
 
         let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
         ctx.db()
             .players()
             .persist(&admin)
@@ -288,6 +808,7 @@ mod tests {
         let ctx = AppContext::init();
 
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         ctx.db()
             .players()
             .persist(&player)
@@ -315,11 +836,44 @@ mod tests {
         assert_eq!(reply.unwrap().into_response().status(), StatusCode::CREATED);
     }
 
+    #[tokio::test]
+    async fn should_rebuild_game_from_events() {
+        let ctx = AppContext::init();
+        let reply = create_game_filter(&ctx).await.unwrap().into_response();
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("Parsing body failed");
+        let game_token = json["game"]["token"].as_str().unwrap();
+        let admin_token = json["token"].as_str().unwrap();
+        for _ in 0..4 {
+            let reply = attend_game_filter(game_token, None, &ctx).await;
+            assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        }
+        let reply = start_game_filter(game_token, admin_token, true, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let events = ctx
+            .db()
+            .events()
+            .read(game_token)
+            .expect("Reading events failed");
+        assert_eq!(events.len(), 6);
+        let game = ctx
+            .db()
+            .games()
+            .get(game_token)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert_eq!(Game::replay(&events), Ok(game));
+    }
+
     #[tokio::test]
     async fn should_not_attend_unknown_game() {
         let ctx = AppContext::init();
 
-        let reply = attend_game_filter("test", &ctx).await;
+        let reply = attend_game_filter("test", None, &ctx).await;
         assert_eq!(
             reply.unwrap().into_response().status(),
             StatusCode::NOT_FOUND
@@ -331,14 +885,14 @@ mod tests {
         let ctx = AppContext::init();
 
         let mut game = Game::new("admin", GAME_TOKEN);
-        game.start();
+        game.start().expect("Starting game has failed");
         ctx.db()
             .games()
             .persist(&game)
             .await
             .expect("Writing game failed");
 
-        let reply = attend_game_filter(GAME_TOKEN, &ctx).await;
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx).await;
         assert_eq!(
             reply.unwrap().into_response().status(),
             StatusCode::NOT_FOUND
@@ -355,7 +909,7 @@ mod tests {
             .await
             .expect("Writing game failed");
 
-        let reply = attend_game_filter(GAME_TOKEN, &ctx).await;
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx).await;
         assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
 
         let updated_game = ctx
@@ -373,6 +927,7 @@ mod tests {
 
         let mut game = Game::new("admin", GAME_TOKEN);
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         let token = generate_jwt_token(&player, &ctx.config().auth_secret);
         game.add_player(player.id());
 
@@ -402,6 +957,7 @@ mod tests {
         let ctx = AppContext::init();
 
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         let game = Game::new(player.id(), GAME_TOKEN);
         let token = generate_jwt_token(&player, &ctx.config().auth_secret);
 
@@ -430,6 +986,7 @@ mod tests {
         let ctx = AppContext::init();
 
         let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
         let mut game = Game::new(admin.id(), GAME_TOKEN);
         let player = Player::new(GAME_TOKEN);
         let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
@@ -461,15 +1018,11 @@ mod tests {
     async fn should_start_game() {
         let ctx = AppContext::init();
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         let token = generate_jwt_token(&player, &ctx.config().auth_secret);
+        init_lobby(&ctx, Game::new(player.id(), GAME_TOKEN), 4).await;
 
-        ctx.db()
-            .games()
-            .persist(&Game::new(player.id(), GAME_TOKEN))
-            .await
-            .expect("Writing game failed");
-
-        let reply = start_game_filter(GAME_TOKEN, &token, &ctx).await;
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
         assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
 
         let updated_game = ctx
@@ -480,25 +1033,490 @@ mod tests {
             .expect("Couldn't find game")
             .unwrap();
         assert_eq!(updated_game.state(), &GameState::Started);
+        assert!(updated_game.role(player.id()).is_some());
     }
 
     #[tokio::test]
-    async fn should_not_start_game() {
+    async fn should_not_start_running_game_again() {
         let ctx = AppContext::init();
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         let token = generate_jwt_token(&player, &ctx.config().auth_secret);
+        init_lobby(&ctx, Game::new(player.id(), GAME_TOKEN), 4).await;
 
-        ctx.db()
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let game = ctx
+            .db()
             .games()
-            .persist(&Game::new("admin", GAME_TOKEN))
+            .get(GAME_TOKEN)
             .await
-            .expect("Writing game failed");
-
-        let reply = start_game_filter(GAME_TOKEN, &token, &ctx).await;
-        assert_eq!(
-            reply.unwrap().into_response().status(),
-            StatusCode::UNAUTHORIZED
-        );
+            .expect("Couldn't find game")
+            .unwrap();
+
+        for force in &[false, true] {
+            let reply = start_game_filter(GAME_TOKEN, &token, *force, &ctx).await;
+            assert_eq!(
+                reply.unwrap().into_response().status(),
+                StatusCode::CONFLICT
+            );
+        }
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Couldn't find game")
+            .unwrap();
+        assert_eq!(updated_game.seats(), game.seats());
+    }
+
+    #[tokio::test]
+    async fn should_assign_roles_after_start() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..6 {
+            let mut player = Player::new(GAME_TOKEN);
+            player.assign_task(TaskDefinition::Settings {});
+            player.set_ready(true);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Writing players failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Writing game failed");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Couldn't find game")
+            .unwrap();
+        let roles = players
+            .iter()
+            .filter_map(|p| updated_game.role(p.id()))
+            .collect::<Vec<_>>();
+        assert_eq!(roles.iter().filter(|r| **r == Role::ClanLeader).count(), 1);
+        assert_eq!(roles.iter().filter(|r| **r == Role::Clan).count(), 2);
+        assert_eq!(roles.iter().filter(|r| **r == Role::Loyal).count(), 4);
+
+        let updated_players = ctx
+            .db()
+            .players()
+            .get_batch(&updated_game.all_player_ids())
+            .await
+            .expect("Reading players failed");
+        for player in updated_players.values() {
+            match player.open_tasks().front() {
+                Some(TaskDefinition::DiscoverRole {
+                    role,
+                    clan_member_ids,
+                    clan_leader_id,
+                }) => {
+                    assert_eq!(Some(*role), updated_game.role(player.id()));
+                    match role {
+                        Role::Clan => {
+                            assert_eq!(clan_member_ids.len(), 1);
+                            assert!(clan_leader_id.is_some());
+                        }
+                        // the leader doesn't know the members in games with seven players
+                        _ => {
+                            assert!(clan_member_ids.is_empty());
+                            assert!(clan_leader_id.is_none());
+                        }
+                    }
+                }
+                _ => panic!("Role should be discoverable"),
+            }
+        }
+
+        let president = updated_players
+            .get(updated_game.presidential_candidate_id().unwrap())
+            .unwrap();
+        assert_eq!(
+            president.open_tasks().get(1).map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_deal_roles_from_settings() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..6 {
+            let mut player = Player::new(GAME_TOKEN);
+            player.set_ready(true);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.update_settings(GameSettings::new(
+            vec![RoleDistribution {
+                player_count: 7,
+                clan_members_count: 1,
+            }],
+            ClanLeaderKnowledge::AlwaysKnown,
+            true,
+            None,
+            ChatAudience::LivingPlayers,
+        ))
+        .expect("Updating settings failed");
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Writing players failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Writing game failed");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Couldn't find game")
+            .unwrap();
+        let clan_members = players
+            .iter()
+            .filter(|p| updated_game.role(p.id()) == Some(Role::Clan))
+            .count();
+        assert_eq!(clan_members, 1);
+        let leader_id = updated_game.clan_leader_id().unwrap();
+        assert_eq!(updated_game.known_clan_member_ids(leader_id).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_start_game_with_too_few_players() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 3).await;
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::CONFLICT);
+        assert_eq!(read_reason(reply).await, "tooFewPlayers");
+    }
+
+    #[tokio::test]
+    async fn should_not_start_game_with_players_not_ready() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let mut players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 5).await;
+        players[0].set_inactive();
+        ctx.db()
+            .players()
+            .persist(&players[0])
+            .await
+            .expect("Writing player failed");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::CONFLICT);
+        assert_eq!(read_reason(reply).await, "playersNotReady");
+    }
+
+    #[tokio::test]
+    async fn should_only_force_start_with_players_not_ready() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let mut players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 4).await;
+        players[0].set_ready(false);
+        ctx.db()
+            .players()
+            .persist(&players[0])
+            .await
+            .expect("Writing player failed");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::CONFLICT);
+        assert_eq!(read_reason(reply).await, "playersNotReady");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, true, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_only_attend_protected_game_with_password() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 1).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+
+        let reply = change_password_filter(GAME_TOKEN, Some("secret"), "invalid", &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let reply = change_password_filter(GAME_TOKEN, Some("secret"), &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(read_reason(reply).await, "passwordRequired");
+        let reply = attend_game_filter(GAME_TOKEN, Some("wrong"), &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(read_reason(reply).await, "wrongPassword");
+        let reply = attend_game_filter(GAME_TOKEN, Some("secret"), &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let reply = change_password_filter(GAME_TOKEN, None, &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_not_attend_locked_game() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 1).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let player_token = generate_jwt_token(&players[0], &ctx.config().auth_secret);
+
+        let reply = lock_lobby_filter(GAME_TOKEN, true, &player_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let reply = lock_lobby_filter(GAME_TOKEN, true, &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::LOCKED);
+        assert_eq!(read_reason(reply).await, "lobbyLocked");
+
+        let reply = lock_lobby_filter(GAME_TOKEN, false, &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn should_not_attend_full_game() {
+        let ctx = AppContext::init();
+        init_lobby(
+            &ctx,
+            Game::new("admin", GAME_TOKEN),
+            ctx.config().max_players_count - 1,
+        )
+        .await;
+
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::CONFLICT);
+        assert_eq!(read_reason(reply).await, "lobbyFull");
+    }
+
+    #[tokio::test]
+    async fn should_report_locked_game_before_full_game() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        init_lobby(
+            &ctx,
+            Game::new(admin.id(), GAME_TOKEN),
+            ctx.config().max_players_count - 1,
+        )
+        .await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+
+        let reply = lock_lobby_filter(GAME_TOKEN, true, &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let reply = attend_game_filter(GAME_TOKEN, None, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::LOCKED);
+        assert_eq!(read_reason(reply).await, "lobbyLocked");
+    }
+
+    #[tokio::test]
+    async fn should_spectate_game_without_taking_a_seat() {
+        let ctx = AppContext::init();
+        let players = init_lobby(
+            &ctx,
+            Game::new("admin", GAME_TOKEN),
+            ctx.config().max_players_count - 1,
+        )
+        .await;
+
+        let reply = spectate_game_filter(GAME_TOKEN, &ctx)
+            .await
+            .unwrap()
+            .into_response();
+        assert_eq!(reply.status(), StatusCode::OK);
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("Parsing body failed");
+        let token = json["token"].as_str().unwrap();
+        let (_, game_token) = extract_verified_spectator(token, &ctx).unwrap();
+        assert_eq!(game_token, GAME_TOKEN);
+
+        let game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert_eq!(game.players_count(), players.len() + 1);
+
+        let reply = leave_game_filter(GAME_TOKEN, token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        let reply = spectate_game_filter("OTHER", &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn should_kick_player() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 2).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let player_token = generate_jwt_token(&players[0], &ctx.config().auth_secret);
+
+        let reply = kick_player_filter(GAME_TOKEN, players[1].id(), &player_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let reply = kick_player_filter(GAME_TOKEN, admin.id(), &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+
+        let reply = kick_player_filter(GAME_TOKEN, players[1].id(), &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert!(!game.player_ids().contains(players[1].id()));
+        assert!(game.player_ids().contains(players[0].id()));
+        assert!(ctx
+            .db()
+            .players()
+            .get(players[1].id())
+            .await
+            .expect("Reading player failed")
+            .is_none());
+
+        // the token of the kicked player isn't accepted anymore
+        let kicked_token = generate_jwt_token(&players[1], &ctx.config().auth_secret);
+        let reply = get_game_filter(GAME_TOKEN, &kicked_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_kick_player_of_running_game() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 4).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let reply = start_game_filter(GAME_TOKEN, &admin_token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let reply = kick_player_filter(GAME_TOKEN, players[0].id(), &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::CONFLICT
+        );
+        assert!(ctx
+            .db()
+            .players()
+            .get(players[0].id())
+            .await
+            .expect("Reading player failed")
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn should_not_start_game() {
+        let ctx = AppContext::init();
+        let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
+        let token = generate_jwt_token(&player, &ctx.config().auth_secret);
+
+        ctx.db()
+            .games()
+            .persist(&Game::new("admin", GAME_TOKEN))
+            .await
+            .expect("Writing game failed");
+
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
 
         let updated_game = ctx
             .db()
@@ -513,9 +1531,124 @@ mod tests {
     async fn should_not_start_unknown_game() {
         let ctx = AppContext::init();
         let player = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &player).await;
         let token = generate_jwt_token(&player, &ctx.config().auth_secret);
 
-        let reply = start_game_filter(GAME_TOKEN, &token, &ctx).await;
+        let reply = start_game_filter(GAME_TOKEN, &token, false, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::NOT_FOUND
+        );
+    }
+
+    #[tokio::test]
+    async fn should_hand_over_admin_role() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 2).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let player_token = generate_jwt_token(&players[0], &ctx.config().auth_secret);
+
+        let reply = hand_over_admin_filter(GAME_TOKEN, players[1].id(), &player_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let reply = hand_over_admin_filter(GAME_TOKEN, "unknown", &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+
+        let reply = hand_over_admin_filter(GAME_TOKEN, players[1].id(), &admin_token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+
+        let game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert_eq!(game.admin_id().as_deref(), Some(players[1].id()));
+        assert!(game.player_ids().contains(admin.id()));
+        assert!(!game.player_ids().contains(players[1].id()));
+    }
+
+    #[tokio::test]
+    async fn should_add_bot_as_admin() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 1).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let player_token = generate_jwt_token(&players[0], &ctx.config().auth_secret);
+
+        let reply = add_bot_filter(GAME_TOKEN, &player_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        for _ in 0..3 {
+            let reply = add_bot_filter(GAME_TOKEN, &admin_token, &ctx).await;
+            assert_eq!(reply.unwrap().into_response().status(), StatusCode::CREATED);
+        }
+
+        let game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert_eq!(game.players_count(), 5);
+        assert_eq!(game.bot_ids().len(), 3);
+        let bots = ctx
+            .db()
+            .players()
+            .get_batch(&game.bot_ids().iter().cloned().collect::<Vec<_>>())
+            .await
+            .expect("Reading bots failed");
+        assert!(bots.values().all(|bot| bot.is_bot() && bot.is_ready()));
+
+        // bots are ready right away, so the game can start without further players
+        let reply = start_game_filter(GAME_TOKEN, &admin_token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let reply = add_bot_filter(GAME_TOKEN, &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::BAD_REQUEST
+        );
+    }
+
+    #[tokio::test]
+    async fn should_only_allow_rematch_of_finished_game_by_admin() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        persist_player(&ctx, &admin).await;
+        let players = init_lobby(&ctx, Game::new(admin.id(), GAME_TOKEN), 4).await;
+        let admin_token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let player_token = generate_jwt_token(&players[0], &ctx.config().auth_secret);
+
+        let reply = rematch_filter(GAME_TOKEN, &player_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let reply = rematch_filter(GAME_TOKEN, &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::CONFLICT
+        );
+        let reply = start_game_filter(GAME_TOKEN, &admin_token, false, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let reply = rematch_filter(GAME_TOKEN, &admin_token, &ctx).await;
+        assert_eq!(
+            reply.unwrap().into_response().status(),
+            StatusCode::CONFLICT
+        );
+        let reply = rematch_filter("OTHER", &admin_token, &ctx).await;
         assert_eq!(
             reply.unwrap().into_response().status(),
             StatusCode::NOT_FOUND
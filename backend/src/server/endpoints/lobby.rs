@@ -0,0 +1,109 @@
+use crate::{model::GameState, server::app_context::AppContext};
+use log::debug;
+
+/// Marks the player as ready or not, which is only possible as long as the game hasn't started.
+/// Everyone in the game is informed with `PlayerUpdated` by the change listener.
+pub async fn change_ready(ready: bool, peer_id: &str, ctx: &AppContext) -> Result<(), String> {
+    let player_id = ctx
+        .ws()
+        .get_authenticated_player_for_peer(peer_id)
+        .await
+        .ok_or_else(|| String::from("Unauthorized user"))?;
+    let mut player = ctx
+        .db()
+        .players()
+        .get(&player_id)
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("Player not found"))?;
+    let game = ctx
+        .db()
+        .games()
+        .get(player.game_token())
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("Game not found"))?;
+
+    if game.state() != &GameState::Initialized {
+        return Err(format!("Game {} has already started", game.token()));
+    }
+    if player.is_ready() == ready {
+        return Ok(());
+    }
+
+    player.set_ready(ready);
+    ctx.db()
+        .players()
+        .persist(&player)
+        .await
+        .map_err(|err| err.to_string())?;
+    debug!("Player {} is ready: {}", player.id(), ready);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::change_ready;
+    use crate::{
+        model::{Game, Player},
+        server::app_context::AppContext,
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_lobby(ctx: &AppContext, game: &Game, player: &Player) {
+        ctx.db()
+            .players()
+            .persist(player)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(game)
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(player.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+    }
+
+    async fn is_ready(ctx: &AppContext, player: &Player) -> bool {
+        ctx.db()
+            .players()
+            .get(player.id())
+            .await
+            .expect("Reading player has failed")
+            .unwrap()
+            .is_ready()
+    }
+
+    #[tokio::test]
+    async fn should_toggle_ready_in_lobby() {
+        let ctx = AppContext::init();
+        let player = Player::new(GAME_TOKEN);
+        init_lobby(&ctx, &Game::new("admin", GAME_TOKEN), &player).await;
+
+        assert!(change_ready(true, "peer", &ctx).await.is_ok());
+        assert!(is_ready(&ctx, &player).await);
+        assert!(change_ready(false, "peer", &ctx).await.is_ok());
+        assert!(!is_ready(&ctx, &player).await);
+        assert!(change_ready(true, "unknown-peer", &ctx).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn should_not_change_ready_after_start() {
+        let ctx = AppContext::init();
+        let player = Player::new(GAME_TOKEN);
+        let mut game = Game::new("admin", GAME_TOKEN);
+        for id in &["player1", "player2", "player3"] {
+            game.add_player(id);
+        }
+        game.add_player(player.id());
+        game.start().expect("Starting game has failed");
+        init_lobby(&ctx, &game, &player).await;
+
+        assert!(change_ready(true, "peer", &ctx).await.is_err());
+        assert!(!is_ready(&ctx, &player).await);
+    }
+}
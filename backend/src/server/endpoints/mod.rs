@@ -1,5 +1,9 @@
 pub mod active_game;
+pub mod chat;
 pub mod client_messages;
+pub mod collective_tasks;
 pub mod games;
+pub mod lobby;
 pub mod players;
+pub mod replays;
 pub mod tasks;
@@ -0,0 +1,412 @@
+use crate::{
+    model::{
+        proto::{self},
+        Game, GameState, LoggedEvent, Party, Role, WinReason,
+    },
+    server::{
+        app_context::AppContext,
+        auth::extract_verified_player,
+        reply::{reply_error, reply_error_with_details},
+    },
+};
+use protobuf::{Message, RepeatedField};
+use serde::Serialize;
+use std::convert::Infallible;
+use warp::{hyper::StatusCode, reply::Response, Reply};
+
+const PROTOBUF_CONTENT_TYPES: [&str; 2] = ["application/x-protobuf", "application/protobuf"];
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PlayerRoleResponse {
+    player_id: String,
+    role: Role,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReplayResponse {
+    token: String,
+    winner: Party,
+    reason: WinReason,
+    roles: Vec<PlayerRoleResponse>,
+    events: Vec<LoggedEvent>,
+}
+
+/// Returns all events of a finished game together with the roles, which have been hidden during the game.
+/// The replay is sent as JSON unless the client accepts protobuf.
+pub async fn get_replay_filter(
+    game_token: &str,
+    authorization: &str,
+    accept: Option<String>,
+    ctx: &AppContext,
+) -> Result<Response, Infallible> {
+    // the hidden roles are only revealed to the players of the game
+    match extract_verified_player(authorization, ctx).await {
+        Some(player) if player.game_token() == game_token => {}
+        Some(_) => return Ok(reply_error(StatusCode::FORBIDDEN).into_response()),
+        None => return Ok(reply_error(StatusCode::UNAUTHORIZED).into_response()),
+    }
+    let game = match ctx
+        .db()
+        .games()
+        .get(game_token)
+        .await
+        .expect("Reading game has failed")
+    {
+        Some(game) => game,
+        None => return Ok(reply_error(StatusCode::NOT_FOUND).into_response()),
+    };
+    let (winner, reason) = match game.state() {
+        GameState::Finished { winner, reason } => (*winner, *reason),
+        _ => {
+            return Ok(
+                reply_error_with_details(StatusCode::CONFLICT, "Game has not finished")
+                    .into_response(),
+            )
+        }
+    };
+    // the roles are only known for the last game, so earlier games of the lobby are left out
+    let events = match ctx.db().events().read(game_token) {
+        Ok(events) => LoggedEvent::latest_game(&events).to_vec(),
+        Err(_) => return Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR).into_response()),
+    };
+
+    let replay = ReplayResponse {
+        token: String::from(game.token()),
+        winner,
+        reason,
+        roles: revealed_roles(&game),
+        events,
+    };
+    if accepts_protobuf(accept) {
+        match Into::<proto::replay::Replay>::into(replay).write_to_bytes() {
+            Ok(bytes) => {
+                Ok(
+                    warp::reply::with_header(bytes, "content-type", PROTOBUF_CONTENT_TYPES[0])
+                        .into_response(),
+                )
+            }
+            Err(_) => Ok(reply_error(StatusCode::INTERNAL_SERVER_ERROR).into_response()),
+        }
+    } else {
+        Ok(warp::reply::with_status(warp::reply::json(&replay), StatusCode::OK).into_response())
+    }
+}
+
+fn accepts_protobuf(accept: Option<String>) -> bool {
+    accept
+        .filter(|accept| {
+            PROTOBUF_CONTENT_TYPES
+                .iter()
+                .any(|content_type| accept.contains(content_type))
+        })
+        .is_some()
+}
+
+fn revealed_roles(game: &Game) -> Vec<PlayerRoleResponse> {
+    game.seats()
+        .iter()
+        .filter_map(|id| {
+            game.role(id).map(|role| PlayerRoleResponse {
+                player_id: String::from(id),
+                role,
+            })
+        })
+        .collect()
+}
+
+impl From<ReplayResponse> for proto::replay::Replay {
+    fn from(response: ReplayResponse) -> Self {
+        let mut replay = proto::replay::Replay::new();
+        replay.set_token(response.token);
+        replay.set_winner(response.winner.into());
+        replay.set_reason(response.reason.into());
+        replay.set_roles(RepeatedField::from_vec(
+            response
+                .roles
+                .into_iter()
+                .map(|player_role| {
+                    let mut role = proto::role::PlayerRole::new();
+                    role.set_player_id(player_role.player_id);
+                    role.set_role(player_role.role.into());
+                    role
+                })
+                .collect(),
+        ));
+        replay.set_events(RepeatedField::from_vec(
+            response
+                .events
+                .into_iter()
+                .map(|event| event.into())
+                .collect(),
+        ));
+        replay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::get_replay_filter;
+    use crate::{
+        model::{proto, ExecutivePower, Game, GameEvent, GameState, Player, Policy},
+        server::{
+            app_context::AppContext, auth::generate_jwt_token, endpoints::games::rematch_filter,
+        },
+    };
+    use protobuf::Message;
+    use warp::{hyper::StatusCode, Reply};
+
+    const GAME_TOKEN: &str = "GAME";
+
+    /// Plays a game with five players until it is finished.
+    async fn init_finished_game(ctx: &AppContext) -> String {
+        let admin = Player::new(GAME_TOKEN);
+        ctx.db()
+            .players()
+            .persist(&admin)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .create_game(admin.id(), GAME_TOKEN)
+            .await
+            .expect("Creating game has failed");
+        let mut events = (0..4)
+            .map(|i| GameEvent::PlayerJoined {
+                player_id: format!("player{}", i),
+                max_players_count: ctx.config().max_players_count,
+            })
+            .collect::<Vec<_>>();
+        events.push(GameEvent::GameStarted);
+        let mut game = ctx
+            .db()
+            .apply_events(GAME_TOKEN, events)
+            .await
+            .expect("Starting game has failed");
+
+        while !game.is_finished() {
+            game = ctx
+                .db()
+                .apply_events(GAME_TOKEN, next_events(&game))
+                .await
+                .expect("Applying events has failed");
+        }
+
+        generate_jwt_token(&admin, &ctx.config().auth_secret)
+    }
+
+    /// Elects governments without the clan leader, which prefer clan policies and execute the clan leader whenever possible.
+    fn next_events(game: &Game) -> Vec<GameEvent> {
+        let leader_id = String::from(game.clan_leader_id().unwrap());
+        let president_id = game.president_id().map(String::from);
+        if let Some(power) = game.pending_power() {
+            let player_id = president_id.unwrap();
+            let target_ids = game.eligible_power_target_ids();
+            let target_id = if target_ids.contains(&leader_id) {
+                leader_id
+            } else {
+                target_ids[0].clone()
+            };
+            return vec![match power {
+                ExecutivePower::PolicyPeek => GameEvent::PoliciesPeeked { player_id },
+                ExecutivePower::InvestigateLoyalty => GameEvent::LoyaltyInvestigated {
+                    player_id,
+                    target_id,
+                },
+                ExecutivePower::SpecialElection => GameEvent::SpecialElectionCalled {
+                    player_id,
+                    target_id,
+                },
+                ExecutivePower::Execution => GameEvent::PlayerExecuted {
+                    player_id,
+                    target_id,
+                },
+            }];
+        }
+        if !game.legislative_policies().is_empty() {
+            let policies = game.legislative_policies();
+            let chancellor_id = String::from(game.chancellor_id().unwrap());
+            let preferred = if policies.contains(&Policy::Clan) {
+                Policy::Clan
+            } else {
+                policies[0]
+            };
+            return if policies.len() == 3 {
+                let discarded = *policies
+                    .iter()
+                    .find(|p| **p != preferred)
+                    .unwrap_or(&preferred);
+                vec![GameEvent::PolicyDiscarded {
+                    player_id: president_id.unwrap(),
+                    policy: discarded,
+                }]
+            } else {
+                vec![GameEvent::PolicyEnacted {
+                    player_id: chancellor_id,
+                    policy: preferred,
+                }]
+            };
+        }
+
+        let candidate_id = String::from(game.presidential_candidate_id().unwrap());
+        let chancellor_id = game
+            .eligible_chancellor_ids()
+            .into_iter()
+            .find(|id| *id != leader_id)
+            .unwrap();
+        let mut events = vec![GameEvent::ChancellorNominated {
+            player_id: candidate_id,
+            chancellor_id,
+        }];
+        for player_id in game.living_player_ids() {
+            events.push(GameEvent::VoteCast {
+                player_id,
+                approved: true,
+            });
+        }
+        events.push(GameEvent::ElectionFinished);
+        events
+    }
+
+    #[tokio::test]
+    async fn should_get_replay_as_json() {
+        let ctx = AppContext::init();
+        let token = init_finished_game(&ctx).await;
+
+        let reply = get_replay_filter(GAME_TOKEN, &token, None, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(reply.status(), StatusCode::OK);
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("Parsing body failed");
+        let events = ctx
+            .db()
+            .events()
+            .read(GAME_TOKEN)
+            .expect("Reading events failed");
+        assert_eq!(json["events"].as_array().unwrap().len(), events.len());
+        assert!(json["events"][0]["event"]["gameCreated"]["adminId"].is_string());
+        assert_eq!(json["roles"].as_array().unwrap().len(), 5);
+        assert!(json["winner"].is_string());
+    }
+
+    #[tokio::test]
+    async fn should_get_replay_as_protobuf() {
+        let ctx = AppContext::init();
+        let token = init_finished_game(&ctx).await;
+
+        let reply = get_replay_filter(
+            GAME_TOKEN,
+            &token,
+            Some(String::from("application/x-protobuf")),
+            &ctx,
+        )
+        .await
+        .unwrap();
+        assert_eq!(reply.status(), StatusCode::OK);
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let replay =
+            proto::replay::Replay::parse_from_bytes(&body).expect("Parsing replay has failed");
+        assert_eq!(replay.get_token(), GAME_TOKEN);
+        assert_eq!(replay.get_roles().len(), 5);
+        assert!(replay.get_events()[0].has_gameCreated());
+        assert_eq!(
+            replay.get_events().len(),
+            ctx.db()
+                .events()
+                .read(GAME_TOKEN)
+                .expect("Reading events failed")
+                .len()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_get_replay_of_running_game() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        ctx.db()
+            .players()
+            .persist(&admin)
+            .await
+            .expect("Persisting player has failed");
+        let token = generate_jwt_token(&admin, &ctx.config().auth_secret);
+        let game = ctx
+            .db()
+            .create_game(admin.id(), GAME_TOKEN)
+            .await
+            .expect("Creating game has failed");
+        assert_eq!(game.state(), &GameState::Initialized);
+
+        let reply = get_replay_filter(GAME_TOKEN, &token, None, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(reply.status(), StatusCode::CONFLICT);
+
+        let reply = get_replay_filter(GAME_TOKEN, "invalid", None, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(reply.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn should_not_get_replay_of_other_game() {
+        let ctx = AppContext::init();
+        init_finished_game(&ctx).await;
+        let other_player = Player::new("OTHER");
+        ctx.db()
+            .players()
+            .persist(&other_player)
+            .await
+            .expect("Persisting player has failed");
+        let token = generate_jwt_token(&other_player, &ctx.config().auth_secret);
+
+        let reply = get_replay_filter(GAME_TOKEN, &token, None, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(reply.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn should_only_replay_latest_game_after_rematch() {
+        let ctx = AppContext::init();
+        let token = init_finished_game(&ctx).await;
+
+        let reply = rematch_filter(GAME_TOKEN, &token, &ctx).await;
+        assert_eq!(reply.unwrap().into_response().status(), StatusCode::OK);
+        let mut game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game failed")
+            .unwrap();
+        assert_eq!(game.state(), &GameState::Started);
+        while !game.is_finished() {
+            game = ctx
+                .db()
+                .apply_events(GAME_TOKEN, next_events(&game))
+                .await
+                .expect("Applying events has failed");
+        }
+
+        let reply = get_replay_filter(GAME_TOKEN, &token, None, &ctx)
+            .await
+            .unwrap();
+        assert_eq!(reply.status(), StatusCode::OK);
+        let body = warp::hyper::body::to_bytes(reply.into_body())
+            .await
+            .expect("Reading body failed");
+        let json: serde_json::Value = serde_json::from_slice(&body).expect("Parsing body failed");
+        let events = json["events"].as_array().unwrap();
+        assert!(events[0]["event"]["rematchStarted"]["playerId"].is_string());
+        assert!(events
+            .iter()
+            .skip(1)
+            .all(|event| event["event"]["rematchStarted"].is_null()));
+        assert_eq!(json["roles"].as_array().unwrap().len(), 5);
+    }
+}
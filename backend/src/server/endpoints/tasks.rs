@@ -1,55 +1,88 @@
-use crate::{model::Task, server::app_context::AppContext};
+use crate::{
+    model::{Player, Task},
+    server::app_context::AppContext,
+};
 use log::warn;
 pub async fn apply_task<T: Task>(task: T, peer_id: &str, ctx: &AppContext) -> Result<(), String> {
     match ctx.ws().get_authenticated_player_for_peer(peer_id).await {
-        Some(player_id) => match ctx
-            .db()
-            .players()
-            .get(&player_id)
-            .await
-            .expect("Reading player has failed")
-        {
-            Some(player) => {
-                // Check if task is assigned
-                if player
+        Some(player_id) => apply_player_task(task, &player_id, ctx).await,
+        None => Err(String::from("Player not authenticated")),
+    }
+}
+
+/// Executed players can't answer any tasks for the rest of the game, which only the game knows about.
+pub async fn is_executed(player: &Player, ctx: &AppContext) -> bool {
+    ctx.db()
+        .games()
+        .get(player.game_token())
+        .await
+        .expect("Reading game has failed")
+        .filter(|game| game.is_executed(player.id()))
+        .is_some()
+}
+
+/// Applies the task in the name of the given player, which is also used to resolve tasks without a connection.
+pub async fn apply_player_task<T: Task>(
+    task: T,
+    player_id: &str,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    match ctx
+        .db()
+        .players()
+        .get(player_id)
+        .await
+        .expect("Reading player has failed")
+    {
+        Some(player) => {
+            if is_executed(&player, ctx).await {
+                return Err(String::from("Dead players can't resolve tasks"));
+            }
+            // Check if task is assigned, repeatable tasks stay open for the whole lobby and don't need to be next
+            let assigned = if task.resolve_after_first_answer() {
+                player
                     .open_tasks()
                     .front()
                     .filter(|def| def.get_type() == task.get_type())
-                    .is_none()
-                {
-                    // Prevent leaking information about assigned tasks of other players by sending still OK
-                    warn!(
-                        "Player {} doesn't have task {:?} to resolve",
-                        player.id(),
-                        task.get_type()
-                    );
-                    return Ok(());
-                }
-                let player_id = player.id().to_owned();
-                match task.apply_result(player, ctx).await {
-                    Ok(_) => {
-                        if task.resolve_after_first_answer() {
-                            let mut player = ctx
-                                .db()
-                                .players()
-                                .get(&player_id)
-                                .await
-                                .expect("Loading player has failed")
-                                .unwrap();
-                            player.resolve_task(task.get_type());
-                            if ctx.db().players().persist(&player).await.is_err() {
-                                return Err(String::from("Updating player has failed"));
-                            }
+                    .is_some()
+            } else {
+                player
+                    .open_tasks()
+                    .iter()
+                    .any(|def| def.get_type() == task.get_type())
+            };
+            if !assigned {
+                // Prevent leaking information about assigned tasks of other players by sending still OK
+                warn!(
+                    "Player {} doesn't have task {:?} to resolve",
+                    player.id(),
+                    task.get_type()
+                );
+                return Ok(());
+            }
+            let player_id = player.id().to_owned();
+            match task.apply_result(player, ctx).await {
+                Ok(_) => {
+                    if task.resolve_after_first_answer() {
+                        let mut player = ctx
+                            .db()
+                            .players()
+                            .get(&player_id)
+                            .await
+                            .expect("Loading player has failed")
+                            .unwrap();
+                        player.resolve_task(task.get_type());
+                        if ctx.db().players().persist(&player).await.is_err() {
+                            return Err(String::from("Updating player has failed"));
                         }
-
-                        Ok(())
                     }
-                    Err(err) => Err(err),
+
+                    Ok(())
                 }
+                Err(err) => Err(err),
             }
-            None => Err(String::from("Player not found")),
-        },
-        None => Err(String::from("Player not authenticated")),
+        }
+        None => Err(String::from("Player not found")),
     }
 }
 
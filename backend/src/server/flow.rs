@@ -0,0 +1,363 @@
+use crate::{
+    model::{
+        proto::{self},
+        ExecutivePower, Game, GameEvent, GameState, Player, Policy, Role, TaskDefinition, TaskType,
+    },
+    server::app_context::AppContext,
+};
+use chrono::{Duration, Utc};
+use log::{debug, error, info, warn};
+use protobuf::RepeatedField;
+use std::collections::HashMap;
+
+/// Starts the game with the given players of the lobby, who discover their roles first.
+/// The presidential candidate is asked to nominate a chancellor right away.
+pub async fn start_game(
+    game_token: &str,
+    players: HashMap<String, Player>,
+    ctx: &AppContext,
+) -> Result<Game, String> {
+    let game = ctx
+        .db()
+        .apply_events(game_token, vec![GameEvent::GameStarted])
+        .await
+        .map_err(|err| err.to_string())?;
+    let players = players
+        .into_values()
+        .map(|mut p| {
+            // bots don't pick a name or rules, so they never get any settings task
+            if !p.is_bot() {
+                p.resolve_task(TaskType::Settings);
+                if game.admin_id().as_deref() == Some(p.id()) {
+                    p.resolve_task(TaskType::GameSettings);
+                }
+            }
+            p
+        })
+        .collect::<Vec<_>>();
+
+    start_first_round(&game, players, ctx).await?;
+    Ok(game)
+}
+
+/// Starts another game with the players of a finished game, who keep their names and the settings of the lobby.
+/// Any task left over from the last game is dropped, as all players start with discovering their new roles.
+pub async fn start_rematch(
+    game_token: &str,
+    admin_id: &str,
+    players: HashMap<String, Player>,
+    ctx: &AppContext,
+) -> Result<Game, String> {
+    let game = ctx
+        .db()
+        .apply_events(game_token, vec![GameEvent::rematch_started(admin_id)])
+        .await
+        .map_err(|err| err.to_string())?;
+    let players = players
+        .into_values()
+        .map(|mut p| {
+            p.clear_tasks();
+            p
+        })
+        .collect::<Vec<_>>();
+
+    start_first_round(&game, players, ctx).await?;
+    Ok(game)
+}
+
+async fn start_first_round(
+    game: &Game,
+    players: Vec<Player>,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let players = players
+        .into_iter()
+        .map(|mut p| {
+            if let Some(task) = discover_role_task(game, p.id()) {
+                p.assign_task(task);
+            }
+            if let Some(task) = nomination_task(game, p.id()) {
+                p.assign_task(task);
+            }
+            p
+        })
+        .collect::<Vec<_>>();
+    ctx.db()
+        .players()
+        .persist_batch(&players)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    inform_about_roles(game, ctx).await;
+    Ok(())
+}
+
+fn discover_role_task(game: &Game, player_id: &str) -> Option<TaskDefinition> {
+    let role = game.role(player_id)?;
+    let clan_member_ids = game.known_clan_member_ids(player_id);
+    let clan_leader_id = match role {
+        Role::Clan => game.clan_leader_id().map(String::from),
+        _ => None,
+    };
+
+    Some(TaskDefinition::DiscoverRole {
+        role,
+        clan_member_ids,
+        clan_leader_id,
+    })
+}
+
+async fn inform_about_roles(game: &Game, ctx: &AppContext) {
+    for player_id in game.all_player_ids() {
+        if let Some(TaskDefinition::DiscoverRole {
+            role,
+            clan_member_ids,
+            clan_leader_id,
+        }) = discover_role_task(game, &player_id)
+        {
+            let mut role_msg = proto::message::Server_RoleAssigned::new();
+            role_msg.set_role(role.into());
+            role_msg.set_clan_member_ids(RepeatedField::from_vec(clan_member_ids));
+            if let Some(id) = clan_leader_id {
+                role_msg.set_clan_leader_id(id);
+            }
+            let mut msg = proto::message::Server::new();
+            msg.set_roleAssigned(role_msg);
+            if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+                warn!("Informing about assigned role failed: {:?}", err);
+            }
+        }
+    }
+}
+
+/// Creates the nomination task for the presidential candidate of the current round.
+pub fn nomination_task(game: &Game, player_id: &str) -> Option<TaskDefinition> {
+    game.presidential_candidate_id()
+        .filter(|candidate_id| *candidate_id == player_id)
+        .map(|_| TaskDefinition::NominateChancellor {
+            eligible_player_ids: game.eligible_chancellor_ids(),
+        })
+}
+
+/// Assigns the same task to each of the given players and persists them, which informs the players about the new task.
+/// With a task timeout in the game settings, the task has to be resolved until the deadline.
+pub async fn assign_tasks(
+    game: &Game,
+    player_ids: &[String],
+    task: TaskDefinition,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let deadline = game
+        .settings()
+        .task_timeout_seconds()
+        .map(|seconds| Utc::now() + Duration::seconds(i64::from(seconds)));
+    let players = ctx
+        .db()
+        .players()
+        .get_batch(player_ids)
+        .await
+        .map_err(|err| err.to_string())?
+        .into_values()
+        .map(|mut player| {
+            debug!("Assigning task {:?} to {}", task.get_type(), player.id());
+            match deadline {
+                Some(deadline) => player.assign_task_until(task.clone(), deadline),
+                None => player.assign_task(task.clone()),
+            }
+            player
+        })
+        .collect::<Vec<_>>();
+
+    ctx.db()
+        .players()
+        .persist_batch(&players)
+        .await
+        .map_err(|err| err.to_string())
+}
+
+/// Asks the presidential candidate to nominate a chancellor.
+pub async fn start_nomination(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    if game.is_finished() {
+        return finish_game(game, ctx).await;
+    }
+
+    match game.presidential_candidate_id() {
+        Some(candidate_id) => {
+            assign_tasks(
+                game,
+                &[String::from(candidate_id)],
+                TaskDefinition::NominateChancellor {
+                    eligible_player_ids: game.eligible_chancellor_ids(),
+                },
+                ctx,
+            )
+            .await
+        }
+        None => Err(format!(
+            "Game {} has no presidential candidate",
+            game.token()
+        )),
+    }
+}
+
+/// Asks all voters to elect or reject the nominated government.
+pub async fn start_election(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    match (
+        game.presidential_candidate_id(),
+        game.nominated_chancellor_id(),
+        game.decision(&TaskType::Vote),
+    ) {
+        (Some(president_id), Some(chancellor_id), Some(decision)) => {
+            assign_tasks(
+                game,
+                decision.voter_ids(),
+                TaskDefinition::Vote {
+                    president_id: String::from(president_id),
+                    chancellor_id: String::from(chancellor_id),
+                },
+                ctx,
+            )
+            .await
+        }
+        _ => Err(format!("Game {} has no nominated government", game.token())),
+    }
+}
+
+/// Passes the drawn policies to the elected president, who needs to discard one of them.
+pub async fn start_legislative_session(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    if game.is_finished() {
+        return finish_game(game, ctx).await;
+    }
+
+    match game.president_id() {
+        Some(president_id) => {
+            assign_tasks(
+                game,
+                &[String::from(president_id)],
+                TaskDefinition::DiscardPolicy {
+                    policies: game.legislative_policies().to_vec(),
+                },
+                ctx,
+            )
+            .await
+        }
+        None => Err(format!("Game {} has no elected president", game.token())),
+    }
+}
+
+/// Passes the remaining policies to the elected chancellor, who needs to enact one of them.
+pub async fn pass_policies_to_chancellor(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    match game.chancellor_id() {
+        Some(chancellor_id) => {
+            assign_tasks(
+                game,
+                &[String::from(chancellor_id)],
+                TaskDefinition::EnactPolicy {
+                    policies: game.legislative_policies().to_vec(),
+                    veto_allowed: game.is_veto_allowed(),
+                },
+                ctx,
+            )
+            .await
+        }
+        None => Err(format!("Game {} has no elected chancellor", game.token())),
+    }
+}
+
+/// Asks the president to confirm or refuse the veto proposed by the chancellor.
+pub async fn start_veto_answer(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    match game.president_id() {
+        Some(president_id) => {
+            assign_tasks(
+                game,
+                &[String::from(president_id)],
+                TaskDefinition::AnswerVeto {},
+                ctx,
+            )
+            .await
+        }
+        None => Err(format!("Game {} has no elected president", game.token())),
+    }
+}
+
+/// Informs all players about the top policy, which has been enacted after too many failed elections.
+pub async fn inform_about_chaos_policy(game: &Game, policy: Policy, ctx: &AppContext) {
+    for player_id in game.all_player_ids() {
+        let mut chaos_msg = proto::message::Server_ChaosPolicyEnacted::new();
+        chaos_msg.set_policy(policy.into());
+        let mut msg = proto::message::Server::new();
+        msg.set_chaosPolicyEnacted(chaos_msg);
+
+        if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+            error!("Sending ChaosPolicyEnacted has failed: {}", &err);
+        }
+    }
+}
+
+/// Asks the president to use the executive power granted by the enacted policy.
+/// Without any power the next round starts immediately.
+pub async fn start_executive_power(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    if game.is_finished() {
+        return finish_game(game, ctx).await;
+    }
+
+    let task = match game.pending_power() {
+        Some(ExecutivePower::InvestigateLoyalty) => TaskDefinition::InvestigateLoyalty {
+            eligible_player_ids: game.eligible_power_target_ids(),
+        },
+        Some(ExecutivePower::SpecialElection) => TaskDefinition::CallSpecialElection {
+            eligible_player_ids: game.eligible_power_target_ids(),
+        },
+        Some(ExecutivePower::PolicyPeek) => TaskDefinition::PeekPolicies {},
+        Some(ExecutivePower::Execution) => TaskDefinition::ExecutePlayer {
+            eligible_player_ids: game.eligible_power_target_ids(),
+        },
+        None => return start_nomination(game, ctx).await,
+    };
+
+    match game.president_id() {
+        Some(president_id) => assign_tasks(game, &[String::from(president_id)], task, ctx).await,
+        None => Err(format!("Game {} has no elected president", game.token())),
+    }
+}
+
+/// Informs all players about the winner and reveals the roles of all players.
+pub async fn finish_game(game: &Game, ctx: &AppContext) -> Result<(), String> {
+    let (winner, reason) = match game.state() {
+        GameState::Finished { winner, reason } => (*winner, *reason),
+        _ => return Err(format!("Game {} has not finished", game.token())),
+    };
+    info!(
+        "Game {} has been won by {:?}: {:?}",
+        game.token(),
+        winner,
+        reason
+    );
+
+    let roles = game
+        .seats()
+        .iter()
+        .filter_map(|id| {
+            game.role(id).map(|role| {
+                let mut player_role = proto::role::PlayerRole::new();
+                player_role.set_player_id(String::from(id));
+                player_role.set_role(role.into());
+                player_role
+            })
+        })
+        .collect::<Vec<_>>();
+    for player_id in game.all_player_ids() {
+        let mut finished_msg = proto::message::Server_GameFinished::new();
+        finished_msg.set_winner(winner.into());
+        finished_msg.set_reason(reason.into());
+        finished_msg.set_roles(roles.clone().into());
+        let mut msg = proto::message::Server::new();
+        msg.set_gameFinished(finished_msg);
+
+        if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+            error!("Sending GameFinished has failed: {}", &err);
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,10 @@
 pub mod app_context;
 mod auth;
 mod endpoints;
+pub(crate) mod flow;
 mod logger;
 mod reply;
-mod tasks;
+pub(crate) mod tasks;
 mod ws;
 
 use self::{
@@ -11,10 +12,14 @@ use self::{
     endpoints::{
         active_game::handle_ws_filter,
         games::{
-            attend_game_filter, create_game_filter, get_game_filter, get_games_count_filter,
-            leave_game_filter, start_game_filter,
+            add_bot_filter, attend_game_filter, change_password_filter, create_game_filter,
+            get_game_filter, get_games_count_filter, hand_over_admin_filter, kick_player_filter,
+            leave_game_filter, lock_lobby_filter, rematch_filter, spectate_game_filter,
+            start_game_filter, AttendGameRequest, ChangePasswordRequest, LockLobbyRequest,
+            StartGameQuery,
         },
         players::get_player_filter,
+        replays::get_replay_filter,
     },
     reply::handle_rejection,
 };
@@ -57,9 +62,26 @@ pub async fn run_server(ctx: &'static AppContext) {
                 )
                 .or(
                     // POST /api/games/:token/attend
-                    warp::post().and(warp::path!(String / "attend")).and_then(
+                    warp::post()
+                        .and(warp::path!(String / "attend"))
+                        // the body is optional, as only protected games need a password
+                        .and(
+                            warp::body::json()
+                                .or(warp::any().map(AttendGameRequest::default))
+                                .unify(),
+                        )
+                        .and_then(
+                            move |game_token: String, request: AttendGameRequest| async move {
+                                attend_game_filter(&game_token, request.password.as_deref(), ctx)
+                                    .await
+                            },
+                        ),
+                )
+                .or(
+                    // POST /api/games/:token/spectate
+                    warp::post().and(warp::path!(String / "spectate")).and_then(
                         move |game_token: String| async move {
-                            attend_game_filter(&game_token, ctx).await
+                            spectate_game_filter(&game_token, ctx).await
                         },
                     ),
                 )
@@ -75,13 +97,117 @@ pub async fn run_server(ctx: &'static AppContext) {
                         ),
                 )
                 .or(
-                    // POST /api/games/:token/start
+                    // POST /api/games/:token/start?force=true
                     warp::post()
                         .and(warp::path!(String / "start"))
                         .and(warp::header(AUTHORIZATION))
+                        .and(warp::query::<StartGameQuery>())
                         .and_then(
-                            move |game_token: String, authorization: String| async move {
-                                start_game_filter(&game_token, &authorization, ctx).await
+                            move |game_token: String,
+                                  authorization: String,
+                                  query: StartGameQuery| async move {
+                                start_game_filter(&game_token, &authorization, query.force, ctx)
+                                    .await
+                            },
+                        ),
+                )
+                .or(
+                    // POST /api/games/:token/rematch
+                    warp::post()
+                        .and(warp::path!(String / "rematch"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and_then(move |game_token: String, authorization: String| async move {
+                            rematch_filter(&game_token, &authorization, ctx).await
+                        }),
+                )
+                .or(
+                    // POST /api/games/:token/players/:id/kick
+                    warp::post()
+                        .and(warp::path!(String / "players" / String / "kick"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and_then(
+                            move |game_token: String,
+                                  player_id: String,
+                                  authorization: String| async move {
+                                kick_player_filter(&game_token, &player_id, &authorization, ctx)
+                                    .await
+                            },
+                        ),
+                )
+                .or(
+                    // POST /api/games/:token/players/:id/admin
+                    warp::post()
+                        .and(warp::path!(String / "players" / String / "admin"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and_then(
+                            move |game_token: String,
+                                  player_id: String,
+                                  authorization: String| async move {
+                                hand_over_admin_filter(&game_token, &player_id, &authorization, ctx)
+                                    .await
+                            },
+                        ),
+                )
+                .or(
+                    // POST /api/games/:token/bots
+                    warp::post()
+                        .and(warp::path!(String / "bots"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and_then(move |game_token: String, authorization: String| async move {
+                            add_bot_filter(&game_token, &authorization, ctx).await
+                        }),
+                )
+                .or(
+                    // PUT /api/games/:token/password
+                    warp::put()
+                        .and(warp::path!(String / "password"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and(warp::body::json())
+                        .and_then(
+                            move |game_token: String,
+                                  authorization: String,
+                                  request: ChangePasswordRequest| async move {
+                                change_password_filter(
+                                    &game_token,
+                                    request.password.as_deref(),
+                                    &authorization,
+                                    ctx,
+                                )
+                                .await
+                            },
+                        ),
+                )
+                .or(
+                    // PUT /api/games/:token/lock
+                    warp::put()
+                        .and(warp::path!(String / "lock"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and(warp::body::json())
+                        .and_then(
+                            move |game_token: String,
+                                  authorization: String,
+                                  request: LockLobbyRequest| async move {
+                                lock_lobby_filter(
+                                    &game_token,
+                                    request.locked,
+                                    &authorization,
+                                    ctx,
+                                )
+                                .await
+                            },
+                        ),
+                )
+                .or(
+                    // GET /api/games/:token/replay
+                    warp::get()
+                        .and(warp::path!(String / "replay"))
+                        .and(warp::header(AUTHORIZATION))
+                        .and(warp::header::optional::<String>("accept"))
+                        .and_then(
+                            move |game_token: String,
+                                  authorization: String,
+                                  accept: Option<String>| async move {
+                                get_replay_filter(&game_token, &authorization, accept, ctx).await
                             },
                         ),
                 )
@@ -11,6 +11,8 @@ struct ErrorMessage {
     code: u16,
     message: String,
     details: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -34,6 +36,19 @@ pub fn reply_error_with_details(status: StatusCode, details: &str) -> WithStatus
     )
 }
 
+/// Replies with a machine readable reason, so clients can react to specific errors.
+pub fn reply_error_with_reason(
+    status: StatusCode,
+    reason: impl Serialize,
+    details: &str,
+) -> WithStatus<Json> {
+    let mut content = build_error_content(&status, details);
+    content.reason = serde_json::to_value(reason)
+        .ok()
+        .and_then(|value| value.as_str().map(String::from));
+    warp::reply::with_status(warp::reply::json(&content), status)
+}
+
 pub async fn handle_rejection(err: Rejection) -> Result<impl Reply, Infallible> {
     if err.is_not_found() {
         return Ok(reply_error_with_details(
@@ -58,6 +73,7 @@ fn build_error_content(status: &StatusCode, details: &str) -> ErrorMessage {
         code: status.as_u16(),
         message: String::from(status.canonical_reason().unwrap_or("unknown")),
         details: String::from(details),
+        reason: None,
     }
 }
 
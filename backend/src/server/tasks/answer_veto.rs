@@ -0,0 +1,172 @@
+use crate::{
+    model::{GameEvent, Player, Task, TaskType},
+    server::{
+        app_context::AppContext,
+        flow::{inform_about_chaos_policy, pass_policies_to_chancellor, start_nomination},
+    },
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The president confirms the veto, so both policies are discarded, or refuses it and the chancellor has to enact one of them.
+pub struct AnswerVetoTask {
+    pub approved: bool,
+}
+
+#[async_trait]
+impl Task for AnswerVetoTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::AnswerVeto
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::VetoAnswered {
+                    player_id: String::from(player.id()),
+                    approved: self.approved,
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        if !self.approved {
+            debug!("President {} has refused the veto", player.id());
+            return pass_policies_to_chancellor(&game, ctx).await;
+        }
+
+        debug!("President {} has confirmed the veto", player.id());
+        if game.failed_elections_count() == 0 {
+            if let Some(policy) = game.last_enacted_policy() {
+                inform_about_chaos_policy(&game, policy, ctx).await;
+            }
+        }
+        start_nomination(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Policy, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext,
+            endpoints::tasks::apply_task,
+            tasks::{
+                answer_veto::AnswerVetoTask, enact_policy::EnactPolicyTask,
+                propose_veto::ProposeVetoTask, tests::init_game_with_veto,
+            },
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    #[tokio::test]
+    async fn should_discard_policies_after_confirmed_veto() {
+        let ctx = AppContext::init();
+        let game = init_game_with_veto(&ctx, 7).await;
+        let chancellor = ctx
+            .db()
+            .players()
+            .get(game.chancellor_id().unwrap())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            chancellor.open_tasks().front(),
+            Some(&TaskDefinition::EnactPolicy {
+                policies: game.legislative_policies().to_vec(),
+                veto_allowed: true,
+            })
+        );
+
+        let res = apply_task(ProposeVetoTask {}, "chancellor", &ctx).await;
+        assert!(res.is_ok());
+        let president = ctx
+            .db()
+            .players()
+            .get(game.president_id().unwrap())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            president.open_tasks().front(),
+            Some(&TaskDefinition::AnswerVeto {})
+        );
+
+        let res = apply_task(AnswerVetoTask { approved: true }, "president", &ctx).await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(updated_game.legislative_policies().is_empty());
+        assert_eq!(
+            updated_game.failed_elections_count(),
+            game.failed_elections_count() + 1
+        );
+        let next_candidate = ctx
+            .db()
+            .players()
+            .get(updated_game.presidential_candidate_id().unwrap())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            next_candidate.open_tasks().back().map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_let_chancellor_enact_policy_after_refused_veto() {
+        let ctx = AppContext::init();
+        let game = init_game_with_veto(&ctx, 7).await;
+        let clan_policies_count = game.enacted_policies_count(Policy::Clan);
+        let policy = game.legislative_policies()[0];
+
+        let res = apply_task(ProposeVetoTask {}, "chancellor", &ctx).await;
+        assert!(res.is_ok());
+        let res = apply_task(AnswerVetoTask { approved: false }, "president", &ctx).await;
+        assert!(res.is_ok());
+
+        let chancellor = ctx
+            .db()
+            .players()
+            .get(game.chancellor_id().unwrap())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            chancellor.open_tasks().front(),
+            Some(&TaskDefinition::EnactPolicy {
+                policies: game.legislative_policies().to_vec(),
+                veto_allowed: false,
+            })
+        );
+
+        let res = apply_task(EnactPolicyTask { policy }, "chancellor", &ctx).await;
+        assert!(res.is_ok());
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(
+            updated_game.enacted_policies_count(Policy::Clan),
+            clan_policies_count + (policy == Policy::Clan) as usize
+        );
+    }
+}
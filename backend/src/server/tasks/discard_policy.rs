@@ -0,0 +1,133 @@
+use crate::{
+    model::{GameEvent, Player, Policy, Task, TaskType},
+    server::{app_context::AppContext, flow::pass_policies_to_chancellor},
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The president discards one of the drawn policies in secret.
+pub struct DiscardPolicyTask {
+    pub policy: Policy,
+}
+
+#[async_trait]
+impl Task for DiscardPolicyTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::DiscardPolicy
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::PolicyDiscarded {
+                    player_id: String::from(player.id()),
+                    policy: self.policy,
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!("President {} has discarded a policy", player.id());
+        pass_policies_to_chancellor(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Game, Player, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext, endpoints::tasks::apply_task,
+            tasks::discard_policy::DiscardPolicyTask,
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    #[tokio::test]
+    async fn should_pass_remaining_policies_to_chancellor() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for id in game.seats().to_vec() {
+            game.answer_decision(&TaskType::Vote, &id, true)
+                .expect("Voting has failed");
+        }
+        game.finish_election().expect("Election has failed");
+        game.start_legislative_session()
+            .expect("Drawing policies has failed");
+        let drawn_policies = game.legislative_policies().to_vec();
+
+        let president_id = String::from(game.president_id().unwrap());
+        for player in players.iter_mut() {
+            if player.id() == president_id {
+                player.assign_task(TaskDefinition::DiscardPolicy {
+                    policies: drawn_policies.clone(),
+                });
+            }
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(&president_id, "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        let res = apply_task(
+            DiscardPolicyTask {
+                policy: drawn_policies[0],
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let chancellor = ctx
+            .db()
+            .players()
+            .get(&chancellor_id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            chancellor.open_tasks().front(),
+            Some(&TaskDefinition::EnactPolicy {
+                policies: drawn_policies[1..].to_vec(),
+                veto_allowed: false,
+            })
+        );
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(updated_game.deck().discard_pile_count(), 1);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    model::{Player, Task, TaskType},
+    server::app_context::AppContext,
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// Confirms that a player has seen the secret role dealt at the start of the game.
+pub struct DiscoverRoleTask {}
+
+#[async_trait]
+impl Task for DiscoverRoleTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::DiscoverRole
+    }
+
+    async fn apply_result(&self, player: Player, _: &AppContext) -> Result<(), String> {
+        debug!("Player {} has discovered its role", player.id());
+        Ok(())
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Player, Role, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext, endpoints::tasks::apply_task,
+            tasks::discover_role::DiscoverRoleTask,
+        },
+    };
+
+    #[tokio::test]
+    async fn should_resolve_discovered_role() {
+        let ctx = AppContext::init();
+        let mut player = Player::new("GAME");
+        player.assign_task(TaskDefinition::DiscoverRole {
+            role: Role::Loyal,
+            clan_member_ids: vec![],
+            clan_leader_id: None,
+        });
+        ctx.db()
+            .players()
+            .persist(&player)
+            .await
+            .expect("Persisting player has failed");
+        ctx.ws()
+            .register_active_player(player.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        let res = apply_task(DiscoverRoleTask {}, "peer", &ctx).await;
+        assert!(res.is_ok());
+
+        let updated_player = ctx
+            .db()
+            .players()
+            .get(player.id())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert!(updated_player
+            .open_tasks()
+            .iter()
+            .all(|t| t.get_type() != TaskType::DiscoverRole));
+    }
+}
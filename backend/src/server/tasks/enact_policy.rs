@@ -0,0 +1,146 @@
+use crate::{
+    model::{GameEvent, Player, Policy, Task, TaskType},
+    server::{app_context::AppContext, flow::start_executive_power},
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The chancellor enacts one of the policies passed by the president, which is then added to the board.
+pub struct EnactPolicyTask {
+    pub policy: Policy,
+}
+
+#[async_trait]
+impl Task for EnactPolicyTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::EnactPolicy
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let policy = self.policy;
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::PolicyEnacted {
+                    player_id: String::from(player.id()),
+                    policy,
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!(
+            "Chancellor {} has enacted a {:?} policy",
+            player.id(),
+            policy
+        );
+        start_executive_power(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Game, Player, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext, endpoints::tasks::apply_task,
+            tasks::enact_policy::EnactPolicyTask,
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    #[tokio::test]
+    async fn should_enact_policy_and_start_next_round() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for id in game.seats().to_vec() {
+            game.answer_decision(&TaskType::Vote, &id, true)
+                .expect("Voting has failed");
+        }
+        game.finish_election().expect("Election has failed");
+        game.start_legislative_session()
+            .expect("Drawing policies has failed");
+        let discarded_policy = game.legislative_policies()[0];
+        let president_id = String::from(game.president_id().unwrap());
+        game.discard_policy(&president_id, discarded_policy)
+            .expect("Discarding policy has failed");
+        let enacted_policy = game.legislative_policies()[0];
+
+        for player in players.iter_mut() {
+            if player.id() == chancellor_id {
+                player.assign_task(TaskDefinition::EnactPolicy {
+                    policies: game.legislative_policies().to_vec(),
+                    veto_allowed: false,
+                });
+            }
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(&chancellor_id, "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        let res = apply_task(
+            EnactPolicyTask {
+                policy: enacted_policy,
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(updated_game.enacted_policies_count(enacted_policy), 1);
+        assert!(updated_game.legislative_policies().is_empty());
+        assert_eq!(updated_game.deck().discard_pile_count(), 2);
+        assert_eq!(
+            updated_game.presidential_candidate_id(),
+            Some(game.seats()[1].as_str())
+        );
+
+        let next_candidate = ctx
+            .db()
+            .players()
+            .get(&game.seats()[1])
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            next_candidate.open_tasks().back().map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use crate::{
+    model::{
+        proto::{self},
+        GameEvent, Player, Task, TaskType,
+    },
+    server::{app_context::AppContext, flow::start_nomination},
+};
+use async_trait::async_trait;
+use log::{debug, error};
+
+/// The president executes another player, who is dead for the rest of the game.
+pub struct ExecutePlayerTask {
+    pub player_id: String,
+}
+
+#[async_trait]
+impl Task for ExecutePlayerTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::ExecutePlayer
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::PlayerExecuted {
+                    player_id: String::from(player.id()),
+                    target_id: self.player_id.clone(),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        if let Some(mut target) = ctx
+            .db()
+            .players()
+            .get(&self.player_id)
+            .await
+            .map_err(|err| err.to_string())?
+        {
+            target.clear_tasks();
+            ctx.db()
+                .players()
+                .persist(&target)
+                .await
+                .map_err(|err| err.to_string())?;
+        }
+
+        debug!("President {} has executed {}", player.id(), &self.player_id);
+        for player_id in game.all_player_ids() {
+            let mut executed_msg = proto::message::Server_PlayerExecuted::new();
+            executed_msg.set_player_id(self.player_id.clone());
+            let mut msg = proto::message::Server::new();
+            msg.set_playerExecuted(executed_msg);
+            if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+                error!("Sending PlayerExecuted has failed: {}", &err);
+            }
+        }
+
+        start_nomination(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{ExecutivePower, GameState, Party, Role, TaskDefinition, WinReason},
+        server::{
+            app_context::AppContext,
+            endpoints::tasks::apply_task,
+            tasks::{
+                discover_role::DiscoverRoleTask, execute_player::ExecutePlayerTask,
+                tests::init_game_with_power,
+            },
+        },
+    };
+
+    #[tokio::test]
+    async fn should_execute_player() {
+        let ctx = AppContext::init();
+        let game = init_game_with_power(&ctx, 5, ExecutivePower::Execution).await;
+        let target_id = game.eligible_power_target_ids()[0].clone();
+
+        let res = apply_task(
+            ExecutePlayerTask {
+                player_id: target_id.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(!updated_game.is_alive(&target_id));
+        assert!(!updated_game.living_player_ids().contains(&target_id));
+        assert!(!updated_game.eligible_chancellor_ids().contains(&target_id));
+        assert_ne!(
+            updated_game.presidential_candidate_id(),
+            Some(target_id.as_str())
+        );
+
+        let target = ctx
+            .db()
+            .players()
+            .get(&target_id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert!(target.open_tasks().is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_finish_game_after_executing_clan_leader() {
+        let ctx = AppContext::init();
+        let game = init_game_with_power(&ctx, 5, ExecutivePower::Execution).await;
+        let leader_id = String::from(game.clan_leader_id().unwrap());
+        let target_id = game
+            .eligible_power_target_ids()
+            .into_iter()
+            .find(|id| *id == leader_id);
+
+        let res = apply_task(
+            ExecutePlayerTask {
+                player_id: target_id
+                    .clone()
+                    .unwrap_or_else(|| game.eligible_power_target_ids()[0].clone()),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        match target_id {
+            Some(_) => assert_eq!(
+                updated_game.state(),
+                &GameState::Finished {
+                    winner: Party::Loyal,
+                    reason: WinReason::ClanLeaderExecuted
+                }
+            ),
+            // the clan leader is president and can't be executed
+            None => assert_eq!(updated_game.state(), &GameState::Started),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_not_resolve_tasks_of_executed_player() {
+        let ctx = AppContext::init();
+        let game = init_game_with_power(&ctx, 5, ExecutivePower::Execution).await;
+        let target_id = game
+            .eligible_power_target_ids()
+            .into_iter()
+            .find(|id| game.clan_leader_id() != Some(id.as_str()))
+            .unwrap();
+        let res = apply_task(
+            ExecutePlayerTask {
+                player_id: target_id.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let mut target = ctx
+            .db()
+            .players()
+            .get(&target_id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        target.assign_task(TaskDefinition::DiscoverRole {
+            role: Role::Loyal,
+            clan_member_ids: vec![],
+            clan_leader_id: None,
+        });
+        ctx.db()
+            .players()
+            .persist(&target)
+            .await
+            .expect("Persisting player has failed");
+        ctx.ws()
+            .register_active_player(&target_id, "target")
+            .await
+            .expect("Setting peer connection failed");
+
+        let res = apply_task(DiscoverRoleTask {}, "target", &ctx).await;
+        assert!(res.is_err());
+
+        let updated_player = ctx
+            .db()
+            .players()
+            .get(&target_id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(updated_player.open_tasks().len(), 1);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    model::{GameEvent, GameSettings, Player, Task, TaskType},
+    server::app_context::AppContext,
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The admin chooses the rule variants in the lobby, which all players see before the game begins.
+pub struct GameSettingsTask {
+    pub settings: GameSettings,
+}
+
+#[async_trait]
+impl Task for GameSettingsTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::GameSettings
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        ctx.db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::SettingsUpdated {
+                    player_id: String::from(player.id()),
+                    settings: self.settings.clone(),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!("Applied game settings of player {}", player.id());
+        Ok(())
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{
+            ChatAudience, ClanLeaderKnowledge, Game, GameSettings, Player, RoleDistribution,
+            TaskDefinition,
+        },
+        server::{
+            app_context::AppContext, endpoints::tasks::apply_task,
+            tasks::game_settings::GameSettingsTask,
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_lobby(ctx: &AppContext) -> Player {
+        let mut admin = Player::new(GAME_TOKEN);
+        admin.assign_task(TaskDefinition::Settings {});
+        admin.assign_task(TaskDefinition::GameSettings {});
+        ctx.db()
+            .players()
+            .persist(&admin)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(&Game::new(admin.id(), GAME_TOKEN))
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(admin.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+        admin
+    }
+
+    async fn get_game(ctx: &AppContext) -> Game {
+        ctx.db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_update_game_settings() {
+        let ctx = AppContext::init();
+        init_lobby(&ctx).await;
+        let settings = GameSettings::new(
+            vec![RoleDistribution {
+                player_count: 7,
+                clan_members_count: 1,
+            }],
+            ClanLeaderKnowledge::NeverKnown,
+            false,
+            Some(60),
+            ChatAudience::LivingPlayers,
+        );
+
+        let res = apply_task(
+            GameSettingsTask {
+                settings: settings.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        assert_eq!(get_game(&ctx).await.settings(), &settings);
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_game_settings() {
+        let ctx = AppContext::init();
+        init_lobby(&ctx).await;
+        let settings = GameSettings::new(
+            vec![],
+            ClanLeaderKnowledge::SmallGamesOnly,
+            true,
+            Some(1),
+            ChatAudience::LivingPlayers,
+        );
+
+        let res = apply_task(GameSettingsTask { settings }, "peer", &ctx).await;
+        assert!(res.is_err());
+
+        assert_eq!(get_game(&ctx).await.settings(), &GameSettings::default());
+    }
+}
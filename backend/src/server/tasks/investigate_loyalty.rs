@@ -0,0 +1,113 @@
+use crate::{
+    model::{
+        proto::{self},
+        GameEvent, Player, Task, TaskType,
+    },
+    server::{app_context::AppContext, flow::start_nomination},
+};
+use async_trait::async_trait;
+use log::{debug, error};
+
+/// The president investigates the party membership of another player, which is only revealed to the president.
+pub struct InvestigateLoyaltyTask {
+    pub player_id: String,
+}
+
+#[async_trait]
+impl Task for InvestigateLoyaltyTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::InvestigateLoyalty
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::LoyaltyInvestigated {
+                    player_id: String::from(player.id()),
+                    target_id: self.player_id.clone(),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+        let party = game
+            .role(&self.player_id)
+            .map(|role| role.party())
+            .ok_or_else(|| format!("Player {} has no role", self.player_id))?;
+
+        debug!(
+            "President {} has investigated {}",
+            player.id(),
+            &self.player_id
+        );
+        let mut loyalty_msg = proto::message::Server_LoyaltyRevealed::new();
+        loyalty_msg.set_player_id(self.player_id.clone());
+        loyalty_msg.set_party(party.into());
+        let mut msg = proto::message::Server::new();
+        msg.set_loyaltyRevealed(loyalty_msg);
+        if let Err(err) = ctx.ws().send_message(String::from(player.id()), msg).await {
+            error!("Sending LoyaltyRevealed has failed: {}", &err);
+        }
+
+        start_nomination(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{ExecutivePower, TaskType},
+        server::{
+            app_context::AppContext,
+            endpoints::tasks::apply_task,
+            tasks::{investigate_loyalty::InvestigateLoyaltyTask, tests::init_game_with_power},
+        },
+    };
+
+    #[tokio::test]
+    async fn should_investigate_player_only_once() {
+        let ctx = AppContext::init();
+        let game = init_game_with_power(&ctx, 7, ExecutivePower::InvestigateLoyalty).await;
+        let target_id = game.eligible_power_target_ids()[0].clone();
+
+        let res = apply_task(
+            InvestigateLoyaltyTask {
+                player_id: target_id.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(updated_game.pending_power().is_none());
+        assert_ne!(
+            updated_game.presidential_candidate_id(),
+            game.president_id()
+        );
+
+        let next_candidate = ctx
+            .db()
+            .players()
+            .get(updated_game.presidential_candidate_id().unwrap())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            next_candidate.open_tasks().back().map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+    }
+}
@@ -1 +1,170 @@
+pub mod answer_veto;
+pub mod discard_policy;
+pub mod discover_role;
+pub mod enact_policy;
+pub mod execute_player;
+pub mod game_settings;
+pub mod investigate_loyalty;
+pub mod nominate_chancellor;
+pub mod peek_policies;
+pub mod propose_veto;
 pub mod settings;
+pub mod special_election;
+pub mod strategy;
+pub mod vote;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{ExecutivePower, Game, Player, Policy, Role, TaskType},
+        server::{
+            app_context::AppContext,
+            flow::{self, start_executive_power},
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    fn init_game(players_count: usize) -> (Game, Vec<Player>) {
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 1..players_count {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        (game, players)
+    }
+
+    fn no_leader(game: &Game, ids: Vec<String>) -> String {
+        ids.into_iter()
+            .find(|id| game.role(id) != Some(Role::ClanLeader))
+            .unwrap()
+    }
+
+    /// Elects a government without the clan leader and lets the president pass the policies to the chancellor.
+    fn pass_policies_to_chancellor(game: &mut Game) -> (String, String) {
+        let chancellor_id = no_leader(game, game.eligible_chancellor_ids());
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for id in game.living_player_ids() {
+            game.answer_decision(&TaskType::Vote, &id, true)
+                .expect("Voting has failed");
+        }
+        game.finish_election().expect("Election has failed");
+        game.start_legislative_session()
+            .expect("Drawing policies has failed");
+
+        let president_id = String::from(game.president_id().unwrap());
+        let discarded = if game.legislative_policies().contains(&Policy::Loyal) {
+            Policy::Loyal
+        } else {
+            Policy::Clan
+        };
+        game.discard_policy(&president_id, discarded)
+            .expect("Discarding policy has failed");
+        (president_id, chancellor_id)
+    }
+
+    /// Plays a legislative session in favor of the clan and uses any granted power other than the given one.
+    fn play_clan_session(game: &mut Game, power: Option<ExecutivePower>) {
+        let (president_id, chancellor_id) = pass_policies_to_chancellor(game);
+        let enacted = if game.legislative_policies().contains(&Policy::Clan) {
+            Policy::Clan
+        } else {
+            Policy::Loyal
+        };
+        game.enact_policy(&chancellor_id, enacted)
+            .expect("Enacting policy has failed");
+
+        match game.pending_power() {
+            pending if pending == power => {}
+            Some(ExecutivePower::InvestigateLoyalty) => {
+                let target_id = game.eligible_power_target_ids()[0].clone();
+                game.investigate_loyalty(&president_id, &target_id)
+                    .expect("Investigating player has failed");
+            }
+            Some(ExecutivePower::SpecialElection) => {
+                let target_id = game.eligible_power_target_ids()[0].clone();
+                game.call_special_election(&president_id, &target_id)
+                    .expect("Calling special election has failed");
+            }
+            Some(ExecutivePower::PolicyPeek) => {
+                game.peek_policies(&president_id)
+                    .expect("Peeking policies has failed");
+            }
+            Some(ExecutivePower::Execution) => {
+                let target_id = no_leader(game, game.eligible_power_target_ids());
+                game.execute_player(&president_id, &target_id)
+                    .expect("Executing player has failed");
+            }
+            None => {}
+        }
+    }
+
+    async fn persist(ctx: &AppContext, game: &Game, players: &[Player]) {
+        ctx.db()
+            .players()
+            .persist_batch(players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(game)
+            .await
+            .expect("Persisting game has failed");
+    }
+
+    /// Plays legislative sessions in favor of the clan until the given power is granted to the president.
+    /// The clan leader is never elected as chancellor or executed to keep the game running.
+    pub async fn init_game_with_power(
+        ctx: &AppContext,
+        players_count: usize,
+        power: ExecutivePower,
+    ) -> Game {
+        let (mut game, players) = init_game(players_count);
+        while game.pending_power() != Some(power) {
+            assert!(game.enacted_policies_count(Policy::Clan) < 5);
+            play_clan_session(&mut game, Some(power));
+        }
+
+        persist(ctx, &game, &players).await;
+        start_executive_power(&game, ctx)
+            .await
+            .expect("Assigning power has failed");
+        ctx.ws()
+            .register_active_player(game.president_id().unwrap(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        game
+    }
+
+    /// Plays legislative sessions in favor of the clan until five clan policies are enacted,
+    /// so the chancellor of the next government may propose a veto.
+    /// The chancellor is connected as peer "chancellor" and the president as peer "president".
+    pub async fn init_game_with_veto(ctx: &AppContext, players_count: usize) -> Game {
+        let (mut game, players) = init_game(players_count);
+        while game.enacted_policies_count(Policy::Clan) < 5 {
+            play_clan_session(&mut game, None);
+        }
+        let (president_id, chancellor_id) = pass_policies_to_chancellor(&mut game);
+
+        persist(ctx, &game, &players).await;
+        flow::pass_policies_to_chancellor(&game, ctx)
+            .await
+            .expect("Passing policies has failed");
+        ctx.ws()
+            .register_active_player(&chancellor_id, "chancellor")
+            .await
+            .expect("Setting peer connection failed");
+        ctx.ws()
+            .register_active_player(&president_id, "president")
+            .await
+            .expect("Setting peer connection failed");
+
+        game
+    }
+}
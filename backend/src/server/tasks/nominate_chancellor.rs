@@ -0,0 +1,166 @@
+use crate::{
+    model::{GameEvent, Player, Task, TaskType},
+    server::{app_context::AppContext, flow::start_election},
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The presidential candidate nominates a chancellor, who needs to be eligible for this round.
+pub struct NominateChancellorTask {
+    pub player_id: String,
+}
+
+#[async_trait]
+impl Task for NominateChancellorTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::NominateChancellor
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::ChancellorNominated {
+                    player_id: String::from(player.id()),
+                    chancellor_id: self.player_id.clone(),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!(
+            "Player {} nominated {} as chancellor",
+            player.id(),
+            &self.player_id
+        );
+        start_election(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{Game, Player, TaskDefinition, TaskType},
+        server::{
+            app_context::AppContext, endpoints::tasks::apply_task,
+            tasks::nominate_chancellor::NominateChancellorTask,
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    async fn init_started_game(ctx: &AppContext) -> (Game, Player) {
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+
+        let mut president = players
+            .into_iter()
+            .find(|p| Some(p.id()) == game.presidential_candidate_id())
+            .unwrap();
+        president.assign_task(TaskDefinition::NominateChancellor {
+            eligible_player_ids: game.eligible_chancellor_ids(),
+        });
+        ctx.db()
+            .players()
+            .persist(&president)
+            .await
+            .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(president.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        (game, president)
+    }
+
+    #[tokio::test]
+    async fn should_nominate_chancellor() {
+        let ctx = AppContext::init();
+        let (game, president) = init_started_game(&ctx).await;
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+
+        let res = apply_task(
+            NominateChancellorTask {
+                player_id: chancellor_id.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(
+            updated_game.nominated_chancellor_id(),
+            Some(chancellor_id.as_str())
+        );
+        let updated_president = ctx
+            .db()
+            .players()
+            .get(president.id())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            updated_president.open_tasks().front().map(|t| t.get_type()),
+            Some(TaskType::Vote)
+        );
+        assert!(updated_game.decision(&TaskType::Vote).is_some());
+    }
+
+    #[tokio::test]
+    async fn should_not_nominate_ineligible_player() {
+        let ctx = AppContext::init();
+        let (_, president) = init_started_game(&ctx).await;
+
+        let res = apply_task(
+            NominateChancellorTask {
+                player_id: String::from(president.id()),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_err());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(updated_game.nominated_chancellor_id().is_none());
+        let updated_president = ctx
+            .db()
+            .players()
+            .get(president.id())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(updated_president.open_tasks().len(), 1);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    model::{
+        proto::{self},
+        GameEvent, Player, Task, TaskType,
+    },
+    server::{app_context::AppContext, flow::start_nomination},
+};
+use async_trait::async_trait;
+use log::{debug, error};
+
+const PEEKED_POLICIES_COUNT: usize = 3;
+
+/// The president secretly looks at the top policies of the draw pile.
+pub struct PeekPoliciesTask {}
+
+#[async_trait]
+impl Task for PeekPoliciesTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::PeekPolicies
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::PoliciesPeeked {
+                    player_id: String::from(player.id()),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!("President {} has peeked at the policies", player.id());
+        let mut policies_msg = proto::message::Server_PoliciesRevealed::new();
+        policies_msg.set_policies(
+            game.deck()
+                .peek(PEEKED_POLICIES_COUNT)
+                .into_iter()
+                .map(|p| p.into())
+                .collect(),
+        );
+        let mut msg = proto::message::Server::new();
+        msg.set_policiesRevealed(policies_msg);
+        if let Err(err) = ctx.ws().send_message(String::from(player.id()), msg).await {
+            error!("Sending PoliciesRevealed has failed: {}", &err);
+        }
+
+        start_nomination(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::ExecutivePower,
+        server::{
+            app_context::AppContext,
+            endpoints::tasks::apply_task,
+            tasks::{peek_policies::PeekPoliciesTask, tests::init_game_with_power},
+        },
+    };
+
+    #[tokio::test]
+    async fn should_peek_without_changing_the_deck() {
+        let ctx = AppContext::init();
+        let game = init_game_with_power(&ctx, 5, ExecutivePower::PolicyPeek).await;
+
+        let res = apply_task(PeekPoliciesTask {}, "peer", &ctx).await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert!(updated_game.pending_power().is_none());
+        assert_eq!(updated_game.deck(), game.deck());
+    }
+}
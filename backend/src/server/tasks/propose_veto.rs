@@ -0,0 +1,36 @@
+use crate::{
+    model::{GameEvent, Player, Task, TaskType},
+    server::{app_context::AppContext, flow::start_veto_answer},
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// The chancellor proposes to veto both policies instead of enacting one of them, which the president needs to confirm.
+pub struct ProposeVetoTask {}
+
+#[async_trait]
+impl Task for ProposeVetoTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::EnactPolicy
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::VetoProposed {
+                    player_id: String::from(player.id()),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!("Chancellor {} has proposed a veto", player.id());
+        start_veto_answer(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
@@ -1,9 +1,12 @@
 use crate::{
-    model::{Player, Task, TaskType},
+    model::{
+        proto::{self},
+        GameEvent, NameRejection, Player, Task, TaskType,
+    },
     server::app_context::AppContext,
 };
 use async_trait::async_trait;
-use log::debug;
+use log::{debug, warn};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -18,7 +21,18 @@ impl Task for SettingsTask {
     }
 
     async fn apply_result(&self, mut player: Player, ctx: &AppContext) -> Result<(), String> {
-        player.set_name(&self.name);
+        let name = match Player::normalize_name(&self.name) {
+            Ok(name) => name,
+            Err(rejection) => return reject_name(&player, &self.name, rejection, ctx).await,
+        };
+        if let Err(err) = choose_name(&player, name, ctx).await {
+            return match check_name(&player, name, ctx).await {
+                Some(rejection) => reject_name(&player, name, rejection, ctx).await,
+                None => Err(err),
+            };
+        }
+
+        player.set_name(name);
         match ctx.db().players().persist(&player).await {
             Ok(_) => {
                 debug!("Applied settings player {}", player.id());
@@ -37,10 +51,59 @@ impl Task for SettingsTask {
     }
 }
 
+/// Reserves the name within the game, which rejects names of other players even if they are chosen at the same time.
+async fn choose_name(player: &Player, name: &str, ctx: &AppContext) -> Result<(), String> {
+    ctx.db()
+        .apply_events(
+            player.game_token(),
+            vec![GameEvent::NameChosen {
+                player_id: String::from(player.id()),
+                name: String::from(name),
+            }],
+        )
+        .await
+        .map(|_| ())
+        .map_err(|err| err.to_string())
+}
+
+/// Looks up why the game has refused the name, if the name is the reason at all.
+async fn check_name(player: &Player, name: &str, ctx: &AppContext) -> Option<NameRejection> {
+    ctx.db()
+        .games()
+        .get(player.game_token())
+        .await
+        .ok()
+        .flatten()
+        .and_then(|game| game.check_name(player.id(), name).err())
+}
+
+/// Tells the player why the name has been rejected, so the previous name is kept.
+async fn reject_name(
+    player: &Player,
+    name: &str,
+    rejection: NameRejection,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let mut rejected_msg = proto::message::Server_NameRejected::new();
+    rejected_msg.set_reason(rejection.into());
+    rejected_msg.set_name(String::from(name));
+    let mut msg = proto::message::Server::new();
+    msg.set_nameRejected(rejected_msg);
+    if let Err(err) = ctx.ws().send_message(String::from(player.id()), msg).await {
+        warn!("Sending NameRejected has failed: {}", &err);
+    }
+
+    Err(format!(
+        "Name of player {} has been rejected: {}",
+        player.id(),
+        rejection
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
-        model::{Player, TaskDefinition},
+        model::{Game, Player, TaskDefinition},
         server::{
             app_context::AppContext, endpoints::tasks::apply_task, tasks::settings::SettingsTask,
         },
@@ -51,11 +114,17 @@ mod tests {
         let ctx = AppContext::init();
         let mut player = Player::new("GAME");
         player.assign_task(TaskDefinition::Settings {});
+        let game = Game::new(player.id(), "GAME");
         ctx.db()
             .players()
             .persist(&player)
             .await
             .expect("Persisting player has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
         ctx.ws()
             .register_active_player(player.id(), "peer")
             .await
@@ -80,4 +149,114 @@ mod tests {
             .unwrap();
         assert_eq!(updated_player.name(), "Test");
     }
+
+    #[tokio::test]
+    async fn should_reject_invalid_and_taken_names() {
+        let ctx = AppContext::init();
+        let mut admin = Player::new("GAME");
+        admin.set_name("Tom");
+        let mut player = Player::new("GAME");
+        player.assign_task(TaskDefinition::Settings {});
+        let mut game = Game::new(admin.id(), "GAME");
+        game.add_player(player.id());
+        game.choose_name(admin.id(), "Tom")
+            .expect("Choosing name has failed");
+        ctx.db()
+            .players()
+            .persist_batch(&[admin, player.clone()])
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        ctx.ws()
+            .register_active_player(player.id(), "peer")
+            .await
+            .expect("Setting peer connection failed");
+
+        for name in &[" tom ", "T", "Tom\tTim", &"a".repeat(30)] {
+            let res = apply_task(
+                SettingsTask {
+                    name: String::from(*name),
+                },
+                "peer",
+                &ctx,
+            )
+            .await;
+            assert!(res.is_err(), "{} has been accepted", name);
+        }
+        let res = apply_task(
+            SettingsTask {
+                name: String::from("  Tim "),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_player = ctx
+            .db()
+            .players()
+            .get(player.id())
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(updated_player.name(), "Tim");
+    }
+
+    #[tokio::test]
+    async fn should_only_accept_name_once_if_chosen_at_the_same_time() {
+        let ctx = AppContext::init();
+        let mut admin = Player::new("GAME");
+        admin.assign_task(TaskDefinition::Settings {});
+        let mut player = Player::new("GAME");
+        player.assign_task(TaskDefinition::Settings {});
+        let mut game = Game::new(admin.id(), "GAME");
+        game.add_player(player.id());
+        ctx.db()
+            .players()
+            .persist_batch(&[admin.clone(), player.clone()])
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        for (id, peer_id) in &[(admin.id(), "admin"), (player.id(), "player")] {
+            ctx.ws()
+                .register_active_player(id, peer_id)
+                .await
+                .expect("Setting peer connection failed");
+        }
+
+        let (admin_res, player_res) = tokio::join!(
+            apply_task(
+                SettingsTask {
+                    name: String::from("Tom"),
+                },
+                "admin",
+                &ctx,
+            ),
+            apply_task(
+                SettingsTask {
+                    name: String::from("tom"),
+                },
+                "player",
+                &ctx,
+            )
+        );
+        assert!(admin_res.is_ok() != player_res.is_ok());
+
+        let players = ctx
+            .db()
+            .players()
+            .get_batch(&[String::from(admin.id()), String::from(player.id())])
+            .await
+            .expect("Reading players has failed");
+        assert_eq!(players.values().filter(|p| p.has_name("Tom")).count(), 1);
+    }
 }
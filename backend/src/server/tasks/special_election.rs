@@ -0,0 +1,122 @@
+use crate::{
+    model::{
+        proto::{self},
+        GameEvent, Player, Task, TaskType,
+    },
+    server::{app_context::AppContext, flow::start_nomination},
+};
+use async_trait::async_trait;
+use log::{debug, error};
+
+/// The president chooses the next presidential candidate, afterwards the order continues as usual.
+pub struct SpecialElectionTask {
+    pub player_id: String,
+}
+
+#[async_trait]
+impl Task for SpecialElectionTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::CallSpecialElection
+    }
+
+    async fn apply_result(&self, player: Player, ctx: &AppContext) -> Result<(), String> {
+        let game = ctx
+            .db()
+            .apply_events(
+                player.game_token(),
+                vec![GameEvent::SpecialElectionCalled {
+                    player_id: String::from(player.id()),
+                    target_id: self.player_id.clone(),
+                }],
+            )
+            .await
+            .map_err(|err| err.to_string())?;
+
+        debug!(
+            "President {} has called a special election for {}",
+            player.id(),
+            &self.player_id
+        );
+        for player_id in game.all_player_ids() {
+            let mut election_msg = proto::message::Server_SpecialElectionCalled::new();
+            election_msg.set_player_id(self.player_id.clone());
+            let mut msg = proto::message::Server::new();
+            msg.set_specialElectionCalled(election_msg);
+            if let Err(err) = ctx.ws().send_message(player_id, msg).await {
+                error!("Sending SpecialElectionCalled has failed: {}", &err);
+            }
+        }
+
+        start_nomination(&game, ctx).await
+    }
+
+    fn resolve_after_first_answer(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{ExecutivePower, TaskType},
+        server::{
+            app_context::AppContext,
+            endpoints::tasks::apply_task,
+            tasks::{special_election::SpecialElectionTask, tests::init_game_with_power},
+        },
+    };
+
+    #[tokio::test]
+    async fn should_continue_after_special_election() {
+        let ctx = AppContext::init();
+        let mut game = init_game_with_power(&ctx, 7, ExecutivePower::SpecialElection).await;
+        let president_id = String::from(game.president_id().unwrap());
+        let president_seat = game
+            .seats()
+            .iter()
+            .position(|id| *id == president_id)
+            .unwrap();
+        let target_id = game.eligible_power_target_ids()[3].clone();
+
+        let res = apply_task(
+            SpecialElectionTask {
+                player_id: target_id.clone(),
+            },
+            "peer",
+            &ctx,
+        )
+        .await;
+        assert!(res.is_ok());
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(
+            updated_game.presidential_candidate_id(),
+            Some(target_id.as_str())
+        );
+        let candidate = ctx
+            .db()
+            .players()
+            .get(&target_id)
+            .await
+            .expect("Reading player has failed")
+            .unwrap();
+        assert_eq!(
+            candidate.open_tasks().back().map(|t| t.get_type()),
+            Some(TaskType::NominateChancellor)
+        );
+
+        // the order continues next to the president who called the special election
+        game = updated_game;
+        game.rotate_presidency();
+        assert_eq!(
+            game.presidential_candidate_id(),
+            Some(game.seats()[(president_seat + 1) % 7].as_str())
+        );
+    }
+}
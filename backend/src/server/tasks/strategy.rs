@@ -0,0 +1,453 @@
+use super::{
+    answer_veto::AnswerVetoTask,
+    discard_policy::DiscardPolicyTask,
+    discover_role::DiscoverRoleTask,
+    enact_policy::EnactPolicyTask,
+    execute_player::ExecutePlayerTask,
+    investigate_loyalty::InvestigateLoyaltyTask,
+    nominate_chancellor::NominateChancellorTask,
+    peek_policies::PeekPoliciesTask,
+    propose_veto::ProposeVetoTask,
+    special_election::SpecialElectionTask,
+    vote::{AbstainTask, VoteTask},
+};
+use crate::{
+    model::{Game, Party, Player, Policy, Role, TaskDefinition, TaskType},
+    server::{
+        app_context::AppContext,
+        endpoints::{collective_tasks::apply_player_collective_task, tasks::apply_player_task},
+    },
+};
+use rand::{seq::SliceRandom, thread_rng, Rng};
+
+/// Decides about the answers of a player without a connection.
+/// Each decision only picks from the given options, the task itself is still applied like the answer of a human.
+pub trait Strategy {
+    fn nominate_chancellor(
+        &self,
+        game: &Game,
+        player_id: &str,
+        eligible_player_ids: &[String],
+    ) -> Option<String>;
+
+    /// Returns `None` to abstain from the vote.
+    fn vote(
+        &self,
+        game: &Game,
+        player_id: &str,
+        president_id: &str,
+        chancellor_id: &str,
+    ) -> Option<bool>;
+
+    fn discard_policy(&self, game: &Game, player_id: &str, policies: &[Policy]) -> Option<Policy>;
+
+    fn enact_policy(&self, game: &Game, player_id: &str, policies: &[Policy]) -> Option<Policy>;
+
+    /// Decides whether the chancellor proposes to veto the given policies instead of enacting one of them.
+    fn propose_veto(&self, game: &Game, player_id: &str, policies: &[Policy]) -> bool;
+
+    /// Decides whether the president confirms the veto proposed by the chancellor.
+    fn answer_veto(&self, game: &Game, player_id: &str) -> bool;
+
+    /// Picks the target of an executive power.
+    fn choose_target(
+        &self,
+        game: &Game,
+        player_id: &str,
+        power: TaskType,
+        eligible_player_ids: &[String],
+    ) -> Option<String>;
+}
+
+/// Keeps the game running without an answer of the player after the task timeout.
+/// Voters abstain, policies are picked randomly, vetoes are refused and otherwise the first eligible player is chosen.
+pub struct TimeoutStrategy;
+
+impl Strategy for TimeoutStrategy {
+    fn nominate_chancellor(
+        &self,
+        _: &Game,
+        _: &str,
+        eligible_player_ids: &[String],
+    ) -> Option<String> {
+        eligible_player_ids.first().cloned()
+    }
+
+    fn vote(&self, _: &Game, _: &str, _: &str, _: &str) -> Option<bool> {
+        None
+    }
+
+    fn discard_policy(&self, _: &Game, _: &str, policies: &[Policy]) -> Option<Policy> {
+        policies.choose(&mut thread_rng()).copied()
+    }
+
+    fn enact_policy(&self, _: &Game, _: &str, policies: &[Policy]) -> Option<Policy> {
+        policies.choose(&mut thread_rng()).copied()
+    }
+
+    fn propose_veto(&self, _: &Game, _: &str, _: &[Policy]) -> bool {
+        false
+    }
+
+    fn answer_veto(&self, _: &Game, _: &str) -> bool {
+        false
+    }
+
+    fn choose_target(
+        &self,
+        _: &Game,
+        _: &str,
+        _: TaskType,
+        eligible_player_ids: &[String],
+    ) -> Option<String> {
+        eligible_player_ids.first().cloned()
+    }
+}
+
+/// Plays in favor of the own party with the knowledge the role grants.
+/// Loyal bots trust everyone, clan bots support the known clan members and never turn powers against them.
+pub struct BotStrategy;
+
+// loyal bots still reject some governments, otherwise the clan would never need to win an election
+const LOYAL_APPROVAL_PROBABILITY: f64 = 0.75;
+
+impl BotStrategy {
+    fn party(game: &Game, player_id: &str) -> Party {
+        game.role(player_id)
+            .map(|role| role.party())
+            .unwrap_or(Party::Loyal)
+    }
+
+    /// Returns the players the bot knows to be in its own party.
+    fn allies(game: &Game, player_id: &str) -> Vec<String> {
+        let mut ids = game.known_clan_member_ids(player_id);
+        if let (Some(Role::Clan), Some(leader_id)) = (game.role(player_id), game.clan_leader_id()) {
+            ids.push(String::from(leader_id));
+        }
+        ids
+    }
+
+    fn preferred_policy(game: &Game, player_id: &str) -> Policy {
+        match BotStrategy::party(game, player_id) {
+            Party::Loyal => Policy::Loyal,
+            Party::Clan => Policy::Clan,
+        }
+    }
+
+    fn pick_ally(game: &Game, player_id: &str, eligible_player_ids: &[String]) -> Option<String> {
+        let allies = BotStrategy::allies(game, player_id);
+        eligible_player_ids
+            .iter()
+            .filter(|id| allies.contains(id))
+            .collect::<Vec<_>>()
+            .choose(&mut thread_rng())
+            .map(|id| String::from(*id))
+            .or_else(|| eligible_player_ids.choose(&mut thread_rng()).cloned())
+    }
+
+    fn pick_opponent(
+        game: &Game,
+        player_id: &str,
+        eligible_player_ids: &[String],
+    ) -> Option<String> {
+        let allies = BotStrategy::allies(game, player_id);
+        eligible_player_ids
+            .iter()
+            .filter(|id| !allies.contains(id))
+            .collect::<Vec<_>>()
+            .choose(&mut thread_rng())
+            .map(|id| String::from(*id))
+            .or_else(|| eligible_player_ids.choose(&mut thread_rng()).cloned())
+    }
+}
+
+impl Strategy for BotStrategy {
+    fn nominate_chancellor(
+        &self,
+        game: &Game,
+        player_id: &str,
+        eligible_player_ids: &[String],
+    ) -> Option<String> {
+        BotStrategy::pick_ally(game, player_id, eligible_player_ids)
+    }
+
+    fn vote(
+        &self,
+        game: &Game,
+        player_id: &str,
+        president_id: &str,
+        chancellor_id: &str,
+    ) -> Option<bool> {
+        match BotStrategy::party(game, player_id) {
+            Party::Loyal => Some(thread_rng().gen_bool(LOYAL_APPROVAL_PROBABILITY)),
+            Party::Clan => {
+                let allies = BotStrategy::allies(game, player_id);
+                let government = [president_id, chancellor_id];
+                Some(
+                    government.contains(&player_id)
+                        || allies.iter().any(|id| government.contains(&id.as_str()))
+                        || thread_rng().gen_bool(LOYAL_APPROVAL_PROBABILITY),
+                )
+            }
+        }
+    }
+
+    fn discard_policy(&self, game: &Game, player_id: &str, policies: &[Policy]) -> Option<Policy> {
+        let preferred = BotStrategy::preferred_policy(game, player_id);
+        policies
+            .iter()
+            .find(|policy| **policy != preferred)
+            .or_else(|| policies.first())
+            .copied()
+    }
+
+    fn enact_policy(&self, game: &Game, player_id: &str, policies: &[Policy]) -> Option<Policy> {
+        let preferred = BotStrategy::preferred_policy(game, player_id);
+        policies
+            .iter()
+            .find(|policy| **policy == preferred)
+            .or_else(|| policies.first())
+            .copied()
+    }
+
+    fn propose_veto(&self, game: &Game, player_id: &str, policies: &[Policy]) -> bool {
+        let preferred = BotStrategy::preferred_policy(game, player_id);
+        !policies.contains(&preferred)
+    }
+
+    fn answer_veto(&self, game: &Game, player_id: &str) -> bool {
+        self.propose_veto(game, player_id, game.legislative_policies())
+    }
+
+    fn choose_target(
+        &self,
+        game: &Game,
+        player_id: &str,
+        power: TaskType,
+        eligible_player_ids: &[String],
+    ) -> Option<String> {
+        match power {
+            TaskType::CallSpecialElection => {
+                BotStrategy::pick_ally(game, player_id, eligible_player_ids)
+            }
+            _ => BotStrategy::pick_opponent(game, player_id, eligible_player_ids),
+        }
+    }
+}
+
+/// Resolves the next open task of the player with the answer of the given strategy.
+pub async fn resolve_with_strategy<S: Strategy + Sync>(
+    player: &Player,
+    strategy: &S,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let player_id = player.id();
+    let game = ctx
+        .db()
+        .games()
+        .get(player.game_token())
+        .await
+        .map_err(|err| err.to_string())?
+        .ok_or_else(|| String::from("Game not found"))?;
+    let no_target = || String::from("No player is eligible");
+    let no_policy = || String::from("No policy is available");
+
+    match player.open_tasks().front().cloned() {
+        Some(TaskDefinition::DiscoverRole { .. }) => {
+            apply_player_task(DiscoverRoleTask {}, player_id, ctx).await
+        }
+        Some(TaskDefinition::NominateChancellor {
+            eligible_player_ids,
+        }) => {
+            let chancellor_id = strategy
+                .nominate_chancellor(&game, player_id, &eligible_player_ids)
+                .ok_or_else(no_target)?;
+            apply_player_task(
+                NominateChancellorTask {
+                    player_id: chancellor_id,
+                },
+                player_id,
+                ctx,
+            )
+            .await
+        }
+        Some(TaskDefinition::Vote {
+            president_id,
+            chancellor_id,
+        }) => match strategy.vote(&game, player_id, &president_id, &chancellor_id) {
+            Some(approved) => {
+                apply_player_collective_task(VoteTask { approved }, player_id, ctx).await
+            }
+            None => apply_player_collective_task(AbstainTask {}, player_id, ctx).await,
+        },
+        Some(TaskDefinition::DiscardPolicy { policies }) => {
+            let policy = strategy
+                .discard_policy(&game, player_id, &policies)
+                .ok_or_else(no_policy)?;
+            apply_player_task(DiscardPolicyTask { policy }, player_id, ctx).await
+        }
+        Some(TaskDefinition::EnactPolicy {
+            policies,
+            veto_allowed,
+        }) => {
+            if veto_allowed && strategy.propose_veto(&game, player_id, &policies) {
+                return apply_player_task(ProposeVetoTask {}, player_id, ctx).await;
+            }
+            let policy = strategy
+                .enact_policy(&game, player_id, &policies)
+                .ok_or_else(no_policy)?;
+            apply_player_task(EnactPolicyTask { policy }, player_id, ctx).await
+        }
+        Some(TaskDefinition::InvestigateLoyalty {
+            eligible_player_ids,
+        }) => {
+            let target_id = strategy
+                .choose_target(
+                    &game,
+                    player_id,
+                    TaskType::InvestigateLoyalty,
+                    &eligible_player_ids,
+                )
+                .ok_or_else(no_target)?;
+            apply_player_task(
+                InvestigateLoyaltyTask {
+                    player_id: target_id,
+                },
+                player_id,
+                ctx,
+            )
+            .await
+        }
+        Some(TaskDefinition::CallSpecialElection {
+            eligible_player_ids,
+        }) => {
+            let target_id = strategy
+                .choose_target(
+                    &game,
+                    player_id,
+                    TaskType::CallSpecialElection,
+                    &eligible_player_ids,
+                )
+                .ok_or_else(no_target)?;
+            apply_player_task(
+                SpecialElectionTask {
+                    player_id: target_id,
+                },
+                player_id,
+                ctx,
+            )
+            .await
+        }
+        Some(TaskDefinition::PeekPolicies {}) => {
+            apply_player_task(PeekPoliciesTask {}, player_id, ctx).await
+        }
+        Some(TaskDefinition::ExecutePlayer {
+            eligible_player_ids,
+        }) => {
+            let target_id = strategy
+                .choose_target(
+                    &game,
+                    player_id,
+                    TaskType::ExecutePlayer,
+                    &eligible_player_ids,
+                )
+                .ok_or_else(no_target)?;
+            apply_player_task(
+                ExecutePlayerTask {
+                    player_id: target_id,
+                },
+                player_id,
+                ctx,
+            )
+            .await
+        }
+        Some(TaskDefinition::AnswerVeto {}) => {
+            let approved = strategy.answer_veto(&game, player_id);
+            apply_player_task(AnswerVetoTask { approved }, player_id, ctx).await
+        }
+        Some(task) => Err(format!(
+            "Task {:?} has no automatic answer",
+            task.get_type()
+        )),
+        None => Err(format!("Player {} has no open task", player_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BotStrategy, Strategy};
+    use crate::model::{Game, Policy, Role, TaskType};
+
+    fn init_game() -> Game {
+        let mut game = Game::new("player0", "GAME");
+        for i in 1..6 {
+            game.add_player(&format!("player{}", i));
+        }
+        game.start().expect("Starting game has failed");
+        game
+    }
+
+    #[test]
+    fn should_play_for_own_party() {
+        let game = init_game();
+        let ids = game.seats().to_vec();
+        let loyal_id = ids
+            .iter()
+            .find(|id| game.role(id) == Some(Role::Loyal))
+            .unwrap();
+        let clan_id = ids
+            .iter()
+            .find(|id| game.role(id) == Some(Role::Clan))
+            .unwrap();
+        let policies = [Policy::Clan, Policy::Loyal, Policy::Clan];
+
+        assert_eq!(
+            BotStrategy.discard_policy(&game, loyal_id, &policies),
+            Some(Policy::Clan)
+        );
+        assert_eq!(
+            BotStrategy.enact_policy(&game, loyal_id, &policies[1..]),
+            Some(Policy::Loyal)
+        );
+        assert_eq!(
+            BotStrategy.discard_policy(&game, clan_id, &policies),
+            Some(Policy::Loyal)
+        );
+        assert_eq!(
+            BotStrategy.enact_policy(&game, clan_id, &[Policy::Loyal, Policy::Clan]),
+            Some(Policy::Clan)
+        );
+    }
+
+    #[test]
+    fn should_protect_known_clan_members() {
+        let game = init_game();
+        let clan_id = game
+            .seats()
+            .iter()
+            .find(|id| game.role(id) == Some(Role::Clan))
+            .unwrap();
+        let leader_id = String::from(game.clan_leader_id().unwrap());
+        let eligible_ids = game
+            .seats()
+            .iter()
+            .filter(|id| *id != clan_id)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for _ in 0..10 {
+            assert_eq!(
+                BotStrategy.nominate_chancellor(&game, clan_id, &eligible_ids),
+                Some(leader_id.clone())
+            );
+            assert_ne!(
+                BotStrategy.choose_target(&game, clan_id, TaskType::ExecutePlayer, &eligible_ids),
+                Some(leader_id.clone())
+            );
+            assert_eq!(
+                BotStrategy.vote(&game, clan_id, clan_id, &leader_id),
+                Some(true)
+            );
+        }
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    model::{CollectiveDecision, CollectiveTask, Game, GameEvent, TaskType},
+    server::{
+        app_context::AppContext,
+        flow::{inform_about_chaos_policy, start_legislative_session, start_nomination},
+    },
+};
+use async_trait::async_trait;
+use log::debug;
+
+/// Each player votes with Ja or Nein for the nominated government.
+pub struct VoteTask {
+    pub approved: bool,
+}
+
+#[async_trait]
+impl CollectiveTask for VoteTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::Vote
+    }
+
+    fn answer_event(&self, player_id: &str) -> GameEvent {
+        GameEvent::VoteCast {
+            player_id: String::from(player_id),
+            approved: self.approved,
+        }
+    }
+
+    async fn apply_decision(
+        &self,
+        game: &Game,
+        decision: &CollectiveDecision,
+        ctx: &AppContext,
+    ) -> Result<(), String> {
+        finish_election(game, decision, ctx).await
+    }
+}
+
+/// Players who haven't voted in time abstain, so the election can still finish.
+pub struct AbstainTask {}
+
+#[async_trait]
+impl CollectiveTask for AbstainTask {
+    fn get_type(&self) -> TaskType {
+        TaskType::Vote
+    }
+
+    fn answer_event(&self, player_id: &str) -> GameEvent {
+        GameEvent::VoteAbstained {
+            player_id: String::from(player_id),
+        }
+    }
+
+    async fn apply_decision(
+        &self,
+        game: &Game,
+        decision: &CollectiveDecision,
+        ctx: &AppContext,
+    ) -> Result<(), String> {
+        finish_election(game, decision, ctx).await
+    }
+}
+
+async fn finish_election(
+    game: &Game,
+    decision: &CollectiveDecision,
+    ctx: &AppContext,
+) -> Result<(), String> {
+    let game = ctx
+        .db()
+        .apply_events(game.token(), vec![GameEvent::ElectionFinished])
+        .await
+        .map_err(|err| err.to_string())?;
+
+    if decision.is_approved() {
+        debug!("Government of game {} has been elected", game.token());
+        start_legislative_session(&game, ctx).await
+    } else {
+        debug!("Government of game {} has been rejected", game.token());
+        if game.failed_elections_count() == 0 {
+            if let Some(policy) = game.last_enacted_policy() {
+                inform_about_chaos_policy(&game, policy, ctx).await;
+            }
+        }
+        start_nomination(&game, ctx).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        model::{
+            ExecutivePower, Game, GameState, Party, Player, TaskDefinition, TaskType, WinReason,
+        },
+        server::{
+            app_context::AppContext,
+            endpoints::collective_tasks::apply_collective_task,
+            tasks::{tests::init_game_with_power, vote::VoteTask},
+        },
+    };
+
+    const GAME_TOKEN: &str = "GAME";
+
+    fn reject_government(game: &mut Game) {
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for id in game.seats().to_vec() {
+            game.answer_decision(&TaskType::Vote, &id, false)
+                .expect("Voting has failed");
+        }
+        assert_eq!(game.finish_election(), Ok(false));
+    }
+
+    #[tokio::test]
+    async fn should_enact_top_policy_after_third_failed_election() {
+        let ctx = AppContext::init();
+        let admin = Player::new(GAME_TOKEN);
+        let mut game = Game::new(admin.id(), GAME_TOKEN);
+        let mut players = vec![admin];
+        for _ in 0..4 {
+            let player = Player::new(GAME_TOKEN);
+            game.add_player(player.id());
+            players.push(player);
+        }
+        game.start().expect("Starting game has failed");
+        reject_government(&mut game);
+        reject_government(&mut game);
+        assert_eq!(game.failed_elections_count(), 2);
+
+        let top_policy = game.deck().peek(1)[0];
+        let chancellor_id = game.eligible_chancellor_ids()[0].clone();
+        game.nominate_chancellor(&chancellor_id)
+            .expect("Nominating chancellor has failed");
+        for player in players.iter_mut() {
+            player.assign_task(TaskDefinition::Vote {
+                president_id: String::from(game.presidential_candidate_id().unwrap()),
+                chancellor_id: chancellor_id.clone(),
+            });
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        for (seat, id) in game.seats().iter().enumerate() {
+            ctx.ws()
+                .register_active_player(id, &format!("peer{}", seat))
+                .await
+                .expect("Setting peer connection failed");
+        }
+
+        for seat in 0..5 {
+            let res =
+                apply_collective_task(VoteTask { approved: false }, &format!("peer{}", seat), &ctx)
+                    .await;
+            assert!(res.is_ok());
+        }
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(GAME_TOKEN)
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(updated_game.failed_elections_count(), 0);
+        assert_eq!(updated_game.enacted_policies_count(top_policy), 1);
+        assert_eq!(updated_game.last_enacted_policy(), Some(top_policy));
+        assert!(updated_game.president_id().is_none());
+        assert!(updated_game.chancellor_id().is_none());
+        assert_eq!(
+            updated_game.presidential_candidate_id(),
+            Some(game.seats()[3].as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_finish_game_when_clan_leader_is_elected() {
+        let ctx = AppContext::init();
+        let mut game = init_game_with_power(&ctx, 5, ExecutivePower::PolicyPeek).await;
+        let president_id = String::from(game.president_id().unwrap());
+        game.peek_policies(&president_id)
+            .expect("Peeking policies has failed");
+        let leader_id = String::from(game.clan_leader_id().unwrap());
+        while game.presidential_candidate_id() == Some(leader_id.as_str())
+            || !game.eligible_chancellor_ids().contains(&leader_id)
+        {
+            game.rotate_presidency();
+        }
+        game.nominate_chancellor(&leader_id)
+            .expect("Nominating chancellor has failed");
+
+        let mut players = ctx
+            .db()
+            .players()
+            .get_batch(game.seats())
+            .await
+            .expect("Reading players has failed")
+            .into_values()
+            .collect::<Vec<_>>();
+        for player in players.iter_mut() {
+            player.resolve_task(TaskType::PeekPolicies);
+            player.assign_task(TaskDefinition::Vote {
+                president_id: String::from(game.presidential_candidate_id().unwrap()),
+                chancellor_id: leader_id.clone(),
+            });
+        }
+        ctx.db()
+            .players()
+            .persist_batch(&players)
+            .await
+            .expect("Persisting players has failed");
+        ctx.db()
+            .games()
+            .persist(&game)
+            .await
+            .expect("Persisting game has failed");
+        for (seat, id) in game.seats().iter().enumerate() {
+            ctx.ws()
+                .register_active_player(id, &format!("vote{}", seat))
+                .await
+                .expect("Setting peer connection failed");
+        }
+
+        for seat in 0..5 {
+            let res =
+                apply_collective_task(VoteTask { approved: true }, &format!("vote{}", seat), &ctx)
+                    .await;
+            assert!(res.is_ok());
+        }
+
+        let updated_game = ctx
+            .db()
+            .games()
+            .get(game.token())
+            .await
+            .expect("Reading game has failed")
+            .unwrap();
+        assert_eq!(
+            updated_game.state(),
+            &GameState::Finished {
+                winner: Party::Clan,
+                reason: WinReason::ClanLeaderElected
+            }
+        );
+        assert!(updated_game.legislative_policies().is_empty());
+    }
+}
@@ -47,6 +47,17 @@ impl WsClient {
             .map_err(|err| err.to_string())
     }
 
+    /// Closes the socket of the player from the server side, the player can't authenticate on it anymore.
+    pub async fn close_connection(&self, player_id: &str) -> Result<(), String> {
+        self.sender
+            .clone()
+            .send(WsCommand::CloseConnection {
+                player_id: String::from(player_id),
+            })
+            .await
+            .map_err(|err| err.to_string())
+    }
+
     pub async fn send_message(
         &self,
         player_id: String,
@@ -59,6 +70,34 @@ impl WsClient {
             .map_err(|err| err.to_string())
     }
 
+    pub async fn send_spectator_message(
+        &self,
+        spectator_id: String,
+        msg: proto::message::Server,
+    ) -> Result<(), String> {
+        self.sender
+            .clone()
+            .send(WsCommand::SendSpectatorMessage { msg, spectator_id })
+            .await
+            .map_err(|err| err.to_string())
+    }
+
+    /// Sends the message to all spectators of the given game.
+    pub async fn broadcast_to_spectators(
+        &self,
+        game_token: &str,
+        msg: proto::message::Server,
+    ) -> Result<(), String> {
+        self.sender
+            .clone()
+            .send(WsCommand::BroadcastToSpectators {
+                msg,
+                game_token: String::from(game_token),
+            })
+            .await
+            .map_err(|err| err.to_string())
+    }
+
     pub async fn register_active_player(
         &self,
         player_id: &str,
@@ -74,6 +113,23 @@ impl WsClient {
             .map_err(|err| err.to_string())
     }
 
+    pub async fn register_spectator(
+        &self,
+        spectator_id: &str,
+        game_token: &str,
+        peer_id: &str,
+    ) -> Result<(), String> {
+        self.sender
+            .clone()
+            .send(WsCommand::RegisterSpectator {
+                spectator_id: String::from(spectator_id),
+                game_token: String::from(game_token),
+                peer_id: String::from(peer_id),
+            })
+            .await
+            .map_err(|err| err.to_string())
+    }
+
     pub async fn get_authenticated_player_for_peer(&self, peer_id: &str) -> Option<String> {
         let (sender, receiver) = oneshot::channel();
         if let Err(err) = self
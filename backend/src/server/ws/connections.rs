@@ -1,9 +1,10 @@
 use super::WsCommand;
+use crate::model::proto::{self};
 use futures::stream::SplitSink;
 use futures::SinkExt;
 use log::{debug, error, info};
 use protobuf::Message;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tokio::sync::mpsc;
 use warp::ws::{Message as WsMessage, WebSocket};
 
@@ -11,6 +12,10 @@ pub struct Connections {
     connections: HashMap<String, SplitSink<WebSocket, WsMessage>>,
     player_to_peer: HashMap<String, String>,
     peer_to_player: HashMap<String, String>,
+    // spectators are kept apart from players, so they can never be mistaken for one
+    spectator_to_peer: HashMap<String, String>,
+    peer_to_spectator: HashMap<String, String>,
+    game_to_spectators: HashMap<String, HashSet<String>>,
     msg_receiver: mpsc::Receiver<WsCommand>,
 }
 
@@ -23,6 +28,9 @@ impl Connections {
             connections: HashMap::default(),
             player_to_peer: HashMap::default(),
             peer_to_player: HashMap::default(),
+            spectator_to_peer: HashMap::default(),
+            peer_to_spectator: HashMap::default(),
+            game_to_spectators: HashMap::default(),
             msg_receiver,
         };
 
@@ -37,38 +45,41 @@ impl Connections {
 
             match request {
                 WsCommand::SendMessage { msg, player_id } => {
-                    if let Err(err) = msg.check_initialized() {
-                        error!("Message not initialized correctly: {:?}", err);
-                        continue;
-                    }
-
-                    if let Some(peer_id) = self.player_to_peer.get(&player_id) {
-                        match msg.write_to_bytes() {
-                            Ok(bytes) => {
-                                if let Err(err) = self
-                                    .connections
-                                    .get_mut(peer_id)
-                                    .unwrap()
-                                    .send(WsMessage::binary(bytes))
-                                    .await
-                                {
-                                    error!(
-                                        "Sending message to {} has failed: {:?}",
-                                        &peer_id, &err
-                                    );
-                                }
-                            }
-                            Err(err) => {
-                                error!(
-                                    "Writing message to binary format {} has failed: {:?}",
-                                    &peer_id, &err
-                                );
-                            }
+                    if let Some(peer_id) = self.player_to_peer.get(&player_id).cloned() {
+                        if let Some(bytes) = to_bytes(&msg, &peer_id) {
+                            self.send_to_peer(&peer_id, bytes).await;
                         }
                     } else {
                         debug!("Player {} has no active connection", &player_id);
                     }
                 }
+                WsCommand::SendSpectatorMessage { msg, spectator_id } => {
+                    if let Some(peer_id) = self.spectator_to_peer.get(&spectator_id).cloned() {
+                        if let Some(bytes) = to_bytes(&msg, &peer_id) {
+                            self.send_to_peer(&peer_id, bytes).await;
+                        }
+                    } else {
+                        debug!("Spectator {} has no active connection", &spectator_id);
+                    }
+                }
+                WsCommand::BroadcastToSpectators { msg, game_token } => {
+                    let peer_ids = self
+                        .game_to_spectators
+                        .get(&game_token)
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|spectator_id| self.spectator_to_peer.get(spectator_id))
+                        .cloned()
+                        .collect::<Vec<_>>();
+                    if peer_ids.is_empty() {
+                        continue;
+                    }
+                    if let Some(bytes) = to_bytes(&msg, &game_token) {
+                        for peer_id in peer_ids {
+                            self.send_to_peer(&peer_id, bytes.clone()).await;
+                        }
+                    }
+                }
                 WsCommand::FetchAuthenticatedPlayer { peer_id, sender } => {
                     if let Err(err) =
                         sender.send(self.peer_to_player.get(&peer_id).map(String::clone))
@@ -85,13 +96,74 @@ impl Connections {
                         self.player_to_peer.remove(player_id);
                     }
                     self.peer_to_player.remove(&peer_id);
+                    if let Some(spectator_id) = self.peer_to_spectator.remove(&peer_id) {
+                        self.spectator_to_peer.remove(&spectator_id);
+                        self.game_to_spectators.retain(|_, spectator_ids| {
+                            spectator_ids.remove(&spectator_id);
+                            !spectator_ids.is_empty()
+                        });
+                    }
+                }
+                WsCommand::CloseConnection { player_id } => {
+                    if let Some(peer_id) = self.player_to_peer.remove(&player_id) {
+                        self.peer_to_player.remove(&peer_id);
+                        if let Some(mut connection) = self.connections.remove(&peer_id) {
+                            if let Err(err) = connection.close().await {
+                                error!("Closing connection to {} has failed: {:?}", &peer_id, &err);
+                            }
+                        }
+                    } else {
+                        debug!("Player {} has no active connection", &player_id);
+                    }
                 }
                 WsCommand::RegisterActivePlayer { player_id, peer_id } => {
                     self.player_to_peer
                         .insert(player_id.clone(), peer_id.clone());
                     self.peer_to_player.insert(peer_id, player_id);
                 }
+                WsCommand::RegisterSpectator {
+                    spectator_id,
+                    game_token,
+                    peer_id,
+                } => {
+                    self.game_to_spectators
+                        .entry(game_token)
+                        .or_default()
+                        .insert(spectator_id.clone());
+                    self.spectator_to_peer
+                        .insert(spectator_id.clone(), peer_id.clone());
+                    self.peer_to_spectator.insert(peer_id, spectator_id);
+                }
+            }
+        }
+    }
+
+    async fn send_to_peer(&mut self, peer_id: &str, bytes: Vec<u8>) {
+        match self.connections.get_mut(peer_id) {
+            Some(connection) => {
+                if let Err(err) = connection.send(WsMessage::binary(bytes)).await {
+                    error!("Sending message to {} has failed: {:?}", &peer_id, &err);
+                }
             }
+            None => debug!("Peer {} has no open connection", &peer_id),
+        }
+    }
+}
+
+fn to_bytes(msg: &proto::message::Server, receiver: &str) -> Option<Vec<u8>> {
+    if let Err(err) = msg.check_initialized() {
+        error!("Message not initialized correctly: {:?}", err);
+        return None;
+    }
+
+    match msg.write_to_bytes() {
+        Ok(bytes) => Some(bytes),
+        Err(err) => {
+            error!(
+                "Writing message to binary format {} has failed: {:?}",
+                &receiver, &err
+            );
+            None
         }
     }
 }
@@ -15,10 +15,23 @@ pub enum WsCommand {
         player_id: String,
         msg: proto::message::Server,
     },
+    SendSpectatorMessage {
+        spectator_id: String,
+        msg: proto::message::Server,
+    },
+    BroadcastToSpectators {
+        game_token: String,
+        msg: proto::message::Server,
+    },
     RegisterActivePlayer {
         player_id: String,
         peer_id: String,
     },
+    RegisterSpectator {
+        spectator_id: String,
+        game_token: String,
+        peer_id: String,
+    },
     FetchAuthenticatedPlayer {
         peer_id: String,
         sender: oneshot::Sender<Option<String>>,
@@ -30,4 +43,7 @@ pub enum WsCommand {
     RemoveConnection {
         peer_id: String,
     },
+    CloseConnection {
+        player_id: String,
+    },
 }
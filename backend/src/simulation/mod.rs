@@ -0,0 +1,272 @@
+mod statistics;
+
+use self::statistics::{GameResult, Statistics};
+use crate::{
+    config::SimulationConfig,
+    model::{ChatAudience, ClanLeaderKnowledge, GameEvent, GameSettings, Player},
+    server::{
+        app_context::AppContext,
+        flow::start_game,
+        tasks::strategy::{resolve_with_strategy, BotStrategy},
+    },
+};
+use envconfig::Envconfig;
+use log::warn;
+use serde::Deserialize;
+use std::{fs, iter};
+
+// games are played concurrently in batches, as most of the time is spent waiting for the databases
+const PARALLEL_GAMES_COUNT: usize = 32;
+// each step answers all open tasks, so a game exceeding this limit is stuck
+const MAX_STEPS_COUNT: usize = 500;
+
+/// Named game settings, whose outcome is compared to the other presets.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RulePreset {
+    name: String,
+    settings: GameSettings,
+}
+
+/// Plays complete games with bots for each rule preset and player count and prints the statistics of their outcomes.
+/// All data is kept in memory and nobody is connected, so a running server isn't affected.
+pub fn run_simulation() {
+    if std::env::var("LOG_LEVEL").is_err() {
+        std::env::set_var("LOG_LEVEL", "warn");
+    }
+    let config = SimulationConfig::init_from_env().expect("Loading simulation config failed");
+    let presets = match &config.presets_path {
+        Some(path) => read_presets(path),
+        None => default_presets(),
+    };
+
+    let rt = tokio::runtime::Builder::new_multi_thread()
+        .thread_name("sim")
+        .build()
+        .expect("Creating runtime failed");
+
+    rt.block_on(async {
+        let ctx: &'static AppContext = Box::leak(Box::new(AppContext::init_in_memory()));
+        let mut game_index = 0;
+
+        for preset in &presets {
+            for players_count in config.min_players_count..=config.max_players_count {
+                println!("Preset \"{}\" with {} players", preset.name, players_count);
+                if let Err(err) = preset.settings.validate_for(players_count) {
+                    println!("  Skipped: {}", err);
+                    continue;
+                }
+
+                let statistics = simulate_games(
+                    ctx,
+                    &preset.settings,
+                    players_count,
+                    config.games_count,
+                    game_index,
+                )
+                .await;
+                game_index += config.games_count;
+                println!("{}", statistics);
+            }
+        }
+    });
+}
+
+fn default_presets() -> Vec<RulePreset> {
+    let preset = |name: &str, knowledge| RulePreset {
+        name: String::from(name),
+        settings: GameSettings::new(
+            GameSettings::default().role_distributions().to_vec(),
+            knowledge,
+            true,
+            None,
+            ChatAudience::LivingPlayers,
+        ),
+    };
+
+    vec![
+        preset("official", ClanLeaderKnowledge::SmallGamesOnly),
+        preset("clanLeaderAlwaysKnown", ClanLeaderKnowledge::AlwaysKnown),
+        preset("clanLeaderNeverKnown", ClanLeaderKnowledge::NeverKnown),
+    ]
+}
+
+fn read_presets(path: &str) -> Vec<RulePreset> {
+    let content = fs::read_to_string(path).expect("Reading presets has failed");
+    serde_json::from_str(&content).expect("Parsing presets has failed")
+}
+
+async fn simulate_games(
+    ctx: &'static AppContext,
+    settings: &GameSettings,
+    players_count: usize,
+    games_count: usize,
+    first_game_index: usize,
+) -> Statistics {
+    let mut statistics = Statistics::default();
+    let mut game_indices = first_game_index..first_game_index + games_count;
+
+    loop {
+        let batch = game_indices
+            .by_ref()
+            .take(PARALLEL_GAMES_COUNT)
+            .map(|index| {
+                let settings = settings.clone();
+                tokio::spawn(async move {
+                    // tokens need to be unique and uppercase like the tokens of real games
+                    let token = format!("SIM{}", index);
+                    simulate_game(&token, settings, players_count, ctx).await
+                })
+            })
+            .collect::<Vec<_>>();
+        if batch.is_empty() {
+            break;
+        }
+
+        for handle in batch {
+            match handle.await {
+                Ok(Ok(result)) => statistics.add(result),
+                Ok(Err(err)) => {
+                    warn!("Simulating game has failed: {}", err);
+                    statistics.add_failure();
+                }
+                Err(err) => {
+                    warn!("Simulating game has panicked: {:?}", err);
+                    statistics.add_failure();
+                }
+            }
+        }
+    }
+
+    statistics
+}
+
+/// Plays a single game with bots only, which answer their tasks the same way as during a real game.
+async fn simulate_game(
+    game_token: &str,
+    settings: GameSettings,
+    players_count: usize,
+    ctx: &AppContext,
+) -> Result<GameResult, String> {
+    let players = iter::repeat_with(|| Player::new_bot(game_token))
+        .take(players_count)
+        .collect::<Vec<_>>();
+    let admin_id = String::from(players[0].id());
+    ctx.db()
+        .players()
+        .persist_batch(&players)
+        .await
+        .map_err(|err| err.to_string())?;
+    ctx.db()
+        .create_game(&admin_id, game_token)
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut lobby_events = players[1..]
+        .iter()
+        .map(|bot| GameEvent::BotAdded {
+            player_id: admin_id.clone(),
+            bot_id: String::from(bot.id()),
+        })
+        .collect::<Vec<_>>();
+    lobby_events.push(GameEvent::SettingsUpdated {
+        player_id: admin_id.clone(),
+        settings,
+    });
+    let game = ctx
+        .db()
+        .apply_events(game_token, lobby_events)
+        .await
+        .map_err(|err| err.to_string())?;
+    game.check_players_count(ctx.config().max_players_count)
+        .map_err(|rejection| rejection.to_string())?;
+    let player_ids = players
+        .iter()
+        .map(|player| String::from(player.id()))
+        .collect::<Vec<_>>();
+    start_game(
+        game_token,
+        players
+            .into_iter()
+            .map(|player| (String::from(player.id()), player))
+            .collect(),
+        ctx,
+    )
+    .await?;
+
+    for _ in 0..MAX_STEPS_COUNT {
+        let game = ctx
+            .db()
+            .games()
+            .get(game_token)
+            .await
+            .map_err(|err| err.to_string())?
+            .ok_or_else(|| format!("Game {} not found", game_token))?;
+        if game.is_finished() {
+            let events = ctx
+                .db()
+                .events()
+                .read(game_token)
+                .map_err(|err| err.to_string())?;
+            return GameResult::from_events(&game, &events)
+                .ok_or_else(|| format!("Game {} has no winner", game_token));
+        }
+
+        let mut resolved = false;
+        for id in &player_ids {
+            let player = ctx
+                .db()
+                .players()
+                .get(id)
+                .await
+                .map_err(|err| err.to_string())?
+                .filter(|player| !player.open_tasks().is_empty());
+            if let Some(player) = player {
+                resolve_with_strategy(&player, &BotStrategy, ctx).await?;
+                resolved = true;
+            }
+        }
+        if !resolved {
+            return Err(format!("Game {} has no open tasks left", game_token));
+        }
+    }
+
+    Err(format!(
+        "Game {} hasn't finished after {} steps",
+        game_token, MAX_STEPS_COUNT
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_presets, simulate_game, simulate_games};
+    use crate::server::app_context::AppContext;
+
+    #[tokio::test]
+    async fn should_play_game_until_the_end() {
+        let ctx = AppContext::init();
+        let settings = default_presets()[0].settings.clone();
+
+        for (index, players_count) in (5..=10).enumerate() {
+            let result = simulate_game(
+                &format!("SIM{}", index),
+                settings.clone(),
+                players_count,
+                &ctx,
+            )
+            .await;
+            assert!(result.is_ok(), "{:?}", result);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_collect_statistics_of_all_games() {
+        let ctx: &'static AppContext = Box::leak(Box::new(AppContext::init()));
+        let settings = default_presets()[1].settings.clone();
+
+        let statistics = simulate_games(ctx, &settings, 7, 40, 0).await;
+
+        assert_eq!(statistics.games_count(), 40);
+        assert!(statistics.average_rounds() >= 5.0);
+    }
+}
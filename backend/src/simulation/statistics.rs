@@ -0,0 +1,175 @@
+use crate::model::{ExecutivePower, Game, GameEvent, GameState, LoggedEvent, Party, WinReason};
+use std::{collections::HashMap, fmt};
+
+const POWERS: [ExecutivePower; 4] = [
+    ExecutivePower::InvestigateLoyalty,
+    ExecutivePower::SpecialElection,
+    ExecutivePower::PolicyPeek,
+    ExecutivePower::Execution,
+];
+const WIN_REASONS: [WinReason; 4] = [
+    WinReason::LoyalPoliciesEnacted,
+    WinReason::ClanPoliciesEnacted,
+    WinReason::ClanLeaderExecuted,
+    WinReason::ClanLeaderElected,
+];
+
+/// The outcome of a single simulated game.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GameResult {
+    winner: Party,
+    reason: WinReason,
+    rounds_count: usize,
+    used_powers: Vec<ExecutivePower>,
+}
+
+impl GameResult {
+    /// Reads the outcome of a finished game from its event log, each nomination starts a new round.
+    /// Earlier games of the same lobby are ignored.
+    pub fn from_events(game: &Game, events: &[LoggedEvent]) -> Option<Self> {
+        let (winner, reason) = match game.state() {
+            GameState::Finished { winner, reason } => (*winner, *reason),
+            _ => return None,
+        };
+        let events = LoggedEvent::latest_game(events);
+        let rounds_count = events
+            .iter()
+            .filter(|logged_event| {
+                matches!(logged_event.event(), GameEvent::ChancellorNominated { .. })
+            })
+            .count();
+        let used_powers = events
+            .iter()
+            .filter_map(|logged_event| match logged_event.event() {
+                GameEvent::LoyaltyInvestigated { .. } => Some(ExecutivePower::InvestigateLoyalty),
+                GameEvent::SpecialElectionCalled { .. } => Some(ExecutivePower::SpecialElection),
+                GameEvent::PoliciesPeeked { .. } => Some(ExecutivePower::PolicyPeek),
+                GameEvent::PlayerExecuted { .. } => Some(ExecutivePower::Execution),
+                _ => None,
+            })
+            .collect();
+
+        Some(GameResult {
+            winner,
+            reason,
+            rounds_count,
+            used_powers,
+        })
+    }
+}
+
+/// Aggregates the results of all games played with the same rules and player count.
+#[derive(Default, Debug)]
+pub struct Statistics {
+    games_count: usize,
+    failed_count: usize,
+    loyal_wins_count: usize,
+    rounds_count: usize,
+    win_reasons: HashMap<WinReason, usize>,
+    used_powers: HashMap<ExecutivePower, usize>,
+}
+
+impl Statistics {
+    pub fn add(&mut self, result: GameResult) {
+        self.games_count += 1;
+        if result.winner == Party::Loyal {
+            self.loyal_wins_count += 1;
+        }
+        self.rounds_count += result.rounds_count;
+        *self.win_reasons.entry(result.reason).or_default() += 1;
+        for power in result.used_powers {
+            *self.used_powers.entry(power).or_default() += 1;
+        }
+    }
+
+    /// Counts games which couldn't be played until the end, they aren't part of any rate.
+    pub fn add_failure(&mut self) {
+        self.failed_count += 1;
+    }
+
+    pub fn games_count(&self) -> usize {
+        self.games_count
+    }
+
+    pub fn loyal_win_rate(&self) -> f64 {
+        self.rate(self.loyal_wins_count)
+    }
+
+    pub fn average_rounds(&self) -> f64 {
+        self.rate(self.rounds_count)
+    }
+
+    /// Returns how often the power has been used per game on average.
+    pub fn power_usage(&self, power: ExecutivePower) -> f64 {
+        self.rate(self.used_powers.get(&power).copied().unwrap_or_default())
+    }
+
+    fn rate(&self, count: usize) -> f64 {
+        if self.games_count == 0 {
+            0.0
+        } else {
+            count as f64 / self.games_count as f64
+        }
+    }
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "  Games: {} finished, {} failed",
+            self.games_count(),
+            self.failed_count
+        )?;
+        writeln!(
+            f,
+            "  Win rate: loyal {:.1}%, clan {:.1}%",
+            self.loyal_win_rate() * 100.0,
+            self.rate(self.games_count - self.loyal_wins_count) * 100.0
+        )?;
+        let reasons = WIN_REASONS
+            .iter()
+            .map(|reason| {
+                let count = self.win_reasons.get(reason).copied().unwrap_or_default();
+                format!("{:?} {:.1}%", reason, self.rate(count) * 100.0)
+            })
+            .collect::<Vec<_>>();
+        writeln!(f, "  Win reasons: {}", reasons.join(", "))?;
+        writeln!(f, "  Average length: {:.1} rounds", self.average_rounds())?;
+        let powers = POWERS
+            .iter()
+            .map(|power| format!("{:?} {:.2}", power, self.power_usage(*power)))
+            .collect::<Vec<_>>();
+        write!(f, "  Executive powers per game: {}", powers.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GameResult, Statistics};
+    use crate::model::{ExecutivePower, Party, WinReason};
+
+    #[test]
+    fn should_aggregate_results() {
+        let mut statistics = Statistics::default();
+        statistics.add(GameResult {
+            winner: Party::Loyal,
+            reason: WinReason::LoyalPoliciesEnacted,
+            rounds_count: 6,
+            used_powers: vec![],
+        });
+        statistics.add(GameResult {
+            winner: Party::Clan,
+            reason: WinReason::ClanLeaderElected,
+            rounds_count: 10,
+            used_powers: vec![ExecutivePower::Execution, ExecutivePower::Execution],
+        });
+        statistics.add_failure();
+
+        assert_eq!(statistics.games_count(), 2);
+        assert!((statistics.loyal_win_rate() - 0.5).abs() < f64::EPSILON);
+        assert!((statistics.average_rounds() - 8.0).abs() < f64::EPSILON);
+        assert!((statistics.power_usage(ExecutivePower::Execution) - 1.0).abs() < f64::EPSILON);
+        assert!(statistics.power_usage(ExecutivePower::PolicyPeek).abs() < f64::EPSILON);
+    }
+}
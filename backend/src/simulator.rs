@@ -0,0 +1,5 @@
+use secret_clan::run_simulation;
+
+fn main() {
+    run_simulation();
+}
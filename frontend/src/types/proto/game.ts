@@ -1,15 +1,144 @@
 /* eslint-disable */
+import { GameSettings } from './settings';
 import { Writer, Reader } from 'protobufjs/minimal';
 
 
+export enum WinReason {
+  LOYAL_POLICIES_ENACTED = 0,
+  CLAN_POLICIES_ENACTED = 1,
+  CLAN_LEADER_EXECUTED = 2,
+  CLAN_LEADER_ELECTED = 3,
+  UNRECOGNIZED = -1,
+}
+
+export function winReasonFromJSON(object: any): WinReason {
+  switch (object) {
+    case 0:
+    case "LOYAL_POLICIES_ENACTED":
+      return WinReason.LOYAL_POLICIES_ENACTED;
+    case 1:
+    case "CLAN_POLICIES_ENACTED":
+      return WinReason.CLAN_POLICIES_ENACTED;
+    case 2:
+    case "CLAN_LEADER_EXECUTED":
+      return WinReason.CLAN_LEADER_EXECUTED;
+    case 3:
+    case "CLAN_LEADER_ELECTED":
+      return WinReason.CLAN_LEADER_ELECTED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return WinReason.UNRECOGNIZED;
+  }
+}
+
+export function winReasonToJSON(object: WinReason): string {
+  switch (object) {
+    case WinReason.LOYAL_POLICIES_ENACTED:
+      return "LOYAL_POLICIES_ENACTED";
+    case WinReason.CLAN_POLICIES_ENACTED:
+      return "CLAN_POLICIES_ENACTED";
+    case WinReason.CLAN_LEADER_EXECUTED:
+      return "CLAN_LEADER_EXECUTED";
+    case WinReason.CLAN_LEADER_ELECTED:
+      return "CLAN_LEADER_ELECTED";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export enum Phase {
+  LOBBY = 0,
+  NOMINATION = 1,
+  ELECTION = 2,
+  LEGISLATIVE_SESSION = 3,
+  EXECUTIVE_ACTION = 4,
+  FINISHED = 5,
+  UNRECOGNIZED = -1,
+}
+
+export function phaseFromJSON(object: any): Phase {
+  switch (object) {
+    case 0:
+    case "LOBBY":
+      return Phase.LOBBY;
+    case 1:
+    case "NOMINATION":
+      return Phase.NOMINATION;
+    case 2:
+    case "ELECTION":
+      return Phase.ELECTION;
+    case 3:
+    case "LEGISLATIVE_SESSION":
+      return Phase.LEGISLATIVE_SESSION;
+    case 4:
+    case "EXECUTIVE_ACTION":
+      return Phase.EXECUTIVE_ACTION;
+    case 5:
+    case "FINISHED":
+      return Phase.FINISHED;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return Phase.UNRECOGNIZED;
+  }
+}
+
+export function phaseToJSON(object: Phase): string {
+  switch (object) {
+    case Phase.LOBBY:
+      return "LOBBY";
+    case Phase.NOMINATION:
+      return "NOMINATION";
+    case Phase.ELECTION:
+      return "ELECTION";
+    case Phase.LEGISLATIVE_SESSION:
+      return "LEGISLATIVE_SESSION";
+    case Phase.EXECUTIVE_ACTION:
+      return "EXECUTIVE_ACTION";
+    case Phase.FINISHED:
+      return "FINISHED";
+    default:
+      return "UNKNOWN";
+  }
+}
+
 export interface Game {
   token: string;
   adminId: string;
+  drawPileCount: number;
+  discardPileCount: number;
+  seatIds: string[];
+  presidentialCandidateId: string;
+  nominatedChancellorId: string;
+  presidentId: string;
+  chancellorId: string;
+  loyalPoliciesCount: number;
+  clanPoliciesCount: number;
+  failedElectionsCount: number;
+  executedPlayerIds: string[];
+  finished: boolean;
+  eligibleChancellorIds: string[];
+  settings?: GameSettings;
+  passwordProtected: boolean;
+  locked: boolean;
 }
 
 const baseGame: object = {
   token: "",
   adminId: "",
+  drawPileCount: 0,
+  discardPileCount: 0,
+  presidentialCandidateId: "",
+  nominatedChancellorId: "",
+  presidentId: "",
+  chancellorId: "",
+  loyalPoliciesCount: 0,
+  clanPoliciesCount: 0,
+  failedElectionsCount: 0,
+  finished: false,
+  passwordProtected: false,
+  locked: false,
 };
 
 export const protobufPackage = ''
@@ -18,12 +147,39 @@ export const Game = {
   encode(message: Game, writer: Writer = Writer.create()): Writer {
     writer.uint32(10).string(message.token);
     writer.uint32(18).string(message.adminId);
+    writer.uint32(24).uint32(message.drawPileCount);
+    writer.uint32(32).uint32(message.discardPileCount);
+    for (const v of message.seatIds) {
+      writer.uint32(42).string(v!);
+    }
+    writer.uint32(50).string(message.presidentialCandidateId);
+    writer.uint32(58).string(message.nominatedChancellorId);
+    writer.uint32(66).string(message.presidentId);
+    writer.uint32(74).string(message.chancellorId);
+    writer.uint32(80).uint32(message.loyalPoliciesCount);
+    writer.uint32(88).uint32(message.clanPoliciesCount);
+    writer.uint32(96).uint32(message.failedElectionsCount);
+    for (const v of message.executedPlayerIds) {
+      writer.uint32(106).string(v!);
+    }
+    writer.uint32(112).bool(message.finished);
+    for (const v of message.eligibleChancellorIds) {
+      writer.uint32(122).string(v!);
+    }
+    if (message.settings !== undefined && message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(130).fork()).ldelim();
+    }
+    writer.uint32(136).bool(message.passwordProtected);
+    writer.uint32(144).bool(message.locked);
     return writer;
   },
   decode(input: Uint8Array | Reader, length?: number): Game {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
     const message = { ...baseGame } as Game;
+    message.seatIds = [];
+    message.executedPlayerIds = [];
+    message.eligibleChancellorIds = [];
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
@@ -33,6 +189,54 @@ export const Game = {
         case 2:
           message.adminId = reader.string();
           break;
+        case 3:
+          message.drawPileCount = reader.uint32();
+          break;
+        case 4:
+          message.discardPileCount = reader.uint32();
+          break;
+        case 5:
+          message.seatIds.push(reader.string());
+          break;
+        case 6:
+          message.presidentialCandidateId = reader.string();
+          break;
+        case 7:
+          message.nominatedChancellorId = reader.string();
+          break;
+        case 8:
+          message.presidentId = reader.string();
+          break;
+        case 9:
+          message.chancellorId = reader.string();
+          break;
+        case 10:
+          message.loyalPoliciesCount = reader.uint32();
+          break;
+        case 11:
+          message.clanPoliciesCount = reader.uint32();
+          break;
+        case 12:
+          message.failedElectionsCount = reader.uint32();
+          break;
+        case 13:
+          message.executedPlayerIds.push(reader.string());
+          break;
+        case 14:
+          message.finished = reader.bool();
+          break;
+        case 15:
+          message.eligibleChancellorIds.push(reader.string());
+          break;
+        case 16:
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          break;
+        case 17:
+          message.passwordProtected = reader.bool();
+          break;
+        case 18:
+          message.locked = reader.bool();
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -42,28 +246,170 @@ export const Game = {
   },
   fromJSON(object: any): Game {
     const message = { ...baseGame } as Game;
+    message.seatIds = [];
+    message.executedPlayerIds = [];
+    message.eligibleChancellorIds = [];
     if (object.token !== undefined && object.token !== null) {
       message.token = String(object.token);
     }
     if (object.adminId !== undefined && object.adminId !== null) {
       message.adminId = String(object.adminId);
     }
+    if (object.drawPileCount !== undefined && object.drawPileCount !== null) {
+      message.drawPileCount = Number(object.drawPileCount);
+    }
+    if (object.discardPileCount !== undefined && object.discardPileCount !== null) {
+      message.discardPileCount = Number(object.discardPileCount);
+    }
+    if (object.seatIds !== undefined && object.seatIds !== null) {
+      for (const e of object.seatIds) {
+        message.seatIds.push(String(e));
+      }
+    }
+    if (object.presidentialCandidateId !== undefined && object.presidentialCandidateId !== null) {
+      message.presidentialCandidateId = String(object.presidentialCandidateId);
+    }
+    if (object.nominatedChancellorId !== undefined && object.nominatedChancellorId !== null) {
+      message.nominatedChancellorId = String(object.nominatedChancellorId);
+    }
+    if (object.presidentId !== undefined && object.presidentId !== null) {
+      message.presidentId = String(object.presidentId);
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = String(object.chancellorId);
+    }
+    if (object.loyalPoliciesCount !== undefined && object.loyalPoliciesCount !== null) {
+      message.loyalPoliciesCount = Number(object.loyalPoliciesCount);
+    }
+    if (object.clanPoliciesCount !== undefined && object.clanPoliciesCount !== null) {
+      message.clanPoliciesCount = Number(object.clanPoliciesCount);
+    }
+    if (object.failedElectionsCount !== undefined && object.failedElectionsCount !== null) {
+      message.failedElectionsCount = Number(object.failedElectionsCount);
+    }
+    if (object.executedPlayerIds !== undefined && object.executedPlayerIds !== null) {
+      for (const e of object.executedPlayerIds) {
+        message.executedPlayerIds.push(String(e));
+      }
+    }
+    if (object.finished !== undefined && object.finished !== null) {
+      message.finished = Boolean(object.finished);
+    }
+    if (object.eligibleChancellorIds !== undefined && object.eligibleChancellorIds !== null) {
+      for (const e of object.eligibleChancellorIds) {
+        message.eligibleChancellorIds.push(String(e));
+      }
+    }
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromJSON(object.settings);
+    }
+    if (object.passwordProtected !== undefined && object.passwordProtected !== null) {
+      message.passwordProtected = Boolean(object.passwordProtected);
+    }
+    if (object.locked !== undefined && object.locked !== null) {
+      message.locked = Boolean(object.locked);
+    }
     return message;
   },
   fromPartial(object: DeepPartial<Game>): Game {
     const message = { ...baseGame } as Game;
+    message.seatIds = [];
+    message.executedPlayerIds = [];
+    message.eligibleChancellorIds = [];
     if (object.token !== undefined && object.token !== null) {
       message.token = object.token;
     }
     if (object.adminId !== undefined && object.adminId !== null) {
       message.adminId = object.adminId;
     }
+    if (object.drawPileCount !== undefined && object.drawPileCount !== null) {
+      message.drawPileCount = object.drawPileCount;
+    }
+    if (object.discardPileCount !== undefined && object.discardPileCount !== null) {
+      message.discardPileCount = object.discardPileCount;
+    }
+    if (object.seatIds !== undefined && object.seatIds !== null) {
+      for (const e of object.seatIds) {
+        message.seatIds.push(e);
+      }
+    }
+    if (object.presidentialCandidateId !== undefined && object.presidentialCandidateId !== null) {
+      message.presidentialCandidateId = object.presidentialCandidateId;
+    }
+    if (object.nominatedChancellorId !== undefined && object.nominatedChancellorId !== null) {
+      message.nominatedChancellorId = object.nominatedChancellorId;
+    }
+    if (object.presidentId !== undefined && object.presidentId !== null) {
+      message.presidentId = object.presidentId;
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = object.chancellorId;
+    }
+    if (object.loyalPoliciesCount !== undefined && object.loyalPoliciesCount !== null) {
+      message.loyalPoliciesCount = object.loyalPoliciesCount;
+    }
+    if (object.clanPoliciesCount !== undefined && object.clanPoliciesCount !== null) {
+      message.clanPoliciesCount = object.clanPoliciesCount;
+    }
+    if (object.failedElectionsCount !== undefined && object.failedElectionsCount !== null) {
+      message.failedElectionsCount = object.failedElectionsCount;
+    }
+    if (object.executedPlayerIds !== undefined && object.executedPlayerIds !== null) {
+      for (const e of object.executedPlayerIds) {
+        message.executedPlayerIds.push(e);
+      }
+    }
+    if (object.finished !== undefined && object.finished !== null) {
+      message.finished = object.finished;
+    }
+    if (object.eligibleChancellorIds !== undefined && object.eligibleChancellorIds !== null) {
+      for (const e of object.eligibleChancellorIds) {
+        message.eligibleChancellorIds.push(e);
+      }
+    }
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromPartial(object.settings);
+    }
+    if (object.passwordProtected !== undefined && object.passwordProtected !== null) {
+      message.passwordProtected = object.passwordProtected;
+    }
+    if (object.locked !== undefined && object.locked !== null) {
+      message.locked = object.locked;
+    }
     return message;
   },
   toJSON(message: Game): unknown {
     const obj: any = {};
     message.token !== undefined && (obj.token = message.token);
     message.adminId !== undefined && (obj.adminId = message.adminId);
+    message.drawPileCount !== undefined && (obj.drawPileCount = message.drawPileCount);
+    message.discardPileCount !== undefined && (obj.discardPileCount = message.discardPileCount);
+    if (message.seatIds) {
+      obj.seatIds = message.seatIds.map(e => e);
+    } else {
+      obj.seatIds = [];
+    }
+    message.presidentialCandidateId !== undefined && (obj.presidentialCandidateId = message.presidentialCandidateId);
+    message.nominatedChancellorId !== undefined && (obj.nominatedChancellorId = message.nominatedChancellorId);
+    message.presidentId !== undefined && (obj.presidentId = message.presidentId);
+    message.chancellorId !== undefined && (obj.chancellorId = message.chancellorId);
+    message.loyalPoliciesCount !== undefined && (obj.loyalPoliciesCount = message.loyalPoliciesCount);
+    message.clanPoliciesCount !== undefined && (obj.clanPoliciesCount = message.clanPoliciesCount);
+    message.failedElectionsCount !== undefined && (obj.failedElectionsCount = message.failedElectionsCount);
+    if (message.executedPlayerIds) {
+      obj.executedPlayerIds = message.executedPlayerIds.map(e => e);
+    } else {
+      obj.executedPlayerIds = [];
+    }
+    message.finished !== undefined && (obj.finished = message.finished);
+    if (message.eligibleChancellorIds) {
+      obj.eligibleChancellorIds = message.eligibleChancellorIds.map(e => e);
+    } else {
+      obj.eligibleChancellorIds = [];
+    }
+    message.settings !== undefined && (obj.settings = message.settings ? GameSettings.toJSON(message.settings) : undefined);
+    message.passwordProtected !== undefined && (obj.passwordProtected = message.passwordProtected);
+    message.locked !== undefined && (obj.locked = message.locked);
     return obj;
   },
 };
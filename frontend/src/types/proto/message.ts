@@ -1,11 +1,14 @@
 /* eslint-disable */
-import { Player, OwnPlayer } from './player';
-import { Game } from './game';
+import { Policy, policyFromJSON, policyToJSON } from './policy';
+import { GameSettings } from './settings';
+import { Player, OwnPlayer, NameRejection, nameRejectionFromJSON, nameRejectionToJSON } from './player';
+import { Game, WinReason, winReasonFromJSON, winReasonToJSON, Phase, phaseFromJSON, phaseToJSON } from './game';
+import { Role, roleFromJSON, roleToJSON, Party, partyFromJSON, partyToJSON, PlayerRole } from './role';
 import { Writer, Reader } from 'protobufjs/minimal';
 
 
 export interface Client {
-  message?: { $case: 'authConfirmed', authConfirmed: Client_AuthConfirmed } | { $case: 'nameUpdated', nameUpdated: Client_NameUpdated };
+  message?: { $case: 'authConfirmed', authConfirmed: Client_AuthConfirmed } | { $case: 'nameUpdated', nameUpdated: Client_NameUpdated } | { $case: 'roleDiscovered', roleDiscovered: Client_RoleDiscovered } | { $case: 'chancellorNominated', chancellorNominated: Client_ChancellorNominated } | { $case: 'voteCast', voteCast: Client_VoteCast } | { $case: 'policyDiscarded', policyDiscarded: Client_PolicyDiscarded } | { $case: 'policyEnacted', policyEnacted: Client_PolicyEnacted } | { $case: 'loyaltyInvestigated', loyaltyInvestigated: Client_LoyaltyInvestigated } | { $case: 'specialElectionCalled', specialElectionCalled: Client_SpecialElectionCalled } | { $case: 'policiesPeeked', policiesPeeked: Client_PoliciesPeeked } | { $case: 'playerExecuted', playerExecuted: Client_PlayerExecuted } | { $case: 'gameSettingsUpdated', gameSettingsUpdated: Client_GameSettingsUpdated } | { $case: 'chatMessage', chatMessage: Client_ChatMessage } | { $case: 'readyChanged', readyChanged: Client_ReadyChanged } | { $case: 'vetoProposed', vetoProposed: Client_VetoProposed } | { $case: 'vetoAnswered', vetoAnswered: Client_VetoAnswered };
 }
 
 export interface Client_AuthConfirmed {
@@ -16,8 +19,61 @@ export interface Client_NameUpdated {
   name: string;
 }
 
+export interface Client_RoleDiscovered {
+}
+
+export interface Client_ChancellorNominated {
+  playerId: string;
+}
+
+export interface Client_VoteCast {
+  approved: boolean;
+}
+
+export interface Client_PolicyDiscarded {
+  policy: Policy;
+}
+
+export interface Client_PolicyEnacted {
+  policy: Policy;
+}
+
+export interface Client_LoyaltyInvestigated {
+  playerId: string;
+}
+
+export interface Client_SpecialElectionCalled {
+  playerId: string;
+}
+
+export interface Client_PoliciesPeeked {
+}
+
+export interface Client_PlayerExecuted {
+  playerId: string;
+}
+
+export interface Client_GameSettingsUpdated {
+  settings?: GameSettings;
+}
+
+export interface Client_ChatMessage {
+  text: string;
+}
+
+export interface Client_ReadyChanged {
+  ready: boolean;
+}
+
+export interface Client_VetoProposed {
+}
+
+export interface Client_VetoAnswered {
+  approved: boolean;
+}
+
 export interface Server {
-  message?: { $case: 'playerUpdated', playerUpdated: Server_PlayerUpdated } | { $case: 'gameUpdated', gameUpdated: Server_GameUpdated } | { $case: 'selfUpdated', selfUpdated: Server_SelfUpdated } | { $case: 'playerEntered', playerEntered: Server_PlayerEntered } | { $case: 'playerLeft', playerLeft: Server_PlayerLeft };
+  message?: { $case: 'playerUpdated', playerUpdated: Server_PlayerUpdated } | { $case: 'gameUpdated', gameUpdated: Server_GameUpdated } | { $case: 'selfUpdated', selfUpdated: Server_SelfUpdated } | { $case: 'playerEntered', playerEntered: Server_PlayerEntered } | { $case: 'playerLeft', playerLeft: Server_PlayerLeft } | { $case: 'roleAssigned', roleAssigned: Server_RoleAssigned } | { $case: 'votesCounted', votesCounted: Server_VotesCounted } | { $case: 'chaosPolicyEnacted', chaosPolicyEnacted: Server_ChaosPolicyEnacted } | { $case: 'loyaltyRevealed', loyaltyRevealed: Server_LoyaltyRevealed } | { $case: 'policiesRevealed', policiesRevealed: Server_PoliciesRevealed } | { $case: 'specialElectionCalled', specialElectionCalled: Server_SpecialElectionCalled } | { $case: 'playerExecuted', playerExecuted: Server_PlayerExecuted } | { $case: 'gameFinished', gameFinished: Server_GameFinished } | { $case: 'snapshot', snapshot: Server_Snapshot } | { $case: 'chatPosted', chatPosted: Server_ChatPosted } | { $case: 'nameRejected', nameRejected: Server_NameRejected };
 }
 
 export interface Server_PlayerUpdated {
@@ -40,6 +96,78 @@ export interface Server_PlayerLeft {
   playerId: string;
 }
 
+export interface Server_RoleAssigned {
+  role: Role;
+  clanMemberIds: string[];
+  clanLeaderId: string;
+}
+
+export interface Server_VotesCounted {
+  approvingPlayerIds: string[];
+  rejectingPlayerIds: string[];
+  approved: boolean;
+  abstainingPlayerIds: string[];
+}
+
+export interface Server_ChaosPolicyEnacted {
+  policy: Policy;
+}
+
+export interface Server_LoyaltyRevealed {
+  playerId: string;
+  party: Party;
+}
+
+export interface Server_PoliciesRevealed {
+  policies: Policy[];
+}
+
+export interface Server_SpecialElectionCalled {
+  playerId: string;
+}
+
+export interface Server_PlayerExecuted {
+  playerId: string;
+}
+
+export interface Server_GameFinished {
+  winner: Party;
+  reason: WinReason;
+  roles: PlayerRole[];
+}
+
+export interface Server_ChatPosted {
+  playerId: string;
+  text: string;
+  time: string;
+}
+
+export interface Server_NameRejected {
+  reason: NameRejection;
+  name: string;
+}
+
+export interface Server_Snapshot {
+  game?: Game;
+  players: Server_Snapshot_PlayerPresence[];
+  ownPlayer?: OwnPlayer;
+  knowledge?: Server_Snapshot_Knowledge;
+  phase: Phase;
+  chat: Server_ChatPosted[];
+}
+
+export interface Server_Snapshot_PlayerPresence {
+  player?: Player;
+  connected: boolean;
+}
+
+export interface Server_Snapshot_Knowledge {
+  role: Role;
+  clanMemberIds: string[];
+  clanLeaderId: string;
+  investigations: Server_LoyaltyRevealed[];
+}
+
 const baseClient: object = {
 };
 
@@ -51,6 +179,58 @@ const baseClient_NameUpdated: object = {
   name: "",
 };
 
+const baseClient_RoleDiscovered: object = {
+};
+
+const baseClient_ChancellorNominated: object = {
+  playerId: "",
+};
+
+const baseClient_VoteCast: object = {
+  approved: false,
+};
+
+const baseClient_PolicyDiscarded: object = {
+  policy: 0,
+};
+
+const baseClient_PolicyEnacted: object = {
+  policy: 0,
+};
+
+const baseClient_LoyaltyInvestigated: object = {
+  playerId: "",
+};
+
+const baseClient_SpecialElectionCalled: object = {
+  playerId: "",
+};
+
+const baseClient_PoliciesPeeked: object = {
+};
+
+const baseClient_PlayerExecuted: object = {
+  playerId: "",
+};
+
+const baseClient_GameSettingsUpdated: object = {
+};
+
+const baseClient_ChatMessage: object = {
+  text: "",
+};
+
+const baseClient_ReadyChanged: object = {
+  ready: false,
+};
+
+const baseClient_VetoProposed: object = {
+};
+
+const baseClient_VetoAnswered: object = {
+  approved: false,
+};
+
 const baseServer: object = {
 };
 
@@ -70,6 +250,64 @@ const baseServer_PlayerLeft: object = {
   playerId: "",
 };
 
+const baseServer_RoleAssigned: object = {
+  role: 0,
+  clanLeaderId: "",
+};
+
+const baseServer_VotesCounted: object = {
+  approved: false,
+};
+
+const baseServer_ChaosPolicyEnacted: object = {
+  policy: 0,
+};
+
+const baseServer_LoyaltyRevealed: object = {
+  playerId: "",
+  party: 0,
+};
+
+const baseServer_PoliciesRevealed: object = {
+};
+
+const baseServer_SpecialElectionCalled: object = {
+  playerId: "",
+};
+
+const baseServer_PlayerExecuted: object = {
+  playerId: "",
+};
+
+const baseServer_GameFinished: object = {
+  winner: 0,
+  reason: 0,
+};
+
+const baseServer_ChatPosted: object = {
+  playerId: "",
+  text: "",
+  time: "",
+};
+
+const baseServer_NameRejected: object = {
+  reason: 0,
+  name: "",
+};
+
+const baseServer_Snapshot: object = {
+  phase: 0,
+};
+
+const baseServer_Snapshot_PlayerPresence: object = {
+  connected: false,
+};
+
+const baseServer_Snapshot_Knowledge: object = {
+  role: 0,
+  clanLeaderId: "",
+};
+
 export const protobufPackage = ''
 
 export const Client = {
@@ -80,6 +318,48 @@ export const Client = {
     if (message.message?.$case === 'nameUpdated') {
       Client_NameUpdated.encode(message.message.nameUpdated, writer.uint32(18).fork()).ldelim();
     }
+    if (message.message?.$case === 'roleDiscovered') {
+      Client_RoleDiscovered.encode(message.message.roleDiscovered, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.message?.$case === 'chancellorNominated') {
+      Client_ChancellorNominated.encode(message.message.chancellorNominated, writer.uint32(34).fork()).ldelim();
+    }
+    if (message.message?.$case === 'voteCast') {
+      Client_VoteCast.encode(message.message.voteCast, writer.uint32(42).fork()).ldelim();
+    }
+    if (message.message?.$case === 'policyDiscarded') {
+      Client_PolicyDiscarded.encode(message.message.policyDiscarded, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.message?.$case === 'policyEnacted') {
+      Client_PolicyEnacted.encode(message.message.policyEnacted, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.message?.$case === 'loyaltyInvestigated') {
+      Client_LoyaltyInvestigated.encode(message.message.loyaltyInvestigated, writer.uint32(66).fork()).ldelim();
+    }
+    if (message.message?.$case === 'specialElectionCalled') {
+      Client_SpecialElectionCalled.encode(message.message.specialElectionCalled, writer.uint32(74).fork()).ldelim();
+    }
+    if (message.message?.$case === 'policiesPeeked') {
+      Client_PoliciesPeeked.encode(message.message.policiesPeeked, writer.uint32(82).fork()).ldelim();
+    }
+    if (message.message?.$case === 'playerExecuted') {
+      Client_PlayerExecuted.encode(message.message.playerExecuted, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.message?.$case === 'gameSettingsUpdated') {
+      Client_GameSettingsUpdated.encode(message.message.gameSettingsUpdated, writer.uint32(98).fork()).ldelim();
+    }
+    if (message.message?.$case === 'chatMessage') {
+      Client_ChatMessage.encode(message.message.chatMessage, writer.uint32(106).fork()).ldelim();
+    }
+    if (message.message?.$case === 'readyChanged') {
+      Client_ReadyChanged.encode(message.message.readyChanged, writer.uint32(114).fork()).ldelim();
+    }
+    if (message.message?.$case === 'vetoProposed') {
+      Client_VetoProposed.encode(message.message.vetoProposed, writer.uint32(122).fork()).ldelim();
+    }
+    if (message.message?.$case === 'vetoAnswered') {
+      Client_VetoAnswered.encode(message.message.vetoAnswered, writer.uint32(130).fork()).ldelim();
+    }
     return writer;
   },
   decode(input: Uint8Array | Reader, length?: number): Client {
@@ -95,6 +375,48 @@ export const Client = {
         case 2:
           message.message = {$case: 'nameUpdated', nameUpdated: Client_NameUpdated.decode(reader, reader.uint32())};
           break;
+        case 3:
+          message.message = {$case: 'roleDiscovered', roleDiscovered: Client_RoleDiscovered.decode(reader, reader.uint32())};
+          break;
+        case 4:
+          message.message = {$case: 'chancellorNominated', chancellorNominated: Client_ChancellorNominated.decode(reader, reader.uint32())};
+          break;
+        case 5:
+          message.message = {$case: 'voteCast', voteCast: Client_VoteCast.decode(reader, reader.uint32())};
+          break;
+        case 6:
+          message.message = {$case: 'policyDiscarded', policyDiscarded: Client_PolicyDiscarded.decode(reader, reader.uint32())};
+          break;
+        case 7:
+          message.message = {$case: 'policyEnacted', policyEnacted: Client_PolicyEnacted.decode(reader, reader.uint32())};
+          break;
+        case 8:
+          message.message = {$case: 'loyaltyInvestigated', loyaltyInvestigated: Client_LoyaltyInvestigated.decode(reader, reader.uint32())};
+          break;
+        case 9:
+          message.message = {$case: 'specialElectionCalled', specialElectionCalled: Client_SpecialElectionCalled.decode(reader, reader.uint32())};
+          break;
+        case 10:
+          message.message = {$case: 'policiesPeeked', policiesPeeked: Client_PoliciesPeeked.decode(reader, reader.uint32())};
+          break;
+        case 11:
+          message.message = {$case: 'playerExecuted', playerExecuted: Client_PlayerExecuted.decode(reader, reader.uint32())};
+          break;
+        case 12:
+          message.message = {$case: 'gameSettingsUpdated', gameSettingsUpdated: Client_GameSettingsUpdated.decode(reader, reader.uint32())};
+          break;
+        case 13:
+          message.message = {$case: 'chatMessage', chatMessage: Client_ChatMessage.decode(reader, reader.uint32())};
+          break;
+        case 14:
+          message.message = {$case: 'readyChanged', readyChanged: Client_ReadyChanged.decode(reader, reader.uint32())};
+          break;
+        case 15:
+          message.message = {$case: 'vetoProposed', vetoProposed: Client_VetoProposed.decode(reader, reader.uint32())};
+          break;
+        case 16:
+          message.message = {$case: 'vetoAnswered', vetoAnswered: Client_VetoAnswered.decode(reader, reader.uint32())};
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -110,6 +432,48 @@ export const Client = {
     if (object.nameUpdated !== undefined && object.nameUpdated !== null) {
       message.message = {$case: 'nameUpdated', nameUpdated: Client_NameUpdated.fromJSON(object.nameUpdated)};
     }
+    if (object.roleDiscovered !== undefined && object.roleDiscovered !== null) {
+      message.message = {$case: 'roleDiscovered', roleDiscovered: Client_RoleDiscovered.fromJSON(object.roleDiscovered)};
+    }
+    if (object.chancellorNominated !== undefined && object.chancellorNominated !== null) {
+      message.message = {$case: 'chancellorNominated', chancellorNominated: Client_ChancellorNominated.fromJSON(object.chancellorNominated)};
+    }
+    if (object.voteCast !== undefined && object.voteCast !== null) {
+      message.message = {$case: 'voteCast', voteCast: Client_VoteCast.fromJSON(object.voteCast)};
+    }
+    if (object.policyDiscarded !== undefined && object.policyDiscarded !== null) {
+      message.message = {$case: 'policyDiscarded', policyDiscarded: Client_PolicyDiscarded.fromJSON(object.policyDiscarded)};
+    }
+    if (object.policyEnacted !== undefined && object.policyEnacted !== null) {
+      message.message = {$case: 'policyEnacted', policyEnacted: Client_PolicyEnacted.fromJSON(object.policyEnacted)};
+    }
+    if (object.loyaltyInvestigated !== undefined && object.loyaltyInvestigated !== null) {
+      message.message = {$case: 'loyaltyInvestigated', loyaltyInvestigated: Client_LoyaltyInvestigated.fromJSON(object.loyaltyInvestigated)};
+    }
+    if (object.specialElectionCalled !== undefined && object.specialElectionCalled !== null) {
+      message.message = {$case: 'specialElectionCalled', specialElectionCalled: Client_SpecialElectionCalled.fromJSON(object.specialElectionCalled)};
+    }
+    if (object.policiesPeeked !== undefined && object.policiesPeeked !== null) {
+      message.message = {$case: 'policiesPeeked', policiesPeeked: Client_PoliciesPeeked.fromJSON(object.policiesPeeked)};
+    }
+    if (object.playerExecuted !== undefined && object.playerExecuted !== null) {
+      message.message = {$case: 'playerExecuted', playerExecuted: Client_PlayerExecuted.fromJSON(object.playerExecuted)};
+    }
+    if (object.gameSettingsUpdated !== undefined && object.gameSettingsUpdated !== null) {
+      message.message = {$case: 'gameSettingsUpdated', gameSettingsUpdated: Client_GameSettingsUpdated.fromJSON(object.gameSettingsUpdated)};
+    }
+    if (object.chatMessage !== undefined && object.chatMessage !== null) {
+      message.message = {$case: 'chatMessage', chatMessage: Client_ChatMessage.fromJSON(object.chatMessage)};
+    }
+    if (object.readyChanged !== undefined && object.readyChanged !== null) {
+      message.message = {$case: 'readyChanged', readyChanged: Client_ReadyChanged.fromJSON(object.readyChanged)};
+    }
+    if (object.vetoProposed !== undefined && object.vetoProposed !== null) {
+      message.message = {$case: 'vetoProposed', vetoProposed: Client_VetoProposed.fromJSON(object.vetoProposed)};
+    }
+    if (object.vetoAnswered !== undefined && object.vetoAnswered !== null) {
+      message.message = {$case: 'vetoAnswered', vetoAnswered: Client_VetoAnswered.fromJSON(object.vetoAnswered)};
+    }
     return message;
   },
   fromPartial(object: DeepPartial<Client>): Client {
@@ -120,12 +484,68 @@ export const Client = {
     if (object.message?.$case === 'nameUpdated' && object.message?.nameUpdated !== undefined && object.message?.nameUpdated !== null) {
       message.message = {$case: 'nameUpdated', nameUpdated: Client_NameUpdated.fromPartial(object.message.nameUpdated)};
     }
+    if (object.message?.$case === 'roleDiscovered' && object.message?.roleDiscovered !== undefined && object.message?.roleDiscovered !== null) {
+      message.message = {$case: 'roleDiscovered', roleDiscovered: Client_RoleDiscovered.fromPartial(object.message.roleDiscovered)};
+    }
+    if (object.message?.$case === 'chancellorNominated' && object.message?.chancellorNominated !== undefined && object.message?.chancellorNominated !== null) {
+      message.message = {$case: 'chancellorNominated', chancellorNominated: Client_ChancellorNominated.fromPartial(object.message.chancellorNominated)};
+    }
+    if (object.message?.$case === 'voteCast' && object.message?.voteCast !== undefined && object.message?.voteCast !== null) {
+      message.message = {$case: 'voteCast', voteCast: Client_VoteCast.fromPartial(object.message.voteCast)};
+    }
+    if (object.message?.$case === 'policyDiscarded' && object.message?.policyDiscarded !== undefined && object.message?.policyDiscarded !== null) {
+      message.message = {$case: 'policyDiscarded', policyDiscarded: Client_PolicyDiscarded.fromPartial(object.message.policyDiscarded)};
+    }
+    if (object.message?.$case === 'policyEnacted' && object.message?.policyEnacted !== undefined && object.message?.policyEnacted !== null) {
+      message.message = {$case: 'policyEnacted', policyEnacted: Client_PolicyEnacted.fromPartial(object.message.policyEnacted)};
+    }
+    if (object.message?.$case === 'loyaltyInvestigated' && object.message?.loyaltyInvestigated !== undefined && object.message?.loyaltyInvestigated !== null) {
+      message.message = {$case: 'loyaltyInvestigated', loyaltyInvestigated: Client_LoyaltyInvestigated.fromPartial(object.message.loyaltyInvestigated)};
+    }
+    if (object.message?.$case === 'specialElectionCalled' && object.message?.specialElectionCalled !== undefined && object.message?.specialElectionCalled !== null) {
+      message.message = {$case: 'specialElectionCalled', specialElectionCalled: Client_SpecialElectionCalled.fromPartial(object.message.specialElectionCalled)};
+    }
+    if (object.message?.$case === 'policiesPeeked' && object.message?.policiesPeeked !== undefined && object.message?.policiesPeeked !== null) {
+      message.message = {$case: 'policiesPeeked', policiesPeeked: Client_PoliciesPeeked.fromPartial(object.message.policiesPeeked)};
+    }
+    if (object.message?.$case === 'playerExecuted' && object.message?.playerExecuted !== undefined && object.message?.playerExecuted !== null) {
+      message.message = {$case: 'playerExecuted', playerExecuted: Client_PlayerExecuted.fromPartial(object.message.playerExecuted)};
+    }
+    if (object.message?.$case === 'gameSettingsUpdated' && object.message?.gameSettingsUpdated !== undefined && object.message?.gameSettingsUpdated !== null) {
+      message.message = {$case: 'gameSettingsUpdated', gameSettingsUpdated: Client_GameSettingsUpdated.fromPartial(object.message.gameSettingsUpdated)};
+    }
+    if (object.message?.$case === 'chatMessage' && object.message?.chatMessage !== undefined && object.message?.chatMessage !== null) {
+      message.message = {$case: 'chatMessage', chatMessage: Client_ChatMessage.fromPartial(object.message.chatMessage)};
+    }
+    if (object.message?.$case === 'readyChanged' && object.message?.readyChanged !== undefined && object.message?.readyChanged !== null) {
+      message.message = {$case: 'readyChanged', readyChanged: Client_ReadyChanged.fromPartial(object.message.readyChanged)};
+    }
+    if (object.message?.$case === 'vetoProposed' && object.message?.vetoProposed !== undefined && object.message?.vetoProposed !== null) {
+      message.message = {$case: 'vetoProposed', vetoProposed: Client_VetoProposed.fromPartial(object.message.vetoProposed)};
+    }
+    if (object.message?.$case === 'vetoAnswered' && object.message?.vetoAnswered !== undefined && object.message?.vetoAnswered !== null) {
+      message.message = {$case: 'vetoAnswered', vetoAnswered: Client_VetoAnswered.fromPartial(object.message.vetoAnswered)};
+    }
     return message;
   },
   toJSON(message: Client): unknown {
     const obj: any = {};
     message.message?.$case === 'authConfirmed' && (obj.authConfirmed = message.message?.authConfirmed ? Client_AuthConfirmed.toJSON(message.message?.authConfirmed) : undefined);
     message.message?.$case === 'nameUpdated' && (obj.nameUpdated = message.message?.nameUpdated ? Client_NameUpdated.toJSON(message.message?.nameUpdated) : undefined);
+    message.message?.$case === 'roleDiscovered' && (obj.roleDiscovered = message.message?.roleDiscovered ? Client_RoleDiscovered.toJSON(message.message?.roleDiscovered) : undefined);
+    message.message?.$case === 'chancellorNominated' && (obj.chancellorNominated = message.message?.chancellorNominated ? Client_ChancellorNominated.toJSON(message.message?.chancellorNominated) : undefined);
+    message.message?.$case === 'voteCast' && (obj.voteCast = message.message?.voteCast ? Client_VoteCast.toJSON(message.message?.voteCast) : undefined);
+    message.message?.$case === 'policyDiscarded' && (obj.policyDiscarded = message.message?.policyDiscarded ? Client_PolicyDiscarded.toJSON(message.message?.policyDiscarded) : undefined);
+    message.message?.$case === 'policyEnacted' && (obj.policyEnacted = message.message?.policyEnacted ? Client_PolicyEnacted.toJSON(message.message?.policyEnacted) : undefined);
+    message.message?.$case === 'loyaltyInvestigated' && (obj.loyaltyInvestigated = message.message?.loyaltyInvestigated ? Client_LoyaltyInvestigated.toJSON(message.message?.loyaltyInvestigated) : undefined);
+    message.message?.$case === 'specialElectionCalled' && (obj.specialElectionCalled = message.message?.specialElectionCalled ? Client_SpecialElectionCalled.toJSON(message.message?.specialElectionCalled) : undefined);
+    message.message?.$case === 'policiesPeeked' && (obj.policiesPeeked = message.message?.policiesPeeked ? Client_PoliciesPeeked.toJSON(message.message?.policiesPeeked) : undefined);
+    message.message?.$case === 'playerExecuted' && (obj.playerExecuted = message.message?.playerExecuted ? Client_PlayerExecuted.toJSON(message.message?.playerExecuted) : undefined);
+    message.message?.$case === 'gameSettingsUpdated' && (obj.gameSettingsUpdated = message.message?.gameSettingsUpdated ? Client_GameSettingsUpdated.toJSON(message.message?.gameSettingsUpdated) : undefined);
+    message.message?.$case === 'chatMessage' && (obj.chatMessage = message.message?.chatMessage ? Client_ChatMessage.toJSON(message.message?.chatMessage) : undefined);
+    message.message?.$case === 'readyChanged' && (obj.readyChanged = message.message?.readyChanged ? Client_ReadyChanged.toJSON(message.message?.readyChanged) : undefined);
+    message.message?.$case === 'vetoProposed' && (obj.vetoProposed = message.message?.vetoProposed ? Client_VetoProposed.toJSON(message.message?.vetoProposed) : undefined);
+    message.message?.$case === 'vetoAnswered' && (obj.vetoAnswered = message.message?.vetoAnswered ? Client_VetoAnswered.toJSON(message.message?.vetoAnswered) : undefined);
     return obj;
   },
 };
@@ -135,15 +555,1490 @@ export const Client_AuthConfirmed = {
     writer.uint32(10).string(message.token);
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Client_AuthConfirmed {
+  decode(input: Uint8Array | Reader, length?: number): Client_AuthConfirmed {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.token = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_AuthConfirmed {
+    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
+    if (object.token !== undefined && object.token !== null) {
+      message.token = String(object.token);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_AuthConfirmed>): Client_AuthConfirmed {
+    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
+    if (object.token !== undefined && object.token !== null) {
+      message.token = object.token;
+    }
+    return message;
+  },
+  toJSON(message: Client_AuthConfirmed): unknown {
+    const obj: any = {};
+    message.token !== undefined && (obj.token = message.token);
+    return obj;
+  },
+};
+
+export const Client_NameUpdated = {
+  encode(message: Client_NameUpdated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.name);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_NameUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.name = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_NameUpdated {
+    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
+    if (object.name !== undefined && object.name !== null) {
+      message.name = String(object.name);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_NameUpdated>): Client_NameUpdated {
+    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
+    if (object.name !== undefined && object.name !== null) {
+      message.name = object.name;
+    }
+    return message;
+  },
+  toJSON(message: Client_NameUpdated): unknown {
+    const obj: any = {};
+    message.name !== undefined && (obj.name = message.name);
+    return obj;
+  },
+};
+
+export const Client_RoleDiscovered = {
+  encode(_: Client_RoleDiscovered, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_RoleDiscovered {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_RoleDiscovered } as Client_RoleDiscovered;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Client_RoleDiscovered {
+    const message = { ...baseClient_RoleDiscovered } as Client_RoleDiscovered;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Client_RoleDiscovered>): Client_RoleDiscovered {
+    const message = { ...baseClient_RoleDiscovered } as Client_RoleDiscovered;
+    return message;
+  },
+  toJSON(_: Client_RoleDiscovered): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const Client_ChancellorNominated = {
+  encode(message: Client_ChancellorNominated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_ChancellorNominated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_ChancellorNominated } as Client_ChancellorNominated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_ChancellorNominated {
+    const message = { ...baseClient_ChancellorNominated } as Client_ChancellorNominated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_ChancellorNominated>): Client_ChancellorNominated {
+    const message = { ...baseClient_ChancellorNominated } as Client_ChancellorNominated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: Client_ChancellorNominated): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const Client_VoteCast = {
+  encode(message: Client_VoteCast, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).bool(message.approved);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_VoteCast {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_VoteCast } as Client_VoteCast;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.approved = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_VoteCast {
+    const message = { ...baseClient_VoteCast } as Client_VoteCast;
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = Boolean(object.approved);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_VoteCast>): Client_VoteCast {
+    const message = { ...baseClient_VoteCast } as Client_VoteCast;
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = object.approved;
+    }
+    return message;
+  },
+  toJSON(message: Client_VoteCast): unknown {
+    const obj: any = {};
+    message.approved !== undefined && (obj.approved = message.approved);
+    return obj;
+  },
+};
+
+export const Client_PolicyDiscarded = {
+  encode(message: Client_PolicyDiscarded, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.policy);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_PolicyDiscarded {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_PolicyDiscarded } as Client_PolicyDiscarded;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.policy = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_PolicyDiscarded {
+    const message = { ...baseClient_PolicyDiscarded } as Client_PolicyDiscarded;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = policyFromJSON(object.policy);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_PolicyDiscarded>): Client_PolicyDiscarded {
+    const message = { ...baseClient_PolicyDiscarded } as Client_PolicyDiscarded;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = object.policy;
+    }
+    return message;
+  },
+  toJSON(message: Client_PolicyDiscarded): unknown {
+    const obj: any = {};
+    message.policy !== undefined && (obj.policy = policyToJSON(message.policy));
+    return obj;
+  },
+};
+
+export const Client_PolicyEnacted = {
+  encode(message: Client_PolicyEnacted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.policy);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_PolicyEnacted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_PolicyEnacted } as Client_PolicyEnacted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.policy = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_PolicyEnacted {
+    const message = { ...baseClient_PolicyEnacted } as Client_PolicyEnacted;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = policyFromJSON(object.policy);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_PolicyEnacted>): Client_PolicyEnacted {
+    const message = { ...baseClient_PolicyEnacted } as Client_PolicyEnacted;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = object.policy;
+    }
+    return message;
+  },
+  toJSON(message: Client_PolicyEnacted): unknown {
+    const obj: any = {};
+    message.policy !== undefined && (obj.policy = policyToJSON(message.policy));
+    return obj;
+  },
+};
+
+export const Client_LoyaltyInvestigated = {
+  encode(message: Client_LoyaltyInvestigated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_LoyaltyInvestigated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_LoyaltyInvestigated } as Client_LoyaltyInvestigated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_LoyaltyInvestigated {
+    const message = { ...baseClient_LoyaltyInvestigated } as Client_LoyaltyInvestigated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_LoyaltyInvestigated>): Client_LoyaltyInvestigated {
+    const message = { ...baseClient_LoyaltyInvestigated } as Client_LoyaltyInvestigated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: Client_LoyaltyInvestigated): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const Client_SpecialElectionCalled = {
+  encode(message: Client_SpecialElectionCalled, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_SpecialElectionCalled {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_SpecialElectionCalled } as Client_SpecialElectionCalled;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_SpecialElectionCalled {
+    const message = { ...baseClient_SpecialElectionCalled } as Client_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_SpecialElectionCalled>): Client_SpecialElectionCalled {
+    const message = { ...baseClient_SpecialElectionCalled } as Client_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: Client_SpecialElectionCalled): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const Client_PoliciesPeeked = {
+  encode(_: Client_PoliciesPeeked, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_PoliciesPeeked {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_PoliciesPeeked } as Client_PoliciesPeeked;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Client_PoliciesPeeked {
+    const message = { ...baseClient_PoliciesPeeked } as Client_PoliciesPeeked;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Client_PoliciesPeeked>): Client_PoliciesPeeked {
+    const message = { ...baseClient_PoliciesPeeked } as Client_PoliciesPeeked;
+    return message;
+  },
+  toJSON(_: Client_PoliciesPeeked): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const Client_PlayerExecuted = {
+  encode(message: Client_PlayerExecuted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_PlayerExecuted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_PlayerExecuted } as Client_PlayerExecuted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_PlayerExecuted {
+    const message = { ...baseClient_PlayerExecuted } as Client_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_PlayerExecuted>): Client_PlayerExecuted {
+    const message = { ...baseClient_PlayerExecuted } as Client_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: Client_PlayerExecuted): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const Client_GameSettingsUpdated = {
+  encode(message: Client_GameSettingsUpdated, writer: Writer = Writer.create()): Writer {
+    if (message.settings !== undefined && message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_GameSettingsUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_GameSettingsUpdated } as Client_GameSettingsUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_GameSettingsUpdated {
+    const message = { ...baseClient_GameSettingsUpdated } as Client_GameSettingsUpdated;
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromJSON(object.settings);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_GameSettingsUpdated>): Client_GameSettingsUpdated {
+    const message = { ...baseClient_GameSettingsUpdated } as Client_GameSettingsUpdated;
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromPartial(object.settings);
+    }
+    return message;
+  },
+  toJSON(message: Client_GameSettingsUpdated): unknown {
+    const obj: any = {};
+    message.settings !== undefined && (obj.settings = message.settings ? GameSettings.toJSON(message.settings) : undefined);
+    return obj;
+  },
+};
+
+export const Client_ChatMessage = {
+  encode(message: Client_ChatMessage, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.text);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_ChatMessage {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_ChatMessage } as Client_ChatMessage;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.text = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_ChatMessage {
+    const message = { ...baseClient_ChatMessage } as Client_ChatMessage;
+    if (object.text !== undefined && object.text !== null) {
+      message.text = String(object.text);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_ChatMessage>): Client_ChatMessage {
+    const message = { ...baseClient_ChatMessage } as Client_ChatMessage;
+    if (object.text !== undefined && object.text !== null) {
+      message.text = object.text;
+    }
+    return message;
+  },
+  toJSON(message: Client_ChatMessage): unknown {
+    const obj: any = {};
+    message.text !== undefined && (obj.text = message.text);
+    return obj;
+  },
+};
+
+export const Client_ReadyChanged = {
+  encode(message: Client_ReadyChanged, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).bool(message.ready);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_ReadyChanged {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_ReadyChanged } as Client_ReadyChanged;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.ready = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_ReadyChanged {
+    const message = { ...baseClient_ReadyChanged } as Client_ReadyChanged;
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = Boolean(object.ready);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_ReadyChanged>): Client_ReadyChanged {
+    const message = { ...baseClient_ReadyChanged } as Client_ReadyChanged;
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = object.ready;
+    }
+    return message;
+  },
+  toJSON(message: Client_ReadyChanged): unknown {
+    const obj: any = {};
+    message.ready !== undefined && (obj.ready = message.ready);
+    return obj;
+  },
+};
+
+export const Client_VetoProposed = {
+  encode(_: Client_VetoProposed, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_VetoProposed {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_VetoProposed } as Client_VetoProposed;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Client_VetoProposed {
+    const message = { ...baseClient_VetoProposed } as Client_VetoProposed;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Client_VetoProposed>): Client_VetoProposed {
+    const message = { ...baseClient_VetoProposed } as Client_VetoProposed;
+    return message;
+  },
+  toJSON(_: Client_VetoProposed): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const Client_VetoAnswered = {
+  encode(message: Client_VetoAnswered, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).bool(message.approved);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Client_VetoAnswered {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseClient_VetoAnswered } as Client_VetoAnswered;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.approved = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Client_VetoAnswered {
+    const message = { ...baseClient_VetoAnswered } as Client_VetoAnswered;
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = Boolean(object.approved);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Client_VetoAnswered>): Client_VetoAnswered {
+    const message = { ...baseClient_VetoAnswered } as Client_VetoAnswered;
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = object.approved;
+    }
+    return message;
+  },
+  toJSON(message: Client_VetoAnswered): unknown {
+    const obj: any = {};
+    message.approved !== undefined && (obj.approved = message.approved);
+    return obj;
+  },
+};
+
+export const Server = {
+  encode(message: Server, writer: Writer = Writer.create()): Writer {
+    if (message.message?.$case === 'playerUpdated') {
+      Server_PlayerUpdated.encode(message.message.playerUpdated, writer.uint32(10).fork()).ldelim();
+    }
+    if (message.message?.$case === 'gameUpdated') {
+      Server_GameUpdated.encode(message.message.gameUpdated, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.message?.$case === 'selfUpdated') {
+      Server_SelfUpdated.encode(message.message.selfUpdated, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.message?.$case === 'playerEntered') {
+      Server_PlayerEntered.encode(message.message.playerEntered, writer.uint32(34).fork()).ldelim();
+    }
+    if (message.message?.$case === 'playerLeft') {
+      Server_PlayerLeft.encode(message.message.playerLeft, writer.uint32(42).fork()).ldelim();
+    }
+    if (message.message?.$case === 'roleAssigned') {
+      Server_RoleAssigned.encode(message.message.roleAssigned, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.message?.$case === 'votesCounted') {
+      Server_VotesCounted.encode(message.message.votesCounted, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.message?.$case === 'chaosPolicyEnacted') {
+      Server_ChaosPolicyEnacted.encode(message.message.chaosPolicyEnacted, writer.uint32(66).fork()).ldelim();
+    }
+    if (message.message?.$case === 'loyaltyRevealed') {
+      Server_LoyaltyRevealed.encode(message.message.loyaltyRevealed, writer.uint32(74).fork()).ldelim();
+    }
+    if (message.message?.$case === 'policiesRevealed') {
+      Server_PoliciesRevealed.encode(message.message.policiesRevealed, writer.uint32(82).fork()).ldelim();
+    }
+    if (message.message?.$case === 'specialElectionCalled') {
+      Server_SpecialElectionCalled.encode(message.message.specialElectionCalled, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.message?.$case === 'playerExecuted') {
+      Server_PlayerExecuted.encode(message.message.playerExecuted, writer.uint32(98).fork()).ldelim();
+    }
+    if (message.message?.$case === 'gameFinished') {
+      Server_GameFinished.encode(message.message.gameFinished, writer.uint32(106).fork()).ldelim();
+    }
+    if (message.message?.$case === 'snapshot') {
+      Server_Snapshot.encode(message.message.snapshot, writer.uint32(114).fork()).ldelim();
+    }
+    if (message.message?.$case === 'chatPosted') {
+      Server_ChatPosted.encode(message.message.chatPosted, writer.uint32(122).fork()).ldelim();
+    }
+    if (message.message?.$case === 'nameRejected') {
+      Server_NameRejected.encode(message.message.nameRejected, writer.uint32(130).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer } as Server;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.decode(reader, reader.uint32())};
+          break;
+        case 2:
+          message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.decode(reader, reader.uint32())};
+          break;
+        case 3:
+          message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.decode(reader, reader.uint32())};
+          break;
+        case 4:
+          message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.decode(reader, reader.uint32())};
+          break;
+        case 5:
+          message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.decode(reader, reader.uint32())};
+          break;
+        case 6:
+          message.message = {$case: 'roleAssigned', roleAssigned: Server_RoleAssigned.decode(reader, reader.uint32())};
+          break;
+        case 7:
+          message.message = {$case: 'votesCounted', votesCounted: Server_VotesCounted.decode(reader, reader.uint32())};
+          break;
+        case 8:
+          message.message = {$case: 'chaosPolicyEnacted', chaosPolicyEnacted: Server_ChaosPolicyEnacted.decode(reader, reader.uint32())};
+          break;
+        case 9:
+          message.message = {$case: 'loyaltyRevealed', loyaltyRevealed: Server_LoyaltyRevealed.decode(reader, reader.uint32())};
+          break;
+        case 10:
+          message.message = {$case: 'policiesRevealed', policiesRevealed: Server_PoliciesRevealed.decode(reader, reader.uint32())};
+          break;
+        case 11:
+          message.message = {$case: 'specialElectionCalled', specialElectionCalled: Server_SpecialElectionCalled.decode(reader, reader.uint32())};
+          break;
+        case 12:
+          message.message = {$case: 'playerExecuted', playerExecuted: Server_PlayerExecuted.decode(reader, reader.uint32())};
+          break;
+        case 13:
+          message.message = {$case: 'gameFinished', gameFinished: Server_GameFinished.decode(reader, reader.uint32())};
+          break;
+        case 14:
+          message.message = {$case: 'snapshot', snapshot: Server_Snapshot.decode(reader, reader.uint32())};
+          break;
+        case 15:
+          message.message = {$case: 'chatPosted', chatPosted: Server_ChatPosted.decode(reader, reader.uint32())};
+          break;
+        case 16:
+          message.message = {$case: 'nameRejected', nameRejected: Server_NameRejected.decode(reader, reader.uint32())};
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server {
+    const message = { ...baseServer } as Server;
+    if (object.playerUpdated !== undefined && object.playerUpdated !== null) {
+      message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.fromJSON(object.playerUpdated)};
+    }
+    if (object.gameUpdated !== undefined && object.gameUpdated !== null) {
+      message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.fromJSON(object.gameUpdated)};
+    }
+    if (object.selfUpdated !== undefined && object.selfUpdated !== null) {
+      message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.fromJSON(object.selfUpdated)};
+    }
+    if (object.playerEntered !== undefined && object.playerEntered !== null) {
+      message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.fromJSON(object.playerEntered)};
+    }
+    if (object.playerLeft !== undefined && object.playerLeft !== null) {
+      message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.fromJSON(object.playerLeft)};
+    }
+    if (object.roleAssigned !== undefined && object.roleAssigned !== null) {
+      message.message = {$case: 'roleAssigned', roleAssigned: Server_RoleAssigned.fromJSON(object.roleAssigned)};
+    }
+    if (object.votesCounted !== undefined && object.votesCounted !== null) {
+      message.message = {$case: 'votesCounted', votesCounted: Server_VotesCounted.fromJSON(object.votesCounted)};
+    }
+    if (object.chaosPolicyEnacted !== undefined && object.chaosPolicyEnacted !== null) {
+      message.message = {$case: 'chaosPolicyEnacted', chaosPolicyEnacted: Server_ChaosPolicyEnacted.fromJSON(object.chaosPolicyEnacted)};
+    }
+    if (object.loyaltyRevealed !== undefined && object.loyaltyRevealed !== null) {
+      message.message = {$case: 'loyaltyRevealed', loyaltyRevealed: Server_LoyaltyRevealed.fromJSON(object.loyaltyRevealed)};
+    }
+    if (object.policiesRevealed !== undefined && object.policiesRevealed !== null) {
+      message.message = {$case: 'policiesRevealed', policiesRevealed: Server_PoliciesRevealed.fromJSON(object.policiesRevealed)};
+    }
+    if (object.specialElectionCalled !== undefined && object.specialElectionCalled !== null) {
+      message.message = {$case: 'specialElectionCalled', specialElectionCalled: Server_SpecialElectionCalled.fromJSON(object.specialElectionCalled)};
+    }
+    if (object.playerExecuted !== undefined && object.playerExecuted !== null) {
+      message.message = {$case: 'playerExecuted', playerExecuted: Server_PlayerExecuted.fromJSON(object.playerExecuted)};
+    }
+    if (object.gameFinished !== undefined && object.gameFinished !== null) {
+      message.message = {$case: 'gameFinished', gameFinished: Server_GameFinished.fromJSON(object.gameFinished)};
+    }
+    if (object.snapshot !== undefined && object.snapshot !== null) {
+      message.message = {$case: 'snapshot', snapshot: Server_Snapshot.fromJSON(object.snapshot)};
+    }
+    if (object.chatPosted !== undefined && object.chatPosted !== null) {
+      message.message = {$case: 'chatPosted', chatPosted: Server_ChatPosted.fromJSON(object.chatPosted)};
+    }
+    if (object.nameRejected !== undefined && object.nameRejected !== null) {
+      message.message = {$case: 'nameRejected', nameRejected: Server_NameRejected.fromJSON(object.nameRejected)};
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server>): Server {
+    const message = { ...baseServer } as Server;
+    if (object.message?.$case === 'playerUpdated' && object.message?.playerUpdated !== undefined && object.message?.playerUpdated !== null) {
+      message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.fromPartial(object.message.playerUpdated)};
+    }
+    if (object.message?.$case === 'gameUpdated' && object.message?.gameUpdated !== undefined && object.message?.gameUpdated !== null) {
+      message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.fromPartial(object.message.gameUpdated)};
+    }
+    if (object.message?.$case === 'selfUpdated' && object.message?.selfUpdated !== undefined && object.message?.selfUpdated !== null) {
+      message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.fromPartial(object.message.selfUpdated)};
+    }
+    if (object.message?.$case === 'playerEntered' && object.message?.playerEntered !== undefined && object.message?.playerEntered !== null) {
+      message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.fromPartial(object.message.playerEntered)};
+    }
+    if (object.message?.$case === 'playerLeft' && object.message?.playerLeft !== undefined && object.message?.playerLeft !== null) {
+      message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.fromPartial(object.message.playerLeft)};
+    }
+    if (object.message?.$case === 'roleAssigned' && object.message?.roleAssigned !== undefined && object.message?.roleAssigned !== null) {
+      message.message = {$case: 'roleAssigned', roleAssigned: Server_RoleAssigned.fromPartial(object.message.roleAssigned)};
+    }
+    if (object.message?.$case === 'votesCounted' && object.message?.votesCounted !== undefined && object.message?.votesCounted !== null) {
+      message.message = {$case: 'votesCounted', votesCounted: Server_VotesCounted.fromPartial(object.message.votesCounted)};
+    }
+    if (object.message?.$case === 'chaosPolicyEnacted' && object.message?.chaosPolicyEnacted !== undefined && object.message?.chaosPolicyEnacted !== null) {
+      message.message = {$case: 'chaosPolicyEnacted', chaosPolicyEnacted: Server_ChaosPolicyEnacted.fromPartial(object.message.chaosPolicyEnacted)};
+    }
+    if (object.message?.$case === 'loyaltyRevealed' && object.message?.loyaltyRevealed !== undefined && object.message?.loyaltyRevealed !== null) {
+      message.message = {$case: 'loyaltyRevealed', loyaltyRevealed: Server_LoyaltyRevealed.fromPartial(object.message.loyaltyRevealed)};
+    }
+    if (object.message?.$case === 'policiesRevealed' && object.message?.policiesRevealed !== undefined && object.message?.policiesRevealed !== null) {
+      message.message = {$case: 'policiesRevealed', policiesRevealed: Server_PoliciesRevealed.fromPartial(object.message.policiesRevealed)};
+    }
+    if (object.message?.$case === 'specialElectionCalled' && object.message?.specialElectionCalled !== undefined && object.message?.specialElectionCalled !== null) {
+      message.message = {$case: 'specialElectionCalled', specialElectionCalled: Server_SpecialElectionCalled.fromPartial(object.message.specialElectionCalled)};
+    }
+    if (object.message?.$case === 'playerExecuted' && object.message?.playerExecuted !== undefined && object.message?.playerExecuted !== null) {
+      message.message = {$case: 'playerExecuted', playerExecuted: Server_PlayerExecuted.fromPartial(object.message.playerExecuted)};
+    }
+    if (object.message?.$case === 'gameFinished' && object.message?.gameFinished !== undefined && object.message?.gameFinished !== null) {
+      message.message = {$case: 'gameFinished', gameFinished: Server_GameFinished.fromPartial(object.message.gameFinished)};
+    }
+    if (object.message?.$case === 'snapshot' && object.message?.snapshot !== undefined && object.message?.snapshot !== null) {
+      message.message = {$case: 'snapshot', snapshot: Server_Snapshot.fromPartial(object.message.snapshot)};
+    }
+    if (object.message?.$case === 'chatPosted' && object.message?.chatPosted !== undefined && object.message?.chatPosted !== null) {
+      message.message = {$case: 'chatPosted', chatPosted: Server_ChatPosted.fromPartial(object.message.chatPosted)};
+    }
+    if (object.message?.$case === 'nameRejected' && object.message?.nameRejected !== undefined && object.message?.nameRejected !== null) {
+      message.message = {$case: 'nameRejected', nameRejected: Server_NameRejected.fromPartial(object.message.nameRejected)};
+    }
+    return message;
+  },
+  toJSON(message: Server): unknown {
+    const obj: any = {};
+    message.message?.$case === 'playerUpdated' && (obj.playerUpdated = message.message?.playerUpdated ? Server_PlayerUpdated.toJSON(message.message?.playerUpdated) : undefined);
+    message.message?.$case === 'gameUpdated' && (obj.gameUpdated = message.message?.gameUpdated ? Server_GameUpdated.toJSON(message.message?.gameUpdated) : undefined);
+    message.message?.$case === 'selfUpdated' && (obj.selfUpdated = message.message?.selfUpdated ? Server_SelfUpdated.toJSON(message.message?.selfUpdated) : undefined);
+    message.message?.$case === 'playerEntered' && (obj.playerEntered = message.message?.playerEntered ? Server_PlayerEntered.toJSON(message.message?.playerEntered) : undefined);
+    message.message?.$case === 'playerLeft' && (obj.playerLeft = message.message?.playerLeft ? Server_PlayerLeft.toJSON(message.message?.playerLeft) : undefined);
+    message.message?.$case === 'roleAssigned' && (obj.roleAssigned = message.message?.roleAssigned ? Server_RoleAssigned.toJSON(message.message?.roleAssigned) : undefined);
+    message.message?.$case === 'votesCounted' && (obj.votesCounted = message.message?.votesCounted ? Server_VotesCounted.toJSON(message.message?.votesCounted) : undefined);
+    message.message?.$case === 'chaosPolicyEnacted' && (obj.chaosPolicyEnacted = message.message?.chaosPolicyEnacted ? Server_ChaosPolicyEnacted.toJSON(message.message?.chaosPolicyEnacted) : undefined);
+    message.message?.$case === 'loyaltyRevealed' && (obj.loyaltyRevealed = message.message?.loyaltyRevealed ? Server_LoyaltyRevealed.toJSON(message.message?.loyaltyRevealed) : undefined);
+    message.message?.$case === 'policiesRevealed' && (obj.policiesRevealed = message.message?.policiesRevealed ? Server_PoliciesRevealed.toJSON(message.message?.policiesRevealed) : undefined);
+    message.message?.$case === 'specialElectionCalled' && (obj.specialElectionCalled = message.message?.specialElectionCalled ? Server_SpecialElectionCalled.toJSON(message.message?.specialElectionCalled) : undefined);
+    message.message?.$case === 'playerExecuted' && (obj.playerExecuted = message.message?.playerExecuted ? Server_PlayerExecuted.toJSON(message.message?.playerExecuted) : undefined);
+    message.message?.$case === 'gameFinished' && (obj.gameFinished = message.message?.gameFinished ? Server_GameFinished.toJSON(message.message?.gameFinished) : undefined);
+    message.message?.$case === 'snapshot' && (obj.snapshot = message.message?.snapshot ? Server_Snapshot.toJSON(message.message?.snapshot) : undefined);
+    message.message?.$case === 'chatPosted' && (obj.chatPosted = message.message?.chatPosted ? Server_ChatPosted.toJSON(message.message?.chatPosted) : undefined);
+    message.message?.$case === 'nameRejected' && (obj.nameRejected = message.message?.nameRejected ? Server_NameRejected.toJSON(message.message?.nameRejected) : undefined);
+    return obj;
+  },
+};
+
+export const Server_PlayerUpdated = {
+  encode(message: Server_PlayerUpdated, writer: Writer = Writer.create()): Writer {
+    if (message.player !== undefined && message.player !== undefined) {
+      Player.encode(message.player, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_PlayerUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.player = Player.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_PlayerUpdated {
+    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = Player.fromJSON(object.player);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_PlayerUpdated>): Server_PlayerUpdated {
+    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = Player.fromPartial(object.player);
+    }
+    return message;
+  },
+  toJSON(message: Server_PlayerUpdated): unknown {
+    const obj: any = {};
+    message.player !== undefined && (obj.player = message.player ? Player.toJSON(message.player) : undefined);
+    return obj;
+  },
+};
+
+export const Server_SelfUpdated = {
+  encode(message: Server_SelfUpdated, writer: Writer = Writer.create()): Writer {
+    if (message.player !== undefined && message.player !== undefined) {
+      OwnPlayer.encode(message.player, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_SelfUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.player = OwnPlayer.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_SelfUpdated {
+    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = OwnPlayer.fromJSON(object.player);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_SelfUpdated>): Server_SelfUpdated {
+    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = OwnPlayer.fromPartial(object.player);
+    }
+    return message;
+  },
+  toJSON(message: Server_SelfUpdated): unknown {
+    const obj: any = {};
+    message.player !== undefined && (obj.player = message.player ? OwnPlayer.toJSON(message.player) : undefined);
+    return obj;
+  },
+};
+
+export const Server_GameUpdated = {
+  encode(message: Server_GameUpdated, writer: Writer = Writer.create()): Writer {
+    if (message.game !== undefined && message.game !== undefined) {
+      Game.encode(message.game, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_GameUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.game = Game.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_GameUpdated {
+    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+    if (object.game !== undefined && object.game !== null) {
+      message.game = Game.fromJSON(object.game);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_GameUpdated>): Server_GameUpdated {
+    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+    if (object.game !== undefined && object.game !== null) {
+      message.game = Game.fromPartial(object.game);
+    }
+    return message;
+  },
+  toJSON(message: Server_GameUpdated): unknown {
+    const obj: any = {};
+    message.game !== undefined && (obj.game = message.game ? Game.toJSON(message.game) : undefined);
+    return obj;
+  },
+};
+
+export const Server_PlayerEntered = {
+  encode(message: Server_PlayerEntered, writer: Writer = Writer.create()): Writer {
+    if (message.player !== undefined && message.player !== undefined) {
+      Player.encode(message.player, writer.uint32(10).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_PlayerEntered {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.player = Player.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_PlayerEntered {
+    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = Player.fromJSON(object.player);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_PlayerEntered>): Server_PlayerEntered {
+    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+    if (object.player !== undefined && object.player !== null) {
+      message.player = Player.fromPartial(object.player);
+    }
+    return message;
+  },
+  toJSON(message: Server_PlayerEntered): unknown {
+    const obj: any = {};
+    message.player !== undefined && (obj.player = message.player ? Player.toJSON(message.player) : undefined);
+    return obj;
+  },
+};
+
+export const Server_PlayerLeft = {
+  encode(message: Server_PlayerLeft, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_PlayerLeft {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_PlayerLeft {
+    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_PlayerLeft>): Server_PlayerLeft {
+    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: Server_PlayerLeft): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const Server_RoleAssigned = {
+  encode(message: Server_RoleAssigned, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.role);
+    for (const v of message.clanMemberIds) {
+      writer.uint32(18).string(v!);
+    }
+    writer.uint32(26).string(message.clanLeaderId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_RoleAssigned {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_RoleAssigned } as Server_RoleAssigned;
+    message.clanMemberIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.role = reader.int32() as any;
+          break;
+        case 2:
+          message.clanMemberIds.push(reader.string());
+          break;
+        case 3:
+          message.clanLeaderId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_RoleAssigned {
+    const message = { ...baseServer_RoleAssigned } as Server_RoleAssigned;
+    message.clanMemberIds = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = roleFromJSON(object.role);
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(String(e));
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = String(object.clanLeaderId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_RoleAssigned>): Server_RoleAssigned {
+    const message = { ...baseServer_RoleAssigned } as Server_RoleAssigned;
+    message.clanMemberIds = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = object.role;
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(e);
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = object.clanLeaderId;
+    }
+    return message;
+  },
+  toJSON(message: Server_RoleAssigned): unknown {
+    const obj: any = {};
+    message.role !== undefined && (obj.role = roleToJSON(message.role));
+    if (message.clanMemberIds) {
+      obj.clanMemberIds = message.clanMemberIds.map(e => e);
+    } else {
+      obj.clanMemberIds = [];
+    }
+    message.clanLeaderId !== undefined && (obj.clanLeaderId = message.clanLeaderId);
+    return obj;
+  },
+};
+
+export const Server_VotesCounted = {
+  encode(message: Server_VotesCounted, writer: Writer = Writer.create()): Writer {
+    for (const v of message.approvingPlayerIds) {
+      writer.uint32(10).string(v!);
+    }
+    for (const v of message.rejectingPlayerIds) {
+      writer.uint32(18).string(v!);
+    }
+    writer.uint32(24).bool(message.approved);
+    for (const v of message.abstainingPlayerIds) {
+      writer.uint32(34).string(v!);
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_VotesCounted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_VotesCounted } as Server_VotesCounted;
+    message.approvingPlayerIds = [];
+    message.rejectingPlayerIds = [];
+    message.abstainingPlayerIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.approvingPlayerIds.push(reader.string());
+          break;
+        case 2:
+          message.rejectingPlayerIds.push(reader.string());
+          break;
+        case 3:
+          message.approved = reader.bool();
+          break;
+        case 4:
+          message.abstainingPlayerIds.push(reader.string());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_VotesCounted {
+    const message = { ...baseServer_VotesCounted } as Server_VotesCounted;
+    message.approvingPlayerIds = [];
+    message.rejectingPlayerIds = [];
+    message.abstainingPlayerIds = [];
+    if (object.approvingPlayerIds !== undefined && object.approvingPlayerIds !== null) {
+      for (const e of object.approvingPlayerIds) {
+        message.approvingPlayerIds.push(String(e));
+      }
+    }
+    if (object.rejectingPlayerIds !== undefined && object.rejectingPlayerIds !== null) {
+      for (const e of object.rejectingPlayerIds) {
+        message.rejectingPlayerIds.push(String(e));
+      }
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = Boolean(object.approved);
+    }
+    if (object.abstainingPlayerIds !== undefined && object.abstainingPlayerIds !== null) {
+      for (const e of object.abstainingPlayerIds) {
+        message.abstainingPlayerIds.push(String(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_VotesCounted>): Server_VotesCounted {
+    const message = { ...baseServer_VotesCounted } as Server_VotesCounted;
+    message.approvingPlayerIds = [];
+    message.rejectingPlayerIds = [];
+    message.abstainingPlayerIds = [];
+    if (object.approvingPlayerIds !== undefined && object.approvingPlayerIds !== null) {
+      for (const e of object.approvingPlayerIds) {
+        message.approvingPlayerIds.push(e);
+      }
+    }
+    if (object.rejectingPlayerIds !== undefined && object.rejectingPlayerIds !== null) {
+      for (const e of object.rejectingPlayerIds) {
+        message.rejectingPlayerIds.push(e);
+      }
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = object.approved;
+    }
+    if (object.abstainingPlayerIds !== undefined && object.abstainingPlayerIds !== null) {
+      for (const e of object.abstainingPlayerIds) {
+        message.abstainingPlayerIds.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Server_VotesCounted): unknown {
+    const obj: any = {};
+    if (message.approvingPlayerIds) {
+      obj.approvingPlayerIds = message.approvingPlayerIds.map(e => e);
+    } else {
+      obj.approvingPlayerIds = [];
+    }
+    if (message.rejectingPlayerIds) {
+      obj.rejectingPlayerIds = message.rejectingPlayerIds.map(e => e);
+    } else {
+      obj.rejectingPlayerIds = [];
+    }
+    message.approved !== undefined && (obj.approved = message.approved);
+    if (message.abstainingPlayerIds) {
+      obj.abstainingPlayerIds = message.abstainingPlayerIds.map(e => e);
+    } else {
+      obj.abstainingPlayerIds = [];
+    }
+    return obj;
+  },
+};
+
+export const Server_ChaosPolicyEnacted = {
+  encode(message: Server_ChaosPolicyEnacted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.policy);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_ChaosPolicyEnacted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_ChaosPolicyEnacted } as Server_ChaosPolicyEnacted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.policy = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_ChaosPolicyEnacted {
+    const message = { ...baseServer_ChaosPolicyEnacted } as Server_ChaosPolicyEnacted;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = policyFromJSON(object.policy);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_ChaosPolicyEnacted>): Server_ChaosPolicyEnacted {
+    const message = { ...baseServer_ChaosPolicyEnacted } as Server_ChaosPolicyEnacted;
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = object.policy;
+    }
+    return message;
+  },
+  toJSON(message: Server_ChaosPolicyEnacted): unknown {
+    const obj: any = {};
+    message.policy !== undefined && (obj.policy = policyToJSON(message.policy));
+    return obj;
+  },
+};
+
+export const Server_LoyaltyRevealed = {
+  encode(message: Server_LoyaltyRevealed, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).int32(message.party);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_LoyaltyRevealed {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_LoyaltyRevealed } as Server_LoyaltyRevealed;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.party = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_LoyaltyRevealed {
+    const message = { ...baseServer_LoyaltyRevealed } as Server_LoyaltyRevealed;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.party !== undefined && object.party !== null) {
+      message.party = partyFromJSON(object.party);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_LoyaltyRevealed>): Server_LoyaltyRevealed {
+    const message = { ...baseServer_LoyaltyRevealed } as Server_LoyaltyRevealed;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.party !== undefined && object.party !== null) {
+      message.party = object.party;
+    }
+    return message;
+  },
+  toJSON(message: Server_LoyaltyRevealed): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.party !== undefined && (obj.party = partyToJSON(message.party));
+    return obj;
+  },
+};
+
+export const Server_PoliciesRevealed = {
+  encode(message: Server_PoliciesRevealed, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).fork();
+    for (const v of message.policies) {
+      writer.int32(v);
+    }
+    writer.ldelim();
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_PoliciesRevealed {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseServer_PoliciesRevealed } as Server_PoliciesRevealed;
+    message.policies = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if ((tag & 7) === 2) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.policies.push(reader.int32() as any);
+            }
+          } else {
+            message.policies.push(reader.int32() as any);
+          }
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Server_PoliciesRevealed {
+    const message = { ...baseServer_PoliciesRevealed } as Server_PoliciesRevealed;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(policyFromJSON(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Server_PoliciesRevealed>): Server_PoliciesRevealed {
+    const message = { ...baseServer_PoliciesRevealed } as Server_PoliciesRevealed;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Server_PoliciesRevealed): unknown {
+    const obj: any = {};
+    if (message.policies) {
+      obj.policies = message.policies.map(e => policyToJSON(e));
+    } else {
+      obj.policies = [];
+    }
+    return obj;
+  },
+};
+
+export const Server_SpecialElectionCalled = {
+  encode(message: Server_SpecialElectionCalled, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Server_SpecialElectionCalled {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
+    const message = { ...baseServer_SpecialElectionCalled } as Server_SpecialElectionCalled;
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.token = reader.string();
+          message.playerId = reader.string();
           break;
         default:
           reader.skipType(tag & 7);
@@ -152,41 +2047,41 @@ export const Client_AuthConfirmed = {
     }
     return message;
   },
-  fromJSON(object: any): Client_AuthConfirmed {
-    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
-    if (object.token !== undefined && object.token !== null) {
-      message.token = String(object.token);
+  fromJSON(object: any): Server_SpecialElectionCalled {
+    const message = { ...baseServer_SpecialElectionCalled } as Server_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Client_AuthConfirmed>): Client_AuthConfirmed {
-    const message = { ...baseClient_AuthConfirmed } as Client_AuthConfirmed;
-    if (object.token !== undefined && object.token !== null) {
-      message.token = object.token;
+  fromPartial(object: DeepPartial<Server_SpecialElectionCalled>): Server_SpecialElectionCalled {
+    const message = { ...baseServer_SpecialElectionCalled } as Server_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
     }
     return message;
   },
-  toJSON(message: Client_AuthConfirmed): unknown {
+  toJSON(message: Server_SpecialElectionCalled): unknown {
     const obj: any = {};
-    message.token !== undefined && (obj.token = message.token);
+    message.playerId !== undefined && (obj.playerId = message.playerId);
     return obj;
   },
 };
 
-export const Client_NameUpdated = {
-  encode(message: Client_NameUpdated, writer: Writer = Writer.create()): Writer {
-    writer.uint32(10).string(message.name);
+export const Server_PlayerExecuted = {
+  encode(message: Server_PlayerExecuted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Client_NameUpdated {
+  decode(input: Uint8Array | Reader, length?: number): Server_PlayerExecuted {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
+    const message = { ...baseServer_PlayerExecuted } as Server_PlayerExecuted;
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.name = reader.string();
+          message.playerId = reader.string();
           break;
         default:
           reader.skipType(tag & 7);
@@ -195,67 +2090,52 @@ export const Client_NameUpdated = {
     }
     return message;
   },
-  fromJSON(object: any): Client_NameUpdated {
-    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
-    if (object.name !== undefined && object.name !== null) {
-      message.name = String(object.name);
+  fromJSON(object: any): Server_PlayerExecuted {
+    const message = { ...baseServer_PlayerExecuted } as Server_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Client_NameUpdated>): Client_NameUpdated {
-    const message = { ...baseClient_NameUpdated } as Client_NameUpdated;
-    if (object.name !== undefined && object.name !== null) {
-      message.name = object.name;
+  fromPartial(object: DeepPartial<Server_PlayerExecuted>): Server_PlayerExecuted {
+    const message = { ...baseServer_PlayerExecuted } as Server_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
     }
     return message;
   },
-  toJSON(message: Client_NameUpdated): unknown {
+  toJSON(message: Server_PlayerExecuted): unknown {
     const obj: any = {};
-    message.name !== undefined && (obj.name = message.name);
+    message.playerId !== undefined && (obj.playerId = message.playerId);
     return obj;
   },
 };
 
-export const Server = {
-  encode(message: Server, writer: Writer = Writer.create()): Writer {
-    if (message.message?.$case === 'playerUpdated') {
-      Server_PlayerUpdated.encode(message.message.playerUpdated, writer.uint32(10).fork()).ldelim();
-    }
-    if (message.message?.$case === 'gameUpdated') {
-      Server_GameUpdated.encode(message.message.gameUpdated, writer.uint32(18).fork()).ldelim();
-    }
-    if (message.message?.$case === 'selfUpdated') {
-      Server_SelfUpdated.encode(message.message.selfUpdated, writer.uint32(26).fork()).ldelim();
-    }
-    if (message.message?.$case === 'playerEntered') {
-      Server_PlayerEntered.encode(message.message.playerEntered, writer.uint32(34).fork()).ldelim();
-    }
-    if (message.message?.$case === 'playerLeft') {
-      Server_PlayerLeft.encode(message.message.playerLeft, writer.uint32(42).fork()).ldelim();
+export const Server_GameFinished = {
+  encode(message: Server_GameFinished, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.winner);
+    writer.uint32(16).int32(message.reason);
+    for (const v of message.roles) {
+      PlayerRole.encode(v!, writer.uint32(26).fork()).ldelim();
     }
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server {
+  decode(input: Uint8Array | Reader, length?: number): Server_GameFinished {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer } as Server;
+    const message = { ...baseServer_GameFinished } as Server_GameFinished;
+    message.roles = [];
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.decode(reader, reader.uint32())};
+          message.winner = reader.int32() as any;
           break;
         case 2:
-          message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.decode(reader, reader.uint32())};
+          message.reason = reader.int32() as any;
           break;
         case 3:
-          message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.decode(reader, reader.uint32())};
-          break;
-        case 4:
-          message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.decode(reader, reader.uint32())};
-          break;
-        case 5:
-          message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.decode(reader, reader.uint32())};
+          message.roles.push(PlayerRole.decode(reader, reader.uint32()));
           break;
         default:
           reader.skipType(tag & 7);
@@ -264,71 +2144,73 @@ export const Server = {
     }
     return message;
   },
-  fromJSON(object: any): Server {
-    const message = { ...baseServer } as Server;
-    if (object.playerUpdated !== undefined && object.playerUpdated !== null) {
-      message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.fromJSON(object.playerUpdated)};
-    }
-    if (object.gameUpdated !== undefined && object.gameUpdated !== null) {
-      message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.fromJSON(object.gameUpdated)};
-    }
-    if (object.selfUpdated !== undefined && object.selfUpdated !== null) {
-      message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.fromJSON(object.selfUpdated)};
+  fromJSON(object: any): Server_GameFinished {
+    const message = { ...baseServer_GameFinished } as Server_GameFinished;
+    message.roles = [];
+    if (object.winner !== undefined && object.winner !== null) {
+      message.winner = partyFromJSON(object.winner);
     }
-    if (object.playerEntered !== undefined && object.playerEntered !== null) {
-      message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.fromJSON(object.playerEntered)};
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = winReasonFromJSON(object.reason);
     }
-    if (object.playerLeft !== undefined && object.playerLeft !== null) {
-      message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.fromJSON(object.playerLeft)};
+    if (object.roles !== undefined && object.roles !== null) {
+      for (const e of object.roles) {
+        message.roles.push(PlayerRole.fromJSON(e));
+      }
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Server>): Server {
-    const message = { ...baseServer } as Server;
-    if (object.message?.$case === 'playerUpdated' && object.message?.playerUpdated !== undefined && object.message?.playerUpdated !== null) {
-      message.message = {$case: 'playerUpdated', playerUpdated: Server_PlayerUpdated.fromPartial(object.message.playerUpdated)};
-    }
-    if (object.message?.$case === 'gameUpdated' && object.message?.gameUpdated !== undefined && object.message?.gameUpdated !== null) {
-      message.message = {$case: 'gameUpdated', gameUpdated: Server_GameUpdated.fromPartial(object.message.gameUpdated)};
-    }
-    if (object.message?.$case === 'selfUpdated' && object.message?.selfUpdated !== undefined && object.message?.selfUpdated !== null) {
-      message.message = {$case: 'selfUpdated', selfUpdated: Server_SelfUpdated.fromPartial(object.message.selfUpdated)};
+  fromPartial(object: DeepPartial<Server_GameFinished>): Server_GameFinished {
+    const message = { ...baseServer_GameFinished } as Server_GameFinished;
+    message.roles = [];
+    if (object.winner !== undefined && object.winner !== null) {
+      message.winner = object.winner;
     }
-    if (object.message?.$case === 'playerEntered' && object.message?.playerEntered !== undefined && object.message?.playerEntered !== null) {
-      message.message = {$case: 'playerEntered', playerEntered: Server_PlayerEntered.fromPartial(object.message.playerEntered)};
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = object.reason;
     }
-    if (object.message?.$case === 'playerLeft' && object.message?.playerLeft !== undefined && object.message?.playerLeft !== null) {
-      message.message = {$case: 'playerLeft', playerLeft: Server_PlayerLeft.fromPartial(object.message.playerLeft)};
+    if (object.roles !== undefined && object.roles !== null) {
+      for (const e of object.roles) {
+        message.roles.push(PlayerRole.fromPartial(e));
+      }
     }
     return message;
   },
-  toJSON(message: Server): unknown {
+  toJSON(message: Server_GameFinished): unknown {
     const obj: any = {};
-    message.message?.$case === 'playerUpdated' && (obj.playerUpdated = message.message?.playerUpdated ? Server_PlayerUpdated.toJSON(message.message?.playerUpdated) : undefined);
-    message.message?.$case === 'gameUpdated' && (obj.gameUpdated = message.message?.gameUpdated ? Server_GameUpdated.toJSON(message.message?.gameUpdated) : undefined);
-    message.message?.$case === 'selfUpdated' && (obj.selfUpdated = message.message?.selfUpdated ? Server_SelfUpdated.toJSON(message.message?.selfUpdated) : undefined);
-    message.message?.$case === 'playerEntered' && (obj.playerEntered = message.message?.playerEntered ? Server_PlayerEntered.toJSON(message.message?.playerEntered) : undefined);
-    message.message?.$case === 'playerLeft' && (obj.playerLeft = message.message?.playerLeft ? Server_PlayerLeft.toJSON(message.message?.playerLeft) : undefined);
+    message.winner !== undefined && (obj.winner = partyToJSON(message.winner));
+    message.reason !== undefined && (obj.reason = winReasonToJSON(message.reason));
+    if (message.roles) {
+      obj.roles = message.roles.map(e => e ? PlayerRole.toJSON(e) : undefined);
+    } else {
+      obj.roles = [];
+    }
     return obj;
   },
 };
 
-export const Server_PlayerUpdated = {
-  encode(message: Server_PlayerUpdated, writer: Writer = Writer.create()): Writer {
-    if (message.player !== undefined && message.player !== undefined) {
-      Player.encode(message.player, writer.uint32(10).fork()).ldelim();
-    }
+export const Server_ChatPosted = {
+  encode(message: Server_ChatPosted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.text);
+    writer.uint32(26).string(message.time);
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server_PlayerUpdated {
+  decode(input: Uint8Array | Reader, length?: number): Server_ChatPosted {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
+    const message = { ...baseServer_ChatPosted } as Server_ChatPosted;
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.player = Player.decode(reader, reader.uint32());
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.text = reader.string();
+          break;
+        case 3:
+          message.time = reader.string();
           break;
         default:
           reader.skipType(tag & 7);
@@ -337,43 +2219,59 @@ export const Server_PlayerUpdated = {
     }
     return message;
   },
-  fromJSON(object: any): Server_PlayerUpdated {
-    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
-    if (object.player !== undefined && object.player !== null) {
-      message.player = Player.fromJSON(object.player);
+  fromJSON(object: any): Server_ChatPosted {
+    const message = { ...baseServer_ChatPosted } as Server_ChatPosted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.text !== undefined && object.text !== null) {
+      message.text = String(object.text);
+    }
+    if (object.time !== undefined && object.time !== null) {
+      message.time = String(object.time);
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Server_PlayerUpdated>): Server_PlayerUpdated {
-    const message = { ...baseServer_PlayerUpdated } as Server_PlayerUpdated;
-    if (object.player !== undefined && object.player !== null) {
-      message.player = Player.fromPartial(object.player);
+  fromPartial(object: DeepPartial<Server_ChatPosted>): Server_ChatPosted {
+    const message = { ...baseServer_ChatPosted } as Server_ChatPosted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.text !== undefined && object.text !== null) {
+      message.text = object.text;
+    }
+    if (object.time !== undefined && object.time !== null) {
+      message.time = object.time;
     }
     return message;
   },
-  toJSON(message: Server_PlayerUpdated): unknown {
+  toJSON(message: Server_ChatPosted): unknown {
     const obj: any = {};
-    message.player !== undefined && (obj.player = message.player ? Player.toJSON(message.player) : undefined);
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.text !== undefined && (obj.text = message.text);
+    message.time !== undefined && (obj.time = message.time);
     return obj;
   },
 };
 
-export const Server_SelfUpdated = {
-  encode(message: Server_SelfUpdated, writer: Writer = Writer.create()): Writer {
-    if (message.player !== undefined && message.player !== undefined) {
-      OwnPlayer.encode(message.player, writer.uint32(10).fork()).ldelim();
-    }
+export const Server_NameRejected = {
+  encode(message: Server_NameRejected, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.reason);
+    writer.uint32(18).string(message.name);
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server_SelfUpdated {
+  decode(input: Uint8Array | Reader, length?: number): Server_NameRejected {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
+    const message = { ...baseServer_NameRejected } as Server_NameRejected;
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.player = OwnPlayer.decode(reader, reader.uint32());
+          message.reason = reader.int32() as any;
+          break;
+        case 2:
+          message.name = reader.string();
           break;
         default:
           reader.skipType(tag & 7);
@@ -382,44 +2280,81 @@ export const Server_SelfUpdated = {
     }
     return message;
   },
-  fromJSON(object: any): Server_SelfUpdated {
-    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
-    if (object.player !== undefined && object.player !== null) {
-      message.player = OwnPlayer.fromJSON(object.player);
+  fromJSON(object: any): Server_NameRejected {
+    const message = { ...baseServer_NameRejected } as Server_NameRejected;
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = nameRejectionFromJSON(object.reason);
+    }
+    if (object.name !== undefined && object.name !== null) {
+      message.name = String(object.name);
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Server_SelfUpdated>): Server_SelfUpdated {
-    const message = { ...baseServer_SelfUpdated } as Server_SelfUpdated;
-    if (object.player !== undefined && object.player !== null) {
-      message.player = OwnPlayer.fromPartial(object.player);
+  fromPartial(object: DeepPartial<Server_NameRejected>): Server_NameRejected {
+    const message = { ...baseServer_NameRejected } as Server_NameRejected;
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = object.reason;
+    }
+    if (object.name !== undefined && object.name !== null) {
+      message.name = object.name;
     }
     return message;
   },
-  toJSON(message: Server_SelfUpdated): unknown {
+  toJSON(message: Server_NameRejected): unknown {
     const obj: any = {};
-    message.player !== undefined && (obj.player = message.player ? OwnPlayer.toJSON(message.player) : undefined);
+    message.reason !== undefined && (obj.reason = nameRejectionToJSON(message.reason));
+    message.name !== undefined && (obj.name = message.name);
     return obj;
   },
 };
 
-export const Server_GameUpdated = {
-  encode(message: Server_GameUpdated, writer: Writer = Writer.create()): Writer {
+export const Server_Snapshot = {
+  encode(message: Server_Snapshot, writer: Writer = Writer.create()): Writer {
     if (message.game !== undefined && message.game !== undefined) {
       Game.encode(message.game, writer.uint32(10).fork()).ldelim();
     }
+    for (const v of message.players) {
+      Server_Snapshot_PlayerPresence.encode(v!, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.ownPlayer !== undefined && message.ownPlayer !== undefined) {
+      OwnPlayer.encode(message.ownPlayer, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.knowledge !== undefined && message.knowledge !== undefined) {
+      Server_Snapshot_Knowledge.encode(message.knowledge, writer.uint32(34).fork()).ldelim();
+    }
+    writer.uint32(40).int32(message.phase);
+    for (const v of message.chat) {
+      Server_ChatPosted.encode(v!, writer.uint32(50).fork()).ldelim();
+    }
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server_GameUpdated {
+  decode(input: Uint8Array | Reader, length?: number): Server_Snapshot {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+    const message = { ...baseServer_Snapshot } as Server_Snapshot;
+    message.players = [];
+    message.chat = [];
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
           message.game = Game.decode(reader, reader.uint32());
           break;
+        case 2:
+          message.players.push(Server_Snapshot_PlayerPresence.decode(reader, reader.uint32()));
+          break;
+        case 3:
+          message.ownPlayer = OwnPlayer.decode(reader, reader.uint32());
+          break;
+        case 4:
+          message.knowledge = Server_Snapshot_Knowledge.decode(reader, reader.uint32());
+          break;
+        case 5:
+          message.phase = reader.int32() as any;
+          break;
+        case 6:
+          message.chat.push(Server_ChatPosted.decode(reader, reader.uint32()));
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -427,44 +2362,103 @@ export const Server_GameUpdated = {
     }
     return message;
   },
-  fromJSON(object: any): Server_GameUpdated {
-    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+  fromJSON(object: any): Server_Snapshot {
+    const message = { ...baseServer_Snapshot } as Server_Snapshot;
+    message.players = [];
+    message.chat = [];
     if (object.game !== undefined && object.game !== null) {
       message.game = Game.fromJSON(object.game);
     }
+    if (object.players !== undefined && object.players !== null) {
+      for (const e of object.players) {
+        message.players.push(Server_Snapshot_PlayerPresence.fromJSON(e));
+      }
+    }
+    if (object.ownPlayer !== undefined && object.ownPlayer !== null) {
+      message.ownPlayer = OwnPlayer.fromJSON(object.ownPlayer);
+    }
+    if (object.knowledge !== undefined && object.knowledge !== null) {
+      message.knowledge = Server_Snapshot_Knowledge.fromJSON(object.knowledge);
+    }
+    if (object.phase !== undefined && object.phase !== null) {
+      message.phase = phaseFromJSON(object.phase);
+    }
+    if (object.chat !== undefined && object.chat !== null) {
+      for (const e of object.chat) {
+        message.chat.push(Server_ChatPosted.fromJSON(e));
+      }
+    }
     return message;
   },
-  fromPartial(object: DeepPartial<Server_GameUpdated>): Server_GameUpdated {
-    const message = { ...baseServer_GameUpdated } as Server_GameUpdated;
+  fromPartial(object: DeepPartial<Server_Snapshot>): Server_Snapshot {
+    const message = { ...baseServer_Snapshot } as Server_Snapshot;
+    message.players = [];
+    message.chat = [];
     if (object.game !== undefined && object.game !== null) {
       message.game = Game.fromPartial(object.game);
     }
+    if (object.players !== undefined && object.players !== null) {
+      for (const e of object.players) {
+        message.players.push(Server_Snapshot_PlayerPresence.fromPartial(e));
+      }
+    }
+    if (object.ownPlayer !== undefined && object.ownPlayer !== null) {
+      message.ownPlayer = OwnPlayer.fromPartial(object.ownPlayer);
+    }
+    if (object.knowledge !== undefined && object.knowledge !== null) {
+      message.knowledge = Server_Snapshot_Knowledge.fromPartial(object.knowledge);
+    }
+    if (object.phase !== undefined && object.phase !== null) {
+      message.phase = object.phase;
+    }
+    if (object.chat !== undefined && object.chat !== null) {
+      for (const e of object.chat) {
+        message.chat.push(Server_ChatPosted.fromPartial(e));
+      }
+    }
     return message;
   },
-  toJSON(message: Server_GameUpdated): unknown {
+  toJSON(message: Server_Snapshot): unknown {
     const obj: any = {};
     message.game !== undefined && (obj.game = message.game ? Game.toJSON(message.game) : undefined);
+    if (message.players) {
+      obj.players = message.players.map(e => e ? Server_Snapshot_PlayerPresence.toJSON(e) : undefined);
+    } else {
+      obj.players = [];
+    }
+    message.ownPlayer !== undefined && (obj.ownPlayer = message.ownPlayer ? OwnPlayer.toJSON(message.ownPlayer) : undefined);
+    message.knowledge !== undefined && (obj.knowledge = message.knowledge ? Server_Snapshot_Knowledge.toJSON(message.knowledge) : undefined);
+    message.phase !== undefined && (obj.phase = phaseToJSON(message.phase));
+    if (message.chat) {
+      obj.chat = message.chat.map(e => e ? Server_ChatPosted.toJSON(e) : undefined);
+    } else {
+      obj.chat = [];
+    }
     return obj;
   },
 };
 
-export const Server_PlayerEntered = {
-  encode(message: Server_PlayerEntered, writer: Writer = Writer.create()): Writer {
+export const Server_Snapshot_PlayerPresence = {
+  encode(message: Server_Snapshot_PlayerPresence, writer: Writer = Writer.create()): Writer {
     if (message.player !== undefined && message.player !== undefined) {
       Player.encode(message.player, writer.uint32(10).fork()).ldelim();
     }
+    writer.uint32(16).bool(message.connected);
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server_PlayerEntered {
+  decode(input: Uint8Array | Reader, length?: number): Server_Snapshot_PlayerPresence {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+    const message = { ...baseServer_Snapshot_PlayerPresence } as Server_Snapshot_PlayerPresence;
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
           message.player = Player.decode(reader, reader.uint32());
           break;
+        case 2:
+          message.connected = reader.bool();
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -472,41 +2466,66 @@ export const Server_PlayerEntered = {
     }
     return message;
   },
-  fromJSON(object: any): Server_PlayerEntered {
-    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+  fromJSON(object: any): Server_Snapshot_PlayerPresence {
+    const message = { ...baseServer_Snapshot_PlayerPresence } as Server_Snapshot_PlayerPresence;
     if (object.player !== undefined && object.player !== null) {
       message.player = Player.fromJSON(object.player);
     }
+    if (object.connected !== undefined && object.connected !== null) {
+      message.connected = Boolean(object.connected);
+    }
     return message;
   },
-  fromPartial(object: DeepPartial<Server_PlayerEntered>): Server_PlayerEntered {
-    const message = { ...baseServer_PlayerEntered } as Server_PlayerEntered;
+  fromPartial(object: DeepPartial<Server_Snapshot_PlayerPresence>): Server_Snapshot_PlayerPresence {
+    const message = { ...baseServer_Snapshot_PlayerPresence } as Server_Snapshot_PlayerPresence;
     if (object.player !== undefined && object.player !== null) {
       message.player = Player.fromPartial(object.player);
     }
+    if (object.connected !== undefined && object.connected !== null) {
+      message.connected = object.connected;
+    }
     return message;
   },
-  toJSON(message: Server_PlayerEntered): unknown {
+  toJSON(message: Server_Snapshot_PlayerPresence): unknown {
     const obj: any = {};
     message.player !== undefined && (obj.player = message.player ? Player.toJSON(message.player) : undefined);
+    message.connected !== undefined && (obj.connected = message.connected);
     return obj;
   },
 };
 
-export const Server_PlayerLeft = {
-  encode(message: Server_PlayerLeft, writer: Writer = Writer.create()): Writer {
-    writer.uint32(10).string(message.playerId);
+export const Server_Snapshot_Knowledge = {
+  encode(message: Server_Snapshot_Knowledge, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.role);
+    for (const v of message.clanMemberIds) {
+      writer.uint32(18).string(v!);
+    }
+    writer.uint32(26).string(message.clanLeaderId);
+    for (const v of message.investigations) {
+      Server_LoyaltyRevealed.encode(v!, writer.uint32(34).fork()).ldelim();
+    }
     return writer;
   },
-  decode(input: Uint8Array | Reader, length?: number): Server_PlayerLeft {
+  decode(input: Uint8Array | Reader, length?: number): Server_Snapshot_Knowledge {
     const reader = input instanceof Uint8Array ? new Reader(input) : input;
     let end = length === undefined ? reader.len : reader.pos + length;
-    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
+    const message = { ...baseServer_Snapshot_Knowledge } as Server_Snapshot_Knowledge;
+    message.clanMemberIds = [];
+    message.investigations = [];
     while (reader.pos < end) {
       const tag = reader.uint32();
       switch (tag >>> 3) {
         case 1:
-          message.playerId = reader.string();
+          message.role = reader.int32() as any;
+          break;
+        case 2:
+          message.clanMemberIds.push(reader.string());
+          break;
+        case 3:
+          message.clanLeaderId = reader.string();
+          break;
+        case 4:
+          message.investigations.push(Server_LoyaltyRevealed.decode(reader, reader.uint32()));
           break;
         default:
           reader.skipType(tag & 7);
@@ -515,23 +2534,64 @@ export const Server_PlayerLeft = {
     }
     return message;
   },
-  fromJSON(object: any): Server_PlayerLeft {
-    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
-    if (object.playerId !== undefined && object.playerId !== null) {
-      message.playerId = String(object.playerId);
+  fromJSON(object: any): Server_Snapshot_Knowledge {
+    const message = { ...baseServer_Snapshot_Knowledge } as Server_Snapshot_Knowledge;
+    message.clanMemberIds = [];
+    message.investigations = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = roleFromJSON(object.role);
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(String(e));
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = String(object.clanLeaderId);
+    }
+    if (object.investigations !== undefined && object.investigations !== null) {
+      for (const e of object.investigations) {
+        message.investigations.push(Server_LoyaltyRevealed.fromJSON(e));
+      }
     }
     return message;
   },
-  fromPartial(object: DeepPartial<Server_PlayerLeft>): Server_PlayerLeft {
-    const message = { ...baseServer_PlayerLeft } as Server_PlayerLeft;
-    if (object.playerId !== undefined && object.playerId !== null) {
-      message.playerId = object.playerId;
+  fromPartial(object: DeepPartial<Server_Snapshot_Knowledge>): Server_Snapshot_Knowledge {
+    const message = { ...baseServer_Snapshot_Knowledge } as Server_Snapshot_Knowledge;
+    message.clanMemberIds = [];
+    message.investigations = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = object.role;
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(e);
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = object.clanLeaderId;
+    }
+    if (object.investigations !== undefined && object.investigations !== null) {
+      for (const e of object.investigations) {
+        message.investigations.push(Server_LoyaltyRevealed.fromPartial(e));
+      }
     }
     return message;
   },
-  toJSON(message: Server_PlayerLeft): unknown {
+  toJSON(message: Server_Snapshot_Knowledge): unknown {
     const obj: any = {};
-    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.role !== undefined && (obj.role = roleToJSON(message.role));
+    if (message.clanMemberIds) {
+      obj.clanMemberIds = message.clanMemberIds.map(e => e);
+    } else {
+      obj.clanMemberIds = [];
+    }
+    message.clanLeaderId !== undefined && (obj.clanLeaderId = message.clanLeaderId);
+    if (message.investigations) {
+      obj.investigations = message.investigations.map(e => e ? Server_LoyaltyRevealed.toJSON(e) : undefined);
+    } else {
+      obj.investigations = [];
+    }
     return obj;
   },
 };
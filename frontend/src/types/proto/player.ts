@@ -3,25 +3,75 @@ import { Task } from './task';
 import { Writer, Reader } from 'protobufjs/minimal';
 
 
+export enum NameRejection {
+  TOO_SHORT = 0,
+  TOO_LONG = 1,
+  INVALID_CHARACTERS = 2,
+  ALREADY_TAKEN = 3,
+  UNRECOGNIZED = -1,
+}
+
+export function nameRejectionFromJSON(object: any): NameRejection {
+  switch (object) {
+    case 0:
+    case "TOO_SHORT":
+      return NameRejection.TOO_SHORT;
+    case 1:
+    case "TOO_LONG":
+      return NameRejection.TOO_LONG;
+    case 2:
+    case "INVALID_CHARACTERS":
+      return NameRejection.INVALID_CHARACTERS;
+    case 3:
+    case "ALREADY_TAKEN":
+      return NameRejection.ALREADY_TAKEN;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return NameRejection.UNRECOGNIZED;
+  }
+}
+
+export function nameRejectionToJSON(object: NameRejection): string {
+  switch (object) {
+    case NameRejection.TOO_SHORT:
+      return "TOO_SHORT";
+    case NameRejection.TOO_LONG:
+      return "TOO_LONG";
+    case NameRejection.INVALID_CHARACTERS:
+      return "INVALID_CHARACTERS";
+    case NameRejection.ALREADY_TAKEN:
+      return "ALREADY_TAKEN";
+    default:
+      return "UNKNOWN";
+  }
+}
+
 export interface Player {
   id: string;
   name: string;
+  ready: boolean;
+  bot: boolean;
 }
 
 export interface OwnPlayer {
   id: string;
   name: string;
   openTasks: Task[];
+  ready: boolean;
 }
 
 const basePlayer: object = {
   id: "",
   name: "",
+  ready: false,
+  bot: false,
 };
 
 const baseOwnPlayer: object = {
   id: "",
   name: "",
+  ready: false,
 };
 
 export const protobufPackage = ''
@@ -30,6 +80,8 @@ export const Player = {
   encode(message: Player, writer: Writer = Writer.create()): Writer {
     writer.uint32(10).string(message.id);
     writer.uint32(18).string(message.name);
+    writer.uint32(32).bool(message.ready);
+    writer.uint32(40).bool(message.bot);
     return writer;
   },
   decode(input: Uint8Array | Reader, length?: number): Player {
@@ -45,6 +97,12 @@ export const Player = {
         case 2:
           message.name = reader.string();
           break;
+        case 4:
+          message.ready = reader.bool();
+          break;
+        case 5:
+          message.bot = reader.bool();
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -60,6 +118,12 @@ export const Player = {
     if (object.name !== undefined && object.name !== null) {
       message.name = String(object.name);
     }
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = Boolean(object.ready);
+    }
+    if (object.bot !== undefined && object.bot !== null) {
+      message.bot = Boolean(object.bot);
+    }
     return message;
   },
   fromPartial(object: DeepPartial<Player>): Player {
@@ -70,12 +134,20 @@ export const Player = {
     if (object.name !== undefined && object.name !== null) {
       message.name = object.name;
     }
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = object.ready;
+    }
+    if (object.bot !== undefined && object.bot !== null) {
+      message.bot = object.bot;
+    }
     return message;
   },
   toJSON(message: Player): unknown {
     const obj: any = {};
     message.id !== undefined && (obj.id = message.id);
     message.name !== undefined && (obj.name = message.name);
+    message.ready !== undefined && (obj.ready = message.ready);
+    message.bot !== undefined && (obj.bot = message.bot);
     return obj;
   },
 };
@@ -87,6 +159,7 @@ export const OwnPlayer = {
     for (const v of message.openTasks) {
       Task.encode(v!, writer.uint32(26).fork()).ldelim();
     }
+    writer.uint32(40).bool(message.ready);
     return writer;
   },
   decode(input: Uint8Array | Reader, length?: number): OwnPlayer {
@@ -106,6 +179,9 @@ export const OwnPlayer = {
         case 3:
           message.openTasks.push(Task.decode(reader, reader.uint32()));
           break;
+        case 5:
+          message.ready = reader.bool();
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -127,6 +203,9 @@ export const OwnPlayer = {
         message.openTasks.push(Task.fromJSON(e));
       }
     }
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = Boolean(object.ready);
+    }
     return message;
   },
   fromPartial(object: DeepPartial<OwnPlayer>): OwnPlayer {
@@ -143,6 +222,9 @@ export const OwnPlayer = {
         message.openTasks.push(Task.fromPartial(e));
       }
     }
+    if (object.ready !== undefined && object.ready !== null) {
+      message.ready = object.ready;
+    }
     return message;
   },
   toJSON(message: OwnPlayer): unknown {
@@ -154,6 +236,7 @@ export const OwnPlayer = {
     } else {
       obj.openTasks = [];
     }
+    message.ready !== undefined && (obj.ready = message.ready);
     return obj;
   },
 };
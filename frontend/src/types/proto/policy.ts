@@ -0,0 +1,35 @@
+/* eslint-disable */
+
+export enum Policy {
+  LOYAL_POLICY = 0,
+  CLAN_POLICY = 1,
+  UNRECOGNIZED = -1,
+}
+
+export function policyFromJSON(object: any): Policy {
+  switch (object) {
+    case 0:
+    case "LOYAL_POLICY":
+      return Policy.LOYAL_POLICY;
+    case 1:
+    case "CLAN_POLICY":
+      return Policy.CLAN_POLICY;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return Policy.UNRECOGNIZED;
+  }
+}
+
+export function policyToJSON(object: Policy): string {
+  switch (object) {
+    case Policy.LOYAL_POLICY:
+      return "LOYAL_POLICY";
+    case Policy.CLAN_POLICY:
+      return "CLAN_POLICY";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export const protobufPackage = ''
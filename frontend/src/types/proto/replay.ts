@@ -0,0 +1,1971 @@
+/* eslint-disable */
+import { GameSettings } from './settings';
+import { Policy, policyFromJSON, policyToJSON } from './policy';
+import { Party, partyFromJSON, partyToJSON, PlayerRole } from './role';
+import { WinReason, winReasonFromJSON, winReasonToJSON } from './game';
+import { Writer, Reader } from 'protobufjs/minimal';
+
+
+export interface GameEvent {
+  time: string;
+  event?: { $case: 'gameCreated', gameCreated: GameEvent_GameCreated } | { $case: 'playerJoined', playerJoined: GameEvent_PlayerJoined } | { $case: 'playerLeft', playerLeft: GameEvent_PlayerLeft } | { $case: 'settingsUpdated', settingsUpdated: GameEvent_SettingsUpdated } | { $case: 'gameStarted', gameStarted: GameEvent_GameStarted } | { $case: 'chancellorNominated', chancellorNominated: GameEvent_ChancellorNominated } | { $case: 'voteCast', voteCast: GameEvent_VoteCast } | { $case: 'electionFinished', electionFinished: GameEvent_ElectionFinished } | { $case: 'policyDiscarded', policyDiscarded: GameEvent_PolicyDiscarded } | { $case: 'policyEnacted', policyEnacted: GameEvent_PolicyEnacted } | { $case: 'loyaltyInvestigated', loyaltyInvestigated: GameEvent_LoyaltyInvestigated } | { $case: 'specialElectionCalled', specialElectionCalled: GameEvent_SpecialElectionCalled } | { $case: 'policiesPeeked', policiesPeeked: GameEvent_PoliciesPeeked } | { $case: 'playerExecuted', playerExecuted: GameEvent_PlayerExecuted } | { $case: 'playerKicked', playerKicked: GameEvent_PlayerKicked } | { $case: 'adminHandedOver', adminHandedOver: GameEvent_AdminHandedOver } | { $case: 'passwordChanged', passwordChanged: GameEvent_PasswordChanged } | { $case: 'lobbyLocked', lobbyLocked: GameEvent_LobbyLocked } | { $case: 'voteAbstained', voteAbstained: GameEvent_VoteAbstained } | { $case: 'botAdded', botAdded: GameEvent_BotAdded } | { $case: 'playerReplacedByBot', playerReplacedByBot: GameEvent_PlayerReplacedByBot } | { $case: 'rematchStarted', rematchStarted: GameEvent_RematchStarted } | { $case: 'vetoProposed', vetoProposed: GameEvent_VetoProposed } | { $case: 'vetoAnswered', vetoAnswered: GameEvent_VetoAnswered } | { $case: 'nameChosen', nameChosen: GameEvent_NameChosen };
+}
+
+export interface GameEvent_GameCreated {
+  adminId: string;
+}
+
+export interface GameEvent_PlayerJoined {
+  playerId: string;
+}
+
+export interface GameEvent_PlayerLeft {
+  playerId: string;
+}
+
+export interface GameEvent_SettingsUpdated {
+  playerId: string;
+  settings?: GameSettings;
+}
+
+export interface GameEvent_GameStarted {
+}
+
+export interface GameEvent_ChancellorNominated {
+  playerId: string;
+  chancellorId: string;
+}
+
+export interface GameEvent_VoteCast {
+  playerId: string;
+  approved: boolean;
+}
+
+export interface GameEvent_VoteAbstained {
+  playerId: string;
+}
+
+export interface GameEvent_ElectionFinished {
+}
+
+export interface GameEvent_PolicyDiscarded {
+  playerId: string;
+  policy: Policy;
+}
+
+export interface GameEvent_PolicyEnacted {
+  playerId: string;
+  policy: Policy;
+}
+
+export interface GameEvent_LoyaltyInvestigated {
+  playerId: string;
+  targetId: string;
+}
+
+export interface GameEvent_SpecialElectionCalled {
+  playerId: string;
+  targetId: string;
+}
+
+export interface GameEvent_PoliciesPeeked {
+  playerId: string;
+}
+
+export interface GameEvent_PlayerExecuted {
+  playerId: string;
+  targetId: string;
+}
+
+export interface GameEvent_PlayerKicked {
+  playerId: string;
+  targetId: string;
+}
+
+export interface GameEvent_AdminHandedOver {
+  playerId: string;
+  targetId: string;
+}
+
+export interface GameEvent_PasswordChanged {
+  playerId: string;
+  passwordProtected: boolean;
+}
+
+export interface GameEvent_LobbyLocked {
+  playerId: string;
+  locked: boolean;
+}
+
+export interface GameEvent_BotAdded {
+  playerId: string;
+  botId: string;
+}
+
+export interface GameEvent_PlayerReplacedByBot {
+  playerId: string;
+}
+
+export interface GameEvent_RematchStarted {
+  playerId: string;
+}
+
+export interface GameEvent_VetoProposed {
+  playerId: string;
+}
+
+export interface GameEvent_VetoAnswered {
+  playerId: string;
+  approved: boolean;
+}
+
+export interface GameEvent_NameChosen {
+  playerId: string;
+  name: string;
+}
+
+export interface Replay {
+  token: string;
+  winner: Party;
+  reason: WinReason;
+  roles: PlayerRole[];
+  events: GameEvent[];
+}
+
+const baseGameEvent: object = {
+  time: "",
+};
+
+const baseGameEvent_GameCreated: object = {
+  adminId: "",
+};
+
+const baseGameEvent_PlayerJoined: object = {
+  playerId: "",
+};
+
+const baseGameEvent_PlayerLeft: object = {
+  playerId: "",
+};
+
+const baseGameEvent_SettingsUpdated: object = {
+  playerId: "",
+};
+
+const baseGameEvent_GameStarted: object = {
+};
+
+const baseGameEvent_ChancellorNominated: object = {
+  playerId: "",
+  chancellorId: "",
+};
+
+const baseGameEvent_VoteCast: object = {
+  playerId: "",
+  approved: false,
+};
+
+const baseGameEvent_VoteAbstained: object = {
+  playerId: "",
+};
+
+const baseGameEvent_ElectionFinished: object = {
+};
+
+const baseGameEvent_PolicyDiscarded: object = {
+  playerId: "",
+  policy: 0,
+};
+
+const baseGameEvent_PolicyEnacted: object = {
+  playerId: "",
+  policy: 0,
+};
+
+const baseGameEvent_LoyaltyInvestigated: object = {
+  playerId: "",
+  targetId: "",
+};
+
+const baseGameEvent_SpecialElectionCalled: object = {
+  playerId: "",
+  targetId: "",
+};
+
+const baseGameEvent_PoliciesPeeked: object = {
+  playerId: "",
+};
+
+const baseGameEvent_PlayerExecuted: object = {
+  playerId: "",
+  targetId: "",
+};
+
+const baseGameEvent_PlayerKicked: object = {
+  playerId: "",
+  targetId: "",
+};
+
+const baseGameEvent_AdminHandedOver: object = {
+  playerId: "",
+  targetId: "",
+};
+
+const baseGameEvent_PasswordChanged: object = {
+  playerId: "",
+  passwordProtected: false,
+};
+
+const baseGameEvent_LobbyLocked: object = {
+  playerId: "",
+  locked: false,
+};
+
+const baseGameEvent_BotAdded: object = {
+  playerId: "",
+  botId: "",
+};
+
+const baseGameEvent_PlayerReplacedByBot: object = {
+  playerId: "",
+};
+
+const baseGameEvent_RematchStarted: object = {
+  playerId: "",
+};
+
+const baseGameEvent_VetoProposed: object = {
+  playerId: "",
+};
+
+const baseGameEvent_VetoAnswered: object = {
+  playerId: "",
+  approved: false,
+};
+
+const baseGameEvent_NameChosen: object = {
+  playerId: "",
+  name: "",
+};
+
+const baseReplay: object = {
+  token: "",
+  winner: 0,
+  reason: 0,
+};
+
+export const protobufPackage = ''
+
+export const GameEvent = {
+  encode(message: GameEvent, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.time);
+    if (message.event?.$case === 'gameCreated') {
+      GameEvent_GameCreated.encode(message.event.gameCreated, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.event?.$case === 'playerJoined') {
+      GameEvent_PlayerJoined.encode(message.event.playerJoined, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.event?.$case === 'playerLeft') {
+      GameEvent_PlayerLeft.encode(message.event.playerLeft, writer.uint32(34).fork()).ldelim();
+    }
+    if (message.event?.$case === 'settingsUpdated') {
+      GameEvent_SettingsUpdated.encode(message.event.settingsUpdated, writer.uint32(42).fork()).ldelim();
+    }
+    if (message.event?.$case === 'gameStarted') {
+      GameEvent_GameStarted.encode(message.event.gameStarted, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.event?.$case === 'chancellorNominated') {
+      GameEvent_ChancellorNominated.encode(message.event.chancellorNominated, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.event?.$case === 'voteCast') {
+      GameEvent_VoteCast.encode(message.event.voteCast, writer.uint32(66).fork()).ldelim();
+    }
+    if (message.event?.$case === 'electionFinished') {
+      GameEvent_ElectionFinished.encode(message.event.electionFinished, writer.uint32(74).fork()).ldelim();
+    }
+    if (message.event?.$case === 'policyDiscarded') {
+      GameEvent_PolicyDiscarded.encode(message.event.policyDiscarded, writer.uint32(82).fork()).ldelim();
+    }
+    if (message.event?.$case === 'policyEnacted') {
+      GameEvent_PolicyEnacted.encode(message.event.policyEnacted, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.event?.$case === 'loyaltyInvestigated') {
+      GameEvent_LoyaltyInvestigated.encode(message.event.loyaltyInvestigated, writer.uint32(98).fork()).ldelim();
+    }
+    if (message.event?.$case === 'specialElectionCalled') {
+      GameEvent_SpecialElectionCalled.encode(message.event.specialElectionCalled, writer.uint32(106).fork()).ldelim();
+    }
+    if (message.event?.$case === 'policiesPeeked') {
+      GameEvent_PoliciesPeeked.encode(message.event.policiesPeeked, writer.uint32(114).fork()).ldelim();
+    }
+    if (message.event?.$case === 'playerExecuted') {
+      GameEvent_PlayerExecuted.encode(message.event.playerExecuted, writer.uint32(122).fork()).ldelim();
+    }
+    if (message.event?.$case === 'playerKicked') {
+      GameEvent_PlayerKicked.encode(message.event.playerKicked, writer.uint32(130).fork()).ldelim();
+    }
+    if (message.event?.$case === 'adminHandedOver') {
+      GameEvent_AdminHandedOver.encode(message.event.adminHandedOver, writer.uint32(138).fork()).ldelim();
+    }
+    if (message.event?.$case === 'passwordChanged') {
+      GameEvent_PasswordChanged.encode(message.event.passwordChanged, writer.uint32(146).fork()).ldelim();
+    }
+    if (message.event?.$case === 'lobbyLocked') {
+      GameEvent_LobbyLocked.encode(message.event.lobbyLocked, writer.uint32(154).fork()).ldelim();
+    }
+    if (message.event?.$case === 'voteAbstained') {
+      GameEvent_VoteAbstained.encode(message.event.voteAbstained, writer.uint32(162).fork()).ldelim();
+    }
+    if (message.event?.$case === 'botAdded') {
+      GameEvent_BotAdded.encode(message.event.botAdded, writer.uint32(170).fork()).ldelim();
+    }
+    if (message.event?.$case === 'playerReplacedByBot') {
+      GameEvent_PlayerReplacedByBot.encode(message.event.playerReplacedByBot, writer.uint32(178).fork()).ldelim();
+    }
+    if (message.event?.$case === 'rematchStarted') {
+      GameEvent_RematchStarted.encode(message.event.rematchStarted, writer.uint32(186).fork()).ldelim();
+    }
+    if (message.event?.$case === 'vetoProposed') {
+      GameEvent_VetoProposed.encode(message.event.vetoProposed, writer.uint32(194).fork()).ldelim();
+    }
+    if (message.event?.$case === 'vetoAnswered') {
+      GameEvent_VetoAnswered.encode(message.event.vetoAnswered, writer.uint32(202).fork()).ldelim();
+    }
+    if (message.event?.$case === 'nameChosen') {
+      GameEvent_NameChosen.encode(message.event.nameChosen, writer.uint32(210).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent } as GameEvent;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.time = reader.string();
+          break;
+        case 2:
+          message.event = {$case: 'gameCreated', gameCreated: GameEvent_GameCreated.decode(reader, reader.uint32())};
+          break;
+        case 3:
+          message.event = {$case: 'playerJoined', playerJoined: GameEvent_PlayerJoined.decode(reader, reader.uint32())};
+          break;
+        case 4:
+          message.event = {$case: 'playerLeft', playerLeft: GameEvent_PlayerLeft.decode(reader, reader.uint32())};
+          break;
+        case 5:
+          message.event = {$case: 'settingsUpdated', settingsUpdated: GameEvent_SettingsUpdated.decode(reader, reader.uint32())};
+          break;
+        case 6:
+          message.event = {$case: 'gameStarted', gameStarted: GameEvent_GameStarted.decode(reader, reader.uint32())};
+          break;
+        case 7:
+          message.event = {$case: 'chancellorNominated', chancellorNominated: GameEvent_ChancellorNominated.decode(reader, reader.uint32())};
+          break;
+        case 8:
+          message.event = {$case: 'voteCast', voteCast: GameEvent_VoteCast.decode(reader, reader.uint32())};
+          break;
+        case 9:
+          message.event = {$case: 'electionFinished', electionFinished: GameEvent_ElectionFinished.decode(reader, reader.uint32())};
+          break;
+        case 10:
+          message.event = {$case: 'policyDiscarded', policyDiscarded: GameEvent_PolicyDiscarded.decode(reader, reader.uint32())};
+          break;
+        case 11:
+          message.event = {$case: 'policyEnacted', policyEnacted: GameEvent_PolicyEnacted.decode(reader, reader.uint32())};
+          break;
+        case 12:
+          message.event = {$case: 'loyaltyInvestigated', loyaltyInvestigated: GameEvent_LoyaltyInvestigated.decode(reader, reader.uint32())};
+          break;
+        case 13:
+          message.event = {$case: 'specialElectionCalled', specialElectionCalled: GameEvent_SpecialElectionCalled.decode(reader, reader.uint32())};
+          break;
+        case 14:
+          message.event = {$case: 'policiesPeeked', policiesPeeked: GameEvent_PoliciesPeeked.decode(reader, reader.uint32())};
+          break;
+        case 15:
+          message.event = {$case: 'playerExecuted', playerExecuted: GameEvent_PlayerExecuted.decode(reader, reader.uint32())};
+          break;
+        case 16:
+          message.event = {$case: 'playerKicked', playerKicked: GameEvent_PlayerKicked.decode(reader, reader.uint32())};
+          break;
+        case 17:
+          message.event = {$case: 'adminHandedOver', adminHandedOver: GameEvent_AdminHandedOver.decode(reader, reader.uint32())};
+          break;
+        case 18:
+          message.event = {$case: 'passwordChanged', passwordChanged: GameEvent_PasswordChanged.decode(reader, reader.uint32())};
+          break;
+        case 19:
+          message.event = {$case: 'lobbyLocked', lobbyLocked: GameEvent_LobbyLocked.decode(reader, reader.uint32())};
+          break;
+        case 20:
+          message.event = {$case: 'voteAbstained', voteAbstained: GameEvent_VoteAbstained.decode(reader, reader.uint32())};
+          break;
+        case 21:
+          message.event = {$case: 'botAdded', botAdded: GameEvent_BotAdded.decode(reader, reader.uint32())};
+          break;
+        case 22:
+          message.event = {$case: 'playerReplacedByBot', playerReplacedByBot: GameEvent_PlayerReplacedByBot.decode(reader, reader.uint32())};
+          break;
+        case 23:
+          message.event = {$case: 'rematchStarted', rematchStarted: GameEvent_RematchStarted.decode(reader, reader.uint32())};
+          break;
+        case 24:
+          message.event = {$case: 'vetoProposed', vetoProposed: GameEvent_VetoProposed.decode(reader, reader.uint32())};
+          break;
+        case 25:
+          message.event = {$case: 'vetoAnswered', vetoAnswered: GameEvent_VetoAnswered.decode(reader, reader.uint32())};
+          break;
+        case 26:
+          message.event = {$case: 'nameChosen', nameChosen: GameEvent_NameChosen.decode(reader, reader.uint32())};
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent {
+    const message = { ...baseGameEvent } as GameEvent;
+    if (object.time !== undefined && object.time !== null) {
+      message.time = String(object.time);
+    }
+    if (object.gameCreated !== undefined && object.gameCreated !== null) {
+      message.event = {$case: 'gameCreated', gameCreated: GameEvent_GameCreated.fromJSON(object.gameCreated)};
+    }
+    if (object.playerJoined !== undefined && object.playerJoined !== null) {
+      message.event = {$case: 'playerJoined', playerJoined: GameEvent_PlayerJoined.fromJSON(object.playerJoined)};
+    }
+    if (object.playerLeft !== undefined && object.playerLeft !== null) {
+      message.event = {$case: 'playerLeft', playerLeft: GameEvent_PlayerLeft.fromJSON(object.playerLeft)};
+    }
+    if (object.settingsUpdated !== undefined && object.settingsUpdated !== null) {
+      message.event = {$case: 'settingsUpdated', settingsUpdated: GameEvent_SettingsUpdated.fromJSON(object.settingsUpdated)};
+    }
+    if (object.gameStarted !== undefined && object.gameStarted !== null) {
+      message.event = {$case: 'gameStarted', gameStarted: GameEvent_GameStarted.fromJSON(object.gameStarted)};
+    }
+    if (object.chancellorNominated !== undefined && object.chancellorNominated !== null) {
+      message.event = {$case: 'chancellorNominated', chancellorNominated: GameEvent_ChancellorNominated.fromJSON(object.chancellorNominated)};
+    }
+    if (object.voteCast !== undefined && object.voteCast !== null) {
+      message.event = {$case: 'voteCast', voteCast: GameEvent_VoteCast.fromJSON(object.voteCast)};
+    }
+    if (object.electionFinished !== undefined && object.electionFinished !== null) {
+      message.event = {$case: 'electionFinished', electionFinished: GameEvent_ElectionFinished.fromJSON(object.electionFinished)};
+    }
+    if (object.policyDiscarded !== undefined && object.policyDiscarded !== null) {
+      message.event = {$case: 'policyDiscarded', policyDiscarded: GameEvent_PolicyDiscarded.fromJSON(object.policyDiscarded)};
+    }
+    if (object.policyEnacted !== undefined && object.policyEnacted !== null) {
+      message.event = {$case: 'policyEnacted', policyEnacted: GameEvent_PolicyEnacted.fromJSON(object.policyEnacted)};
+    }
+    if (object.loyaltyInvestigated !== undefined && object.loyaltyInvestigated !== null) {
+      message.event = {$case: 'loyaltyInvestigated', loyaltyInvestigated: GameEvent_LoyaltyInvestigated.fromJSON(object.loyaltyInvestigated)};
+    }
+    if (object.specialElectionCalled !== undefined && object.specialElectionCalled !== null) {
+      message.event = {$case: 'specialElectionCalled', specialElectionCalled: GameEvent_SpecialElectionCalled.fromJSON(object.specialElectionCalled)};
+    }
+    if (object.policiesPeeked !== undefined && object.policiesPeeked !== null) {
+      message.event = {$case: 'policiesPeeked', policiesPeeked: GameEvent_PoliciesPeeked.fromJSON(object.policiesPeeked)};
+    }
+    if (object.playerExecuted !== undefined && object.playerExecuted !== null) {
+      message.event = {$case: 'playerExecuted', playerExecuted: GameEvent_PlayerExecuted.fromJSON(object.playerExecuted)};
+    }
+    if (object.playerKicked !== undefined && object.playerKicked !== null) {
+      message.event = {$case: 'playerKicked', playerKicked: GameEvent_PlayerKicked.fromJSON(object.playerKicked)};
+    }
+    if (object.adminHandedOver !== undefined && object.adminHandedOver !== null) {
+      message.event = {$case: 'adminHandedOver', adminHandedOver: GameEvent_AdminHandedOver.fromJSON(object.adminHandedOver)};
+    }
+    if (object.passwordChanged !== undefined && object.passwordChanged !== null) {
+      message.event = {$case: 'passwordChanged', passwordChanged: GameEvent_PasswordChanged.fromJSON(object.passwordChanged)};
+    }
+    if (object.lobbyLocked !== undefined && object.lobbyLocked !== null) {
+      message.event = {$case: 'lobbyLocked', lobbyLocked: GameEvent_LobbyLocked.fromJSON(object.lobbyLocked)};
+    }
+    if (object.voteAbstained !== undefined && object.voteAbstained !== null) {
+      message.event = {$case: 'voteAbstained', voteAbstained: GameEvent_VoteAbstained.fromJSON(object.voteAbstained)};
+    }
+    if (object.botAdded !== undefined && object.botAdded !== null) {
+      message.event = {$case: 'botAdded', botAdded: GameEvent_BotAdded.fromJSON(object.botAdded)};
+    }
+    if (object.playerReplacedByBot !== undefined && object.playerReplacedByBot !== null) {
+      message.event = {$case: 'playerReplacedByBot', playerReplacedByBot: GameEvent_PlayerReplacedByBot.fromJSON(object.playerReplacedByBot)};
+    }
+    if (object.rematchStarted !== undefined && object.rematchStarted !== null) {
+      message.event = {$case: 'rematchStarted', rematchStarted: GameEvent_RematchStarted.fromJSON(object.rematchStarted)};
+    }
+    if (object.vetoProposed !== undefined && object.vetoProposed !== null) {
+      message.event = {$case: 'vetoProposed', vetoProposed: GameEvent_VetoProposed.fromJSON(object.vetoProposed)};
+    }
+    if (object.vetoAnswered !== undefined && object.vetoAnswered !== null) {
+      message.event = {$case: 'vetoAnswered', vetoAnswered: GameEvent_VetoAnswered.fromJSON(object.vetoAnswered)};
+    }
+    if (object.nameChosen !== undefined && object.nameChosen !== null) {
+      message.event = {$case: 'nameChosen', nameChosen: GameEvent_NameChosen.fromJSON(object.nameChosen)};
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent>): GameEvent {
+    const message = { ...baseGameEvent } as GameEvent;
+    if (object.time !== undefined && object.time !== null) {
+      message.time = object.time;
+    }
+    if (object.event?.$case === 'gameCreated' && object.event?.gameCreated !== undefined && object.event?.gameCreated !== null) {
+      message.event = {$case: 'gameCreated', gameCreated: GameEvent_GameCreated.fromPartial(object.event.gameCreated)};
+    }
+    if (object.event?.$case === 'playerJoined' && object.event?.playerJoined !== undefined && object.event?.playerJoined !== null) {
+      message.event = {$case: 'playerJoined', playerJoined: GameEvent_PlayerJoined.fromPartial(object.event.playerJoined)};
+    }
+    if (object.event?.$case === 'playerLeft' && object.event?.playerLeft !== undefined && object.event?.playerLeft !== null) {
+      message.event = {$case: 'playerLeft', playerLeft: GameEvent_PlayerLeft.fromPartial(object.event.playerLeft)};
+    }
+    if (object.event?.$case === 'settingsUpdated' && object.event?.settingsUpdated !== undefined && object.event?.settingsUpdated !== null) {
+      message.event = {$case: 'settingsUpdated', settingsUpdated: GameEvent_SettingsUpdated.fromPartial(object.event.settingsUpdated)};
+    }
+    if (object.event?.$case === 'gameStarted' && object.event?.gameStarted !== undefined && object.event?.gameStarted !== null) {
+      message.event = {$case: 'gameStarted', gameStarted: GameEvent_GameStarted.fromPartial(object.event.gameStarted)};
+    }
+    if (object.event?.$case === 'chancellorNominated' && object.event?.chancellorNominated !== undefined && object.event?.chancellorNominated !== null) {
+      message.event = {$case: 'chancellorNominated', chancellorNominated: GameEvent_ChancellorNominated.fromPartial(object.event.chancellorNominated)};
+    }
+    if (object.event?.$case === 'voteCast' && object.event?.voteCast !== undefined && object.event?.voteCast !== null) {
+      message.event = {$case: 'voteCast', voteCast: GameEvent_VoteCast.fromPartial(object.event.voteCast)};
+    }
+    if (object.event?.$case === 'electionFinished' && object.event?.electionFinished !== undefined && object.event?.electionFinished !== null) {
+      message.event = {$case: 'electionFinished', electionFinished: GameEvent_ElectionFinished.fromPartial(object.event.electionFinished)};
+    }
+    if (object.event?.$case === 'policyDiscarded' && object.event?.policyDiscarded !== undefined && object.event?.policyDiscarded !== null) {
+      message.event = {$case: 'policyDiscarded', policyDiscarded: GameEvent_PolicyDiscarded.fromPartial(object.event.policyDiscarded)};
+    }
+    if (object.event?.$case === 'policyEnacted' && object.event?.policyEnacted !== undefined && object.event?.policyEnacted !== null) {
+      message.event = {$case: 'policyEnacted', policyEnacted: GameEvent_PolicyEnacted.fromPartial(object.event.policyEnacted)};
+    }
+    if (object.event?.$case === 'loyaltyInvestigated' && object.event?.loyaltyInvestigated !== undefined && object.event?.loyaltyInvestigated !== null) {
+      message.event = {$case: 'loyaltyInvestigated', loyaltyInvestigated: GameEvent_LoyaltyInvestigated.fromPartial(object.event.loyaltyInvestigated)};
+    }
+    if (object.event?.$case === 'specialElectionCalled' && object.event?.specialElectionCalled !== undefined && object.event?.specialElectionCalled !== null) {
+      message.event = {$case: 'specialElectionCalled', specialElectionCalled: GameEvent_SpecialElectionCalled.fromPartial(object.event.specialElectionCalled)};
+    }
+    if (object.event?.$case === 'policiesPeeked' && object.event?.policiesPeeked !== undefined && object.event?.policiesPeeked !== null) {
+      message.event = {$case: 'policiesPeeked', policiesPeeked: GameEvent_PoliciesPeeked.fromPartial(object.event.policiesPeeked)};
+    }
+    if (object.event?.$case === 'playerExecuted' && object.event?.playerExecuted !== undefined && object.event?.playerExecuted !== null) {
+      message.event = {$case: 'playerExecuted', playerExecuted: GameEvent_PlayerExecuted.fromPartial(object.event.playerExecuted)};
+    }
+    if (object.event?.$case === 'playerKicked' && object.event?.playerKicked !== undefined && object.event?.playerKicked !== null) {
+      message.event = {$case: 'playerKicked', playerKicked: GameEvent_PlayerKicked.fromPartial(object.event.playerKicked)};
+    }
+    if (object.event?.$case === 'adminHandedOver' && object.event?.adminHandedOver !== undefined && object.event?.adminHandedOver !== null) {
+      message.event = {$case: 'adminHandedOver', adminHandedOver: GameEvent_AdminHandedOver.fromPartial(object.event.adminHandedOver)};
+    }
+    if (object.event?.$case === 'passwordChanged' && object.event?.passwordChanged !== undefined && object.event?.passwordChanged !== null) {
+      message.event = {$case: 'passwordChanged', passwordChanged: GameEvent_PasswordChanged.fromPartial(object.event.passwordChanged)};
+    }
+    if (object.event?.$case === 'lobbyLocked' && object.event?.lobbyLocked !== undefined && object.event?.lobbyLocked !== null) {
+      message.event = {$case: 'lobbyLocked', lobbyLocked: GameEvent_LobbyLocked.fromPartial(object.event.lobbyLocked)};
+    }
+    if (object.event?.$case === 'voteAbstained' && object.event?.voteAbstained !== undefined && object.event?.voteAbstained !== null) {
+      message.event = {$case: 'voteAbstained', voteAbstained: GameEvent_VoteAbstained.fromPartial(object.event.voteAbstained)};
+    }
+    if (object.event?.$case === 'botAdded' && object.event?.botAdded !== undefined && object.event?.botAdded !== null) {
+      message.event = {$case: 'botAdded', botAdded: GameEvent_BotAdded.fromPartial(object.event.botAdded)};
+    }
+    if (object.event?.$case === 'playerReplacedByBot' && object.event?.playerReplacedByBot !== undefined && object.event?.playerReplacedByBot !== null) {
+      message.event = {$case: 'playerReplacedByBot', playerReplacedByBot: GameEvent_PlayerReplacedByBot.fromPartial(object.event.playerReplacedByBot)};
+    }
+    if (object.event?.$case === 'rematchStarted' && object.event?.rematchStarted !== undefined && object.event?.rematchStarted !== null) {
+      message.event = {$case: 'rematchStarted', rematchStarted: GameEvent_RematchStarted.fromPartial(object.event.rematchStarted)};
+    }
+    if (object.event?.$case === 'vetoProposed' && object.event?.vetoProposed !== undefined && object.event?.vetoProposed !== null) {
+      message.event = {$case: 'vetoProposed', vetoProposed: GameEvent_VetoProposed.fromPartial(object.event.vetoProposed)};
+    }
+    if (object.event?.$case === 'vetoAnswered' && object.event?.vetoAnswered !== undefined && object.event?.vetoAnswered !== null) {
+      message.event = {$case: 'vetoAnswered', vetoAnswered: GameEvent_VetoAnswered.fromPartial(object.event.vetoAnswered)};
+    }
+    if (object.event?.$case === 'nameChosen' && object.event?.nameChosen !== undefined && object.event?.nameChosen !== null) {
+      message.event = {$case: 'nameChosen', nameChosen: GameEvent_NameChosen.fromPartial(object.event.nameChosen)};
+    }
+    return message;
+  },
+  toJSON(message: GameEvent): unknown {
+    const obj: any = {};
+    message.time !== undefined && (obj.time = message.time);
+    message.event?.$case === 'gameCreated' && (obj.gameCreated = message.event?.gameCreated ? GameEvent_GameCreated.toJSON(message.event?.gameCreated) : undefined);
+    message.event?.$case === 'playerJoined' && (obj.playerJoined = message.event?.playerJoined ? GameEvent_PlayerJoined.toJSON(message.event?.playerJoined) : undefined);
+    message.event?.$case === 'playerLeft' && (obj.playerLeft = message.event?.playerLeft ? GameEvent_PlayerLeft.toJSON(message.event?.playerLeft) : undefined);
+    message.event?.$case === 'settingsUpdated' && (obj.settingsUpdated = message.event?.settingsUpdated ? GameEvent_SettingsUpdated.toJSON(message.event?.settingsUpdated) : undefined);
+    message.event?.$case === 'gameStarted' && (obj.gameStarted = message.event?.gameStarted ? GameEvent_GameStarted.toJSON(message.event?.gameStarted) : undefined);
+    message.event?.$case === 'chancellorNominated' && (obj.chancellorNominated = message.event?.chancellorNominated ? GameEvent_ChancellorNominated.toJSON(message.event?.chancellorNominated) : undefined);
+    message.event?.$case === 'voteCast' && (obj.voteCast = message.event?.voteCast ? GameEvent_VoteCast.toJSON(message.event?.voteCast) : undefined);
+    message.event?.$case === 'electionFinished' && (obj.electionFinished = message.event?.electionFinished ? GameEvent_ElectionFinished.toJSON(message.event?.electionFinished) : undefined);
+    message.event?.$case === 'policyDiscarded' && (obj.policyDiscarded = message.event?.policyDiscarded ? GameEvent_PolicyDiscarded.toJSON(message.event?.policyDiscarded) : undefined);
+    message.event?.$case === 'policyEnacted' && (obj.policyEnacted = message.event?.policyEnacted ? GameEvent_PolicyEnacted.toJSON(message.event?.policyEnacted) : undefined);
+    message.event?.$case === 'loyaltyInvestigated' && (obj.loyaltyInvestigated = message.event?.loyaltyInvestigated ? GameEvent_LoyaltyInvestigated.toJSON(message.event?.loyaltyInvestigated) : undefined);
+    message.event?.$case === 'specialElectionCalled' && (obj.specialElectionCalled = message.event?.specialElectionCalled ? GameEvent_SpecialElectionCalled.toJSON(message.event?.specialElectionCalled) : undefined);
+    message.event?.$case === 'policiesPeeked' && (obj.policiesPeeked = message.event?.policiesPeeked ? GameEvent_PoliciesPeeked.toJSON(message.event?.policiesPeeked) : undefined);
+    message.event?.$case === 'playerExecuted' && (obj.playerExecuted = message.event?.playerExecuted ? GameEvent_PlayerExecuted.toJSON(message.event?.playerExecuted) : undefined);
+    message.event?.$case === 'playerKicked' && (obj.playerKicked = message.event?.playerKicked ? GameEvent_PlayerKicked.toJSON(message.event?.playerKicked) : undefined);
+    message.event?.$case === 'adminHandedOver' && (obj.adminHandedOver = message.event?.adminHandedOver ? GameEvent_AdminHandedOver.toJSON(message.event?.adminHandedOver) : undefined);
+    message.event?.$case === 'passwordChanged' && (obj.passwordChanged = message.event?.passwordChanged ? GameEvent_PasswordChanged.toJSON(message.event?.passwordChanged) : undefined);
+    message.event?.$case === 'lobbyLocked' && (obj.lobbyLocked = message.event?.lobbyLocked ? GameEvent_LobbyLocked.toJSON(message.event?.lobbyLocked) : undefined);
+    message.event?.$case === 'voteAbstained' && (obj.voteAbstained = message.event?.voteAbstained ? GameEvent_VoteAbstained.toJSON(message.event?.voteAbstained) : undefined);
+    message.event?.$case === 'botAdded' && (obj.botAdded = message.event?.botAdded ? GameEvent_BotAdded.toJSON(message.event?.botAdded) : undefined);
+    message.event?.$case === 'playerReplacedByBot' && (obj.playerReplacedByBot = message.event?.playerReplacedByBot ? GameEvent_PlayerReplacedByBot.toJSON(message.event?.playerReplacedByBot) : undefined);
+    message.event?.$case === 'rematchStarted' && (obj.rematchStarted = message.event?.rematchStarted ? GameEvent_RematchStarted.toJSON(message.event?.rematchStarted) : undefined);
+    message.event?.$case === 'vetoProposed' && (obj.vetoProposed = message.event?.vetoProposed ? GameEvent_VetoProposed.toJSON(message.event?.vetoProposed) : undefined);
+    message.event?.$case === 'vetoAnswered' && (obj.vetoAnswered = message.event?.vetoAnswered ? GameEvent_VetoAnswered.toJSON(message.event?.vetoAnswered) : undefined);
+    message.event?.$case === 'nameChosen' && (obj.nameChosen = message.event?.nameChosen ? GameEvent_NameChosen.toJSON(message.event?.nameChosen) : undefined);
+    return obj;
+  },
+};
+
+export const GameEvent_GameCreated = {
+  encode(message: GameEvent_GameCreated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.adminId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_GameCreated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_GameCreated } as GameEvent_GameCreated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.adminId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_GameCreated {
+    const message = { ...baseGameEvent_GameCreated } as GameEvent_GameCreated;
+    if (object.adminId !== undefined && object.adminId !== null) {
+      message.adminId = String(object.adminId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_GameCreated>): GameEvent_GameCreated {
+    const message = { ...baseGameEvent_GameCreated } as GameEvent_GameCreated;
+    if (object.adminId !== undefined && object.adminId !== null) {
+      message.adminId = object.adminId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_GameCreated): unknown {
+    const obj: any = {};
+    message.adminId !== undefined && (obj.adminId = message.adminId);
+    return obj;
+  },
+};
+
+export const GameEvent_PlayerJoined = {
+  encode(message: GameEvent_PlayerJoined, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PlayerJoined {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PlayerJoined } as GameEvent_PlayerJoined;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PlayerJoined {
+    const message = { ...baseGameEvent_PlayerJoined } as GameEvent_PlayerJoined;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PlayerJoined>): GameEvent_PlayerJoined {
+    const message = { ...baseGameEvent_PlayerJoined } as GameEvent_PlayerJoined;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PlayerJoined): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_PlayerLeft = {
+  encode(message: GameEvent_PlayerLeft, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PlayerLeft {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PlayerLeft } as GameEvent_PlayerLeft;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PlayerLeft {
+    const message = { ...baseGameEvent_PlayerLeft } as GameEvent_PlayerLeft;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PlayerLeft>): GameEvent_PlayerLeft {
+    const message = { ...baseGameEvent_PlayerLeft } as GameEvent_PlayerLeft;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PlayerLeft): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_SettingsUpdated = {
+  encode(message: GameEvent_SettingsUpdated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    if (message.settings !== undefined && message.settings !== undefined) {
+      GameSettings.encode(message.settings, writer.uint32(18).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_SettingsUpdated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_SettingsUpdated } as GameEvent_SettingsUpdated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.settings = GameSettings.decode(reader, reader.uint32());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_SettingsUpdated {
+    const message = { ...baseGameEvent_SettingsUpdated } as GameEvent_SettingsUpdated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromJSON(object.settings);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_SettingsUpdated>): GameEvent_SettingsUpdated {
+    const message = { ...baseGameEvent_SettingsUpdated } as GameEvent_SettingsUpdated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.settings !== undefined && object.settings !== null) {
+      message.settings = GameSettings.fromPartial(object.settings);
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_SettingsUpdated): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.settings !== undefined && (obj.settings = message.settings ? GameSettings.toJSON(message.settings) : undefined);
+    return obj;
+  },
+};
+
+export const GameEvent_GameStarted = {
+  encode(_: GameEvent_GameStarted, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_GameStarted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_GameStarted } as GameEvent_GameStarted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): GameEvent_GameStarted {
+    const message = { ...baseGameEvent_GameStarted } as GameEvent_GameStarted;
+    return message;
+  },
+  fromPartial(_: DeepPartial<GameEvent_GameStarted>): GameEvent_GameStarted {
+    const message = { ...baseGameEvent_GameStarted } as GameEvent_GameStarted;
+    return message;
+  },
+  toJSON(_: GameEvent_GameStarted): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const GameEvent_ChancellorNominated = {
+  encode(message: GameEvent_ChancellorNominated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.chancellorId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_ChancellorNominated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_ChancellorNominated } as GameEvent_ChancellorNominated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.chancellorId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_ChancellorNominated {
+    const message = { ...baseGameEvent_ChancellorNominated } as GameEvent_ChancellorNominated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = String(object.chancellorId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_ChancellorNominated>): GameEvent_ChancellorNominated {
+    const message = { ...baseGameEvent_ChancellorNominated } as GameEvent_ChancellorNominated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = object.chancellorId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_ChancellorNominated): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.chancellorId !== undefined && (obj.chancellorId = message.chancellorId);
+    return obj;
+  },
+};
+
+export const GameEvent_VoteCast = {
+  encode(message: GameEvent_VoteCast, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).bool(message.approved);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_VoteCast {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_VoteCast } as GameEvent_VoteCast;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.approved = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_VoteCast {
+    const message = { ...baseGameEvent_VoteCast } as GameEvent_VoteCast;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = Boolean(object.approved);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_VoteCast>): GameEvent_VoteCast {
+    const message = { ...baseGameEvent_VoteCast } as GameEvent_VoteCast;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = object.approved;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_VoteCast): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.approved !== undefined && (obj.approved = message.approved);
+    return obj;
+  },
+};
+
+export const GameEvent_VoteAbstained = {
+  encode(message: GameEvent_VoteAbstained, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_VoteAbstained {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_VoteAbstained } as GameEvent_VoteAbstained;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_VoteAbstained {
+    const message = { ...baseGameEvent_VoteAbstained } as GameEvent_VoteAbstained;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_VoteAbstained>): GameEvent_VoteAbstained {
+    const message = { ...baseGameEvent_VoteAbstained } as GameEvent_VoteAbstained;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_VoteAbstained): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_ElectionFinished = {
+  encode(_: GameEvent_ElectionFinished, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_ElectionFinished {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_ElectionFinished } as GameEvent_ElectionFinished;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): GameEvent_ElectionFinished {
+    const message = { ...baseGameEvent_ElectionFinished } as GameEvent_ElectionFinished;
+    return message;
+  },
+  fromPartial(_: DeepPartial<GameEvent_ElectionFinished>): GameEvent_ElectionFinished {
+    const message = { ...baseGameEvent_ElectionFinished } as GameEvent_ElectionFinished;
+    return message;
+  },
+  toJSON(_: GameEvent_ElectionFinished): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const GameEvent_PolicyDiscarded = {
+  encode(message: GameEvent_PolicyDiscarded, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).int32(message.policy);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PolicyDiscarded {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PolicyDiscarded } as GameEvent_PolicyDiscarded;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.policy = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PolicyDiscarded {
+    const message = { ...baseGameEvent_PolicyDiscarded } as GameEvent_PolicyDiscarded;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = policyFromJSON(object.policy);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PolicyDiscarded>): GameEvent_PolicyDiscarded {
+    const message = { ...baseGameEvent_PolicyDiscarded } as GameEvent_PolicyDiscarded;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = object.policy;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PolicyDiscarded): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.policy !== undefined && (obj.policy = policyToJSON(message.policy));
+    return obj;
+  },
+};
+
+export const GameEvent_PolicyEnacted = {
+  encode(message: GameEvent_PolicyEnacted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).int32(message.policy);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PolicyEnacted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PolicyEnacted } as GameEvent_PolicyEnacted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.policy = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PolicyEnacted {
+    const message = { ...baseGameEvent_PolicyEnacted } as GameEvent_PolicyEnacted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = policyFromJSON(object.policy);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PolicyEnacted>): GameEvent_PolicyEnacted {
+    const message = { ...baseGameEvent_PolicyEnacted } as GameEvent_PolicyEnacted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.policy !== undefined && object.policy !== null) {
+      message.policy = object.policy;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PolicyEnacted): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.policy !== undefined && (obj.policy = policyToJSON(message.policy));
+    return obj;
+  },
+};
+
+export const GameEvent_LoyaltyInvestigated = {
+  encode(message: GameEvent_LoyaltyInvestigated, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.targetId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_LoyaltyInvestigated {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_LoyaltyInvestigated } as GameEvent_LoyaltyInvestigated;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.targetId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_LoyaltyInvestigated {
+    const message = { ...baseGameEvent_LoyaltyInvestigated } as GameEvent_LoyaltyInvestigated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = String(object.targetId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_LoyaltyInvestigated>): GameEvent_LoyaltyInvestigated {
+    const message = { ...baseGameEvent_LoyaltyInvestigated } as GameEvent_LoyaltyInvestigated;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = object.targetId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_LoyaltyInvestigated): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.targetId !== undefined && (obj.targetId = message.targetId);
+    return obj;
+  },
+};
+
+export const GameEvent_SpecialElectionCalled = {
+  encode(message: GameEvent_SpecialElectionCalled, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.targetId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_SpecialElectionCalled {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_SpecialElectionCalled } as GameEvent_SpecialElectionCalled;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.targetId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_SpecialElectionCalled {
+    const message = { ...baseGameEvent_SpecialElectionCalled } as GameEvent_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = String(object.targetId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_SpecialElectionCalled>): GameEvent_SpecialElectionCalled {
+    const message = { ...baseGameEvent_SpecialElectionCalled } as GameEvent_SpecialElectionCalled;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = object.targetId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_SpecialElectionCalled): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.targetId !== undefined && (obj.targetId = message.targetId);
+    return obj;
+  },
+};
+
+export const GameEvent_PoliciesPeeked = {
+  encode(message: GameEvent_PoliciesPeeked, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PoliciesPeeked {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PoliciesPeeked } as GameEvent_PoliciesPeeked;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PoliciesPeeked {
+    const message = { ...baseGameEvent_PoliciesPeeked } as GameEvent_PoliciesPeeked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PoliciesPeeked>): GameEvent_PoliciesPeeked {
+    const message = { ...baseGameEvent_PoliciesPeeked } as GameEvent_PoliciesPeeked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PoliciesPeeked): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_PlayerExecuted = {
+  encode(message: GameEvent_PlayerExecuted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.targetId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PlayerExecuted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PlayerExecuted } as GameEvent_PlayerExecuted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.targetId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PlayerExecuted {
+    const message = { ...baseGameEvent_PlayerExecuted } as GameEvent_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = String(object.targetId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PlayerExecuted>): GameEvent_PlayerExecuted {
+    const message = { ...baseGameEvent_PlayerExecuted } as GameEvent_PlayerExecuted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = object.targetId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PlayerExecuted): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.targetId !== undefined && (obj.targetId = message.targetId);
+    return obj;
+  },
+};
+
+export const GameEvent_PlayerKicked = {
+  encode(message: GameEvent_PlayerKicked, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.targetId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PlayerKicked {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PlayerKicked } as GameEvent_PlayerKicked;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.targetId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PlayerKicked {
+    const message = { ...baseGameEvent_PlayerKicked } as GameEvent_PlayerKicked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = String(object.targetId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PlayerKicked>): GameEvent_PlayerKicked {
+    const message = { ...baseGameEvent_PlayerKicked } as GameEvent_PlayerKicked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = object.targetId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PlayerKicked): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.targetId !== undefined && (obj.targetId = message.targetId);
+    return obj;
+  },
+};
+
+export const GameEvent_AdminHandedOver = {
+  encode(message: GameEvent_AdminHandedOver, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.targetId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_AdminHandedOver {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_AdminHandedOver } as GameEvent_AdminHandedOver;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.targetId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_AdminHandedOver {
+    const message = { ...baseGameEvent_AdminHandedOver } as GameEvent_AdminHandedOver;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = String(object.targetId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_AdminHandedOver>): GameEvent_AdminHandedOver {
+    const message = { ...baseGameEvent_AdminHandedOver } as GameEvent_AdminHandedOver;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.targetId !== undefined && object.targetId !== null) {
+      message.targetId = object.targetId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_AdminHandedOver): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.targetId !== undefined && (obj.targetId = message.targetId);
+    return obj;
+  },
+};
+
+export const GameEvent_PasswordChanged = {
+  encode(message: GameEvent_PasswordChanged, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).bool(message.passwordProtected);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PasswordChanged {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PasswordChanged } as GameEvent_PasswordChanged;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.passwordProtected = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PasswordChanged {
+    const message = { ...baseGameEvent_PasswordChanged } as GameEvent_PasswordChanged;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.passwordProtected !== undefined && object.passwordProtected !== null) {
+      message.passwordProtected = Boolean(object.passwordProtected);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PasswordChanged>): GameEvent_PasswordChanged {
+    const message = { ...baseGameEvent_PasswordChanged } as GameEvent_PasswordChanged;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.passwordProtected !== undefined && object.passwordProtected !== null) {
+      message.passwordProtected = object.passwordProtected;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PasswordChanged): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.passwordProtected !== undefined && (obj.passwordProtected = message.passwordProtected);
+    return obj;
+  },
+};
+
+export const GameEvent_LobbyLocked = {
+  encode(message: GameEvent_LobbyLocked, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).bool(message.locked);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_LobbyLocked {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_LobbyLocked } as GameEvent_LobbyLocked;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.locked = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_LobbyLocked {
+    const message = { ...baseGameEvent_LobbyLocked } as GameEvent_LobbyLocked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.locked !== undefined && object.locked !== null) {
+      message.locked = Boolean(object.locked);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_LobbyLocked>): GameEvent_LobbyLocked {
+    const message = { ...baseGameEvent_LobbyLocked } as GameEvent_LobbyLocked;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.locked !== undefined && object.locked !== null) {
+      message.locked = object.locked;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_LobbyLocked): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.locked !== undefined && (obj.locked = message.locked);
+    return obj;
+  },
+};
+
+export const GameEvent_BotAdded = {
+  encode(message: GameEvent_BotAdded, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.botId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_BotAdded {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_BotAdded } as GameEvent_BotAdded;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.botId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_BotAdded {
+    const message = { ...baseGameEvent_BotAdded } as GameEvent_BotAdded;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.botId !== undefined && object.botId !== null) {
+      message.botId = String(object.botId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_BotAdded>): GameEvent_BotAdded {
+    const message = { ...baseGameEvent_BotAdded } as GameEvent_BotAdded;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.botId !== undefined && object.botId !== null) {
+      message.botId = object.botId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_BotAdded): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.botId !== undefined && (obj.botId = message.botId);
+    return obj;
+  },
+};
+
+export const GameEvent_PlayerReplacedByBot = {
+  encode(message: GameEvent_PlayerReplacedByBot, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_PlayerReplacedByBot {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_PlayerReplacedByBot } as GameEvent_PlayerReplacedByBot;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_PlayerReplacedByBot {
+    const message = { ...baseGameEvent_PlayerReplacedByBot } as GameEvent_PlayerReplacedByBot;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_PlayerReplacedByBot>): GameEvent_PlayerReplacedByBot {
+    const message = { ...baseGameEvent_PlayerReplacedByBot } as GameEvent_PlayerReplacedByBot;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_PlayerReplacedByBot): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_RematchStarted = {
+  encode(message: GameEvent_RematchStarted, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_RematchStarted {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_RematchStarted } as GameEvent_RematchStarted;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_RematchStarted {
+    const message = { ...baseGameEvent_RematchStarted } as GameEvent_RematchStarted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_RematchStarted>): GameEvent_RematchStarted {
+    const message = { ...baseGameEvent_RematchStarted } as GameEvent_RematchStarted;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_RematchStarted): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_VetoProposed = {
+  encode(message: GameEvent_VetoProposed, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_VetoProposed {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_VetoProposed } as GameEvent_VetoProposed;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_VetoProposed {
+    const message = { ...baseGameEvent_VetoProposed } as GameEvent_VetoProposed;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_VetoProposed>): GameEvent_VetoProposed {
+    const message = { ...baseGameEvent_VetoProposed } as GameEvent_VetoProposed;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_VetoProposed): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    return obj;
+  },
+};
+
+export const GameEvent_VetoAnswered = {
+  encode(message: GameEvent_VetoAnswered, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).bool(message.approved);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_VetoAnswered {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_VetoAnswered } as GameEvent_VetoAnswered;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.approved = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_VetoAnswered {
+    const message = { ...baseGameEvent_VetoAnswered } as GameEvent_VetoAnswered;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = Boolean(object.approved);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_VetoAnswered>): GameEvent_VetoAnswered {
+    const message = { ...baseGameEvent_VetoAnswered } as GameEvent_VetoAnswered;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.approved !== undefined && object.approved !== null) {
+      message.approved = object.approved;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_VetoAnswered): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.approved !== undefined && (obj.approved = message.approved);
+    return obj;
+  },
+};
+
+export const GameEvent_NameChosen = {
+  encode(message: GameEvent_NameChosen, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(18).string(message.name);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameEvent_NameChosen {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameEvent_NameChosen } as GameEvent_NameChosen;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.name = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameEvent_NameChosen {
+    const message = { ...baseGameEvent_NameChosen } as GameEvent_NameChosen;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.name !== undefined && object.name !== null) {
+      message.name = String(object.name);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameEvent_NameChosen>): GameEvent_NameChosen {
+    const message = { ...baseGameEvent_NameChosen } as GameEvent_NameChosen;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.name !== undefined && object.name !== null) {
+      message.name = object.name;
+    }
+    return message;
+  },
+  toJSON(message: GameEvent_NameChosen): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.name !== undefined && (obj.name = message.name);
+    return obj;
+  },
+};
+
+export const Replay = {
+  encode(message: Replay, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.token);
+    writer.uint32(16).int32(message.winner);
+    writer.uint32(24).int32(message.reason);
+    for (const v of message.roles) {
+      PlayerRole.encode(v!, writer.uint32(34).fork()).ldelim();
+    }
+    for (const v of message.events) {
+      GameEvent.encode(v!, writer.uint32(42).fork()).ldelim();
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Replay {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseReplay } as Replay;
+    message.roles = [];
+    message.events = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.token = reader.string();
+          break;
+        case 2:
+          message.winner = reader.int32() as any;
+          break;
+        case 3:
+          message.reason = reader.int32() as any;
+          break;
+        case 4:
+          message.roles.push(PlayerRole.decode(reader, reader.uint32()));
+          break;
+        case 5:
+          message.events.push(GameEvent.decode(reader, reader.uint32()));
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Replay {
+    const message = { ...baseReplay } as Replay;
+    message.roles = [];
+    message.events = [];
+    if (object.token !== undefined && object.token !== null) {
+      message.token = String(object.token);
+    }
+    if (object.winner !== undefined && object.winner !== null) {
+      message.winner = partyFromJSON(object.winner);
+    }
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = winReasonFromJSON(object.reason);
+    }
+    if (object.roles !== undefined && object.roles !== null) {
+      for (const e of object.roles) {
+        message.roles.push(PlayerRole.fromJSON(e));
+      }
+    }
+    if (object.events !== undefined && object.events !== null) {
+      for (const e of object.events) {
+        message.events.push(GameEvent.fromJSON(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Replay>): Replay {
+    const message = { ...baseReplay } as Replay;
+    message.roles = [];
+    message.events = [];
+    if (object.token !== undefined && object.token !== null) {
+      message.token = object.token;
+    }
+    if (object.winner !== undefined && object.winner !== null) {
+      message.winner = object.winner;
+    }
+    if (object.reason !== undefined && object.reason !== null) {
+      message.reason = object.reason;
+    }
+    if (object.roles !== undefined && object.roles !== null) {
+      for (const e of object.roles) {
+        message.roles.push(PlayerRole.fromPartial(e));
+      }
+    }
+    if (object.events !== undefined && object.events !== null) {
+      for (const e of object.events) {
+        message.events.push(GameEvent.fromPartial(e));
+      }
+    }
+    return message;
+  },
+  toJSON(message: Replay): unknown {
+    const obj: any = {};
+    message.token !== undefined && (obj.token = message.token);
+    message.winner !== undefined && (obj.winner = partyToJSON(message.winner));
+    message.reason !== undefined && (obj.reason = winReasonToJSON(message.reason));
+    if (message.roles) {
+      obj.roles = message.roles.map(e => e ? PlayerRole.toJSON(e) : undefined);
+    } else {
+      obj.roles = [];
+    }
+    if (message.events) {
+      obj.events = message.events.map(e => e ? GameEvent.toJSON(e) : undefined);
+    } else {
+      obj.events = [];
+    }
+    return obj;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | undefined;
+export type DeepPartial<T> = T extends Builtin
+  ? T
+  : T extends Array<infer U>
+  ? Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U>
+  ? ReadonlyArray<DeepPartial<U>>
+  : T extends { $case: string }
+  ? { [K in keyof Omit<T, '$case'>]?: DeepPartial<T[K]> } & { $case: T['$case'] }
+  : T extends {}
+  ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
\ No newline at end of file
@@ -0,0 +1,152 @@
+/* eslint-disable */
+import { Writer, Reader } from 'protobufjs/minimal';
+
+
+export enum Role {
+  LOYAL = 0,
+  CLAN = 1,
+  CLAN_LEADER = 2,
+  UNRECOGNIZED = -1,
+}
+
+export function roleFromJSON(object: any): Role {
+  switch (object) {
+    case 0:
+    case "LOYAL":
+      return Role.LOYAL;
+    case 1:
+    case "CLAN":
+      return Role.CLAN;
+    case 2:
+    case "CLAN_LEADER":
+      return Role.CLAN_LEADER;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return Role.UNRECOGNIZED;
+  }
+}
+
+export function roleToJSON(object: Role): string {
+  switch (object) {
+    case Role.LOYAL:
+      return "LOYAL";
+    case Role.CLAN:
+      return "CLAN";
+    case Role.CLAN_LEADER:
+      return "CLAN_LEADER";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export enum Party {
+  LOYAL_PARTY = 0,
+  CLAN_PARTY = 1,
+  UNRECOGNIZED = -1,
+}
+
+export function partyFromJSON(object: any): Party {
+  switch (object) {
+    case 0:
+    case "LOYAL_PARTY":
+      return Party.LOYAL_PARTY;
+    case 1:
+    case "CLAN_PARTY":
+      return Party.CLAN_PARTY;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return Party.UNRECOGNIZED;
+  }
+}
+
+export function partyToJSON(object: Party): string {
+  switch (object) {
+    case Party.LOYAL_PARTY:
+      return "LOYAL_PARTY";
+    case Party.CLAN_PARTY:
+      return "CLAN_PARTY";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export interface PlayerRole {
+  playerId: string;
+  role: Role;
+}
+
+const basePlayerRole: object = {
+  playerId: "",
+  role: 0,
+};
+
+export const protobufPackage = ''
+
+export const PlayerRole = {
+  encode(message: PlayerRole, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.playerId);
+    writer.uint32(16).int32(message.role);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): PlayerRole {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...basePlayerRole } as PlayerRole;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerId = reader.string();
+          break;
+        case 2:
+          message.role = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): PlayerRole {
+    const message = { ...basePlayerRole } as PlayerRole;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = String(object.playerId);
+    }
+    if (object.role !== undefined && object.role !== null) {
+      message.role = roleFromJSON(object.role);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<PlayerRole>): PlayerRole {
+    const message = { ...basePlayerRole } as PlayerRole;
+    if (object.playerId !== undefined && object.playerId !== null) {
+      message.playerId = object.playerId;
+    }
+    if (object.role !== undefined && object.role !== null) {
+      message.role = object.role;
+    }
+    return message;
+  },
+  toJSON(message: PlayerRole): unknown {
+    const obj: any = {};
+    message.playerId !== undefined && (obj.playerId = message.playerId);
+    message.role !== undefined && (obj.role = roleToJSON(message.role));
+    return obj;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | undefined;
+export type DeepPartial<T> = T extends Builtin
+  ? T
+  : T extends Array<infer U>
+  ? Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U>
+  ? ReadonlyArray<DeepPartial<U>>
+  : T extends { $case: string }
+  ? { [K in keyof Omit<T, '$case'>]?: DeepPartial<T[K]> } & { $case: T['$case'] }
+  : T extends {}
+  ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
\ No newline at end of file
@@ -0,0 +1,273 @@
+/* eslint-disable */
+import { Writer, Reader } from 'protobufjs/minimal';
+
+
+export enum ClanLeaderKnowledge {
+  SMALL_GAMES_ONLY = 0,
+  ALWAYS_KNOWN = 1,
+  NEVER_KNOWN = 2,
+  UNRECOGNIZED = -1,
+}
+
+export function clanLeaderKnowledgeFromJSON(object: any): ClanLeaderKnowledge {
+  switch (object) {
+    case 0:
+    case "SMALL_GAMES_ONLY":
+      return ClanLeaderKnowledge.SMALL_GAMES_ONLY;
+    case 1:
+    case "ALWAYS_KNOWN":
+      return ClanLeaderKnowledge.ALWAYS_KNOWN;
+    case 2:
+    case "NEVER_KNOWN":
+      return ClanLeaderKnowledge.NEVER_KNOWN;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ClanLeaderKnowledge.UNRECOGNIZED;
+  }
+}
+
+export function clanLeaderKnowledgeToJSON(object: ClanLeaderKnowledge): string {
+  switch (object) {
+    case ClanLeaderKnowledge.SMALL_GAMES_ONLY:
+      return "SMALL_GAMES_ONLY";
+    case ClanLeaderKnowledge.ALWAYS_KNOWN:
+      return "ALWAYS_KNOWN";
+    case ClanLeaderKnowledge.NEVER_KNOWN:
+      return "NEVER_KNOWN";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export enum ChatAudience {
+  LIVING_PLAYERS = 0,
+  ALL_PLAYERS = 1,
+  EVERYONE = 2,
+  UNRECOGNIZED = -1,
+}
+
+export function chatAudienceFromJSON(object: any): ChatAudience {
+  switch (object) {
+    case 0:
+    case "LIVING_PLAYERS":
+      return ChatAudience.LIVING_PLAYERS;
+    case 1:
+    case "ALL_PLAYERS":
+      return ChatAudience.ALL_PLAYERS;
+    case 2:
+    case "EVERYONE":
+      return ChatAudience.EVERYONE;
+    case -1:
+    case "UNRECOGNIZED":
+    default:
+      return ChatAudience.UNRECOGNIZED;
+  }
+}
+
+export function chatAudienceToJSON(object: ChatAudience): string {
+  switch (object) {
+    case ChatAudience.LIVING_PLAYERS:
+      return "LIVING_PLAYERS";
+    case ChatAudience.ALL_PLAYERS:
+      return "ALL_PLAYERS";
+    case ChatAudience.EVERYONE:
+      return "EVERYONE";
+    default:
+      return "UNKNOWN";
+  }
+}
+
+export interface RoleDistribution {
+  playerCount: number;
+  clanMembersCount: number;
+}
+
+export interface GameSettings {
+  roleDistributions: RoleDistribution[];
+  clanLeaderKnowledge: ClanLeaderKnowledge;
+  vetoEnabled: boolean;
+  taskTimeoutSeconds: number;
+  chatAudience: ChatAudience;
+}
+
+const baseRoleDistribution: object = {
+  playerCount: 0,
+  clanMembersCount: 0,
+};
+
+const baseGameSettings: object = {
+  clanLeaderKnowledge: 0,
+  vetoEnabled: false,
+  taskTimeoutSeconds: 0,
+  chatAudience: 0,
+};
+
+export const protobufPackage = ''
+
+export const RoleDistribution = {
+  encode(message: RoleDistribution, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).uint32(message.playerCount);
+    writer.uint32(16).uint32(message.clanMembersCount);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): RoleDistribution {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseRoleDistribution } as RoleDistribution;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.playerCount = reader.uint32();
+          break;
+        case 2:
+          message.clanMembersCount = reader.uint32();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): RoleDistribution {
+    const message = { ...baseRoleDistribution } as RoleDistribution;
+    if (object.playerCount !== undefined && object.playerCount !== null) {
+      message.playerCount = Number(object.playerCount);
+    }
+    if (object.clanMembersCount !== undefined && object.clanMembersCount !== null) {
+      message.clanMembersCount = Number(object.clanMembersCount);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<RoleDistribution>): RoleDistribution {
+    const message = { ...baseRoleDistribution } as RoleDistribution;
+    if (object.playerCount !== undefined && object.playerCount !== null) {
+      message.playerCount = object.playerCount;
+    }
+    if (object.clanMembersCount !== undefined && object.clanMembersCount !== null) {
+      message.clanMembersCount = object.clanMembersCount;
+    }
+    return message;
+  },
+  toJSON(message: RoleDistribution): unknown {
+    const obj: any = {};
+    message.playerCount !== undefined && (obj.playerCount = message.playerCount);
+    message.clanMembersCount !== undefined && (obj.clanMembersCount = message.clanMembersCount);
+    return obj;
+  },
+};
+
+export const GameSettings = {
+  encode(message: GameSettings, writer: Writer = Writer.create()): Writer {
+    for (const v of message.roleDistributions) {
+      RoleDistribution.encode(v!, writer.uint32(10).fork()).ldelim();
+    }
+    writer.uint32(16).int32(message.clanLeaderKnowledge);
+    writer.uint32(24).bool(message.vetoEnabled);
+    writer.uint32(32).uint32(message.taskTimeoutSeconds);
+    writer.uint32(40).int32(message.chatAudience);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): GameSettings {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseGameSettings } as GameSettings;
+    message.roleDistributions = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.roleDistributions.push(RoleDistribution.decode(reader, reader.uint32()));
+          break;
+        case 2:
+          message.clanLeaderKnowledge = reader.int32() as any;
+          break;
+        case 3:
+          message.vetoEnabled = reader.bool();
+          break;
+        case 4:
+          message.taskTimeoutSeconds = reader.uint32();
+          break;
+        case 5:
+          message.chatAudience = reader.int32() as any;
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): GameSettings {
+    const message = { ...baseGameSettings } as GameSettings;
+    message.roleDistributions = [];
+    if (object.roleDistributions !== undefined && object.roleDistributions !== null) {
+      for (const e of object.roleDistributions) {
+        message.roleDistributions.push(RoleDistribution.fromJSON(e));
+      }
+    }
+    if (object.clanLeaderKnowledge !== undefined && object.clanLeaderKnowledge !== null) {
+      message.clanLeaderKnowledge = clanLeaderKnowledgeFromJSON(object.clanLeaderKnowledge);
+    }
+    if (object.vetoEnabled !== undefined && object.vetoEnabled !== null) {
+      message.vetoEnabled = Boolean(object.vetoEnabled);
+    }
+    if (object.taskTimeoutSeconds !== undefined && object.taskTimeoutSeconds !== null) {
+      message.taskTimeoutSeconds = Number(object.taskTimeoutSeconds);
+    }
+    if (object.chatAudience !== undefined && object.chatAudience !== null) {
+      message.chatAudience = chatAudienceFromJSON(object.chatAudience);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<GameSettings>): GameSettings {
+    const message = { ...baseGameSettings } as GameSettings;
+    message.roleDistributions = [];
+    if (object.roleDistributions !== undefined && object.roleDistributions !== null) {
+      for (const e of object.roleDistributions) {
+        message.roleDistributions.push(RoleDistribution.fromPartial(e));
+      }
+    }
+    if (object.clanLeaderKnowledge !== undefined && object.clanLeaderKnowledge !== null) {
+      message.clanLeaderKnowledge = object.clanLeaderKnowledge;
+    }
+    if (object.vetoEnabled !== undefined && object.vetoEnabled !== null) {
+      message.vetoEnabled = object.vetoEnabled;
+    }
+    if (object.taskTimeoutSeconds !== undefined && object.taskTimeoutSeconds !== null) {
+      message.taskTimeoutSeconds = object.taskTimeoutSeconds;
+    }
+    if (object.chatAudience !== undefined && object.chatAudience !== null) {
+      message.chatAudience = object.chatAudience;
+    }
+    return message;
+  },
+  toJSON(message: GameSettings): unknown {
+    const obj: any = {};
+    if (message.roleDistributions) {
+      obj.roleDistributions = message.roleDistributions.map(e => e ? RoleDistribution.toJSON(e) : undefined);
+    } else {
+      obj.roleDistributions = [];
+    }
+    message.clanLeaderKnowledge !== undefined && (obj.clanLeaderKnowledge = clanLeaderKnowledgeToJSON(message.clanLeaderKnowledge));
+    message.vetoEnabled !== undefined && (obj.vetoEnabled = message.vetoEnabled);
+    message.taskTimeoutSeconds !== undefined && (obj.taskTimeoutSeconds = message.taskTimeoutSeconds);
+    message.chatAudience !== undefined && (obj.chatAudience = chatAudienceToJSON(message.chatAudience));
+    return obj;
+  },
+};
+
+type Builtin = Date | Function | Uint8Array | string | number | undefined;
+export type DeepPartial<T> = T extends Builtin
+  ? T
+  : T extends Array<infer U>
+  ? Array<DeepPartial<U>>
+  : T extends ReadonlyArray<infer U>
+  ? ReadonlyArray<DeepPartial<U>>
+  : T extends { $case: string }
+  ? { [K in keyof Omit<T, '$case'>]?: DeepPartial<T[K]> } & { $case: T['$case'] }
+  : T extends {}
+  ? { [K in keyof T]?: DeepPartial<T[K]> }
+  : Partial<T>;
\ No newline at end of file
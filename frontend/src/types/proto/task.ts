@@ -1,20 +1,107 @@
 /* eslint-disable */
+import { Role, roleFromJSON, roleToJSON } from './role';
+import { Policy, policyFromJSON, policyToJSON } from './policy';
 import { Writer, Reader } from 'protobufjs/minimal';
 
 
 export interface Task {
-  definition?: { $case: 'settings', settings: Task_Settings };
+  definition?: { $case: 'settings', settings: Task_Settings } | { $case: 'discoverRole', discoverRole: Task_DiscoverRole } | { $case: 'nominateChancellor', nominateChancellor: Task_NominateChancellor } | { $case: 'vote', vote: Task_Vote } | { $case: 'discardPolicy', discardPolicy: Task_DiscardPolicy } | { $case: 'enactPolicy', enactPolicy: Task_EnactPolicy } | { $case: 'investigateLoyalty', investigateLoyalty: Task_InvestigateLoyalty } | { $case: 'callSpecialElection', callSpecialElection: Task_CallSpecialElection } | { $case: 'peekPolicies', peekPolicies: Task_PeekPolicies } | { $case: 'executePlayer', executePlayer: Task_ExecutePlayer } | { $case: 'gameSettings', gameSettings: Task_GameSettings } | { $case: 'answerVeto', answerVeto: Task_AnswerVeto };
+  deadline: string;
 }
 
 export interface Task_Settings {
 }
 
+export interface Task_GameSettings {
+}
+
+export interface Task_DiscoverRole {
+  role: Role;
+  clanMemberIds: string[];
+  clanLeaderId: string;
+}
+
+export interface Task_NominateChancellor {
+  eligiblePlayerIds: string[];
+}
+
+export interface Task_Vote {
+  presidentId: string;
+  chancellorId: string;
+}
+
+export interface Task_DiscardPolicy {
+  policies: Policy[];
+}
+
+export interface Task_EnactPolicy {
+  policies: Policy[];
+  vetoAllowed: boolean;
+}
+
+export interface Task_InvestigateLoyalty {
+  eligiblePlayerIds: string[];
+}
+
+export interface Task_CallSpecialElection {
+  eligiblePlayerIds: string[];
+}
+
+export interface Task_PeekPolicies {
+}
+
+export interface Task_ExecutePlayer {
+  eligiblePlayerIds: string[];
+}
+
+export interface Task_AnswerVeto {
+}
+
 const baseTask: object = {
+  deadline: "",
 };
 
 const baseTask_Settings: object = {
 };
 
+const baseTask_GameSettings: object = {
+};
+
+const baseTask_DiscoverRole: object = {
+  role: 0,
+  clanLeaderId: "",
+};
+
+const baseTask_NominateChancellor: object = {
+};
+
+const baseTask_Vote: object = {
+  presidentId: "",
+  chancellorId: "",
+};
+
+const baseTask_DiscardPolicy: object = {
+};
+
+const baseTask_EnactPolicy: object = {
+  vetoAllowed: false,
+};
+
+const baseTask_InvestigateLoyalty: object = {
+};
+
+const baseTask_CallSpecialElection: object = {
+};
+
+const baseTask_PeekPolicies: object = {
+};
+
+const baseTask_ExecutePlayer: object = {
+};
+
+const baseTask_AnswerVeto: object = {
+};
+
 export const protobufPackage = ''
 
 export const Task = {
@@ -22,6 +109,40 @@ export const Task = {
     if (message.definition?.$case === 'settings') {
       Task_Settings.encode(message.definition.settings, writer.uint32(10).fork()).ldelim();
     }
+    if (message.definition?.$case === 'discoverRole') {
+      Task_DiscoverRole.encode(message.definition.discoverRole, writer.uint32(18).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'nominateChancellor') {
+      Task_NominateChancellor.encode(message.definition.nominateChancellor, writer.uint32(26).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'vote') {
+      Task_Vote.encode(message.definition.vote, writer.uint32(34).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'discardPolicy') {
+      Task_DiscardPolicy.encode(message.definition.discardPolicy, writer.uint32(42).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'enactPolicy') {
+      Task_EnactPolicy.encode(message.definition.enactPolicy, writer.uint32(50).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'investigateLoyalty') {
+      Task_InvestigateLoyalty.encode(message.definition.investigateLoyalty, writer.uint32(58).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'callSpecialElection') {
+      Task_CallSpecialElection.encode(message.definition.callSpecialElection, writer.uint32(66).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'peekPolicies') {
+      Task_PeekPolicies.encode(message.definition.peekPolicies, writer.uint32(74).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'executePlayer') {
+      Task_ExecutePlayer.encode(message.definition.executePlayer, writer.uint32(82).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'gameSettings') {
+      Task_GameSettings.encode(message.definition.gameSettings, writer.uint32(90).fork()).ldelim();
+    }
+    if (message.definition?.$case === 'answerVeto') {
+      Task_AnswerVeto.encode(message.definition.answerVeto, writer.uint32(106).fork()).ldelim();
+    }
+    writer.uint32(98).string(message.deadline);
     return writer;
   },
   decode(input: Uint8Array | Reader, length?: number): Task {
@@ -34,6 +155,42 @@ export const Task = {
         case 1:
           message.definition = {$case: 'settings', settings: Task_Settings.decode(reader, reader.uint32())};
           break;
+        case 2:
+          message.definition = {$case: 'discoverRole', discoverRole: Task_DiscoverRole.decode(reader, reader.uint32())};
+          break;
+        case 3:
+          message.definition = {$case: 'nominateChancellor', nominateChancellor: Task_NominateChancellor.decode(reader, reader.uint32())};
+          break;
+        case 4:
+          message.definition = {$case: 'vote', vote: Task_Vote.decode(reader, reader.uint32())};
+          break;
+        case 5:
+          message.definition = {$case: 'discardPolicy', discardPolicy: Task_DiscardPolicy.decode(reader, reader.uint32())};
+          break;
+        case 6:
+          message.definition = {$case: 'enactPolicy', enactPolicy: Task_EnactPolicy.decode(reader, reader.uint32())};
+          break;
+        case 7:
+          message.definition = {$case: 'investigateLoyalty', investigateLoyalty: Task_InvestigateLoyalty.decode(reader, reader.uint32())};
+          break;
+        case 8:
+          message.definition = {$case: 'callSpecialElection', callSpecialElection: Task_CallSpecialElection.decode(reader, reader.uint32())};
+          break;
+        case 9:
+          message.definition = {$case: 'peekPolicies', peekPolicies: Task_PeekPolicies.decode(reader, reader.uint32())};
+          break;
+        case 10:
+          message.definition = {$case: 'executePlayer', executePlayer: Task_ExecutePlayer.decode(reader, reader.uint32())};
+          break;
+        case 11:
+          message.definition = {$case: 'gameSettings', gameSettings: Task_GameSettings.decode(reader, reader.uint32())};
+          break;
+        case 13:
+          message.definition = {$case: 'answerVeto', answerVeto: Task_AnswerVeto.decode(reader, reader.uint32())};
+          break;
+        case 12:
+          message.deadline = reader.string();
+          break;
         default:
           reader.skipType(tag & 7);
           break;
@@ -46,6 +203,42 @@ export const Task = {
     if (object.settings !== undefined && object.settings !== null) {
       message.definition = {$case: 'settings', settings: Task_Settings.fromJSON(object.settings)};
     }
+    if (object.discoverRole !== undefined && object.discoverRole !== null) {
+      message.definition = {$case: 'discoverRole', discoverRole: Task_DiscoverRole.fromJSON(object.discoverRole)};
+    }
+    if (object.nominateChancellor !== undefined && object.nominateChancellor !== null) {
+      message.definition = {$case: 'nominateChancellor', nominateChancellor: Task_NominateChancellor.fromJSON(object.nominateChancellor)};
+    }
+    if (object.vote !== undefined && object.vote !== null) {
+      message.definition = {$case: 'vote', vote: Task_Vote.fromJSON(object.vote)};
+    }
+    if (object.discardPolicy !== undefined && object.discardPolicy !== null) {
+      message.definition = {$case: 'discardPolicy', discardPolicy: Task_DiscardPolicy.fromJSON(object.discardPolicy)};
+    }
+    if (object.enactPolicy !== undefined && object.enactPolicy !== null) {
+      message.definition = {$case: 'enactPolicy', enactPolicy: Task_EnactPolicy.fromJSON(object.enactPolicy)};
+    }
+    if (object.investigateLoyalty !== undefined && object.investigateLoyalty !== null) {
+      message.definition = {$case: 'investigateLoyalty', investigateLoyalty: Task_InvestigateLoyalty.fromJSON(object.investigateLoyalty)};
+    }
+    if (object.callSpecialElection !== undefined && object.callSpecialElection !== null) {
+      message.definition = {$case: 'callSpecialElection', callSpecialElection: Task_CallSpecialElection.fromJSON(object.callSpecialElection)};
+    }
+    if (object.peekPolicies !== undefined && object.peekPolicies !== null) {
+      message.definition = {$case: 'peekPolicies', peekPolicies: Task_PeekPolicies.fromJSON(object.peekPolicies)};
+    }
+    if (object.executePlayer !== undefined && object.executePlayer !== null) {
+      message.definition = {$case: 'executePlayer', executePlayer: Task_ExecutePlayer.fromJSON(object.executePlayer)};
+    }
+    if (object.gameSettings !== undefined && object.gameSettings !== null) {
+      message.definition = {$case: 'gameSettings', gameSettings: Task_GameSettings.fromJSON(object.gameSettings)};
+    }
+    if (object.answerVeto !== undefined && object.answerVeto !== null) {
+      message.definition = {$case: 'answerVeto', answerVeto: Task_AnswerVeto.fromJSON(object.answerVeto)};
+    }
+    if (object.deadline !== undefined && object.deadline !== null) {
+      message.deadline = String(object.deadline);
+    }
     return message;
   },
   fromPartial(object: DeepPartial<Task>): Task {
@@ -53,11 +246,59 @@ export const Task = {
     if (object.definition?.$case === 'settings' && object.definition?.settings !== undefined && object.definition?.settings !== null) {
       message.definition = {$case: 'settings', settings: Task_Settings.fromPartial(object.definition.settings)};
     }
+    if (object.definition?.$case === 'discoverRole' && object.definition?.discoverRole !== undefined && object.definition?.discoverRole !== null) {
+      message.definition = {$case: 'discoverRole', discoverRole: Task_DiscoverRole.fromPartial(object.definition.discoverRole)};
+    }
+    if (object.definition?.$case === 'nominateChancellor' && object.definition?.nominateChancellor !== undefined && object.definition?.nominateChancellor !== null) {
+      message.definition = {$case: 'nominateChancellor', nominateChancellor: Task_NominateChancellor.fromPartial(object.definition.nominateChancellor)};
+    }
+    if (object.definition?.$case === 'vote' && object.definition?.vote !== undefined && object.definition?.vote !== null) {
+      message.definition = {$case: 'vote', vote: Task_Vote.fromPartial(object.definition.vote)};
+    }
+    if (object.definition?.$case === 'discardPolicy' && object.definition?.discardPolicy !== undefined && object.definition?.discardPolicy !== null) {
+      message.definition = {$case: 'discardPolicy', discardPolicy: Task_DiscardPolicy.fromPartial(object.definition.discardPolicy)};
+    }
+    if (object.definition?.$case === 'enactPolicy' && object.definition?.enactPolicy !== undefined && object.definition?.enactPolicy !== null) {
+      message.definition = {$case: 'enactPolicy', enactPolicy: Task_EnactPolicy.fromPartial(object.definition.enactPolicy)};
+    }
+    if (object.definition?.$case === 'investigateLoyalty' && object.definition?.investigateLoyalty !== undefined && object.definition?.investigateLoyalty !== null) {
+      message.definition = {$case: 'investigateLoyalty', investigateLoyalty: Task_InvestigateLoyalty.fromPartial(object.definition.investigateLoyalty)};
+    }
+    if (object.definition?.$case === 'callSpecialElection' && object.definition?.callSpecialElection !== undefined && object.definition?.callSpecialElection !== null) {
+      message.definition = {$case: 'callSpecialElection', callSpecialElection: Task_CallSpecialElection.fromPartial(object.definition.callSpecialElection)};
+    }
+    if (object.definition?.$case === 'peekPolicies' && object.definition?.peekPolicies !== undefined && object.definition?.peekPolicies !== null) {
+      message.definition = {$case: 'peekPolicies', peekPolicies: Task_PeekPolicies.fromPartial(object.definition.peekPolicies)};
+    }
+    if (object.definition?.$case === 'executePlayer' && object.definition?.executePlayer !== undefined && object.definition?.executePlayer !== null) {
+      message.definition = {$case: 'executePlayer', executePlayer: Task_ExecutePlayer.fromPartial(object.definition.executePlayer)};
+    }
+    if (object.definition?.$case === 'gameSettings' && object.definition?.gameSettings !== undefined && object.definition?.gameSettings !== null) {
+      message.definition = {$case: 'gameSettings', gameSettings: Task_GameSettings.fromPartial(object.definition.gameSettings)};
+    }
+    if (object.definition?.$case === 'answerVeto' && object.definition?.answerVeto !== undefined && object.definition?.answerVeto !== null) {
+      message.definition = {$case: 'answerVeto', answerVeto: Task_AnswerVeto.fromPartial(object.definition.answerVeto)};
+    }
+    if (object.deadline !== undefined && object.deadline !== null) {
+      message.deadline = object.deadline;
+    }
     return message;
   },
   toJSON(message: Task): unknown {
     const obj: any = {};
     message.definition?.$case === 'settings' && (obj.settings = message.definition?.settings ? Task_Settings.toJSON(message.definition?.settings) : undefined);
+    message.definition?.$case === 'discoverRole' && (obj.discoverRole = message.definition?.discoverRole ? Task_DiscoverRole.toJSON(message.definition?.discoverRole) : undefined);
+    message.definition?.$case === 'nominateChancellor' && (obj.nominateChancellor = message.definition?.nominateChancellor ? Task_NominateChancellor.toJSON(message.definition?.nominateChancellor) : undefined);
+    message.definition?.$case === 'vote' && (obj.vote = message.definition?.vote ? Task_Vote.toJSON(message.definition?.vote) : undefined);
+    message.definition?.$case === 'discardPolicy' && (obj.discardPolicy = message.definition?.discardPolicy ? Task_DiscardPolicy.toJSON(message.definition?.discardPolicy) : undefined);
+    message.definition?.$case === 'enactPolicy' && (obj.enactPolicy = message.definition?.enactPolicy ? Task_EnactPolicy.toJSON(message.definition?.enactPolicy) : undefined);
+    message.definition?.$case === 'investigateLoyalty' && (obj.investigateLoyalty = message.definition?.investigateLoyalty ? Task_InvestigateLoyalty.toJSON(message.definition?.investigateLoyalty) : undefined);
+    message.definition?.$case === 'callSpecialElection' && (obj.callSpecialElection = message.definition?.callSpecialElection ? Task_CallSpecialElection.toJSON(message.definition?.callSpecialElection) : undefined);
+    message.definition?.$case === 'peekPolicies' && (obj.peekPolicies = message.definition?.peekPolicies ? Task_PeekPolicies.toJSON(message.definition?.peekPolicies) : undefined);
+    message.definition?.$case === 'executePlayer' && (obj.executePlayer = message.definition?.executePlayer ? Task_ExecutePlayer.toJSON(message.definition?.executePlayer) : undefined);
+    message.definition?.$case === 'gameSettings' && (obj.gameSettings = message.definition?.gameSettings ? Task_GameSettings.toJSON(message.definition?.gameSettings) : undefined);
+    message.definition?.$case === 'answerVeto' && (obj.answerVeto = message.definition?.answerVeto ? Task_AnswerVeto.toJSON(message.definition?.answerVeto) : undefined);
+    message.deadline !== undefined && (obj.deadline = message.deadline);
     return obj;
   },
 };
@@ -94,6 +335,599 @@ export const Task_Settings = {
   },
 };
 
+export const Task_GameSettings = {
+  encode(_: Task_GameSettings, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_GameSettings {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_GameSettings } as Task_GameSettings;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Task_GameSettings {
+    const message = { ...baseTask_GameSettings } as Task_GameSettings;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Task_GameSettings>): Task_GameSettings {
+    const message = { ...baseTask_GameSettings } as Task_GameSettings;
+    return message;
+  },
+  toJSON(_: Task_GameSettings): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const Task_DiscoverRole = {
+  encode(message: Task_DiscoverRole, writer: Writer = Writer.create()): Writer {
+    writer.uint32(8).int32(message.role);
+    for (const v of message.clanMemberIds) {
+      writer.uint32(18).string(v!);
+    }
+    writer.uint32(26).string(message.clanLeaderId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_DiscoverRole {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_DiscoverRole } as Task_DiscoverRole;
+    message.clanMemberIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.role = reader.int32() as any;
+          break;
+        case 2:
+          message.clanMemberIds.push(reader.string());
+          break;
+        case 3:
+          message.clanLeaderId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_DiscoverRole {
+    const message = { ...baseTask_DiscoverRole } as Task_DiscoverRole;
+    message.clanMemberIds = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = roleFromJSON(object.role);
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(String(e));
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = String(object.clanLeaderId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_DiscoverRole>): Task_DiscoverRole {
+    const message = { ...baseTask_DiscoverRole } as Task_DiscoverRole;
+    message.clanMemberIds = [];
+    if (object.role !== undefined && object.role !== null) {
+      message.role = object.role;
+    }
+    if (object.clanMemberIds !== undefined && object.clanMemberIds !== null) {
+      for (const e of object.clanMemberIds) {
+        message.clanMemberIds.push(e);
+      }
+    }
+    if (object.clanLeaderId !== undefined && object.clanLeaderId !== null) {
+      message.clanLeaderId = object.clanLeaderId;
+    }
+    return message;
+  },
+  toJSON(message: Task_DiscoverRole): unknown {
+    const obj: any = {};
+    message.role !== undefined && (obj.role = roleToJSON(message.role));
+    if (message.clanMemberIds) {
+      obj.clanMemberIds = message.clanMemberIds.map(e => e);
+    } else {
+      obj.clanMemberIds = [];
+    }
+    message.clanLeaderId !== undefined && (obj.clanLeaderId = message.clanLeaderId);
+    return obj;
+  },
+};
+
+export const Task_NominateChancellor = {
+  encode(message: Task_NominateChancellor, writer: Writer = Writer.create()): Writer {
+    for (const v of message.eligiblePlayerIds) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_NominateChancellor {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_NominateChancellor } as Task_NominateChancellor;
+    message.eligiblePlayerIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.eligiblePlayerIds.push(reader.string());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_NominateChancellor {
+    const message = { ...baseTask_NominateChancellor } as Task_NominateChancellor;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(String(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_NominateChancellor>): Task_NominateChancellor {
+    const message = { ...baseTask_NominateChancellor } as Task_NominateChancellor;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Task_NominateChancellor): unknown {
+    const obj: any = {};
+    if (message.eligiblePlayerIds) {
+      obj.eligiblePlayerIds = message.eligiblePlayerIds.map(e => e);
+    } else {
+      obj.eligiblePlayerIds = [];
+    }
+    return obj;
+  },
+};
+
+export const Task_Vote = {
+  encode(message: Task_Vote, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).string(message.presidentId);
+    writer.uint32(18).string(message.chancellorId);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_Vote {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_Vote } as Task_Vote;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.presidentId = reader.string();
+          break;
+        case 2:
+          message.chancellorId = reader.string();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_Vote {
+    const message = { ...baseTask_Vote } as Task_Vote;
+    if (object.presidentId !== undefined && object.presidentId !== null) {
+      message.presidentId = String(object.presidentId);
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = String(object.chancellorId);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_Vote>): Task_Vote {
+    const message = { ...baseTask_Vote } as Task_Vote;
+    if (object.presidentId !== undefined && object.presidentId !== null) {
+      message.presidentId = object.presidentId;
+    }
+    if (object.chancellorId !== undefined && object.chancellorId !== null) {
+      message.chancellorId = object.chancellorId;
+    }
+    return message;
+  },
+  toJSON(message: Task_Vote): unknown {
+    const obj: any = {};
+    message.presidentId !== undefined && (obj.presidentId = message.presidentId);
+    message.chancellorId !== undefined && (obj.chancellorId = message.chancellorId);
+    return obj;
+  },
+};
+
+export const Task_DiscardPolicy = {
+  encode(message: Task_DiscardPolicy, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).fork();
+    for (const v of message.policies) {
+      writer.int32(v);
+    }
+    writer.ldelim();
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_DiscardPolicy {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_DiscardPolicy } as Task_DiscardPolicy;
+    message.policies = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if ((tag & 7) === 2) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.policies.push(reader.int32() as any);
+            }
+          } else {
+            message.policies.push(reader.int32() as any);
+          }
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_DiscardPolicy {
+    const message = { ...baseTask_DiscardPolicy } as Task_DiscardPolicy;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(policyFromJSON(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_DiscardPolicy>): Task_DiscardPolicy {
+    const message = { ...baseTask_DiscardPolicy } as Task_DiscardPolicy;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Task_DiscardPolicy): unknown {
+    const obj: any = {};
+    if (message.policies) {
+      obj.policies = message.policies.map(e => policyToJSON(e));
+    } else {
+      obj.policies = [];
+    }
+    return obj;
+  },
+};
+
+export const Task_EnactPolicy = {
+  encode(message: Task_EnactPolicy, writer: Writer = Writer.create()): Writer {
+    writer.uint32(10).fork();
+    for (const v of message.policies) {
+      writer.int32(v);
+    }
+    writer.ldelim();
+    writer.uint32(16).bool(message.vetoAllowed);
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_EnactPolicy {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_EnactPolicy } as Task_EnactPolicy;
+    message.policies = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if ((tag & 7) === 2) {
+            const end2 = reader.uint32() + reader.pos;
+            while (reader.pos < end2) {
+              message.policies.push(reader.int32() as any);
+            }
+          } else {
+            message.policies.push(reader.int32() as any);
+          }
+          break;
+        case 2:
+          message.vetoAllowed = reader.bool();
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_EnactPolicy {
+    const message = { ...baseTask_EnactPolicy } as Task_EnactPolicy;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(policyFromJSON(e));
+      }
+    }
+    if (object.vetoAllowed !== undefined && object.vetoAllowed !== null) {
+      message.vetoAllowed = Boolean(object.vetoAllowed);
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_EnactPolicy>): Task_EnactPolicy {
+    const message = { ...baseTask_EnactPolicy } as Task_EnactPolicy;
+    message.policies = [];
+    if (object.policies !== undefined && object.policies !== null) {
+      for (const e of object.policies) {
+        message.policies.push(e);
+      }
+    }
+    if (object.vetoAllowed !== undefined && object.vetoAllowed !== null) {
+      message.vetoAllowed = object.vetoAllowed;
+    }
+    return message;
+  },
+  toJSON(message: Task_EnactPolicy): unknown {
+    const obj: any = {};
+    if (message.policies) {
+      obj.policies = message.policies.map(e => policyToJSON(e));
+    } else {
+      obj.policies = [];
+    }
+    message.vetoAllowed !== undefined && (obj.vetoAllowed = message.vetoAllowed);
+    return obj;
+  },
+};
+
+export const Task_InvestigateLoyalty = {
+  encode(message: Task_InvestigateLoyalty, writer: Writer = Writer.create()): Writer {
+    for (const v of message.eligiblePlayerIds) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_InvestigateLoyalty {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_InvestigateLoyalty } as Task_InvestigateLoyalty;
+    message.eligiblePlayerIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.eligiblePlayerIds.push(reader.string());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_InvestigateLoyalty {
+    const message = { ...baseTask_InvestigateLoyalty } as Task_InvestigateLoyalty;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(String(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_InvestigateLoyalty>): Task_InvestigateLoyalty {
+    const message = { ...baseTask_InvestigateLoyalty } as Task_InvestigateLoyalty;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Task_InvestigateLoyalty): unknown {
+    const obj: any = {};
+    if (message.eligiblePlayerIds) {
+      obj.eligiblePlayerIds = message.eligiblePlayerIds.map(e => e);
+    } else {
+      obj.eligiblePlayerIds = [];
+    }
+    return obj;
+  },
+};
+
+export const Task_CallSpecialElection = {
+  encode(message: Task_CallSpecialElection, writer: Writer = Writer.create()): Writer {
+    for (const v of message.eligiblePlayerIds) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_CallSpecialElection {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_CallSpecialElection } as Task_CallSpecialElection;
+    message.eligiblePlayerIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.eligiblePlayerIds.push(reader.string());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_CallSpecialElection {
+    const message = { ...baseTask_CallSpecialElection } as Task_CallSpecialElection;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(String(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_CallSpecialElection>): Task_CallSpecialElection {
+    const message = { ...baseTask_CallSpecialElection } as Task_CallSpecialElection;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Task_CallSpecialElection): unknown {
+    const obj: any = {};
+    if (message.eligiblePlayerIds) {
+      obj.eligiblePlayerIds = message.eligiblePlayerIds.map(e => e);
+    } else {
+      obj.eligiblePlayerIds = [];
+    }
+    return obj;
+  },
+};
+
+export const Task_PeekPolicies = {
+  encode(_: Task_PeekPolicies, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_PeekPolicies {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_PeekPolicies } as Task_PeekPolicies;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Task_PeekPolicies {
+    const message = { ...baseTask_PeekPolicies } as Task_PeekPolicies;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Task_PeekPolicies>): Task_PeekPolicies {
+    const message = { ...baseTask_PeekPolicies } as Task_PeekPolicies;
+    return message;
+  },
+  toJSON(_: Task_PeekPolicies): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
+export const Task_ExecutePlayer = {
+  encode(message: Task_ExecutePlayer, writer: Writer = Writer.create()): Writer {
+    for (const v of message.eligiblePlayerIds) {
+      writer.uint32(10).string(v!);
+    }
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_ExecutePlayer {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_ExecutePlayer } as Task_ExecutePlayer;
+    message.eligiblePlayerIds = [];
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          message.eligiblePlayerIds.push(reader.string());
+          break;
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(object: any): Task_ExecutePlayer {
+    const message = { ...baseTask_ExecutePlayer } as Task_ExecutePlayer;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(String(e));
+      }
+    }
+    return message;
+  },
+  fromPartial(object: DeepPartial<Task_ExecutePlayer>): Task_ExecutePlayer {
+    const message = { ...baseTask_ExecutePlayer } as Task_ExecutePlayer;
+    message.eligiblePlayerIds = [];
+    if (object.eligiblePlayerIds !== undefined && object.eligiblePlayerIds !== null) {
+      for (const e of object.eligiblePlayerIds) {
+        message.eligiblePlayerIds.push(e);
+      }
+    }
+    return message;
+  },
+  toJSON(message: Task_ExecutePlayer): unknown {
+    const obj: any = {};
+    if (message.eligiblePlayerIds) {
+      obj.eligiblePlayerIds = message.eligiblePlayerIds.map(e => e);
+    } else {
+      obj.eligiblePlayerIds = [];
+    }
+    return obj;
+  },
+};
+
+export const Task_AnswerVeto = {
+  encode(_: Task_AnswerVeto, writer: Writer = Writer.create()): Writer {
+    return writer;
+  },
+  decode(input: Uint8Array | Reader, length?: number): Task_AnswerVeto {
+    const reader = input instanceof Uint8Array ? new Reader(input) : input;
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = { ...baseTask_AnswerVeto } as Task_AnswerVeto;
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        default:
+          reader.skipType(tag & 7);
+          break;
+      }
+    }
+    return message;
+  },
+  fromJSON(_: any): Task_AnswerVeto {
+    const message = { ...baseTask_AnswerVeto } as Task_AnswerVeto;
+    return message;
+  },
+  fromPartial(_: DeepPartial<Task_AnswerVeto>): Task_AnswerVeto {
+    const message = { ...baseTask_AnswerVeto } as Task_AnswerVeto;
+    return message;
+  },
+  toJSON(_: Task_AnswerVeto): unknown {
+    const obj: any = {};
+    return obj;
+  },
+};
+
 type Builtin = Date | Function | Uint8Array | string | number | undefined;
 export type DeepPartial<T> = T extends Builtin
   ? T